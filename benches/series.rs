@@ -0,0 +1,50 @@
+//! Series evaluation benchmarks.
+
+#[macro_use]
+extern crate criterion;
+extern crate rand;
+extern crate vsop87;
+
+use criterion::Criterion;
+use rand::{thread_rng, Rng};
+use vsop87::{calculate_t, calculate_var, calculate_var_const};
+
+/// Number of terms of the benchmarked series, the size of the largest *VSOP87D* Mars series.
+const TERMS: usize = 1217;
+
+/// Creates a random series, with amplitudes, phases and frequencies in the ranges of *VSOP87*.
+fn random_series() -> [[f64; TERMS]; 3] {
+    let mut rng = thread_rng();
+    let mut series = [[0_f64; TERMS]; 3];
+    for (row, &(min, max)) in series
+        .iter_mut()
+        .zip(&[(1e-11, 1e-2), (0.0, 6.3), (0.0, 1e5)])
+    {
+        for value in row.iter_mut() {
+            *value = rng.gen_range(min, max);
+        }
+    }
+    series
+}
+
+fn series_slice(c: &mut Criterion) {
+    let series = random_series();
+    let mut rng = thread_rng();
+    c.bench_function("Series (slices)", move |b| {
+        b.iter(|| {
+            let t = calculate_t(rng.gen_range(990930.5, 3912521.5));
+            calculate_var(t, &series[0], &series[1], &series[2])
+        })
+    });
+}
+
+fn series_const(c: &mut Criterion) {
+    let series = random_series();
+    let mut rng = thread_rng();
+    c.bench_function("Series (const generic)", move |b| {
+        b.iter(|| calculate_var_const(calculate_t(rng.gen_range(990930.5, 3912521.5)), &series))
+    });
+}
+
+criterion_group!(series_benches, series_slice, series_const);
+criterion_main!(series_benches);
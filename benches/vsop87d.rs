@@ -7,7 +7,7 @@ extern crate vsop87;
 
 use criterion::Criterion;
 use rand::{thread_rng, Rng};
-use vsop87::vsop87d;
+use vsop87::{calculate_t, calculate_var, vsop87d, Planet, Series, SphericalCoordinates};
 
 fn vsop87d_mercury(c: &mut Criterion) {
     let mut rng = thread_rng();
@@ -65,6 +65,54 @@ fn vsop87d_neptune(c: &mut Criterion) {
     });
 }
 
+fn vsop87d_earth_loop(c: &mut Criterion) {
+    let mut rng = thread_rng();
+    let jdes: Vec<f64> = (0..1_000)
+        .map(|_| rng.gen_range(990930.5, 3912521.5))
+        .collect();
+    c.bench_function("VSOP87d Earth loop (1000 dates)", move |b| {
+        b.iter(|| {
+            jdes.iter()
+                .map(|&jde| vsop87d::earth(jde))
+                .collect::<Vec<_>>()
+        })
+    });
+}
+
+fn vsop87d_earth_many(c: &mut Criterion) {
+    let mut rng = thread_rng();
+    let jdes: Vec<f64> = (0..1_000)
+        .map(|_| rng.gen_range(990930.5, 3912521.5))
+        .collect();
+    let mut output = vec![SphericalCoordinates::new(0.0, 0.0, 0.0); jdes.len()];
+    c.bench_function("VSOP87d Earth batch (1000 dates)", move |b| {
+        b.iter(|| vsop87d::planet_into(Planet::Earth, &jdes, &mut output))
+    });
+}
+
+/// Calculates a variable raising `t` to the power of each series, instead of with Horner's method.
+fn polynomial_with_powers(t: f64, series: &[Series<'_>]) -> f64 {
+    series
+        .iter()
+        .enumerate()
+        .map(|(power, [a, b, c])| calculate_var(t, a, b, c) * t.powi(power as i32))
+        .sum()
+}
+
+fn vsop87d_earth_powers(c: &mut Criterion) {
+    let mut rng = thread_rng();
+    c.bench_function("VSOP87d Earth (powers of t per coordinate)", move |b| {
+        b.iter(|| {
+            let t = calculate_t(rng.gen_range(990930.5, 3912521.5));
+            (
+                polynomial_with_powers(t, vsop87d::EARTH.longitude),
+                polynomial_with_powers(t, vsop87d::EARTH.latitude),
+                polynomial_with_powers(t, vsop87d::EARTH.distance),
+            )
+        })
+    });
+}
+
 criterion_group!(
     vsop87d_benches,
     vsop87d_mercury,
@@ -74,6 +122,9 @@ criterion_group!(
     vsop87d_jupiter,
     vsop87d_saturn,
     vsop87d_uranus,
-    vsop87d_neptune
+    vsop87d_neptune,
+    vsop87d_earth_loop,
+    vsop87d_earth_many,
+    vsop87d_earth_powers
 );
 criterion_main!(vsop87d_benches);
@@ -0,0 +1,148 @@
+//! Configurable pipeline of geocentric corrections.
+
+use super::{
+    delta_t, geocentric, mean_obliquity, normalize_angle, nutation, topocentric, Planet,
+    SphericalCoordinates,
+};
+
+/// Builder of the geocentric coordinates of a planet, with a chosen set of corrections.
+///
+/// The [`geocentric`](geocentric/index.html) and [`topocentric`](topocentric/index.html) modules
+/// have one function per usual combination of corrections. This builder lets the caller opt into
+/// each of them, and applies the chosen ones in the canonical order:
+///
+/// 1. The [light-time](geocentric/fn.apparent_planet.html): the planet is computed at the time the
+///    light left it.
+/// 2. The annual [aberration](geocentric/fn.aberration.html).
+/// 3. The nutation in longitude, *Δψ*, which refers the coordinates to the true equinox of the day.
+/// 4. The [topocentric parallax](topocentric/fn.planet.html), for an observer on the surface of the
+///    Earth. The position of the observer is rotated to the ecliptic with the true obliquity if
+///    the nutation is applied, and with the mean obliquity otherwise.
+///
+/// With no options, the result is the geometric geocentric position, the same as
+/// [`geocentric::planet()`](geocentric/fn.planet.html). The coordinates are ecliptic spherical
+/// coordinates for the equinox of the day, with the distance in *AU*. Only available with the
+/// `earth` feature.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{geocentric, EphemerisBuilder, Planet};
+///
+/// let jde = 2452880.0;
+/// let apparent = EphemerisBuilder::new(Planet::Mars).apparent().compute(jde);
+/// assert_eq!(apparent, geocentric::apparent(Planet::Mars, jde));
+///
+/// // Mars seen from Palomar Observatory.
+/// let topocentric = EphemerisBuilder::new(Planet::Mars)
+///     .apparent()
+///     .topocentric(0.5822, -2.0396, 1706.0)
+///     .compute(jde);
+/// assert!((topocentric.distance() - apparent.distance()).abs() < 5e-5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EphemerisBuilder {
+    planet: Planet,
+    light_time: bool,
+    aberration: bool,
+    nutation: bool,
+    observer: Option<(f64, f64, f64)>,
+}
+
+impl EphemerisBuilder {
+    /// Creates a builder for the geometric geocentric coordinates of the given planet.
+    pub fn new(planet: Planet) -> Self {
+        Self {
+            planet,
+            light_time: false,
+            aberration: false,
+            nutation: false,
+            observer: None,
+        }
+    }
+
+    /// Corrects the position for the time the light takes to reach the Earth.
+    #[must_use]
+    pub fn light_time(mut self) -> Self {
+        self.light_time = true;
+        self
+    }
+
+    /// Applies the annual aberration.
+    #[must_use]
+    pub fn aberration(mut self) -> Self {
+        self.aberration = true;
+        self
+    }
+
+    /// Applies the nutation in longitude, to refer the coordinates to the true equinox of the day.
+    #[must_use]
+    pub fn nutation(mut self) -> Self {
+        self.nutation = true;
+        self
+    }
+
+    /// Applies all the corrections of the apparent geocentric position: the light-time, the
+    /// aberration and the nutation, as [`geocentric::apparent()`](geocentric/fn.apparent.html).
+    #[must_use]
+    pub fn apparent(self) -> Self {
+        self.light_time().aberration().nutation()
+    }
+
+    /// Computes the position as seen by an observer on the surface of the Earth.
+    ///
+    /// The parameters are the geographic latitude and longitude of the observer, in radians, with
+    /// the longitude positive east of Greenwich, and its elevation above sea level, in meters. The
+    /// local sidereal time is computed from the Universal Time of the date, using
+    /// [`delta_t()`](fn.delta_t.html).
+    #[must_use]
+    pub fn topocentric(mut self, lat: f64, lon: f64, elevation_m: f64) -> Self {
+        self.observer = Some((lat, lon, elevation_m));
+        self
+    }
+
+    /// Computes the coordinates of the planet at the given Julian Day (*JDE*), with the chosen
+    /// corrections.
+    pub fn compute(&self, jde: f64) -> SphericalCoordinates {
+        let coordinates = if self.light_time {
+            geocentric::apparent_planet(self.planet, jde)
+        } else {
+            geocentric::planet(self.planet, jde)
+        };
+
+        let coordinates = if self.aberration {
+            geocentric::aberration(jde, &coordinates)
+        } else {
+            coordinates
+        };
+
+        let (delta_psi, delta_epsilon) = if self.nutation {
+            nutation::nutation(jde)
+        } else {
+            (0_f64, 0_f64)
+        };
+        let coordinates = if self.nutation {
+            SphericalCoordinates::new(
+                normalize_angle(coordinates.longitude() + delta_psi),
+                coordinates.latitude(),
+                coordinates.distance(),
+            )
+        } else {
+            coordinates
+        };
+
+        match self.observer {
+            Some((lat, lon, elevation_m)) => {
+                let year = 2_000_f64 + (jde - 2_451_545_f64) / 365.25;
+                let jd_ut = jde - delta_t(year) / 86_400_f64;
+                let sidereal_time = topocentric::local_mean_sidereal_time_from_ut(jd_ut, lon);
+
+                let observer = topocentric::observer_position(lat, elevation_m, sidereal_time)
+                    .to_equatorial_frame(-(mean_obliquity(jde) + delta_epsilon));
+
+                (coordinates.to_rectangular() - observer).to_spherical()
+            }
+            None => coordinates,
+        }
+    }
+}
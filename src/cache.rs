@@ -0,0 +1,144 @@
+//! Memoization of the positions of the planets.
+
+use super::{Planet, Vsop87D, Vsop87Variant};
+
+use std::{collections::VecDeque, marker::PhantomData};
+
+/// Cache of the positions of the planets, for dates rounded to a fixed step.
+///
+/// Applications often need the same planet at nearly the same time from several places, for
+/// example once per frame from different subsystems. The cache rounds every Julian Day to the
+/// nearest multiple of its step, and computes the position at that rounded date only once: other
+/// queries rounded to the same date get the stored result. The least recently used positions are
+/// dropped when the cache is full.
+///
+/// The solution is chosen with the `V` type parameter, with the
+/// [`Vsop87Variant`](trait.Vsop87Variant.html) marker types, and is
+/// [`Vsop87D`](struct.Vsop87D.html) by default. Only available with the standard library.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87d, Planet, PositionCache};
+///
+/// // One minute steps, keeping up to 16 positions.
+/// let mut cache: PositionCache = PositionCache::new(1.0 / 1440.0, 16);
+///
+/// let first = cache.get(Planet::Mars, 2451545.0);
+/// let second = cache.get(Planet::Mars, 2451545.0 + 1e-5);
+///
+/// assert_eq!(first, second);
+/// assert_eq!(first, vsop87d::mars(2451545.0));
+/// assert_eq!((cache.hits(), cache.misses()), (1, 1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PositionCache<V: Vsop87Variant = Vsop87D> {
+    step_days: f64,
+    capacity: usize,
+    entries: VecDeque<(Planet, i64, V::Output)>,
+    hits: u64,
+    misses: u64,
+    variant: PhantomData<V>,
+}
+
+impl<V> PositionCache<V>
+where
+    V: Vsop87Variant,
+    V::Output: Copy,
+{
+    /// Creates an empty cache, with the given step in days and the maximum number of positions
+    /// to keep.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the step is not a positive, finite number.
+    pub fn new(step_days: f64, capacity: usize) -> Self {
+        assert!(
+            step_days > 0_f64 && step_days.is_finite(),
+            "the step of the cache must be positive and finite"
+        );
+
+        Self {
+            step_days,
+            capacity,
+            entries: VecDeque::with_capacity(capacity),
+            hits: 0,
+            misses: 0,
+            variant: PhantomData,
+        }
+    }
+
+    /// Gets the position of the planet at the given Julian Day (*JD*), rounded to the step of the
+    /// cache.
+    ///
+    /// The result is the position at the rounded date, even when it is computed for this query,
+    /// so that it does not depend on the order of the queries. A date that is not a finite number,
+    /// or too far away to be rounded to the step, is never cached: its position is computed
+    /// directly, with the date as given.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    pub fn get(&mut self, planet: Planet, jde: f64) -> V::Output {
+        // Beyond 2⁵³ steps, the rounded dates are no longer exact multiples of the step.
+        let steps = (jde / self.step_days).round();
+        if !steps.is_finite() || steps.abs() > 9_007_199_254_740_992_f64 {
+            self.misses += 1;
+            return V::compute(planet, jde);
+        }
+        let key = steps as i64;
+
+        let cached = self
+            .entries
+            .iter()
+            .position(|&(cached, cached_key, _)| cached == planet && cached_key == key)
+            .and_then(|index| self.entries.remove(index));
+        if let Some(entry) = cached {
+            self.hits += 1;
+
+            // Moves the entry to the back, as the most recently used.
+            self.entries.push_back(entry);
+            return entry.2;
+        }
+
+        self.misses += 1;
+        let position = V::compute(planet, key as f64 * self.step_days);
+        if self.capacity > 0 {
+            if self.entries.len() == self.capacity {
+                let _ = self.entries.pop_front();
+            }
+            self.entries.push_back((planet, key, position));
+        }
+
+        position
+    }
+
+    /// Removes every position from the cache, and resets the counters.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+
+    /// Gets the step of the cache, in days.
+    pub fn step_days(&self) -> f64 {
+        self.step_days
+    }
+
+    /// Gets the number of positions stored in the cache.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Checks whether the cache has no positions.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Gets the number of queries that reused a stored position.
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Gets the number of queries that computed a new position.
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+}
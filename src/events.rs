@@ -0,0 +1,221 @@
+//! Oppositions, conjunctions and stations of the planets.
+//!
+//! A planet is at opposition when its apparent geocentric ecliptic longitude differs from that of
+//! the Sun by 180°, and in conjunction when both longitudes are equal. At a station, its apparent
+//! motion in longitude reverses. The functions in this module search forward in time for the next
+//! of these events, stepping through the dates until the relevant quantity changes its sign, and
+//! then refining the date by bisection. Only available with the `earth` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use vsop87::{events, Planet};
+//!
+//! let opposition = events::next_opposition(Planet::Jupiter, 2451545.0).unwrap();
+//!
+//! // Jupiter was at opposition on November 28th, 2000.
+//! assert!((opposition - 2451877.0).abs() < 1.0);
+//! ```
+
+use super::{geocentric, normalize_angle_signed, Planet};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "no_std")]
+use core::f64::consts::{FRAC_PI_2, PI};
+
+#[cfg(not(feature = "no_std"))]
+use std::f64::consts::{FRAC_PI_2, PI};
+
+/// Maximum number of days to search, longer than the synodic period of every planet.
+const MAX_SEARCH_DAYS: f64 = 1_000_f64;
+
+/// Step of the coarse search, in days.
+const STEP_DAYS: f64 = 2_f64;
+
+/// Precision of the refined dates, in days.
+const PRECISION_DAYS: f64 = 1e-6;
+
+/// Half of the interval used to compute the motion of a planet in longitude, in days.
+const STATION_STEP_DAYS: f64 = 0.5;
+
+/// Finds the next opposition of a planet.
+///
+/// This function searches forward from the given Julian Day (*JD*) for the first time the
+/// apparent geocentric ecliptic longitude of the planet equals the longitude of the Sun plus 180°,
+/// and returns the Julian Day of the opposition, with a precision of about 0.1 seconds.
+///
+/// The search covers 1,000 days, which is longer than the synodic period of every planet, so a
+/// superior planet always has an opposition in that span. Mercury and Venus, whose orbits are
+/// inside the orbit of the Earth, are never at opposition, and the function returns `None` for
+/// them, as well as for the Earth.
+///
+/// # Example
+///
+/// The opposition of Mars of August 28th, 2003, close to its nearest approach to the Earth in
+/// almost 60,000 years:
+///
+/// ```
+/// use vsop87::{events, Planet};
+///
+/// let opposition = events::next_opposition(Planet::Mars, 2452700.5).unwrap();
+/// assert!((opposition - 2452880.25).abs() < 0.5);
+///
+/// assert_eq!(events::next_opposition(Planet::Venus, 2452700.5), None);
+/// ```
+pub fn next_opposition(planet: Planet, start_jde: f64) -> Option<f64> {
+    find_event(planet, start_jde, PI)
+}
+
+/// Finds the next conjunction of a planet with the Sun.
+///
+/// This function searches forward from the given Julian Day (*JD*) for the first time the
+/// apparent geocentric ecliptic longitude of the planet equals the longitude of the Sun, and
+/// returns the Julian Day of the conjunction, with a precision of about 0.1 seconds. For Mercury
+/// and Venus, it can be either an inferior conjunction, with the planet between the Earth and the
+/// Sun, or a superior conjunction, with the planet behind the Sun.
+///
+/// The search covers 1,000 days, which is longer than the synodic period of every planet, so the
+/// function only returns `None` for the Earth.
+///
+/// # Example
+///
+/// The inferior conjunction of Venus of June 8th, 2004, when it transited the disk of the Sun:
+///
+/// ```
+/// use vsop87::{events, Planet};
+///
+/// let conjunction = events::next_conjunction(Planet::Venus, 2453100.5).unwrap();
+///
+/// assert!((conjunction - 2453164.86).abs() < 0.5);
+/// ```
+pub fn next_conjunction(planet: Planet, start_jde: f64) -> Option<f64> {
+    find_event(planet, start_jde, 0_f64)
+}
+
+/// Finds the next station of a planet.
+///
+/// A station is a date when the apparent motion of the planet in longitude reverses, as seen from
+/// the Earth. Between a [retrograde](enum.Station.html#variant.Retrograde) station and the next
+/// [direct](enum.Station.html#variant.Direct) one, the planet moves westward among the stars. This
+/// function searches forward from the given Julian Day (*JD*) for the first date when the rate of
+/// change of the apparent geocentric ecliptic longitude, computed by finite differences, crosses
+/// zero, and returns it with the kind of station.
+///
+/// The motion of the planet is very slow close to a station, so the date is only accurate to some
+/// minutes. The search covers 1,000 days, which always includes a station, so the function only
+/// returns `None` for the Earth.
+///
+/// # Example
+///
+/// Mars started its retrograde motion on September 9th, 2020:
+///
+/// ```
+/// use vsop87::{events, Planet};
+/// use vsop87::events::Station;
+///
+/// let (jde, station) = events::next_station(Planet::Mars, 2459000.5).unwrap();
+///
+/// assert_eq!(station, Station::Retrograde);
+/// assert!((jde - 2459102.4).abs() < 1.0);
+/// ```
+pub fn next_station(planet: Planet, start_jde: f64) -> Option<(f64, Station)> {
+    if planet == Planet::Earth {
+        return None;
+    }
+
+    let rate = |jde: f64| {
+        normalize_angle_signed(
+            geocentric::apparent(planet, jde + STATION_STEP_DAYS).longitude()
+                - geocentric::apparent(planet, jde - STATION_STEP_DAYS).longitude(),
+        )
+    };
+
+    let jde = find_sign_change(start_jde, rate, |_| true)?;
+    let station = if rate(jde - STEP_DAYS) > 0_f64 {
+        Station::Retrograde
+    } else {
+        Station::Direct
+    };
+
+    Some((jde, station))
+}
+
+/// Kinds of station of a planet.
+///
+/// With the `serde` feature, the stations are serialized by name, such as `"Retrograde"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Station {
+    /// The planet stops moving eastward, and starts its retrograde motion.
+    Retrograde,
+    /// The planet stops moving westward, and resumes its direct motion.
+    Direct,
+}
+
+/// Finds the next time the difference of longitudes between the planet and the Sun is `target`.
+fn find_event(planet: Planet, start_jde: f64, target: f64) -> Option<f64> {
+    // Planets inside the orbit of the Earth are never at opposition.
+    if planet == Planet::Earth
+        || (target > 0_f64 && planet.sidereal_period() < Planet::Earth.sidereal_period())
+    {
+        return None;
+    }
+
+    // The offset also changes its sign where the difference of longitudes crosses `target + π`,
+    // at the opposite event. Oppositions happen with the planet far from the Sun, and
+    // conjunctions with the planet close to it, so the elongation tells both crossings apart.
+    find_sign_change(
+        start_jde,
+        |jde| normalize_angle_signed(longitude_difference(planet, jde) - target),
+        |jde| (geocentric::elongation(planet, jde) > FRAC_PI_2) == (target > 0_f64),
+    )
+}
+
+/// Finds the next time the given function changes its sign.
+///
+/// The search steps forward from `start_jde`, and only considers the sign changes for which
+/// `accept` returns `true` in the middle of the step. The date is then refined by bisection.
+fn find_sign_change<F, A>(start_jde: f64, function: F, accept: A) -> Option<f64>
+where
+    F: Fn(f64) -> f64,
+    A: Fn(f64) -> bool,
+{
+    let mut start = start_jde;
+    let mut start_value = function(start);
+    while start - start_jde < MAX_SEARCH_DAYS {
+        let mut end = start + STEP_DAYS;
+        let end_value = function(end);
+
+        if (start_value < 0_f64) != (end_value < 0_f64) && accept(start + STEP_DAYS / 2_f64) {
+            while end - start > PRECISION_DAYS {
+                let middle = start + (end - start) / 2_f64;
+                let middle_value = function(middle);
+
+                if (start_value < 0_f64) == (middle_value < 0_f64) {
+                    start = middle;
+                    start_value = middle_value;
+                } else {
+                    end = middle;
+                }
+            }
+
+            return Some(start + (end - start) / 2_f64);
+        }
+
+        start = end;
+        start_value = end_value;
+    }
+
+    None
+}
+
+/// Calculates the difference between the apparent longitudes of the planet and the Sun.
+fn longitude_difference(planet: Planet, jde: f64) -> f64 {
+    // The nutation in longitude moves both bodies by the same amount, so it is not needed.
+    let planet = geocentric::aberration(jde, &geocentric::apparent_planet(planet, jde));
+    let sun = geocentric::aberration(jde, &geocentric::sun(jde));
+
+    planet.longitude() - sun.longitude()
+}
@@ -0,0 +1,74 @@
+//! Export of ephemeris tables for external tools.
+//!
+//! The functions in this module write the positions of a planet over a span of time in common
+//! text formats, to be loaded in spreadsheets, plotting programs or other languages. Only
+//! available with the standard library.
+//!
+//! # Example
+//!
+//! ```
+//! use vsop87::{export, Planet};
+//!
+//! let mut csv = Vec::new();
+//! export::write_csv(&mut csv, Planet::Mars, 2451545.0, 2451575.0, 10.0).unwrap();
+//!
+//! let csv = String::from_utf8(csv).unwrap();
+//! assert_eq!(csv.lines().count(), 5);
+//! assert!(csv.starts_with("jd,longitude_deg,latitude_deg,distance_au\n2451545,"));
+//! ```
+
+use super::{vsop87d, Planet};
+
+use std::io::{self, Write};
+
+/// Writes the *VSOP87D* positions of a planet between two dates as comma-separated values.
+///
+/// The first line is the `jd,longitude_deg,latitude_deg,distance_au` header, and it is followed
+/// by one line per date, from `start_jd` to `end_jd` both included, every `step` days, as given
+/// by [`vsop87d::ephemeris_range()`](../vsop87d/fn.ephemeris_range.html). The longitude and the
+/// latitude are heliocentric ecliptic coordinates for the equinox of the day, in degrees, and
+/// the distance is in *AU*. The values are written with the shortest representation that parses
+/// back to the same `f64`, and every line ends with a new line.
+///
+/// The step can be negative to write the dates backwards, with `end_jd` before `start_jd`.
+///
+/// # Errors
+///
+/// Returns an error of the [`InvalidInput`](https://doc.rust-lang.org/std/io/enum.ErrorKind.html)
+/// kind, without writing anything, if any of the dates or the step is not a finite number, if the
+/// step is zero, or if it goes away from `end_jd`. Otherwise, returns any error produced by the
+/// writer.
+pub fn write_csv<W: Write>(
+    w: &mut W,
+    planet: Planet,
+    start_jd: f64,
+    end_jd: f64,
+    step: f64,
+) -> io::Result<()> {
+    if !start_jd.is_finite() || !end_jd.is_finite() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "the start and end dates must be finite numbers",
+        ));
+    }
+    if step == 0_f64 || !step.is_finite() || (end_jd - start_jd) * step < 0_f64 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "the step must be a finite, non-zero number towards the end date",
+        ));
+    }
+
+    writeln!(w, "jd,longitude_deg,latitude_deg,distance_au")?;
+    for (jd, coordinates) in vsop87d::ephemeris_range(planet, start_jd, end_jd, step) {
+        writeln!(
+            w,
+            "{},{},{},{}",
+            jd,
+            coordinates.longitude_deg(),
+            coordinates.latitude_deg(),
+            coordinates.distance()
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,260 @@
+//! C-compatible interface, to use the crate from other languages.
+//!
+//! The functions of this module are exported with their own names, so that they can be called
+//! from C, or from any language with a C foreign function interface, such as Python with
+//! `ctypes`. A shared library can be built with
+//! `cargo rustc --release --features ffi --crate-type cdylib`. Only available with the `ffi`
+//! feature.
+//!
+//! Every function writes its results into pointers given by the caller, and returns a status
+//! code: [`VSOP87_OK`](constant.VSOP87_OK.html) on success, or one of the error codes of this
+//! module. Nothing is written if an error is returned. The planets are identified by their index
+//! in [`Planet::ALL`](../enum.Planet.html#associatedconstant.ALL), from 0 for Mercury to 7 for
+//! Neptune.
+//!
+//! ```c
+//! double lon, lat, dist;
+//! if (vsop87d_planet(2, 2451545.0, &lon, &lat, &dist) != VSOP87_OK) {
+//!     /* Handle the error. */
+//! }
+//! ```
+#![allow(unsafe_code)]
+
+use super::{vsop87a, vsop87d, Planet};
+
+/// Status code of a successful call.
+pub const VSOP87_OK: i32 = 0;
+
+/// Status code returned when one of the output pointers is null.
+pub const VSOP87_NULL_POINTER: i32 = 1;
+
+/// Status code returned when the planet index is not valid, or the planet is not enabled.
+pub const VSOP87_INVALID_PLANET: i32 = 2;
+
+/// Calculates the *VSOP87D* coordinates of a planet, given its index.
+///
+/// The heliocentric ecliptic longitude and latitude, in radians, and the distance, in *AU*, for
+/// the equinox of the day, are written into `out_lon`, `out_lat` and `out_dist`. This is the
+/// same as [`vsop87d::planet()`](../vsop87d/fn.planet.html).
+///
+/// # Safety
+///
+/// The output pointers must be null, or valid for writes and properly aligned.
+#[no_mangle]
+pub unsafe extern "C" fn vsop87d_planet(
+    planet: i32,
+    jde: f64,
+    out_lon: *mut f64,
+    out_lat: *mut f64,
+    out_dist: *mut f64,
+) -> i32 {
+    match planet_from_index(planet) {
+        Some(planet) => write(
+            vsop87d::planet(planet, jde).as_tuple(),
+            out_lon,
+            out_lat,
+            out_dist,
+        ),
+        None => VSOP87_INVALID_PLANET,
+    }
+}
+
+/// Calculates the *VSOP87A* coordinates of a planet, given its index.
+///
+/// The heliocentric ecliptic rectangular coordinates, in *AU*, for the equinox J2000.0, are
+/// written into `out_x`, `out_y` and `out_z`. This is the same as
+/// [`vsop87a::planet()`](../vsop87a/fn.planet.html).
+///
+/// # Safety
+///
+/// The output pointers must be null, or valid for writes and properly aligned.
+#[no_mangle]
+pub unsafe extern "C" fn vsop87a_planet(
+    planet: i32,
+    jde: f64,
+    out_x: *mut f64,
+    out_y: *mut f64,
+    out_z: *mut f64,
+) -> i32 {
+    match planet_from_index(planet) {
+        Some(planet) => write(vsop87a::planet(planet, jde).as_tuple(), out_x, out_y, out_z),
+        None => VSOP87_INVALID_PLANET,
+    }
+}
+
+/// Calculates the *VSOP87D* coordinates of Mercury. See
+/// [`vsop87d_planet`](fn.vsop87d_planet.html).
+///
+/// # Safety
+///
+/// The output pointers must be null, or valid for writes and properly aligned.
+#[cfg(feature = "mercury")]
+#[no_mangle]
+pub unsafe extern "C" fn vsop87d_mercury(
+    jde: f64,
+    out_lon: *mut f64,
+    out_lat: *mut f64,
+    out_dist: *mut f64,
+) -> i32 {
+    write(vsop87d::mercury(jde).as_tuple(), out_lon, out_lat, out_dist)
+}
+
+/// Calculates the *VSOP87D* coordinates of Venus. See
+/// [`vsop87d_planet`](fn.vsop87d_planet.html).
+///
+/// # Safety
+///
+/// The output pointers must be null, or valid for writes and properly aligned.
+#[cfg(feature = "venus")]
+#[no_mangle]
+pub unsafe extern "C" fn vsop87d_venus(
+    jde: f64,
+    out_lon: *mut f64,
+    out_lat: *mut f64,
+    out_dist: *mut f64,
+) -> i32 {
+    write(vsop87d::venus(jde).as_tuple(), out_lon, out_lat, out_dist)
+}
+
+/// Calculates the *VSOP87D* coordinates of the Earth. See
+/// [`vsop87d_planet`](fn.vsop87d_planet.html).
+///
+/// # Safety
+///
+/// The output pointers must be null, or valid for writes and properly aligned.
+#[cfg(feature = "earth")]
+#[no_mangle]
+pub unsafe extern "C" fn vsop87d_earth(
+    jde: f64,
+    out_lon: *mut f64,
+    out_lat: *mut f64,
+    out_dist: *mut f64,
+) -> i32 {
+    write(vsop87d::earth(jde).as_tuple(), out_lon, out_lat, out_dist)
+}
+
+/// Calculates the *VSOP87D* coordinates of Mars. See
+/// [`vsop87d_planet`](fn.vsop87d_planet.html).
+///
+/// # Safety
+///
+/// The output pointers must be null, or valid for writes and properly aligned.
+#[cfg(feature = "mars")]
+#[no_mangle]
+pub unsafe extern "C" fn vsop87d_mars(
+    jde: f64,
+    out_lon: *mut f64,
+    out_lat: *mut f64,
+    out_dist: *mut f64,
+) -> i32 {
+    write(vsop87d::mars(jde).as_tuple(), out_lon, out_lat, out_dist)
+}
+
+/// Calculates the *VSOP87D* coordinates of Jupiter. See
+/// [`vsop87d_planet`](fn.vsop87d_planet.html).
+///
+/// # Safety
+///
+/// The output pointers must be null, or valid for writes and properly aligned.
+#[cfg(feature = "jupiter")]
+#[no_mangle]
+pub unsafe extern "C" fn vsop87d_jupiter(
+    jde: f64,
+    out_lon: *mut f64,
+    out_lat: *mut f64,
+    out_dist: *mut f64,
+) -> i32 {
+    write(vsop87d::jupiter(jde).as_tuple(), out_lon, out_lat, out_dist)
+}
+
+/// Calculates the *VSOP87D* coordinates of Saturn. See
+/// [`vsop87d_planet`](fn.vsop87d_planet.html).
+///
+/// # Safety
+///
+/// The output pointers must be null, or valid for writes and properly aligned.
+#[cfg(feature = "saturn")]
+#[no_mangle]
+pub unsafe extern "C" fn vsop87d_saturn(
+    jde: f64,
+    out_lon: *mut f64,
+    out_lat: *mut f64,
+    out_dist: *mut f64,
+) -> i32 {
+    write(vsop87d::saturn(jde).as_tuple(), out_lon, out_lat, out_dist)
+}
+
+/// Calculates the *VSOP87D* coordinates of Uranus. See
+/// [`vsop87d_planet`](fn.vsop87d_planet.html).
+///
+/// # Safety
+///
+/// The output pointers must be null, or valid for writes and properly aligned.
+#[cfg(feature = "uranus")]
+#[no_mangle]
+pub unsafe extern "C" fn vsop87d_uranus(
+    jde: f64,
+    out_lon: *mut f64,
+    out_lat: *mut f64,
+    out_dist: *mut f64,
+) -> i32 {
+    write(vsop87d::uranus(jde).as_tuple(), out_lon, out_lat, out_dist)
+}
+
+/// Calculates the *VSOP87D* coordinates of Neptune. See
+/// [`vsop87d_planet`](fn.vsop87d_planet.html).
+///
+/// # Safety
+///
+/// The output pointers must be null, or valid for writes and properly aligned.
+#[cfg(feature = "neptune")]
+#[no_mangle]
+pub unsafe extern "C" fn vsop87d_neptune(
+    jde: f64,
+    out_lon: *mut f64,
+    out_lat: *mut f64,
+    out_dist: *mut f64,
+) -> i32 {
+    write(vsop87d::neptune(jde).as_tuple(), out_lon, out_lat, out_dist)
+}
+
+/// Gets the planet with the given index, if it is valid and enabled.
+fn planet_from_index(index: i32) -> Option<Planet> {
+    match index {
+        #[cfg(feature = "mercury")]
+        0 => Some(Planet::Mercury),
+        #[cfg(feature = "venus")]
+        1 => Some(Planet::Venus),
+        #[cfg(feature = "earth")]
+        2 => Some(Planet::Earth),
+        #[cfg(feature = "mars")]
+        3 => Some(Planet::Mars),
+        #[cfg(feature = "jupiter")]
+        4 => Some(Planet::Jupiter),
+        #[cfg(feature = "saturn")]
+        5 => Some(Planet::Saturn),
+        #[cfg(feature = "uranus")]
+        6 => Some(Planet::Uranus),
+        #[cfg(feature = "neptune")]
+        7 => Some(Planet::Neptune),
+        _ => None,
+    }
+}
+
+/// Writes the three values into the given pointers, if none of them is null.
+unsafe fn write(
+    values: (f64, f64, f64),
+    first: *mut f64,
+    second: *mut f64,
+    third: *mut f64,
+) -> i32 {
+    if first.is_null() || second.is_null() || third.is_null() {
+        return VSOP87_NULL_POINTER;
+    }
+
+    *first = values.0;
+    *second = values.1;
+    *third = values.2;
+
+    VSOP87_OK
+}
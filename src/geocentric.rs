@@ -0,0 +1,488 @@
+//! Geocentric ecliptic coordinates for the equinox of the day.
+//!
+//! This module contains the functions to calculate where the planets appear as seen from the center
+//! of the Earth. The positions are computed from the *VSOP87C* heliocentric rectangular
+//! coordinates of the planet and of the Earth, for the equinox of the day, by subtracting the
+//! position of the Earth and converting the result to spherical coordinates. Only available with
+//! the `earth` feature.
+//!
+//! Those geometric positions are not exactly where the planets are seen. For the apparent
+//! position, use [`apparent()`](fn.apparent.html), which iterates on the light-time, and applies
+//! the annual aberration and the nutation, or
+//! [`apparent_equatorial()`](fn.apparent_equatorial.html) to get the right ascension and
+//! declination of the date. The corrections can also be applied one by one with
+//! [`apparent_planet()`](fn.apparent_planet.html) and [`aberration()`](fn.aberration.html).
+//!
+//! # Example
+//!
+//! Mars had its closest approach to the Earth in almost 60,000 years on August 27th, 2003, at
+//! about 0.3727 *AU*:
+//!
+//! ```
+//! use vsop87::{geocentric, Planet};
+//!
+//! let coordinates = geocentric::planet(Planet::Mars, 2452878.9);
+//!
+//! assert!(coordinates.distance() > 0.3727 && coordinates.distance() < 0.3728);
+//! ```
+
+use super::{
+    acos, mean_obliquity, normalize_angle, normalize_angle_signed, nutation, sin_cos, vsop87c,
+    vsop87d, EquatorialCoordinates, Planet, RectangularCoordinates, SphericalCoordinates,
+    SPEED_OF_LIGHT_AU_PER_DAY,
+};
+
+#[cfg(feature = "no_std")]
+use core::f64::consts::PI;
+
+#[cfg(not(feature = "no_std"))]
+use std::f64::consts::PI;
+
+/// Constant of aberration (*κ*), in arcseconds.
+///
+/// It is the ratio between the mean orbital speed of the Earth and the speed of light, and the
+/// maximum displacement caused by the annual aberration, for an object in the ecliptic.
+pub const ABERRATION_CONSTANT: f64 = 20.49552;
+
+/// Calculates the geometric geocentric ecliptic coordinates of a planet.
+///
+/// This function calculates the position of the given planet as seen from the center of the Earth,
+/// for the equinox of the day. The parameter needed is the Julian Day (*JD*) for the given date.
+/// The longitude is normalized into the `[0, 2π)` range, in the same way it is done for the
+/// heliocentric coordinates, and the distance is given in *AU*.
+///
+/// These are geometric coordinates: they do not take into account the time light takes to reach
+/// the Earth. For [`Planet::Earth`](../enum.Planet.html#variant.Earth) the position is the origin,
+/// so the angles are not defined.
+///
+/// # Example
+///
+/// At the opposition of Mars of August 28th, 2003, the planet was in the direction opposite to
+/// the Sun, so its geocentric longitude was the heliocentric longitude of the Earth:
+///
+/// ```
+/// use vsop87::{geocentric, vsop87d, Planet};
+///
+/// let jde = 2452880.25;
+/// let mars = geocentric::planet(Planet::Mars, jde);
+/// let earth = vsop87d::earth(jde);
+///
+/// assert!((mars.longitude() - earth.longitude()).abs() < 0.001);
+/// ```
+pub fn planet(planet: Planet, jde: f64) -> SphericalCoordinates {
+    from_earth(planet, jde, &vsop87c::earth(jde)).to_spherical()
+}
+
+/// Calculates the geometric distance between a planet and the Earth, in *AU*.
+///
+/// This is the norm of the difference between the heliocentric rectangular coordinates of the
+/// planet and of the Earth, at the same Julian Day (*JD*), without converting anything to
+/// spherical coordinates. It is the distance given by [`planet()`](fn.planet.html), and it is
+/// zero for [`Planet::Earth`](../enum.Planet.html#variant.Earth).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{geocentric, Planet};
+///
+/// // Mars at its closest approach in almost 60,000 years, on August 27th, 2003.
+/// let distance = geocentric::distance(Planet::Mars, 2452878.9);
+///
+/// assert!(distance > 0.3727 && distance < 0.3728);
+/// ```
+pub fn distance(planet: Planet, jde: f64) -> f64 {
+    from_earth(planet, jde, &vsop87c::earth(jde)).norm()
+}
+
+/// Calculates the time the light takes to travel from a planet to the Earth, in days.
+///
+/// It is the geometric [`distance()`](fn.distance.html) divided by the speed of light. The light
+/// observed at `jde` actually left the planet when it was slightly elsewhere, and
+/// [`apparent_planet()`](fn.apparent_planet.html) iterates to take that into account, but the
+/// difference in light-time is below a second.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{geocentric, Planet};
+///
+/// // The light of Neptune takes about 4 hours to reach the Earth.
+/// let hours = geocentric::distance_light_time(Planet::Neptune, 2451545.0) * 24.0;
+///
+/// assert!(hours > 4.0 && hours < 4.5);
+/// ```
+pub fn distance_light_time(planet: Planet, jde: f64) -> f64 {
+    distance(planet, jde) * LIGHT_TIME_DAYS_PER_AU
+}
+
+/// Calculates the apparent geocentric ecliptic coordinates of a planet, corrected for light-time.
+///
+/// The light of a planet observed at a given time left it some minutes earlier, so the planet is
+/// seen where it was at that earlier time. This function computes the geometric distance to the
+/// planet, and then recomputes the heliocentric position of the planet `τ = 0.0057755183·Δ` days
+/// earlier, where `Δ` is the distance in *AU*, while the Earth stays at the given `jde`. The
+/// process is repeated with the new distance until `τ` changes by less than 10⁻⁹ days (less than
+/// 0.1 milliseconds), with a maximum of 10 iterations.
+///
+/// The coordinates are still for the equinox of the day, and the returned distance is the distance
+/// the light traveled. Aberration and nutation are not taken into account.
+///
+/// # Example
+///
+/// This is example 33.a from *Astronomical Algorithms*, by Jean Meeus, for Venus on December
+/// 20th, 1992 at 0h TD:
+///
+/// ```
+/// use vsop87::{geocentric, Planet};
+///
+/// let coordinates = geocentric::apparent_planet(Planet::Venus, 2448976.5);
+///
+/// assert!((coordinates.longitude().to_degrees() - 313.08102).abs() < 0.001);
+/// assert!((coordinates.latitude().to_degrees() - -2.08474).abs() < 0.001);
+/// assert!((coordinates.distance() - 0.910947).abs() < 0.000001);
+/// ```
+pub fn apparent_planet(planet: Planet, jde: f64) -> SphericalCoordinates {
+    let earth = vsop87c::earth(jde);
+
+    let mut coordinates = from_earth(planet, jde, &earth).to_spherical();
+    let mut tau = 0_f64;
+    for _ in 0..MAX_LIGHT_TIME_ITERATIONS {
+        let new_tau = LIGHT_TIME_DAYS_PER_AU * coordinates.distance();
+        if (new_tau - tau).abs() < LIGHT_TIME_THRESHOLD {
+            break;
+        }
+
+        tau = new_tau;
+        coordinates = from_earth(planet, jde - tau, &earth).to_spherical();
+    }
+
+    coordinates
+}
+
+/// Calculates the apparent geocentric ecliptic coordinates of a planet.
+///
+/// This combines all the corrections to get the position where the planet is actually seen from
+/// the center of the Earth: the [light-time](fn.apparent_planet.html), the
+/// [annual aberration](fn.aberration.html), and the nutation in longitude from the
+/// [`nutation`](../nutation/index.html) module. The coordinates are referred to the true ecliptic
+/// and equinox of the day, and the distance is the distance the light traveled. To convert them
+/// to equatorial coordinates, use the true obliquity, `mean_obliquity(jde) + Δε`.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{geocentric, Planet};
+///
+/// let geometric = geocentric::apparent_planet(Planet::Venus, 2448976.5);
+/// let apparent = geocentric::apparent(Planet::Venus, 2448976.5);
+///
+/// // The corrections move the planet by some arcseconds.
+/// let difference = (apparent.longitude() - geometric.longitude()).to_degrees() * 3600.0;
+/// assert!(difference.abs() < 40.0);
+/// ```
+pub fn apparent(planet: Planet, jde: f64) -> SphericalCoordinates {
+    let coordinates = aberration(jde, &apparent_planet(planet, jde));
+    let (delta_psi, _) = nutation::nutation(jde);
+
+    SphericalCoordinates::new(
+        normalize_angle(coordinates.longitude() + delta_psi),
+        coordinates.latitude(),
+        coordinates.distance(),
+    )
+}
+
+/// Calculates the apparent right ascension and declination of a planet.
+///
+/// This is the complete pipeline from the *VSOP87* series to the position of the planet in the
+/// sky of the date, as seen from the center of the Earth. The steps are applied in this order:
+///
+/// 1. The heliocentric positions of the Earth at `jde`, and of the planet at `jde − τ`, where *τ*
+///    is the light-time, found by iteration ([`apparent_planet()`](fn.apparent_planet.html)).
+/// 2. The annual [aberration](fn.aberration.html), caused by the motion of the Earth.
+/// 3. The nutation in longitude, *Δψ*, which moves the coordinates to the true equinox of the day
+///    ([`apparent()`](fn.apparent.html) does steps 1 to 3).
+/// 4. The conversion to equatorial coordinates with the true obliquity of the ecliptic,
+///    `mean_obliquity(jde) + Δε`, where *Δε* is the nutation in obliquity.
+///
+/// The result is referred to the true equator and equinox of the day, with the distance the
+/// light traveled, in *AU*. The parallax of an observer on the surface of the Earth is not taken
+/// into account: see the [`topocentric`](../topocentric/index.html) module for that.
+///
+/// # Example
+///
+/// This is example 33.a from *Astronomical Algorithms*, by Jean Meeus: Venus on December 20th,
+/// 1992 at 0h TD, at 21h04m41.454s and −18°53'16.84":
+///
+/// ```
+/// use vsop87::{geocentric, Planet};
+///
+/// let venus = geocentric::apparent_equatorial(Planet::Venus, 2448976.5);
+///
+/// assert!((venus.right_ascension_hours() - 21.078182).abs() < 2e-5);
+/// assert!((venus.declination_deg() - -18.888011).abs() < 1e-4);
+/// ```
+pub fn apparent_equatorial(planet: Planet, jde: f64) -> EquatorialCoordinates {
+    let (_, delta_epsilon) = nutation::nutation(jde);
+
+    apparent(planet, jde).to_equatorial(mean_obliquity(jde) + delta_epsilon)
+}
+
+/// Calculates the geometric geocentric ecliptic coordinates of the Sun.
+///
+/// The Sun is seen from the Earth in the direction opposite to the heliocentric position of the
+/// Earth, so this function takes the [*VSOP87D*](../vsop87d/fn.earth.html) coordinates of the
+/// Earth, adds `π` to the longitude, normalized into the `[0, 2π)` range, and negates the latitude.
+/// The distance is the same. The coordinates are referred to the mean ecliptic and equinox of the
+/// day, and the parameter needed is the Julian Day (*JD*) for the given date.
+///
+/// # Example
+///
+/// This is example 25.b from *Astronomical Algorithms*, by Jean Meeus, for October 13th, 1992 at
+/// 0h TD. The book uses a truncated version of the *VSOP87D* series, so the results differ by some
+/// tenths of an arcsecond:
+///
+/// ```
+/// use vsop87::geocentric;
+///
+/// let sun = geocentric::sun(2448908.5);
+///
+/// assert!((sun.longitude().to_degrees() - 199.907372).abs() < 1e-4);
+/// assert!((sun.latitude().to_degrees() * 3600.0 - 0.644).abs() < 0.2);
+/// assert!((sun.distance() - 0.99760775).abs() < 1e-6);
+/// ```
+pub fn sun(jde: f64) -> SphericalCoordinates {
+    let earth = vsop87d::earth(jde);
+
+    SphericalCoordinates::new(
+        normalize_angle(earth.longitude() + PI),
+        -earth.latitude(),
+        earth.distance(),
+    )
+}
+
+/// Calculates the apparent geocentric ecliptic coordinates of the Sun.
+///
+/// It applies the [annual aberration](fn.aberration.html) and the nutation in longitude to the
+/// [geometric position](fn.sun.html) of the Sun. For the Sun, the aberration is about −20.5",
+/// and it already accounts for the time light takes to reach the Earth. The coordinates are
+/// referred to the true ecliptic and equinox of the day.
+///
+/// # Example
+///
+/// This is example 25.b from *Astronomical Algorithms*, by Jean Meeus, for October 13th, 1992 at
+/// 0h TD, where the apparent longitude of the Sun is 199°54'21.82":
+///
+/// ```
+/// use vsop87::geocentric;
+///
+/// let sun = geocentric::apparent_sun(2448908.5);
+///
+/// assert!((sun.longitude().to_degrees() - 199.906061).abs() < 1e-4);
+/// ```
+pub fn apparent_sun(jde: f64) -> SphericalCoordinates {
+    let coordinates = aberration(jde, &sun(jde));
+    let (delta_psi, _) = nutation::nutation(jde);
+
+    SphericalCoordinates::new(
+        normalize_angle(coordinates.longitude() + delta_psi),
+        coordinates.latitude(),
+        coordinates.distance(),
+    )
+}
+
+/// Calculates the equation of time, in minutes.
+///
+/// The equation of time is the difference between the apparent solar time, read on a sundial,
+/// and the mean solar time, read on a clock: it is positive when the sundial is ahead of the
+/// clock. It is computed as in *Astronomical Algorithms*, by Jean Meeus, chapter 28, as
+/// `E = L₀ − 0.0057183° − α + Δψ·cos ε`, where *L₀* is the mean longitude of the Sun, *α* the
+/// right ascension of the [apparent Sun](fn.apparent_sun.html), and *Δψ·cos ε* the nutation in
+/// right ascension, which the mean Sun does not follow. The difference is normalized into the
+/// `(−π, π]` range before being converted to minutes. The parameter needed is the Julian Day
+/// (*JD*) for the given date.
+///
+/// Over the year, the equation of time goes from about −14 minutes in February to about +16
+/// minutes at the beginning of November, crossing zero in April, June, September and December.
+///
+/// # Example
+///
+/// This is example 28.a from *Astronomical Algorithms*: on October 13th, 1992 at 0h TD, the
+/// equation of time is 13m42.6s:
+///
+/// ```
+/// use vsop87::geocentric;
+///
+/// let minutes = geocentric::equation_of_time(2448908.5);
+///
+/// assert!((minutes - 13.71).abs() < 0.01);
+/// ```
+pub fn equation_of_time(jde: f64) -> f64 {
+    let tau = (jde - 2_451_545_f64) / 365_250_f64;
+    let mean_longitude = (280.466_456_7
+        + tau
+            * (360_007.698_277_9
+                + tau
+                    * (0.030_320_28
+                        + tau * (1_f64 / 49_931_f64 - tau * (1_f64 / 15_300_f64 + tau / 2e6)))))
+        .to_radians();
+
+    let (delta_psi, delta_epsilon) = nutation::nutation(jde);
+    let obliquity = mean_obliquity(jde) + delta_epsilon;
+    let right_ascension = apparent_sun(jde).to_equatorial(obliquity).right_ascension();
+    let (_, cos_obliquity) = sin_cos(obliquity);
+
+    let difference =
+        mean_longitude - 0.005_718_3_f64.to_radians() - right_ascension + delta_psi * cos_obliquity;
+
+    normalize_angle_signed(difference).to_degrees() * 4_f64
+}
+
+/// Applies the annual aberration to geocentric ecliptic coordinates.
+///
+/// The motion of the Earth around the Sun displaces the apparent direction of every object
+/// towards the direction of that motion, by up to the
+/// [constant of aberration](constant.ABERRATION_CONSTANT.html), about 20.5". This function uses the
+/// classical formulas from *Astronomical Algorithms*, by Jean Meeus, chapter 23, with the true
+/// longitude of the Sun from the *VSOP87D* position of the Earth and the eccentricity and
+/// perihelion of the orbit of the Earth. The coordinates must be referred to the ecliptic and
+/// equinox of the day, and the distance is not modified.
+///
+/// The displacement is largest for objects in the direction of the Sun or opposite to it, and
+/// vanishes for objects 90° west or east of the Sun in the ecliptic, in the direction of the
+/// motion of the Earth. It grows as `1 / cos β` near the poles of the ecliptic, where the
+/// longitude is not well defined.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{geocentric, vsop87d, SphericalCoordinates};
+///
+/// // An object close to the Sun in the sky is displaced by about κ in longitude.
+/// let jde = 2451545.0;
+/// let sun = vsop87d::earth(jde).longitude() + std::f64::consts::PI;
+/// let object = SphericalCoordinates::new(sun, 0.0, 1.0);
+/// let apparent = geocentric::aberration(jde, &object);
+///
+/// let difference = (apparent.longitude() - object.longitude()).to_degrees() * 3600.0;
+/// assert!((difference - -20.5).abs() < 0.5);
+/// ```
+pub fn aberration(jde: f64, coordinates: &SphericalCoordinates) -> SphericalCoordinates {
+    let t = (jde - 2_451_545_f64) / 36_525_f64;
+    let eccentricity = 0.016708634 - t * (0.000042037 + 0.0000001267 * t);
+    let perihelion = (102.93735 + t * (1.71946 + 0.00046 * t)).to_radians();
+    let sun = vsop87d::earth(jde).longitude() + PI;
+    let kappa = (ABERRATION_CONSTANT / 3_600_f64).to_radians();
+
+    let longitude = coordinates.longitude();
+    let latitude = coordinates.latitude();
+    let (sin_sun, cos_sun) = sin_cos(sun - longitude);
+    let (sin_perihelion, cos_perihelion) = sin_cos(perihelion - longitude);
+    let (sin_latitude, cos_latitude) = sin_cos(latitude);
+
+    let delta_longitude = kappa * (eccentricity * cos_perihelion - cos_sun) / cos_latitude;
+    let delta_latitude = -kappa * sin_latitude * (sin_sun - eccentricity * sin_perihelion);
+
+    SphericalCoordinates::new(
+        normalize_angle(longitude + delta_longitude),
+        latitude + delta_latitude,
+        coordinates.distance(),
+    )
+}
+
+/// Calculates the elongation of a planet, in radians.
+///
+/// The elongation is the angle between the Sun and the planet, as seen from the center of the
+/// Earth, in the `[0, π]` range. The direction to the Sun is the opposite of the heliocentric
+/// position of the Earth. Planets close to the Sun in the sky, with a small elongation, cannot be
+/// observed, while planets at opposition have an elongation of `π`. The geometric positions are
+/// used, and for [`Planet::Earth`](../enum.Planet.html#variant.Earth) the elongation is not
+/// defined.
+///
+/// # Example
+///
+/// Venus reached its greatest eastern elongation, 46.1°, on March 24th, 2020:
+///
+/// ```
+/// use vsop87::{geocentric, Planet};
+///
+/// let elongation = geocentric::elongation(Planet::Venus, 2458932.5);
+///
+/// assert!((elongation.to_degrees() - 46.1).abs() < 0.1);
+/// ```
+pub fn elongation(planet: Planet, jde: f64) -> f64 {
+    let earth = vsop87c::earth(jde);
+    let geocentric = from_earth(planet, jde, &earth);
+
+    // The Sun is in the direction opposite to the Earth's heliocentric position.
+    let cos = -geocentric.dot(&earth) / (geocentric.norm() * earth.norm());
+
+    acos(cos.clamp(-1_f64, 1_f64))
+}
+
+/// Calculates the phase angle of a planet, in radians.
+///
+/// The phase angle is the angle between the Sun and the Earth, as seen from the planet, in the
+/// `[0, π]` range. It is computed with the law of cosines from the heliocentric distance of the
+/// planet, its geocentric distance and the distance between the Sun and the Earth. A phase angle
+/// of 0 means the planet is seen fully lit, and a phase angle of `π` means only its dark side
+/// faces the Earth. The geometric positions are used, and for
+/// [`Planet::Earth`](../enum.Planet.html#variant.Earth) the phase angle is not defined.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{geocentric, Planet};
+///
+/// // At its greatest elongation, Venus is seen about half lit.
+/// let phase_angle = geocentric::phase_angle(Planet::Venus, 2458932.5);
+///
+/// assert!((phase_angle.to_degrees() - 90.0).abs() < 2.0);
+/// ```
+pub fn phase_angle(planet: Planet, jde: f64) -> f64 {
+    acos(cos_phase_angle(planet, jde))
+}
+
+/// Calculates the illuminated fraction of the disk of a planet.
+///
+/// It is the fraction of the apparent disk of the planet lit by the Sun, between 0 and 1, computed
+/// as `(1 + cos i) / 2`, where *i* is the [phase angle](fn.phase_angle.html).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{geocentric, Planet};
+///
+/// // Inferior conjunction of Venus, on June 3rd, 2020.
+/// let fraction = geocentric::illuminated_fraction(Planet::Venus, 2459004.25);
+///
+/// assert!(fraction < 0.01);
+/// ```
+pub fn illuminated_fraction(planet: Planet, jde: f64) -> f64 {
+    0.5 * (1_f64 + cos_phase_angle(planet, jde))
+}
+
+/// Calculates the cosine of the phase angle of a planet, clamped to `[-1, 1]`.
+fn cos_phase_angle(planet: Planet, jde: f64) -> f64 {
+    let earth = vsop87c::earth(jde);
+    let heliocentric = vsop87c::planet(planet, jde);
+
+    let r = heliocentric.norm();
+    let delta = (heliocentric - earth).norm();
+    let big_r = earth.norm();
+
+    ((r * r + delta * delta - big_r * big_r) / (2_f64 * r * delta)).clamp(-1_f64, 1_f64)
+}
+
+/// Time it takes the light to travel one *AU*, in days.
+const LIGHT_TIME_DAYS_PER_AU: f64 = 1_f64 / SPEED_OF_LIGHT_AU_PER_DAY;
+
+/// Convergence threshold of the light-time iterations, in days.
+const LIGHT_TIME_THRESHOLD: f64 = 1e-9;
+
+/// Maximum number of light-time iterations.
+const MAX_LIGHT_TIME_ITERATIONS: u8 = 10;
+
+/// Calculates the position of a planet at the given date, relative to the given Earth position.
+fn from_earth(planet: Planet, jde: f64, earth: &RectangularCoordinates) -> RectangularCoordinates {
+    vsop87c::planet(planet, jde) - *earth
+}
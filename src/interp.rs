@@ -0,0 +1,179 @@
+//! Chebyshev interpolation of the planet positions over a time window.
+//!
+//! Evaluating the full *VSOP87* series takes thousands of cosines per position, which is wasteful
+//! when the same span of time is queried many times, for example to draw every frame of a smooth
+//! animation. A [`ChebFit`](struct.ChebFit.html) evaluates the series once per node, fits
+//! Chebyshev polynomials to the *VSOP87D* coordinates of a planet over a window of Julian Days,
+//! and then gives the coordinates at any date of the window with a few multiplications. Only
+//! available with the standard library.
+//!
+//! # Example
+//!
+//! ```
+//! use vsop87::{angular_separation, interp::ChebFit, vsop87d, Planet};
+//!
+//! let fit = ChebFit::new(Planet::Mars, 2451545.0, 2451575.0, 12);
+//!
+//! for day in 0..30 {
+//!     let jde = 2451545.0 + f64::from(day) + 0.5;
+//!     let error = angular_separation(&fit.at(jde), &vsop87d::mars(jde)).to_degrees() * 3600.0;
+//!     assert!(error < 1e-3);
+//! }
+//! ```
+
+use super::{normalize_angle, normalize_angle_signed, vsop87d, Planet, SphericalCoordinates};
+
+use std::f64::consts::PI;
+
+/// Chebyshev fit of the *VSOP87D* coordinates of a planet over a window of Julian Days.
+///
+/// The longitude, the latitude and the distance are fitted separately, each with a Chebyshev
+/// polynomial of the given degree, interpolating the series at the `degree + 1` Chebyshev nodes of
+/// the window. The longitude is unwrapped before the fit, so that it stays continuous when it goes
+/// through 0, and it is normalized again when evaluated.
+///
+/// The degree needed depends on the length of the window and on how fast the coordinates change.
+/// Over a 30-day window, these are the largest errors measured in the direction of the planet,
+/// compared to the full series:
+///
+/// | Degree | Mercury | Venus   | Earth   | Mars    | Jupiter to Neptune |
+/// |--------|---------|---------|---------|---------|--------------------|
+/// | 6      | 62"     | 2·10⁻⁴" | 0.17"   | 10⁻⁶"   | 3·10⁻⁶"            |
+/// | 8      | 5.4"    | 2·10⁻⁶" | 0.03"   | 10⁻⁶"   | 2·10⁻⁷"            |
+/// | 12     | 0.05"   | 2·10⁻⁶" | 0.002"  | 10⁻⁶"   | 2·10⁻⁷"            |
+/// | 16     | 0.0009" | 3·10⁻⁶" | 8·10⁻⁵" | 10⁻⁶"   | 2·10⁻⁷"            |
+/// | 20     | 2·10⁻⁵" | 3·10⁻⁶" | 3·10⁻⁶" | 10⁻⁶"   | 2·10⁻⁷"            |
+///
+/// Errors of about 10⁻⁶" are the rounding errors of the series themselves. Mercury, which is fast
+/// and has the most eccentric orbit, and the Earth, whose position wobbles every month around the
+/// barycenter with the Moon, need the highest degrees: a degree of 12 keeps every planet within
+/// 0.05" over a month, well below the 1" precision of *VSOP87*. Outside of the window the
+/// polynomials are extrapolated, and the error grows very quickly.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChebFit {
+    planet: Planet,
+    degree: u32,
+    start: f64,
+    end: f64,
+    longitude: Vec<f64>,
+    latitude: Vec<f64>,
+    distance: Vec<f64>,
+}
+
+impl ChebFit {
+    /// Fits the coordinates of the planet between the `start_jde` and `end_jde` Julian Days
+    /// (*JD*), with polynomials of the given degree.
+    ///
+    /// The *VSOP87D* series are evaluated `degree + 1` times. The nodes must be close enough
+    /// for the planet to move less than half a turn between two of them, which is always the case
+    /// with the degrees needed for a useful precision.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `end_jde` is not after `start_jde`.
+    pub fn new(planet: Planet, start_jde: f64, end_jde: f64, degree: u32) -> Self {
+        assert!(
+            end_jde > start_jde,
+            "the end of the window must be after its start"
+        );
+
+        let nodes = f64::from(degree) + 1_f64;
+        let half_width = (end_jde - start_jde) / 2_f64;
+        let middle = start_jde + half_width;
+
+        // Angles of the Chebyshev nodes, and the coordinates of the planet at each of them.
+        let angles: Vec<f64> = (0..=degree)
+            .map(|k| PI * (f64::from(k) + 0.5) / nodes)
+            .collect();
+        let mut samples: Vec<SphericalCoordinates> = angles
+            .iter()
+            .map(|angle| vsop87d::planet(planet, middle + half_width * angle.cos()))
+            .collect();
+
+        // Unwraps the longitude, so that it is continuous from one node to the next.
+        let mut previous = samples[0].longitude();
+        for sample in samples.iter_mut().skip(1) {
+            let longitude = previous + normalize_angle_signed(sample.longitude() - previous);
+            *sample = SphericalCoordinates::new(longitude, sample.latitude(), sample.distance());
+            previous = longitude;
+        }
+
+        let fit = |value: fn(&SphericalCoordinates) -> f64| -> Vec<f64> {
+            (0..=degree)
+                .map(|j| {
+                    let sum: f64 = samples
+                        .iter()
+                        .zip(&angles)
+                        .map(|(sample, angle)| value(sample) * (f64::from(j) * angle).cos())
+                        .sum();
+                    let coefficient = 2_f64 * sum / nodes;
+
+                    if j == 0 {
+                        coefficient / 2_f64
+                    } else {
+                        coefficient
+                    }
+                })
+                .collect()
+        };
+
+        Self {
+            planet,
+            degree,
+            start: start_jde,
+            end: end_jde,
+            longitude: fit(SphericalCoordinates::longitude),
+            latitude: fit(SphericalCoordinates::latitude),
+            distance: fit(SphericalCoordinates::distance),
+        }
+    }
+
+    /// Calculates the *VSOP87D* coordinates of the planet at the given Julian Day (*JD*), from the
+    /// fitted polynomials.
+    ///
+    /// The coordinates are heliocentric ecliptic spherical coordinates for the equinox of the day,
+    /// as with [`vsop87d::planet()`](../vsop87d/fn.planet.html). The Julian Day should be inside
+    /// the window of the fit.
+    pub fn at(&self, jde: f64) -> SphericalCoordinates {
+        let x = (2_f64 * jde - self.start - self.end) / (self.end - self.start);
+
+        SphericalCoordinates::new(
+            normalize_angle(clenshaw(&self.longitude, x)),
+            clenshaw(&self.latitude, x),
+            clenshaw(&self.distance, x),
+        )
+    }
+
+    /// Gets the planet of the fit.
+    pub fn planet(&self) -> Planet {
+        self.planet
+    }
+
+    /// Gets the first Julian Day (*JD*) of the window.
+    pub fn start(&self) -> f64 {
+        self.start
+    }
+
+    /// Gets the last Julian Day (*JD*) of the window.
+    pub fn end(&self) -> f64 {
+        self.end
+    }
+
+    /// Gets the degree of the fitted polynomials.
+    pub fn degree(&self) -> u32 {
+        self.degree
+    }
+}
+
+/// Evaluates a Chebyshev series at `x`, in the `[-1, 1]` range, with Clenshaw's algorithm.
+fn clenshaw(coefficients: &[f64], x: f64) -> f64 {
+    let (b1, b2) = coefficients
+        .iter()
+        .skip(1)
+        .rev()
+        .fold((0_f64, 0_f64), |(b1, b2), coefficient| {
+            (coefficient + 2_f64 * x * b1 - b2, b1)
+        });
+
+    coefficients[0] + x * b1 - b2
+}
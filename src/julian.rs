@@ -0,0 +1,129 @@
+//! Conversions between calendar dates, Julian Days and Modified Julian Days.
+//!
+//! The algorithms are the ones described by Jean Meeus in *Astronomical Algorithms*, chapter 7.
+//! Dates from October 15th, 1582 onwards are in the Gregorian calendar, and earlier dates are in
+//! the Julian calendar.
+
+use super::floor;
+
+/// Calculates the Julian Day (*JD*) of a calendar date.
+///
+/// The `month` goes from 1 (January) to 12 (December), and the `day` can have a fractional part
+/// representing the time of the day, so that `1.5` is noon of the first day of the month. Years
+/// are astronomical years: the year 1 BC is year 0, the year 2 BC is year -1, and so on.
+///
+/// Dates on or after October 15th, 1582 are taken as Gregorian calendar dates, and earlier dates
+/// as Julian calendar dates.
+///
+/// # Example
+///
+/// The J2000.0 epoch is January 1st, 2000 at noon:
+///
+/// ```
+/// use vsop87::julian_day;
+///
+/// assert_eq!(julian_day(2000, 1, 1.5), 2451545.0);
+/// assert_eq!(julian_day(1582, 10, 15.0), 2299160.5);
+/// assert_eq!(julian_day(1582, 10, 4.0), 2299159.5);
+/// ```
+pub fn julian_day(year: i32, month: u32, day: f64) -> f64 {
+    let gregorian = (year, month) > (1582, 10) || (year, month) == (1582, 10) && day >= 15_f64;
+
+    let (year, month) = if month > 2 {
+        (f64::from(year), f64::from(month))
+    } else {
+        (f64::from(year) - 1_f64, f64::from(month) + 12_f64)
+    };
+
+    let b = if gregorian {
+        let a = floor(year / 100_f64);
+        2_f64 - a + floor(a / 4_f64)
+    } else {
+        0_f64
+    };
+
+    floor(365.25 * (year + 4_716_f64)) + floor(30.6001 * (month + 1_f64)) + day + b - 1_524.5
+}
+
+/// Calculates the calendar date of a Julian Day (*JD*).
+///
+/// It returns the `(year, month, day)` tuple of the date, where the day has a fractional part
+/// representing the time of the day. This is the inverse of [`julian_day`](fn.julian_day.html),
+/// and uses the same calendar and year conventions. The Julian Day must not be negative.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::calendar_date;
+///
+/// assert_eq!(calendar_date(2451545.0), (2000, 1, 1.5));
+///
+/// let (year, month, day) = calendar_date(2436116.31);
+/// assert_eq!((year, month), (1957, 10));
+/// assert!((day - 4.81).abs() < 1e-8);
+/// ```
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn calendar_date(jd: f64) -> (i32, u32, f64) {
+    let z = floor(jd + 0.5);
+    let f = jd + 0.5 - z;
+
+    // 2299161 is the integral Julian Day of October 15th, 1582, the first Gregorian date.
+    let a = if z < 2_299_161_f64 {
+        z
+    } else {
+        let alpha = floor((z - 1_867_216.25) / 36_524.25);
+        z + 1_f64 + alpha - floor(alpha / 4_f64)
+    };
+
+    let b = a + 1_524_f64;
+    let c = floor((b - 122.1) / 365.25);
+    let d = floor(365.25 * c);
+    let e = floor((b - d) / 30.6001);
+
+    let day = b - d - floor(30.6001 * e) + f;
+    let month = if e < 14_f64 { e - 1_f64 } else { e - 13_f64 };
+    let year = if month > 2_f64 {
+        c - 4_716_f64
+    } else {
+        c - 4_715_f64
+    };
+
+    // All the values are integral at this point, and well within the range of the target types.
+    (year as i32, month as u32, day)
+}
+
+/// Converts a Julian Day (*JD*) into a Modified Julian Day (*MJD*).
+///
+/// The Modified Julian Day is `JD − 2400000.5`: it starts at midnight instead of noon, and counts
+/// the days since November 17th, 1858, which keeps the numbers small for recent dates. It is used
+/// by many catalogs and observatory logs, and is in the same time scale as the Julian Day.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{jd_to_mjd, julian_day};
+///
+/// assert_eq!(jd_to_mjd(2451545.0), 51544.5);
+/// assert_eq!(jd_to_mjd(julian_day(1858, 11, 17.0)), 0.0);
+/// ```
+pub fn jd_to_mjd(jd: f64) -> f64 {
+    jd - 2_400_000.5
+}
+
+/// Converts a Modified Julian Day (*MJD*) into a Julian Day (*JD*).
+///
+/// This is the inverse of [`jd_to_mjd`](fn.jd_to_mjd.html). A Modified Julian Day in Terrestrial
+/// Time (*TT*) gives a Julian Ephemeris Day that can be directly used with the *VSOP87* functions.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{mjd_to_jd, vsop87d};
+///
+/// assert_eq!(mjd_to_jd(51544.5), 2451545.0);
+///
+/// let coordinates = vsop87d::mars(mjd_to_jd(60000.0));
+/// ```
+pub fn mjd_to_jd(mjd: f64) -> f64 {
+    mjd + 2_400_000.5
+}
@@ -27,6 +27,12 @@
 //! [Julian Day](https://en.wikipedia.org/wiki/Julian_day) (*JD*). In our case, that date is
 //! `2451545.0`.
 //!
+//! Strictly speaking, all the functions in this crate take a Julian Ephemeris Day (*JDE*), a
+//! Julian Day in Terrestrial Time (*TT*). Civil dates are in Universal Time (*UT*), and must be
+//! shifted by *ΔT*, which is more than a minute nowadays and hours in ancient times. The
+//! [`ut_to_tt`](fn.ut_to_tt.html) and [`delta_t`](fn.delta_t.html) functions help with that,
+//! and [`julian_day`](fn.julian_day.html) converts calendar dates into Julian Days.
+//!
 //! We first calculate the VSOP87 elements:
 //!
 //! ```
@@ -66,16 +72,39 @@
 //! assert!(elements.inclination() > 0.122260 && elements.inclination() < 0.122261);
 //! assert!(elements.ascending_node() > 0.843525 && elements.ascending_node() < 0.843527);
 //! assert!(elements.periapsis() > 1.35183 && elements.periapsis() < 1.35185);
-//! assert!(elements.mean_anomaly() > 4.40259 && elements.mean_anomaly() < 4.40261);
+//! assert!(elements.mean_anomaly() > 3.05075 && elements.mean_anomaly() < 3.05077);
 //! ```
 //!
 //! As you can see, these numbers perfectly match
 //! [those from NASA](http://solarsystem.nasa.gov/planets/mercury/facts).
+//!
+//! # Planet features
+//!
+//! The coefficient tables make up most of the size of the crate. Each planet has a cargo feature
+//! (`mercury`, `venus`, `earth`, `mars`, `jupiter`, `saturn`, `uranus` and `neptune`) that
+//! includes its tables and functions in every solution, and the default `all_planets` feature
+//! enables all of them. An application that only needs a
+//! few planets can disable the default features and select them:
+//!
+//! ```toml
+//! [dependencies]
+//! vsop87 = { version = "2", default-features = false, features = ["simd", "earth", "mars"] }
+//! ```
+//!
+//! This reduces the size of the compiled library to less than a third. The
+//! [`geocentric`](geocentric/index.html) module and the functions that need the position of the
+//! Earth require the `earth` feature, and the functions that return all the planets at once
+//! require `all_planets`. At least one planet must be enabled.
+//!
+//! The features are additive: the [`Planet`](enum.Planet.html) enum always has every variant,
+//! and the functions that take a `Planet` panic if its feature is disabled, which
+//! [`Planet::is_enabled`](enum.Planet.html#method.is_enabled) checks beforehand. The fallible
+//! [`vsop87d::try_planet()`](vsop87d/fn.try_planet.html) returns
+//! [`Vsop87Error::NotEnabled`](enum.Vsop87Error.html#variant.NotEnabled) instead.
 
 #![forbid(
     missing_docs,
     anonymous_parameters,
-    unused_extern_crates,
     unused_import_braces,
     missing_copy_implementations,
     trivial_casts,
@@ -83,204 +112,2942 @@
     missing_debug_implementations,
     trivial_numeric_casts
 )]
-// Debug trait derivation will show an error if forbidden.
-#![deny(unused_qualifications, unsafe_code)]
+// Debug trait derivation will show an error if forbidden, and so will the serde derives.
+#![deny(unused_qualifications, unused_extern_crates, unsafe_code)]
 #![deny(clippy::all)]
 #![warn(clippy::pedantic)]
 #![allow(
     clippy::many_single_char_names,
     clippy::unreadable_literal,
     clippy::excessive_precision,
-    clippy::must_use_candidate
+    clippy::must_use_candidate,
+    clippy::large_const_arrays
 )]
 #![cfg_attr(all(test, feature = "no_std"), allow(unused_imports))]
 // Features
 #![cfg_attr(feature = "no_std", no_std)]
 // All the "allow by default" lints
-#![warn(box_pointers, unused_results)]
+#![warn(unused_results)]
+
+#[cfg(not(any(
+    feature = "mercury",
+    feature = "venus",
+    feature = "earth",
+    feature = "mars",
+    feature = "jupiter",
+    feature = "saturn",
+    feature = "uranus",
+    feature = "neptune"
+)))]
+compile_error!("at least one planet feature must be enabled, such as `mars` or `all_planets`");
 
+#[cfg(feature = "earth")]
+pub mod events;
+#[cfg(not(feature = "no_std"))]
+pub mod export;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "earth")]
+pub mod geocentric;
+#[cfg(not(feature = "no_std"))]
+pub mod interp;
+pub mod moon;
+pub mod nutation;
+#[cfg(feature = "earth")]
+pub mod observer;
+#[cfg(feature = "earth")]
+pub mod physical;
+pub mod precession;
+#[cfg(feature = "earth")]
+pub mod topocentric;
 pub mod vsop87a;
 pub mod vsop87b;
 pub mod vsop87c;
 pub mod vsop87d;
 pub mod vsop87e;
 
+#[cfg(feature = "earth")]
+mod builder;
+#[cfg(not(feature = "no_std"))]
+mod cache;
+#[cfg(feature = "earth")]
 mod earth_moon;
+mod julian;
+#[cfg(feature = "jupiter")]
 mod jupiter;
+#[cfg(feature = "mars")]
 mod mars;
+#[cfg(feature = "mercury")]
 mod mercury;
+#[cfg(feature = "neptune")]
 mod neptune;
+#[cfg(feature = "saturn")]
 mod saturn;
+mod timescale;
+#[cfg(feature = "uranus")]
 mod uranus;
+mod variant;
+#[cfg(feature = "venus")]
 mod venus;
 
-#[cfg(feature = "no_std")]
-use core::f64::consts::PI;
-#[cfg(feature = "no_std")]
-use libm::{acos, asin, atan, cos, sin, sincos, sqrt};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "earth")]
+pub use builder::EphemerisBuilder;
+#[cfg(not(feature = "no_std"))]
+pub use cache::PositionCache;
+pub use julian::{calendar_date, jd_to_mjd, julian_day, mjd_to_jd};
+#[cfg(feature = "chrono")]
+pub use timescale::jde_from_chrono;
+#[cfg(feature = "time")]
+pub use timescale::jde_from_time;
+pub use timescale::{
+    apparent_sidereal_time, delta_t, greenwich_mean_sidereal_time, local_sidereal_time, tdb_to_tt,
+    tt_to_tdb, tt_to_ut, ut_to_tt,
+};
+pub use variant::{Vsop87A, Vsop87B, Vsop87C, Vsop87D, Vsop87E, Vsop87Variant};
+
+#[cfg(feature = "no_std")]
+use core::{
+    f64::consts::PI,
+    fmt,
+    ops::{Add, Div, Index, Mul, Sub},
+};
+
+#[cfg(not(feature = "no_std"))]
+use std::{
+    error::Error,
+    f64::consts::PI,
+    fmt,
+    io::{self, Write},
+    ops::{Add, Div, Index, Mul, Sub},
+};
+
+/// Gaussian gravitational constant (*k*), in radians per day.
+///
+/// It is the mean motion of a massless body orbiting the Sun at 1 *AU*, so that
+/// `GM☉ = k² AU³/d²`.
+pub const GAUSSIAN_GRAVITATIONAL_CONSTANT: f64 = 0.01720209895;
+
+/// Astronomical unit (*AU*), in kilometers.
+///
+/// This is the exact value adopted by the IAU in 2012.
+pub const AU_KM: f64 = 149_597_870.7;
+
+/// Speed of light, in *AU* per day.
+///
+/// It is 299,792.458 km/s, the exact value of the SI, converted with
+/// [`AU_KM`](constant.AU_KM.html), so the light takes about 8.3 minutes to travel one *AU*.
+pub const SPEED_OF_LIGHT_AU_PER_DAY: f64 = 173.144_632_674_240_3;
+
+/// Ratio between the mass of the Earth and the mass of the Moon (*M⊕ / M☾*).
+///
+/// The center of the Earth and the barycenter of the Earth - Moon system are about 4,670 km
+/// (3.1·10⁻⁵ *AU*) apart, in the direction of the Moon. See
+/// [`earth_moon_offset()`](fn.earth_moon_offset.html).
+pub const EARTH_MOON_MASS_RATIO: f64 = 81.30056;
+
+/// Earliest Julian Day (*JD*) accepted by the fallible functions, such as
+/// [`vsop87d::try_planet`](vsop87d/fn.try_planet.html).
+///
+/// It is J2000.0 minus 4,000 Julian years (JD 990545.0, in the year 2001 BC), the validity span
+/// given by the authors of *VSOP87* for the most precise solutions of the inner planets. The
+/// precision of the series degrades slowly outside this span, it does not fail suddenly. The span
+/// of each planet is given by [`Planet::valid_range()`](enum.Planet.html#method.valid_range).
+pub const MIN_JDE: f64 = 990_545_f64;
+
+/// Latest Julian Day (*JD*) accepted by the fallible functions, such as
+/// [`vsop87d::try_planet`](vsop87d/fn.try_planet.html).
+///
+/// It is J2000.0 plus 4,000 Julian years (JD 3912545.0, in the year 6000 AD). See
+/// [`MIN_JDE`](constant.MIN_JDE.html) for details.
+pub const MAX_JDE: f64 = 3_912_545_f64;
+
+/// Structure representing the keplerian elements of an orbit.
+///
+/// With the `serde` feature, the elements are serialized with the `eccentricity`,
+/// `semimajor_axis`, `inclination`, `ascending_node`, `periapsis` and `mean_longitude` fields.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KeplerianElements {
+    #[cfg_attr(feature = "serde", serde(rename = "eccentricity"))]
+    ecc: f64,
+    #[cfg_attr(feature = "serde", serde(rename = "semimajor_axis"))]
+    sma: f64,
+    #[cfg_attr(feature = "serde", serde(rename = "inclination"))]
+    incl: f64,
+    #[cfg_attr(feature = "serde", serde(rename = "ascending_node"))]
+    lan: f64,
+    #[cfg_attr(feature = "serde", serde(rename = "periapsis"))]
+    lper: f64,
+    #[cfg_attr(feature = "serde", serde(rename = "mean_longitude"))]
+    l0: f64,
+}
+
+impl KeplerianElements {
+    /// Gets the eccentricity of the orbit (*e*).
+    ///
+    /// A number smaller to one would be a closed ellipse, while 0 would be a circle orbit. Values
+    /// higher than one would be hyperbolic orbits, that are not closed, while a 1 would be a
+    /// parabolic orbit. Negative values cannot exist.
+    pub fn eccentricity(&self) -> f64 {
+        self.ecc
+    }
+
+    /// Gets the semimajor axis of an orbit (*a*), in *AU* (Astronomical units).
+    ///
+    /// This value represents the average distance from the orbiting body to the center of mass.
+    pub fn semimajor_axis(&self) -> f64 {
+        self.sma
+    }
+
+    /// Gets the inclination of the orbit (*i*), in radians.
+    ///
+    /// This value represents the inclination of the plane where the object is orbiting with
+    /// respect to the reference plane.
+    pub fn inclination(&self) -> f64 {
+        self.incl
+    }
+
+    /// Gets the longitude of the ascending node of the orbit (*Ω*), in radians.
+    ///
+    /// This value represents the angle in the orbit ellipse of the point where the reference plane
+    /// and the orbit plane cross when the orbiting body crosses the plane *ascending* in the orbit.
+    pub fn ascending_node(&self) -> f64 {
+        self.lan
+    }
+
+    /// Gets the longitude of the periapsis of the orbit (*ϖ*), in radians.
+    ///
+    /// This value represents the angle in the orbit ellipse of the nearest point of the orbit to
+    /// the center of mass of the system.
+    pub fn periapsis(&self) -> f64 {
+        self.lper
+    }
+
+    /// Gets the longitude of the perihelion of the orbit (*ϖ*), in radians.
+    ///
+    /// This is the same value as the [`periapsis`](#method.periapsis), with the name it takes
+    /// for heliocentric orbits. It is the sum of the longitude of the ascending node and the
+    /// argument of the perihelion.
+    pub fn perihelion_longitude(&self) -> f64 {
+        self.lper
+    }
+
+    /// Gets the argument of the periapsis of the orbit (*ω*), in radians.
+    ///
+    /// This is the classical element used instead of the [longitude of the
+    /// periapsis](#method.periapsis) by most orbital mechanics software: the angle from the
+    /// ascending node to the periapsis, measured in the plane of the orbit, `ω = ϖ − Ω`. It is
+    /// normalized into the `[0, 2π)` range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vsop87::KeplerianElements;
+    ///
+    /// let mercury = KeplerianElements::from(vsop87::mercury(2451545.0));
+    ///
+    /// assert!((mercury.argument_of_periapsis().to_degrees() - 29.12).abs() < 0.01);
+    /// ```
+    pub fn argument_of_periapsis(&self) -> f64 {
+        normalize_angle(self.lper - self.lan)
+    }
+
+    /// Gets the mean longitude of the orbiting object at the given epoch (*λ*), in radians.
+    ///
+    /// This value represents the longitude the object would have if its orbit were a circle
+    /// traveled at constant speed, measured from the reference direction.
+    pub fn mean_longitude(&self) -> f64 {
+        self.l0
+    }
+
+    /// Gets the mean motion of the orbiting object (*n*), in radians per day.
+    ///
+    /// It is computed from the semimajor axis with Kepler's third law, as `n = k / a^1.5`, where
+    /// *k* is the [Gaussian gravitational constant](constant.GAUSSIAN_GRAVITATIONAL_CONSTANT.html).
+    /// The mass of the orbiting body is neglected, so the result is slightly smaller than the
+    /// actual mean motion of the massive planets (about 0.1% for Jupiter).
+    pub fn mean_motion(&self) -> f64 {
+        GAUSSIAN_GRAVITATIONAL_CONSTANT / (self.sma * sqrt(self.sma))
+    }
+
+    /// Gets the orbital period of the orbiting object (*P*), in days.
+    ///
+    /// It is computed as `2π / n`, where *n* is the [mean motion](#method.mean_motion), so it is
+    /// about `365.25·a^1.5` days for an orbit around the Sun.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vsop87::KeplerianElements;
+    ///
+    /// let earth = KeplerianElements::from(vsop87::earth_moon(2451545.0));
+    ///
+    /// assert!(earth.period() > 365.25 && earth.period() < 365.26);
+    /// ```
+    pub fn period(&self) -> f64 {
+        2_f64 * PI / self.mean_motion()
+    }
+
+    /// Calculates the position of the orbiting object at the epoch of the elements.
+    ///
+    /// The position in the plane of the orbit is computed from the
+    /// [eccentric anomaly](#method.eccentric_anomaly), and then rotated by the argument of the
+    /// periapsis, the inclination and the longitude of the ascending node. The coordinates are
+    /// referred to the same frame as the elements, so for the *VSOP87* elements they are
+    /// heliocentric ecliptic coordinates for the J2000 equinox, in *AU*, like the
+    /// [*VSOP87A*](vsop87a/index.html) ones. Only closed orbits, with an eccentricity below one,
+    /// are supported.
+    ///
+    /// At the epoch, the *VSOP87* elements give the position of the *VSOP87A* solution. Moving the
+    /// mean longitude forward with the [mean motion](#method.mean_motion) gives a two-body orbit
+    /// that ignores the perturbations of the other planets, so it drifts away from the *VSOP87*
+    /// positions over time: it is only useful for quick interpolations close to the epoch.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vsop87::{vsop87a, KeplerianElements};
+    ///
+    /// let elements = KeplerianElements::from(vsop87::mars(2451545.0));
+    /// let position = elements.to_rectangular();
+    ///
+    /// assert!((position - vsop87a::mars(2451545.0)).norm() < 1e-6);
+    /// ```
+    pub fn to_rectangular(&self) -> RectangularCoordinates {
+        // Position in the plane of the orbit, with the X axis towards the periapsis.
+        let (sin_e, cos_e) = sin_cos(self.eccentric_anomaly());
+        let orbit_x = self.sma * (cos_e - self.ecc);
+        let orbit_y = self.semiminor_axis() * sin_e;
+
+        let (p, q) = self.orbit_axes();
+        p * orbit_x + q * orbit_y
+    }
+
+    /// Calculates the state vector of the orbiting object, `days` after the epoch of the elements.
+    ///
+    /// The mean longitude is moved forward by `days` times the [mean motion](#method.mean_motion),
+    /// and the position is computed like in [`to_rectangular`](#method.to_rectangular). The
+    /// velocity, in *AU* per day, is the derivative of that position along the orbit. With a
+    /// `days` of `0.0`, this is the state at the epoch of the elements. The orbit is a two-body
+    /// one, around a central body of gravitational parameter `k²`, so it is the inverse of
+    /// [`StateVector::to_elements`](struct.StateVector.html#method.to_elements) with that
+    /// parameter.
+    ///
+    /// The perturbations of the other planets are ignored, as in `to_rectangular`: for the
+    /// *VSOP87* elements, the state drifts away from the
+    /// [`vsop87a::state()`](vsop87a/fn.state.html) one as `days` grows.
+    ///
+    /// The elements do not store the date of their epoch, so the time is given in days since that
+    /// epoch instead of as a Julian Ephemeris Day (*JDE*). For elements computed with
+    /// [`mars(epoch)`](fn.mars.html), for example, the state at `jde` is
+    /// `to_state_vector(jde - epoch)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vsop87::{vsop87a, KeplerianElements, Planet};
+    ///
+    /// let elements = KeplerianElements::from(vsop87::mars(2451545.0));
+    /// let state = elements.to_state_vector(0.0);
+    /// let (position, velocity) = vsop87a::state(Planet::Mars, 2451545.0);
+    ///
+    /// assert!((state.position - position).norm() < 1e-6);
+    /// assert!((state.velocity - velocity).norm() < 1e-3 * velocity.norm());
+    /// ```
+    pub fn to_state_vector(&self, days: f64) -> StateVector {
+        let mean_motion = self.mean_motion();
+        let elements = Self {
+            l0: self.l0 + mean_motion * days,
+            ..*self
+        };
+
+        // Rate of the eccentric anomaly, from the derivative of Kepler's equation.
+        let (sin_e, cos_e) = sin_cos(elements.eccentric_anomaly());
+        let rate = mean_motion / (1_f64 - self.ecc * cos_e);
+
+        let (p, q) = self.orbit_axes();
+        StateVector {
+            position: elements.to_rectangular(),
+            velocity: p * (-self.sma * sin_e * rate) + q * (self.semiminor_axis() * cos_e * rate),
+        }
+    }
+
+    /// Gets the semiminor axis of the orbit (*b*), in *AU*.
+    fn semiminor_axis(&self) -> f64 {
+        self.sma * sqrt(1_f64 - self.ecc * self.ecc)
+    }
+
+    /// Gets the directions of the periapsis (*P*) and of the point 90° ahead in the orbit (*Q*),
+    /// in the reference frame of the elements.
+    fn orbit_axes(&self) -> (RectangularCoordinates, RectangularCoordinates) {
+        let (sin_w, cos_w) = sin_cos(self.argument_of_periapsis());
+        let (sin_i, cos_i) = sin_cos(self.incl);
+        let (sin_o, cos_o) = sin_cos(self.lan);
+
+        let p = RectangularCoordinates {
+            x: cos_w * cos_o - sin_w * sin_o * cos_i,
+            y: cos_w * sin_o + sin_w * cos_o * cos_i,
+            z: sin_w * sin_i,
+        };
+        let q = RectangularCoordinates {
+            x: -sin_w * cos_o - cos_w * sin_o * cos_i,
+            y: -sin_w * sin_o + cos_w * cos_o * cos_i,
+            z: cos_w * sin_i,
+        };
+
+        (p, q)
+    }
+
+    /// Gets the mean anomaly of the orbiting object at the given epoch, in radians.
+    ///
+    /// This is the angle from the periapsis that the body would have if it moved at a constant
+    /// speed along its orbit, `M = λ − ϖ`, the [mean longitude](#method.mean_longitude) minus the
+    /// [longitude of the periapsis](#method.periapsis), normalized into the `[0, 2π)` range.
+    pub fn mean_anomaly(&self) -> f64 {
+        normalize_angle(self.l0 - self.lper)
+    }
+
+    /// Gets the eccentric anomaly of the orbiting object at the given epoch, in radians.
+    ///
+    /// This is the angle from the center of the orbit ellipse, measured from the periapsis, of
+    /// the projection of the body on the circle circumscribed around the ellipse. It is computed
+    /// by solving Kepler's equation, `M = E − e·sin(E)`, with Newton's method, and normalized
+    /// into the `[0, 2π)` range. Only closed orbits, with an eccentricity below one, are
+    /// supported.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vsop87::KeplerianElements;
+    ///
+    /// let mercury = KeplerianElements::from(vsop87::mercury(2451545.0));
+    /// let e = mercury.eccentricity();
+    /// let anomaly = mercury.eccentric_anomaly();
+    ///
+    /// assert!((anomaly - e * anomaly.sin() - mercury.mean_anomaly()).abs() < 1e-12);
+    /// ```
+    pub fn eccentric_anomaly(&self) -> f64 {
+        /// Maximum number of iterations of Newton's method.
+        const MAX_ITERATIONS: u8 = 30;
+
+        let mean_anomaly = self.mean_anomaly();
+        let mut eccentric_anomaly = mean_anomaly;
+        for _ in 0..MAX_ITERATIONS {
+            let (sin_e, cos_e) = sin_cos(eccentric_anomaly);
+            let delta =
+                (eccentric_anomaly - self.ecc * sin_e - mean_anomaly) / (1_f64 - self.ecc * cos_e);
+            eccentric_anomaly -= delta;
+
+            if delta.abs() < 1e-15 {
+                break;
+            }
+        }
+
+        normalize_angle(eccentric_anomaly)
+    }
+
+    /// Gets the true anomaly of the orbiting object at the given epoch, in radians.
+    ///
+    /// This is the actual angle between the periapsis and the body, as seen from the focus of the
+    /// orbit (the Sun, for the planets), normalized into the `[0, 2π)` range. It is computed from
+    /// the [eccentric anomaly](#method.eccentric_anomaly) as
+    /// `ν = 2·atan2(√(1 + e)·sin(E / 2), √(1 − e)·cos(E / 2))`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vsop87::KeplerianElements;
+    ///
+    /// // Mercury was close to its aphelion on January 1st, 2000.
+    /// let mercury = KeplerianElements::from(vsop87::mercury(2451545.0));
+    ///
+    /// assert!((mercury.true_anomaly().to_degrees() - 180.0).abs() < 5.0);
+    /// ```
+    pub fn true_anomaly(&self) -> f64 {
+        let (sin_half, cos_half) = sin_cos(self.eccentric_anomaly() / 2_f64);
+
+        let true_anomaly = 2_f64
+            * atan2(
+                sqrt(1_f64 + self.ecc) * sin_half,
+                sqrt(1_f64 - self.ecc) * cos_half,
+            );
+
+        normalize_angle(true_anomaly)
+    }
+}
+
+impl From<VSOP87Elements> for KeplerianElements {
+    fn from(elts: VSOP87Elements) -> Self {
+        let ecc = sqrt(elts.h * elts.h + elts.k * elts.k);
+        let i = acos(1_f64 - 2_f64 * (elts.p * elts.p + elts.q * elts.q));
+        let lan = atan2(elts.p, elts.q);
+        let lper = atan2(elts.h, elts.k);
+
+        Self {
+            ecc,
+            sma: elts.a,
+            incl: i,
+            lan: normalize_angle(lan),
+            lper: normalize_angle(lper),
+            l0: elts.l,
+        }
+    }
+}
+
+/// Structure representing 3 dimensional rectangular coordinates.
+///
+/// With the `serde` feature, the coordinates are serialized with the `x`, `y` and `z` fields.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RectangularCoordinates {
+    /// X coordinate.
+    pub x: f64,
+    /// Y coordinate.
+    pub y: f64,
+    /// Z coordinate.
+    pub z: f64,
+}
+
+impl RectangularCoordinates {
+    /// Gets the norm (length) of the vector from the origin to the coordinates.
+    ///
+    /// For heliocentric coordinates, this is the distance to the Sun, in *AU*.
+    pub fn norm(&self) -> f64 {
+        sqrt(self.dot(self))
+    }
+
+    /// Gets the norm (length) of the vector from the origin to the coordinates, in kilometers.
+    ///
+    /// This is [`norm`](#method.norm) converted with [`AU_KM`](constant.AU_KM.html).
+    pub fn norm_km(&self) -> f64 {
+        self.norm() * AU_KM
+    }
+
+    /// Checks whether two positions are equal within the given tolerance, in *AU*.
+    ///
+    /// The positions are equal if the norm of their difference is at most `tol`.
+    pub fn approx_eq(&self, other: &Self, tol: f64) -> bool {
+        (*self - *other).norm() <= tol
+    }
+
+    /// Gets the coordinates as a `(x, y, z)` tuple.
+    pub fn as_tuple(&self) -> (f64, f64, f64) {
+        (self.x, self.y, self.z)
+    }
+
+    /// Calculates the dot (scalar) product of the two vectors.
+    pub fn dot(&self, other: &Self) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Calculates the cross (vector) product of the two vectors.
+    ///
+    /// The result is perpendicular to both vectors, and follows the right-hand rule.
+    #[must_use]
+    pub fn cross(&self, other: &Self) -> Self {
+        Self {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+
+    /// Rotates the coordinates from the ecliptic and equinox of one date to those of another.
+    ///
+    /// The `from` and `to` parameters are the Julian Days (*JD*) of the mean ecliptic and
+    /// equinox the coordinates are referred to before and after the rotation. See the
+    /// [`precession`](precession/index.html) module for more information.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vsop87::vsop87a;
+    ///
+    /// // Precessing to another date and back gives the original position again.
+    /// let j2000 = vsop87a::jupiter(2451545.0);
+    /// let back = j2000.precess(2451545.0, 2415020.0).precess(2415020.0, 2451545.0);
+    ///
+    /// assert!((back - j2000).norm() < 1e-9);
+    /// ```
+    #[must_use]
+    pub fn precess(&self, from: f64, to: f64) -> Self {
+        let m = precession::ecliptic_precession_matrix(from, to);
+
+        Self {
+            x: m[0][0] * self.x + m[0][1] * self.y + m[0][2] * self.z,
+            y: m[1][0] * self.x + m[1][1] * self.y + m[1][2] * self.z,
+            z: m[2][0] * self.x + m[2][1] * self.y + m[2][2] * self.z,
+        }
+    }
+
+    /// Rotates ecliptic rectangular coordinates into the equatorial frame.
+    ///
+    /// The rotation is around the *X* axis, which points to the equinox in both frames, by the
+    /// given obliquity of the ecliptic, in radians: `x' = x`, `y' = y·cos(ε) − z·sin(ε)` and
+    /// `z' = y·sin(ε) + z·cos(ε)`. For coordinates referred to the equinox of the day, such as the
+    /// *VSOP87C* ones, the obliquity can be computed with
+    /// [`mean_obliquity`](fn.mean_obliquity.html). The resulting *Z* axis points to the north
+    /// celestial pole.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vsop87::{mean_obliquity, vsop87c};
+    ///
+    /// let jde = 2451545.0;
+    /// let ecliptic = vsop87c::mars(jde);
+    /// let equatorial = ecliptic.to_equatorial_frame(mean_obliquity(jde));
+    ///
+    /// // The rotation keeps the distance.
+    /// assert!((equatorial.norm() - ecliptic.norm()).abs() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn to_equatorial_frame(&self, obliquity: f64) -> Self {
+        let (sin_obl, cos_obl) = sin_cos(obliquity);
+
+        Self {
+            x: self.x,
+            y: self.y * cos_obl - self.z * sin_obl,
+            z: self.y * sin_obl + self.z * cos_obl,
+        }
+    }
+
+    /// Converts the rectangular coordinates into spherical coordinates.
+    ///
+    /// The distance is computed as `r = sqrt(x² + y² + z²)`, the longitude as `l = atan2(y, x)`,
+    /// normalized into the `[0, 2π)` range in the same way the *VSOP87B* and *VSOP87D* solutions
+    /// do, and the latitude as `b = asin(z / r)`.
+    ///
+    /// # Example
+    ///
+    /// The *VSOP87A* coordinates converted to spherical coordinates match the *VSOP87B*
+    /// solution, since both are referred to the J2000 equinox:
+    ///
+    /// ```
+    /// use vsop87::{vsop87a, vsop87b};
+    ///
+    /// let spherical = vsop87a::jupiter(2451545.0).to_spherical();
+    /// let expected = vsop87b::jupiter(2451545.0);
+    ///
+    /// assert!((spherical.longitude() - expected.longitude()).abs() < 1e-7);
+    /// assert!((spherical.latitude() - expected.latitude()).abs() < 1e-7);
+    /// assert!((spherical.distance() - expected.distance()).abs() < 1e-6);
+    /// ```
+    pub fn to_spherical(&self) -> SphericalCoordinates {
+        let dist = self.norm();
+        let lon = atan2(self.y, self.x);
+
+        SphericalCoordinates {
+            lon: normalize_angle(lon),
+            lat: asin(self.z / dist),
+            dist,
+        }
+    }
+
+    /// Gets a 4×4 transformation matrix that translates the origin to the coordinates, in *AU*.
+    ///
+    /// This is [`to_translation_matrix_scaled`](#method.to_translation_matrix_scaled) with a
+    /// scale of 1.
+    pub fn to_translation_matrix(&self) -> [[f64; 4]; 4] {
+        self.to_translation_matrix_scaled(1_f64)
+    }
+
+    /// Gets a 4×4 transformation matrix that translates the origin to the coordinates, with the
+    /// given number of world units per *AU*.
+    ///
+    /// The matrix is in column-major order, as used by OpenGL, Vulkan and most graphics
+    /// libraries: `matrix[column][row]`. The first three columns are the identity rotation, and
+    /// the fourth one is the translation, `[x·scale, y·scale, z·scale, 1]`. With the `glam`
+    /// feature, it can be converted into a model matrix with `glam::DMat4::from_cols_array_2d()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vsop87::vsop87a;
+    ///
+    /// let coordinates = vsop87a::mars(2451545.0);
+    /// let matrix = coordinates.to_translation_matrix_scaled(100.0);
+    ///
+    /// assert_eq!(matrix[3][0], coordinates.x * 100.0);
+    /// assert_eq!(matrix[3][3], 1.0);
+    /// ```
+    pub fn to_translation_matrix_scaled(&self, scale: f64) -> [[f64; 4]; 4] {
+        [
+            [1_f64, 0_f64, 0_f64, 0_f64],
+            [0_f64, 1_f64, 0_f64, 0_f64],
+            [0_f64, 0_f64, 1_f64, 0_f64],
+            [self.x * scale, self.y * scale, self.z * scale, 1_f64],
+        ]
+    }
+}
+
+impl Add for RectangularCoordinates {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl Sub for RectangularCoordinates {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+impl Mul<f64> for RectangularCoordinates {
+    type Output = Self;
+
+    fn mul(self, factor: f64) -> Self {
+        Self {
+            x: self.x * factor,
+            y: self.y * factor,
+            z: self.z * factor,
+        }
+    }
+}
+
+impl Div<f64> for RectangularCoordinates {
+    type Output = Self;
+
+    fn div(self, divisor: f64) -> Self {
+        Self {
+            x: self.x / divisor,
+            y: self.y / divisor,
+            z: self.z / divisor,
+        }
+    }
+}
+
+impl From<SphericalCoordinates> for RectangularCoordinates {
+    /// Converts spherical coordinates into rectangular coordinates, with
+    /// [`to_rectangular`](struct.SphericalCoordinates.html#method.to_rectangular).
+    fn from(coordinates: SphericalCoordinates) -> Self {
+        coordinates.to_rectangular()
+    }
+}
+
+impl From<RectangularCoordinates> for (f64, f64, f64) {
+    /// Converts the coordinates into a `(x, y, z)` tuple, with
+    /// [`as_tuple`](struct.RectangularCoordinates.html#method.as_tuple).
+    fn from(coordinates: RectangularCoordinates) -> Self {
+        coordinates.as_tuple()
+    }
+}
+
+/// Converts the coordinates into a `glam` vector, in *AU*.
+///
+/// The components keep the convention of the *VSOP87* solutions: the *X* axis points towards the
+/// equinox, the *Y* axis is 90° east of it in the ecliptic, and the *Z* axis points towards the
+/// north pole of the ecliptic. Only available with the `glam` feature.
+#[cfg(feature = "glam")]
+impl From<RectangularCoordinates> for glam::DVec3 {
+    fn from(coordinates: RectangularCoordinates) -> Self {
+        Self::new(coordinates.x, coordinates.y, coordinates.z)
+    }
+}
+
+/// Converts a `glam` vector, in *AU*, into rectangular coordinates. Only available with the
+/// `glam` feature.
+#[cfg(feature = "glam")]
+impl From<glam::DVec3> for RectangularCoordinates {
+    fn from(vector: glam::DVec3) -> Self {
+        Self {
+            x: vector.x,
+            y: vector.y,
+            z: vector.z,
+        }
+    }
+}
+
+/// Converts the coordinates into a single precision `glam` vector, in *AU*.
+///
+/// The axes are the same as for the `DVec3` conversion. Only available with the `glam` feature.
+#[cfg(feature = "glam")]
+impl From<RectangularCoordinates> for glam::Vec3 {
+    #[allow(clippy::cast_possible_truncation)]
+    fn from(coordinates: RectangularCoordinates) -> Self {
+        Self::new(
+            coordinates.x as f32,
+            coordinates.y as f32,
+            coordinates.z as f32,
+        )
+    }
+}
+
+/// Converts a single precision `glam` vector, in *AU*, into rectangular coordinates. Only
+/// available with the `glam` feature.
+#[cfg(feature = "glam")]
+impl From<glam::Vec3> for RectangularCoordinates {
+    fn from(vector: glam::Vec3) -> Self {
+        Self {
+            x: f64::from(vector.x),
+            y: f64::from(vector.y),
+            z: f64::from(vector.z),
+        }
+    }
+}
+
+/// Converts the coordinates into a `nalgebra` vector, in *AU*.
+///
+/// The components keep the convention of the *VSOP87* solutions: the *X* axis points towards the
+/// equinox, the *Y* axis is 90° east of it in the ecliptic, and the *Z* axis points towards the
+/// north pole of the ecliptic. Only available with the `nalgebra` feature.
+#[cfg(feature = "nalgebra")]
+impl From<RectangularCoordinates> for nalgebra::Vector3<f64> {
+    fn from(coordinates: RectangularCoordinates) -> Self {
+        Self::new(coordinates.x, coordinates.y, coordinates.z)
+    }
+}
+
+/// Converts a `nalgebra` vector, in *AU*, into rectangular coordinates. Only available with the
+/// `nalgebra` feature.
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Vector3<f64>> for RectangularCoordinates {
+    fn from(vector: nalgebra::Vector3<f64>) -> Self {
+        Self {
+            x: vector.x,
+            y: vector.y,
+            z: vector.z,
+        }
+    }
+}
+
+/// Formats the coordinates in *AU*.
+///
+/// The precision of the formatter sets the number of decimals, 8 by default (about 1.5 km).
+impl fmt::Display for RectangularCoordinates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "x {:.p$} AU, y {:.p$} AU, z {:.p$} AU",
+            self.x,
+            self.y,
+            self.z,
+            p = f.precision().unwrap_or(8)
+        )
+    }
+}
+
+/// Structure representing the state vector of a body: its position and velocity.
+///
+/// The position is in *AU*, and the velocity in *AU* per day, in the same reference frame. The
+/// `state()` functions of the rectangular solutions, such as
+/// [`vsop87a::state()`](vsop87a/fn.state.html), return a `(position, velocity)` pair that can be
+/// converted into a state vector with `StateVector::from()`.
+///
+/// With the `serde` feature, the state vector is serialized with the `position` and `velocity`
+/// fields.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StateVector {
+    /// Position of the body, in *AU*.
+    pub position: RectangularCoordinates,
+    /// Velocity of the body, in *AU* per day.
+    pub velocity: RectangularCoordinates,
+}
+
+impl StateVector {
+    /// Calculates the keplerian elements of the orbit that goes through this state.
+    ///
+    /// The `mu` parameter is the gravitational parameter of the central body, in *AU³/day²*. For
+    /// heliocentric states, it is the square of the
+    /// [Gaussian gravitational constant](constant.GAUSSIAN_GRAVITATIONAL_CONSTANT.html), the one
+    /// used by [`to_state_vector`](struct.KeplerianElements.html#method.to_state_vector), possibly
+    /// multiplied by `1 + m`, where *m* is the mass of the planet in solar masses. The elements are
+    /// referred to the same frame as the state vector, and their mean longitude is the one at the
+    /// time of the state. Only closed orbits, with an eccentricity below one, are supported. For
+    /// circular orbits the periapsis is placed at the ascending node, and for orbits in the
+    /// reference plane the ascending node is placed at the reference direction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vsop87::{vsop87a, Planet, StateVector, GAUSSIAN_GRAVITATIONAL_CONSTANT};
+    ///
+    /// let state = StateVector::from(vsop87a::state(Planet::Earth, 2451545.0));
+    /// let elements = state.to_elements(GAUSSIAN_GRAVITATIONAL_CONSTANT.powi(2));
+    ///
+    /// assert!((elements.semimajor_axis() - 1.0).abs() < 1e-3);
+    /// assert!((elements.eccentricity() - 0.0167).abs() < 1e-3);
+    /// ```
+    pub fn to_elements(&self, mu: f64) -> KeplerianElements {
+        let position = self.position;
+        let velocity = self.velocity;
+        let distance = position.norm();
+        let speed_squared = velocity.dot(&velocity);
+        let momentum = position.cross(&velocity);
+
+        // The eccentricity vector points towards the periapsis.
+        let eccentricity =
+            (position * (speed_squared - mu / distance) - velocity * position.dot(&velocity)) / mu;
+        let ecc = eccentricity.norm();
+
+        let momentum_xy = sqrt(momentum.x * momentum.x + momentum.y * momentum.y);
+        let axis_ratio = sqrt(1_f64 - ecc * ecc);
+
+        let incl = atan2(momentum_xy, momentum.z);
+        let lan = if momentum_xy > 0_f64 {
+            atan2(momentum.x, -momentum.y)
+        } else {
+            0_f64
+        };
+
+        // Directions of the ascending node and of the point 90° ahead in the orbit.
+        let (sin_o, cos_o) = sin_cos(lan);
+        let node = RectangularCoordinates {
+            x: cos_o,
+            y: sin_o,
+            z: 0_f64,
+        };
+        let ahead = (momentum / momentum.norm()).cross(&node);
+
+        let periapsis = atan2(eccentricity.dot(&ahead), eccentricity.dot(&node));
+        let latitude = atan2(position.dot(&ahead), position.dot(&node));
+        let (sin_v, cos_v) = sin_cos(latitude - periapsis);
+        let eccentric_anomaly = atan2(axis_ratio * sin_v, ecc + cos_v);
+        let mean_anomaly = eccentric_anomaly - ecc * sin_cos(eccentric_anomaly).0;
+
+        KeplerianElements {
+            ecc,
+            sma: 1_f64 / (2_f64 / distance - speed_squared / mu),
+            incl,
+            lan: normalize_angle(lan),
+            lper: normalize_angle(lan + periapsis),
+            l0: normalize_angle(lan + periapsis + mean_anomaly),
+        }
+    }
+}
+
+impl From<(RectangularCoordinates, RectangularCoordinates)> for StateVector {
+    fn from((position, velocity): (RectangularCoordinates, RectangularCoordinates)) -> Self {
+        Self { position, velocity }
+    }
+}
+
+/// Structure representing spherical coordinates of a body.
+///
+/// The coordinates are `f64` values by default. The solutions are always computed in `f64`, but
+/// they can be converted to `f32` with [`to_f32`](#method.to_f32) for code working in single
+/// precision.
+///
+/// With the `serde` feature, the coordinates are serialized with the `longitude`, `latitude` and
+/// `distance` fields.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SphericalCoordinates<T = f64> {
+    #[cfg_attr(feature = "serde", serde(rename = "longitude"))]
+    lon: T,
+    #[cfg_attr(feature = "serde", serde(rename = "latitude"))]
+    lat: T,
+    #[cfg_attr(feature = "serde", serde(rename = "distance"))]
+    dist: T,
+}
+
+impl<T: Copy> SphericalCoordinates<T> {
+    /// Creates spherical coordinates from the given longitude and latitude, in radians, and
+    /// distance, in *AU*.
+    pub fn new(longitude: T, latitude: T, distance: T) -> Self {
+        Self {
+            lon: longitude,
+            lat: latitude,
+            dist: distance,
+        }
+    }
+
+    /// Gets the ecliptic longitude of the body, in radians.
+    ///
+    /// This value represents the angular distance of an object along the ecliptic plane from the
+    /// primary direction. In the case of heliocentric coordinates, it represents the *l* parameter,
+    /// in geocentric coordinates, represents the *λ* parameter.
+    pub fn longitude(&self) -> T {
+        self.lon
+    }
+
+    /// Gets the ecliptic latitude of the body, in radians.
+    ///
+    /// This value represents the angular distance of an object from the ecliptic towards the north
+    /// ecliptic pole. In the case of heliocentric coordinates, it represents the *b* parameter, in
+    /// geocentric coordinates, represents the *β* parameter.
+    pub fn latitude(&self) -> T {
+        self.lat
+    }
+
+    /// Gets the distance to the center of mass, in astronomical units (*AU*).
+    ///
+    /// In the case of heliocentric coordinates, it represents the *r* parameter, in geocentric
+    /// coordinates, represents the *Δ* parameter.
+    pub fn distance(&self) -> T {
+        self.dist
+    }
+
+    /// Gets the given variable of the coordinates.
+    ///
+    /// This is useful for generic code, and is equivalent to calling the accessor of the
+    /// variable, or to indexing the coordinates with it.
+    pub fn get(&self, variable: Variable) -> T {
+        self[variable]
+    }
+
+    /// Gets the coordinates as a `(longitude, latitude, distance)` tuple.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vsop87::vsop87d;
+    ///
+    /// let (longitude, latitude, distance) = vsop87d::saturn(2451545.0).as_tuple();
+    ///
+    /// assert!(distance > 9.0 && distance < 10.1);
+    /// ```
+    pub fn as_tuple(&self) -> (T, T, T) {
+        (self.lon, self.lat, self.dist)
+    }
+}
+
+impl SphericalCoordinates {
+    /// Gets the distance to the center of mass, in kilometers.
+    ///
+    /// This is [`distance`](#method.distance) converted with [`AU_KM`](constant.AU_KM.html).
+    pub fn distance_km(&self) -> f64 {
+        self.dist * AU_KM
+    }
+
+    /// Gets the time the light takes to travel the distance to the center of mass, in minutes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vsop87::vsop87d;
+    ///
+    /// // The light of the Sun reaches Jupiter in about 41 minutes.
+    /// let minutes = vsop87d::jupiter(2451545.0).distance_light_minutes();
+    ///
+    /// assert!(minutes > 40.0 && minutes < 42.0);
+    /// ```
+    pub fn distance_light_minutes(&self) -> f64 {
+        self.dist / SPEED_OF_LIGHT_AU_PER_DAY * 1_440_f64
+    }
+
+    /// Gets the longitude of the body, in degrees.
+    ///
+    /// This is [`longitude`](#method.longitude) converted to degrees, in the `[0, 360)` range.
+    pub fn longitude_deg(&self) -> f64 {
+        self.lon.to_degrees()
+    }
+
+    /// Gets the latitude of the body, in degrees.
+    ///
+    /// This is [`latitude`](#method.latitude) converted to degrees, in the `[-90, 90]` range.
+    pub fn latitude_deg(&self) -> f64 {
+        self.lat.to_degrees()
+    }
+
+    /// Converts the coordinates to single precision.
+    ///
+    /// Single precision floats have 24 bits of mantissa, so the angles keep a precision of about
+    /// 2·10⁻⁷ radians (0.05"), and the distance a relative precision of about 6·10⁻⁸, which is
+    /// enough for graphics and most visualization needs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vsop87::vsop87d;
+    ///
+    /// let coordinates = vsop87d::mars(2451545.0);
+    /// let single = coordinates.to_f32();
+    ///
+    /// assert!((f64::from(single.longitude()) - coordinates.longitude()).abs() < 1e-6);
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_f32(&self) -> SphericalCoordinates<f32> {
+        SphericalCoordinates {
+            lon: self.lon as f32,
+            lat: self.lat as f32,
+            dist: self.dist as f32,
+        }
+    }
+
+    /// Checks whether two sets of coordinates are equal within the given tolerances.
+    ///
+    /// The longitudes and the latitudes must differ by at most `angle_tol` radians. The difference
+    /// of the longitudes is taken modulo 2π, so that 0.001 and 2π − 0.001 differ by 0.002. The
+    /// distances must differ by at most `dist_tol` times the largest of them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use vsop87::SphericalCoordinates;
+    ///
+    /// let a = SphericalCoordinates::new(0.001, 0.1, 1.0);
+    /// let b = SphericalCoordinates::new(2.0 * PI - 0.001, 0.1, 1.0 + 1e-9);
+    ///
+    /// assert!(a.approx_eq(&b, 0.003, 1e-8));
+    /// assert!(!a.approx_eq(&b, 0.001, 1e-8));
+    /// ```
+    pub fn approx_eq(&self, other: &Self, angle_tol: f64, dist_tol: f64) -> bool {
+        normalize_angle_signed(self.lon - other.lon).abs() <= angle_tol
+            && (self.lat - other.lat).abs() <= angle_tol
+            && (self.dist - other.dist).abs() <= dist_tol * self.dist.abs().max(other.dist.abs())
+    }
+
+    /// Interpolates linearly between two sets of coordinates.
+    ///
+    /// `frac` is the fraction of the way from `self`, at 0, to `other`, at 1. The longitude goes
+    /// along the shortest arc between both longitudes, even across the 0 / 2π seam, and the result
+    /// is normalized to the `[0, 2π)` range. The latitude and the distance are interpolated
+    /// linearly.
+    ///
+    /// This is only accurate for close positions, for example two samples of the same planet a
+    /// few days apart, and should not be used for longitudes more than π apart.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vsop87::SphericalCoordinates;
+    ///
+    /// let a = SphericalCoordinates::new(359_f64.to_radians(), 0.0, 1.0);
+    /// let b = SphericalCoordinates::new(3_f64.to_radians(), 0.02, 1.2);
+    /// let middle = a.lerp(&b, 0.5);
+    ///
+    /// assert!((middle.longitude_deg() - 1.0).abs() < 1e-9);
+    /// assert!((middle.latitude() - 0.01).abs() < 1e-12);
+    /// assert!((middle.distance() - 1.1).abs() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn lerp(&self, other: &Self, frac: f64) -> Self {
+        Self {
+            lon: normalize_angle(self.lon + frac * normalize_angle_signed(other.lon - self.lon)),
+            lat: self.lat + frac * (other.lat - self.lat),
+            dist: self.dist + frac * (other.dist - self.dist),
+        }
+    }
+
+    /// Converts the coordinates to fixed-point integers.
+    ///
+    /// The angles are rounded to the nearest microarcsecond, and the distance to the nearest
+    /// millimeter, as documented in [`FixedCoordinates`](struct.FixedCoordinates.html). Unlike
+    /// floats, the result can be hashed, compared for exact equality and stored compactly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vsop87::vsop87d;
+    ///
+    /// let coordinates = vsop87d::mars(2451545.0);
+    /// let fixed = coordinates.to_fixed();
+    ///
+    /// assert!((fixed.to_spherical().longitude() - coordinates.longitude()).abs() < 1e-11);
+    /// ```
+    pub fn to_fixed(&self) -> FixedCoordinates {
+        FixedCoordinates {
+            lon: to_fixed_unit(self.lon, FixedCoordinates::ANGLE_SCALE),
+            lat: to_fixed_unit(self.lat, FixedCoordinates::ANGLE_SCALE),
+            dist: to_fixed_unit(self.dist, FixedCoordinates::DISTANCE_SCALE),
+        }
+    }
+
+    /// Gets the direction cosines of the body, as `(cos b cos l, cos b sin l, sin b)`.
+    ///
+    /// These are the components of the unit vector pointing from the center of mass towards the
+    /// body, in the same ecliptic reference frame as the coordinates. They do not depend on the
+    /// distance to the body.
+    pub fn direction_cosines(&self) -> (f64, f64, f64) {
+        let (lon_sin, lon_cos) = sin_cos(self.lon);
+        let (lat_sin, lat_cos) = sin_cos(self.lat);
+        (lat_cos * lon_cos, lat_cos * lon_sin, lat_sin)
+    }
+
+    /// Converts the spherical coordinates into rectangular coordinates.
+    ///
+    /// The rectangular coordinates share the reference frame of the spherical ones: the *X* axis
+    /// points towards the equinox (longitude 0), and the *Z* axis towards the north ecliptic pole.
+    /// They are computed as `x = r·cos(b)·cos(l)`, `y = r·cos(b)·sin(l)` and `z = r·sin(b)`, in
+    /// *AU*.
+    ///
+    /// # Example
+    ///
+    /// The *VSOP87D* coordinates converted to rectangular coordinates match the *VSOP87C*
+    /// solution, since both are referred to the equinox of the day:
+    ///
+    /// ```
+    /// use vsop87::{vsop87c, vsop87d};
+    ///
+    /// let rectangular = vsop87d::mars(2451545.0).to_rectangular();
+    /// let expected = vsop87c::mars(2451545.0);
+    ///
+    /// assert!((rectangular.x - expected.x).abs() < 1e-7);
+    /// assert!((rectangular.y - expected.y).abs() < 1e-7);
+    /// assert!((rectangular.z - expected.z).abs() < 1e-7);
+    /// ```
+    pub fn to_rectangular(&self) -> RectangularCoordinates {
+        let (x, y, z) = self.direction_cosines();
+
+        RectangularCoordinates { x, y, z } * self.dist
+    }
+
+    /// Converts the ecliptic coordinates into equatorial coordinates.
+    ///
+    /// The `obliquity` is the angle between the ecliptic and the celestial equator, in radians. For
+    /// coordinates referred to the equinox of the day, it can be computed with
+    /// [`mean_obliquity`](fn.mean_obliquity.html), and for coordinates referred to the J2000
+    /// equinox it is the mean obliquity at J2000. The right ascension is normalized into the
+    /// `[0, 2π)` range, and the distance is kept as is.
+    ///
+    /// # Example
+    ///
+    /// This is example 13.a from *Astronomical Algorithms*, by Jean Meeus, for the star Pollux:
+    ///
+    /// ```
+    /// use vsop87::SphericalCoordinates;
+    ///
+    /// let ecliptic = SphericalCoordinates::new(
+    ///     113.215630_f64.to_radians(),
+    ///     6.684170_f64.to_radians(),
+    ///     1.0,
+    /// );
+    /// let equatorial = ecliptic.to_equatorial(23.4392911_f64.to_radians());
+    ///
+    /// assert!((equatorial.right_ascension().to_degrees() - 116.328942).abs() < 1e-6);
+    /// assert!((equatorial.declination().to_degrees() - 28.026183).abs() < 1e-6);
+    /// ```
+    pub fn to_equatorial(&self, obliquity: f64) -> EquatorialCoordinates {
+        let (sin_lon, cos_lon) = sin_cos(self.lon);
+        let (sin_lat, cos_lat) = sin_cos(self.lat);
+        let (sin_obl, cos_obl) = sin_cos(obliquity);
+
+        let ra = atan2(sin_lon * cos_obl - tan(self.lat) * sin_obl, cos_lon);
+
+        EquatorialCoordinates {
+            ra: normalize_angle(ra),
+            dec: asin(sin_lat * cos_obl + cos_lat * sin_obl * sin_lon),
+            dist: self.dist,
+        }
+    }
+
+    /// Converts the ecliptic coordinates into galactic coordinates.
+    ///
+    /// The coordinates are first converted to [equatorial coordinates](#method.to_equatorial) with
+    /// the given obliquity of the ecliptic, in radians, and then to galactic coordinates with
+    /// [`to_galactic`](struct.EquatorialCoordinates.html#method.to_galactic).
+    ///
+    /// The galactic frame is defined from the J2000 equator and equinox, so the coordinates must be
+    /// referred to the J2000 ecliptic and equinox, such as the *VSOP87B* ones, with the mean
+    /// obliquity at J2000. Coordinates for the equinox of the day, such as the *VSOP87D* ones,
+    /// should be [precessed](struct.RectangularCoordinates.html#method.precess) to J2000 first;
+    /// otherwise, the error grows by about 50" per year from J2000.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vsop87::{mean_obliquity, vsop87b};
+    ///
+    /// let galactic = vsop87b::jupiter(2451545.0).to_galactic(mean_obliquity(2451545.0));
+    ///
+    /// assert!(galactic.galactic_latitude().abs() < 90_f64.to_radians());
+    /// ```
+    pub fn to_galactic(&self, obliquity: f64) -> GalacticCoordinates {
+        self.to_equatorial(obliquity).to_galactic()
+    }
+
+    /// Writes the coordinates to the given writer as a JSON object.
+    ///
+    /// The object has the `longitude`, `latitude` and `distance` fields, in radians and *AU*, and
+    /// is written without any whitespace or trailing new line, which makes it suitable for
+    /// appending to [JSON Lines](https://jsonlines.org/) logs without the overhead of a full
+    /// serialization framework. JSON has no representation for the infinities and NaN, so
+    /// non-finite fields are written as `null`. Only available with the standard library.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error produced by the writer.
+    #[cfg(not(feature = "no_std"))]
+    pub fn write_json<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        write!(
+            w,
+            r#"{{"longitude":{},"latitude":{},"distance":{}}}"#,
+            JsonNumber(self.lon),
+            JsonNumber(self.lat),
+            JsonNumber(self.dist)
+        )
+    }
+}
+
+impl<T> Index<Variable> for SphericalCoordinates<T> {
+    type Output = T;
+
+    fn index(&self, variable: Variable) -> &T {
+        match variable {
+            Variable::Longitude => &self.lon,
+            Variable::Latitude => &self.lat,
+            Variable::Distance => &self.dist,
+        }
+    }
+}
+
+impl From<RectangularCoordinates> for SphericalCoordinates {
+    /// Converts rectangular coordinates into spherical coordinates, with
+    /// [`to_spherical`](struct.RectangularCoordinates.html#method.to_spherical).
+    fn from(coordinates: RectangularCoordinates) -> Self {
+        coordinates.to_spherical()
+    }
+}
+
+impl<T: Copy> From<SphericalCoordinates<T>> for (T, T, T) {
+    /// Converts the coordinates into a `(longitude, latitude, distance)` tuple, with
+    /// [`as_tuple`](struct.SphericalCoordinates.html#method.as_tuple).
+    fn from(coordinates: SphericalCoordinates<T>) -> Self {
+        coordinates.as_tuple()
+    }
+}
+
+/// Formats the longitude and latitude in degrees, and the distance in *AU*.
+///
+/// The precision of the formatter sets the number of decimals of the angles, 6 by default, and the
+/// distance is written with 8 decimals. The alternate form (`{:#}`) writes the angles in degrees,
+/// arcminutes and arcseconds, and the precision then sets the decimals of the arcseconds, 2 by
+/// default.
+impl fmt::Display for SphericalCoordinates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            let decimals = f.precision().unwrap_or(2);
+            f.write_str("longitude ")?;
+            write_sexagesimal(f, self.longitude_deg(), decimals, DEGREE_SYMBOLS)?;
+            f.write_str(", latitude ")?;
+            write_sexagesimal(f, self.latitude_deg(), decimals, DEGREE_SYMBOLS)?;
+        } else {
+            write!(
+                f,
+                "longitude {:.p$}°, latitude {:.p$}°",
+                self.longitude_deg(),
+                self.latitude_deg(),
+                p = f.precision().unwrap_or(6)
+            )?;
+        }
+
+        write!(f, ", distance {:.8} AU", self.dist)
+    }
+}
+
+/// Structure representing equatorial coordinates of a body.
+///
+/// With the `serde` feature, the coordinates are serialized with the `right_ascension`,
+/// `declination` and `distance` fields.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EquatorialCoordinates {
+    #[cfg_attr(feature = "serde", serde(rename = "right_ascension"))]
+    ra: f64,
+    #[cfg_attr(feature = "serde", serde(rename = "declination"))]
+    dec: f64,
+    #[cfg_attr(feature = "serde", serde(rename = "distance"))]
+    dist: f64,
+}
+
+impl EquatorialCoordinates {
+    /// Converts ecliptic coordinates for the equinox of the day into equatorial coordinates.
+    ///
+    /// This is [`to_equatorial`](struct.SphericalCoordinates.html#method.to_equatorial) with the
+    /// [`mean_obliquity`](fn.mean_obliquity.html) at the given Julian Day (*JD*), which suits the
+    /// *VSOP87D* coordinates, or geocentric ones. For coordinates referred to the J2000 equinox,
+    /// such as the *VSOP87B* ones, use J2000 (JD 2451545.0) as `jde`. The result is referred to the
+    /// mean equator and equinox of that date: for the true ones, add the nutation to the obliquity
+    /// and use `to_equatorial` instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vsop87::{geocentric, mean_obliquity, EquatorialCoordinates, Planet};
+    ///
+    /// let jde = 2451545.0;
+    /// let mars = geocentric::planet(Planet::Mars, jde);
+    /// let equatorial = EquatorialCoordinates::from_ecliptic(&mars, jde);
+    ///
+    /// assert_eq!(equatorial, mars.to_equatorial(mean_obliquity(jde)));
+    /// ```
+    pub fn from_ecliptic(coordinates: &SphericalCoordinates, jde: f64) -> Self {
+        coordinates.to_equatorial(mean_obliquity(jde))
+    }
+
+    /// Gets the right ascension of the body, in radians.
+    ///
+    /// This value represents the angular distance of an object along the celestial equator from
+    /// the vernal equinox, eastwards, in the `[0, 2π)` range. It is the *α* parameter.
+    pub fn right_ascension(&self) -> f64 {
+        self.ra
+    }
+
+    /// Gets the declination of the body, in radians.
+    ///
+    /// This value represents the angular distance of an object from the celestial equator towards
+    /// the north (positive) or the south (negative). It is the *δ* parameter.
+    pub fn declination(&self) -> f64 {
+        self.dec
+    }
+
+    /// Gets the distance to the body, in *AU*.
+    pub fn distance(&self) -> f64 {
+        self.dist
+    }
+
+    /// Gets the right ascension of the body, in degrees.
+    ///
+    /// This is [`right_ascension`](#method.right_ascension) converted to degrees, in the
+    /// `[0, 360)` range.
+    pub fn right_ascension_deg(&self) -> f64 {
+        self.ra.to_degrees()
+    }
+
+    /// Gets the right ascension of the body, in hours.
+    ///
+    /// The right ascension is usually given in hours, in the `[0, 24)` range, where one hour is
+    /// 15 degrees.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vsop87::SphericalCoordinates;
+    ///
+    /// let coordinates = SphericalCoordinates::new(90_f64.to_radians(), 0.0, 1.0);
+    /// let equatorial = coordinates.to_equatorial(0.0);
+    ///
+    /// assert!((equatorial.right_ascension_hours() - 6.0).abs() < 1e-12);
+    /// ```
+    pub fn right_ascension_hours(&self) -> f64 {
+        self.ra.to_degrees() / 15_f64
+    }
+
+    /// Gets the declination of the body, in degrees.
+    ///
+    /// This is [`declination`](#method.declination) converted to degrees, in the `[-90, 90]`
+    /// range.
+    pub fn declination_deg(&self) -> f64 {
+        self.dec.to_degrees()
+    }
+
+    /// Converts the equatorial coordinates into galactic coordinates.
+    ///
+    /// It uses the position of the north galactic pole and the galactic longitude of the north
+    /// celestial pole for the J2000 equinox, so the coordinates must be referred to the J2000
+    /// equator and equinox. The galactic longitude is normalized into the `[0, 2π)` range, and the
+    /// distance is kept as is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vsop87::SphericalCoordinates;
+    ///
+    /// // The north galactic pole.
+    /// let pole = SphericalCoordinates::new(
+    ///     192.85948_f64.to_radians(),
+    ///     27.12825_f64.to_radians(),
+    ///     1.0,
+    /// )
+    /// .to_equatorial(0.0);
+    ///
+    /// assert!((pole.to_galactic().galactic_latitude().to_degrees() - 90.0).abs() < 1e-6);
+    /// ```
+    pub fn to_galactic(&self) -> GalacticCoordinates {
+        let (sin_pole_dec, cos_pole_dec) = sin_cos(GALACTIC_POLE_DEC.to_radians());
+        let (sin_ra, cos_ra) = sin_cos(self.ra - GALACTIC_POLE_RA.to_radians());
+        let (sin_dec, cos_dec) = sin_cos(self.dec);
+
+        let y = cos_dec * sin_ra;
+        let x = sin_dec * cos_pole_dec - cos_dec * sin_pole_dec * cos_ra;
+        let sin_lat = sin_dec * sin_pole_dec + cos_dec * cos_pole_dec * cos_ra;
+
+        let lon = GALACTIC_LONGITUDE_CELESTIAL_POLE.to_radians() - atan2(y, x);
+        let lat = asin(sin_lat);
+
+        GalacticCoordinates {
+            lon: normalize_angle(lon),
+            lat,
+            dist: self.dist,
+        }
+    }
+}
+
+/// Formats the right ascension in hours, the declination in degrees, and the distance in *AU*.
+///
+/// The precision of the formatter sets the number of decimals of the angles, 6 by default, and the
+/// distance is written with 8 decimals. The alternate form (`{:#}`) writes the right ascension in
+/// hours, minutes and seconds, and the declination in degrees, arcminutes and arcseconds. The
+/// precision then sets the decimals of the seconds, 2 by default.
+impl fmt::Display for EquatorialCoordinates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hours = self.right_ascension_hours();
+        if f.alternate() {
+            let decimals = f.precision().unwrap_or(2);
+            f.write_str("right ascension ")?;
+            write_sexagesimal(f, hours, decimals, ['h', 'm', 's'])?;
+            f.write_str(", declination ")?;
+            write_sexagesimal(f, self.declination_deg(), decimals, DEGREE_SYMBOLS)?;
+        } else {
+            write!(
+                f,
+                "right ascension {:.p$}h, declination {:.p$}°",
+                hours,
+                self.declination_deg(),
+                p = f.precision().unwrap_or(6)
+            )?;
+        }
+
+        write!(f, ", distance {:.8} AU", self.dist)
+    }
+}
+
+/// Right ascension of the north galactic pole, in degrees, for the J2000 equinox.
+const GALACTIC_POLE_RA: f64 = 192.85948;
+
+/// Declination of the north galactic pole, in degrees, for the J2000 equinox.
+const GALACTIC_POLE_DEC: f64 = 27.12825;
+
+/// Galactic longitude of the north celestial pole, in degrees, for the J2000 equinox.
+const GALACTIC_LONGITUDE_CELESTIAL_POLE: f64 = 122.93192;
+
+/// Structure representing galactic coordinates of a body.
+///
+/// The galactic latitude is measured from the plane of the Milky Way, and the galactic longitude
+/// from the direction of the galactic center, towards the east.
+///
+/// With the `serde` feature, the coordinates are serialized with the `galactic_longitude`,
+/// `galactic_latitude` and `distance` fields.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GalacticCoordinates {
+    #[cfg_attr(feature = "serde", serde(rename = "galactic_longitude"))]
+    lon: f64,
+    #[cfg_attr(feature = "serde", serde(rename = "galactic_latitude"))]
+    lat: f64,
+    #[cfg_attr(feature = "serde", serde(rename = "distance"))]
+    dist: f64,
+}
+
+impl GalacticCoordinates {
+    /// Gets the galactic longitude of the body, in radians.
+    ///
+    /// This value represents the angular distance of an object along the galactic equator from
+    /// the galactic center, eastwards, in the `[0, 2π)` range. It is the *l* parameter.
+    pub fn galactic_longitude(&self) -> f64 {
+        self.lon
+    }
+
+    /// Gets the galactic latitude of the body, in radians.
+    ///
+    /// This value represents the angular distance of an object from the galactic equator towards
+    /// the north galactic pole (positive) or the south (negative). It is the *b* parameter.
+    pub fn galactic_latitude(&self) -> f64 {
+        self.lat
+    }
+
+    /// Gets the distance to the body, in *AU*.
+    pub fn distance(&self) -> f64 {
+        self.dist
+    }
+}
+
+/// Structure representing spherical coordinates of a body as fixed-point integers.
+///
+/// The longitude and the latitude are stored in microarcseconds (µas), and the distance in
+/// millimeters, so that a coordinate `x` in radians or *AU* is stored as the nearest integer to
+/// `x * ANGLE_SCALE` or `x * DISTANCE_SCALE`. A microarcsecond is about 5·10⁻¹² radians, far below
+/// the precision of *VSOP87*, and 64 bits are enough for distances of thousands of *AU* at the
+/// millimeter.
+///
+/// Fixed-point coordinates can be hashed and compared for exact equality, which makes them
+/// useful as keys of caches or to check reproducible results across platforms. They are created
+/// with [`SphericalCoordinates::to_fixed()`](struct.SphericalCoordinates.html#method.to_fixed).
+///
+/// With the `serde` feature, the coordinates are serialized with the `longitude`, `latitude` and
+/// `distance` fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FixedCoordinates {
+    #[cfg_attr(feature = "serde", serde(rename = "longitude"))]
+    lon: i64,
+    #[cfg_attr(feature = "serde", serde(rename = "latitude"))]
+    lat: i64,
+    #[cfg_attr(feature = "serde", serde(rename = "distance"))]
+    dist: i64,
+}
+
+impl FixedCoordinates {
+    /// Number of microarcseconds in a radian, the scale factor of the angles.
+    pub const ANGLE_SCALE: f64 = 180_f64 * 3_600_000_000_f64 / PI;
+
+    /// Number of millimeters in an astronomical unit, the scale factor of the distance.
+    pub const DISTANCE_SCALE: f64 = AU_KM * 1_000_000_f64;
+
+    /// Creates fixed-point coordinates from the given longitude and latitude, in
+    /// microarcseconds, and distance, in millimeters.
+    pub fn new(longitude: i64, latitude: i64, distance: i64) -> Self {
+        Self {
+            lon: longitude,
+            lat: latitude,
+            dist: distance,
+        }
+    }
+
+    /// Gets the longitude of the body, in microarcseconds.
+    pub fn longitude(&self) -> i64 {
+        self.lon
+    }
+
+    /// Gets the latitude of the body, in microarcseconds.
+    pub fn latitude(&self) -> i64 {
+        self.lat
+    }
+
+    /// Gets the distance to the center of mass, in millimeters.
+    pub fn distance(&self) -> i64 {
+        self.dist
+    }
+
+    /// Converts the coordinates back to floating point spherical coordinates.
+    ///
+    /// The result is within half a unit of the scale of the original coordinates: 2.5·10⁻¹²
+    /// radians for the angles, and 3.4·10⁻¹⁵ *AU* for the distance.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn to_spherical(&self) -> SphericalCoordinates {
+        SphericalCoordinates::new(
+            self.lon as f64 / Self::ANGLE_SCALE,
+            self.lat as f64 / Self::ANGLE_SCALE,
+            self.dist as f64 / Self::DISTANCE_SCALE,
+        )
+    }
+}
+
+/// Converts a value to the nearest integer multiple of the unit of the given scale.
+#[allow(clippy::cast_possible_truncation)]
+fn to_fixed_unit(value: f64, scale: f64) -> i64 {
+    #[cfg(feature = "no_std")]
+    {
+        libm::round(value * scale) as i64
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    {
+        (value * scale).round() as i64
+    }
+}
+
+/// Calculates the sine of the given angle.
+#[inline]
+pub(crate) fn sin(angle: f64) -> f64 {
+    #[cfg(feature = "no_std")]
+    {
+        libm::sin(angle)
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    {
+        angle.sin()
+    }
+}
+
+/// Calculates the cosine of the given angle.
+#[inline]
+pub(crate) fn cos(angle: f64) -> f64 {
+    #[cfg(feature = "no_std")]
+    {
+        libm::cos(angle)
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    {
+        angle.cos()
+    }
+}
+
+/// Calculates the sine and cosine of the given angle.
+#[inline]
+pub(crate) fn sin_cos(angle: f64) -> (f64, f64) {
+    #[cfg(feature = "no_std")]
+    {
+        libm::sincos(angle)
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    {
+        angle.sin_cos()
+    }
+}
+
+/// Calculates the tangent of the given angle.
+#[inline]
+pub(crate) fn tan(angle: f64) -> f64 {
+    #[cfg(feature = "no_std")]
+    {
+        libm::tan(angle)
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    {
+        angle.tan()
+    }
+}
+
+/// Rounds the given value down to the nearest integer.
+#[inline]
+pub(crate) fn floor(value: f64) -> f64 {
+    #[cfg(feature = "no_std")]
+    {
+        libm::floor(value)
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    {
+        value.floor()
+    }
+}
+
+/// Calculates the square root of the given value.
+#[inline]
+pub(crate) fn sqrt(value: f64) -> f64 {
+    #[cfg(feature = "no_std")]
+    {
+        libm::sqrt(value)
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    {
+        value.sqrt()
+    }
+}
+
+/// Calculates the arc sine of the given value.
+#[inline]
+pub(crate) fn asin(value: f64) -> f64 {
+    #[cfg(feature = "no_std")]
+    {
+        libm::asin(value)
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    {
+        value.asin()
+    }
+}
+
+/// Calculates the arc cosine of the given value.
+#[inline]
+pub(crate) fn acos(value: f64) -> f64 {
+    #[cfg(feature = "no_std")]
+    {
+        libm::acos(value)
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    {
+        value.acos()
+    }
+}
+
+/// Calculates the four-quadrant arc tangent of `y / x`, in radians.
+#[inline]
+pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+    #[cfg(feature = "no_std")]
+    {
+        libm::atan2(y, x)
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    {
+        y.atan2(x)
+    }
+}
+
+/// Symbols of the degrees, arcminutes and arcseconds.
+const DEGREE_SYMBOLS: [char; 3] = ['°', '′', '″'];
+
+/// Writes a value in sexagesimal notation, with the given decimals in the last unit.
+///
+/// The value is rounded as a whole, so that 59.999″ is written as 1′00.00″, and not as 60.00″.
+/// The number of decimals is limited to 9.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+fn write_sexagesimal(
+    f: &mut fmt::Formatter<'_>,
+    value: f64,
+    decimals: usize,
+    symbols: [char; 3],
+) -> fmt::Result {
+    let decimals = decimals.min(9);
+    let scale = 10_u64.pow(decimals as u32);
+
+    let total = (value.abs() * 3_600_f64 * scale as f64 + 0.5) as u64;
+    let units = total / (3_600 * scale);
+    let minutes = total / (60 * scale) % 60;
+    let seconds = total / scale % 60;
+
+    if value < 0_f64 && total > 0 {
+        f.write_str("-")?;
+    }
+    write!(
+        f,
+        "{}{}{:02}{}{:02}",
+        units, symbols[0], minutes, symbols[1], seconds
+    )?;
+    if decimals > 0 {
+        write!(f, ".{:0d$}", total % scale, d = decimals)?;
+    }
+
+    write!(f, "{}", symbols[2])
+}
+
+/// Number written as a JSON value, with `null` for the values that JSON cannot represent.
+#[cfg(not(feature = "no_std"))]
+struct JsonNumber(f64);
+
+#[cfg(not(feature = "no_std"))]
+impl fmt::Display for JsonNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_finite() {
+            write!(f, "{}", self.0)
+        } else {
+            f.write_str("null")
+        }
+    }
+}
+
+/// Calculates the mean obliquity of the ecliptic for the given Julian Day (*JD*), in radians.
+///
+/// The mean obliquity is the angle between the ecliptic and the mean celestial equator of the day.
+/// It is computed with the polynomial by J. Laskar, valid over 10,000 years before and after
+/// J2000. It does not include the nutation in obliquity. The expressions of the IAU models are
+/// available with [`mean_obliquity_with`](fn.mean_obliquity_with.html): this one is not the IAU
+/// 1980 expression that goes with the [`nutation`](nutation/fn.nutation.html), as explained in
+/// [`Model`](enum.Model.html).
+///
+/// # Example
+///
+/// This is example 22.a from *Astronomical Algorithms*, by Jean Meeus, for April 10th, 1987:
+///
+/// ```
+/// use vsop87::mean_obliquity;
+///
+/// let obliquity = mean_obliquity(2446895.5);
+///
+/// assert!((obliquity.to_degrees() - 23.440946).abs() < 1e-6);
+/// ```
+pub fn mean_obliquity(jde: f64) -> f64 {
+    // Time in units of 10,000 Julian years since J2000.
+    let u = calculate_t(jde) / 10_f64;
+
+    let seconds = [
+        84_381.448, -4_680.93, -1.55, 1_999.25, -51.38, -249.67, -39.05, 7.12, 27.87, 5.79, 2.45,
+    ]
+    .iter()
+    .rev()
+    .fold(0_f64, |value, coefficient| value * u + coefficient);
+
+    (seconds / 3_600_f64).to_radians()
+}
+
+/// Calculates the mean obliquity of the ecliptic with the given model, in radians.
+///
+/// With [`Model::Iau1980`](enum.Model.html), the obliquity is the cubic polynomial by J. H. Lieske
+/// (1979), and with [`Model::Iau2006`](enum.Model.html), the quintic polynomial of the P03
+/// precession by N. Capitaine et al. (2003). They are meant for a few centuries around J2000.0:
+/// from 1800 to 2200 they agree with [`mean_obliquity`](fn.mean_obliquity.html) to 0.1", and a
+/// thousand years away from J2000.0 they are a few tenths of an arcsecond off. The parameter
+/// needed is the Julian Day (*JD*) for the given date.
+///
+/// # Example
+///
+/// The IAU 2006 value of the obliquity at J2000.0 is 0.042" smaller than the IAU 1980 one:
+///
+/// ```
+/// use vsop87::{mean_obliquity_with, Model};
+///
+/// let iau_1980 = mean_obliquity_with(2451545.0, Model::Iau1980);
+/// let iau_2006 = mean_obliquity_with(2451545.0, Model::Iau2006);
+///
+/// assert!(((iau_1980 - iau_2006).to_degrees() * 3600.0 - 0.042).abs() < 1e-9);
+/// ```
+pub fn mean_obliquity_with(jde: f64, model: Model) -> f64 {
+    // Time in Julian centuries since J2000.
+    let t = calculate_t(jde) * 10_f64;
+
+    let coefficients: &[f64] = match model {
+        Model::Iau1980 => &[84_381.448, -46.815, -0.000_59, 0.001_813],
+        Model::Iau2006 => &[
+            84_381.406,
+            -46.836_769,
+            -0.000_183_1,
+            0.002_003_40,
+            -0.000_000_576,
+            -0.000_000_043_4,
+        ],
+    };
+    let seconds = coefficients
+        .iter()
+        .rev()
+        .fold(0_f64, |value, coefficient| value * t + coefficient);
+
+    (seconds / 3_600_f64).to_radians()
+}
+
+/// Models of the obliquity of the ecliptic and of the nutation adopted by the IAU.
+///
+/// The IAU 1980 model is the obliquity by J. H. Lieske (1979) with the IAU 1980 theory of
+/// nutation. The IAU 2006 model is the obliquity of the P03 precession by N. Capitaine et al.
+/// (2003) with the IAU 2000 theory of nutation. Their mean obliquities differ by 0.042" at J2000.0,
+/// because of a better measurement of the obliquity at that epoch, and the difference slowly
+/// changes with time. Their nutations differ by a few hundredths of an arcsecond, because the IAU
+/// 2000 theory models the response of the non-rigid Earth better.
+///
+/// Neither model is the default of the crate. [`nutation::nutation`](nutation/fn.nutation.html)
+/// uses the IAU 1980 theory of nutation, but [`mean_obliquity`](fn.mean_obliquity.html) uses the
+/// polynomial by J. Laskar, which stays accurate over the whole span of *VSOP87*, while the IAU
+/// expressions are only meant for a few centuries around J2000.0. The apparent positions of the
+/// [`geocentric`](geocentric/index.html) and [`observer`](observer/index.html) modules use that
+/// combination, which differs from the IAU 1980 model by less than 0.1" from 1800 to 2200.
+///
+/// With the `serde` feature, the models are serialized by name, such as `"Iau2006"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Model {
+    /// The IAU 1980 obliquity and nutation.
+    Iau1980,
+    /// The IAU 2006 obliquity, with the IAU 2000B nutation.
+    Iau2006,
+}
+
+/// Calculates the angular separation between two bodies, in radians.
+///
+/// The separation is the great-circle angle between the directions of the two bodies, in the
+/// `[0, π]` range. It only depends on the longitudes and latitudes of the coordinates, and not on
+/// their distances, so both coordinates must be referred to the same center and frame, such as
+/// two geocentric positions for the same date. It is computed with the haversine formula, which
+/// is accurate for small separations, and the intermediate value is clamped to `[0, 1]` to avoid
+/// domain errors due to rounding.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{angular_separation, geocentric, Planet};
+///
+/// // Great conjunction of Jupiter and Saturn, on December 21st, 2020.
+/// let jupiter = geocentric::planet(Planet::Jupiter, 2459205.25);
+/// let saturn = geocentric::planet(Planet::Saturn, 2459205.25);
+///
+/// assert!(angular_separation(&jupiter, &saturn).to_degrees() < 0.11);
+/// ```
+pub fn angular_separation(a: &SphericalCoordinates, b: &SphericalCoordinates) -> f64 {
+    let hav_lat = sin((b.lat - a.lat) / 2_f64);
+    let hav_lon = sin((b.lon - a.lon) / 2_f64);
+    let h = hav_lat * hav_lat + cos(a.lat) * cos(b.lat) * hav_lon * hav_lon;
+
+    2_f64 * asin(sqrt(h.clamp(0_f64, 1_f64)))
+}
+
+/// Normalizes an angle into the `[0, 2π)` range.
+///
+/// This is the range of the longitudes and right ascensions returned by the crate, and every
+/// solution uses this function for them. Angles already in range are returned unchanged, and
+/// `NaN` stays `NaN`.
+///
+/// # Example
+///
+/// ```
+/// use std::f64::consts::PI;
+/// use vsop87::normalize_angle;
+///
+/// assert_eq!(normalize_angle(1.0), 1.0);
+/// assert!((normalize_angle(-PI / 2.0) - 3.0 * PI / 2.0).abs() < 1e-15);
+/// assert!((normalize_angle(9.0 * PI / 2.0) - PI / 2.0).abs() < 1e-14);
+/// assert_eq!(normalize_angle(2.0 * PI), 0.0);
+/// ```
+pub fn normalize_angle(radians: f64) -> f64 {
+    let angle = radians % (2_f64 * PI);
+    let angle = if angle < 0_f64 {
+        angle + 2_f64 * PI
+    } else {
+        angle
+    };
+
+    // Adding 2π to a tiny negative angle can round to 2π itself.
+    if angle >= 2_f64 * PI {
+        0_f64
+    } else {
+        angle
+    }
+}
+
+/// Normalizes an angle into the `(-π, π]` range.
+///
+/// This is useful for latitude-like values and for differences of angles, such as the difference
+/// between two longitudes, whose sign tells which one is ahead. Angles already in range are
+/// returned unchanged, and `NaN` stays `NaN`.
+///
+/// # Example
+///
+/// ```
+/// use std::f64::consts::PI;
+/// use vsop87::normalize_angle_signed;
+///
+/// assert_eq!(normalize_angle_signed(-1.0), -1.0);
+/// assert!((normalize_angle_signed(3.0 * PI / 2.0) + PI / 2.0).abs() < 1e-15);
+/// assert_eq!(normalize_angle_signed(-PI), PI);
+/// ```
+pub fn normalize_angle_signed(radians: f64) -> f64 {
+    if radians > -PI && radians <= PI {
+        return radians;
+    }
+
+    let angle = normalize_angle(radians);
+    if angle > PI {
+        angle - 2_f64 * PI
+    } else {
+        angle
+    }
+}
+
+/// Calculates the position of the Earth - Moon barycenter relative to the center of the Earth.
+///
+/// The *VSOP87A* to *VSOP87E* solutions give the position of the center of the Earth, while the
+/// main *VSOP87* solution and [`vsop87a::earth_moon()`](vsop87a/fn.earth_moon.html) give the
+/// position of the barycenter of the Earth - Moon system. The barycenter is in the direction of
+/// the Moon, at `1 / (1 + μ)` of its distance, where *μ* is the
+/// [`EARTH_MOON_MASS_RATIO`](constant.EARTH_MOON_MASS_RATIO.html). That is about 4,670 km, or
+/// 3.1·10⁻⁵ *AU*, which shifts the heliocentric direction of the Earth by up to 6.4".
+///
+/// The `moon` parameter is the geocentric position of the Moon, in ecliptic coordinates, with the
+/// distance in *AU*. The result is referred to the same ecliptic and equinox, and can be added to
+/// the heliocentric rectangular coordinates of the Earth to get the barycenter.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{earth_moon_offset, vsop87a, SphericalCoordinates, AU_KM};
+///
+/// // Geocentric position of the Moon on April 12th, 1992, from example 47.a of *Astronomical
+/// // Algorithms*, by Jean Meeus.
+/// let moon = SphericalCoordinates::new(
+///     133.162655_f64.to_radians(),
+///     -3.229126_f64.to_radians(),
+///     368_409.7 / AU_KM,
+/// );
+///
+/// let offset = earth_moon_offset(&moon);
+/// let barycenter = vsop87a::earth(2448724.5) + offset;
+///
+/// assert!((barycenter - vsop87a::earth_moon(2448724.5)).norm() < 1e-7);
+/// ```
+pub fn earth_moon_offset(moon: &SphericalCoordinates) -> RectangularCoordinates {
+    moon.to_rectangular() / (1_f64 + EARTH_MOON_MASS_RATIO)
+}
+
+/// Converts a barycentric position into a heliocentric position.
+///
+/// Both parameters are positions relative to the barycenter of the solar system, such as the
+/// [*VSOP87E*](vsop87e/index.html) ones, for the same date: the position of the body, and the one
+/// of the Sun, from [`vsop87e::sun()`](vsop87e/fn.sun.html). The result is the position of the
+/// body relative to the center of the Sun, in the same reference frame. With the *VSOP87E*
+/// solutions, it closely matches the [*VSOP87A*](vsop87a/index.html) coordinates, as in
+/// [`vsop87e::heliocentric()`](vsop87e/fn.heliocentric.html).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{barycentric_to_heliocentric, vsop87a, vsop87e};
+///
+/// let jde = 2451545.0;
+/// let mars = barycentric_to_heliocentric(&vsop87e::mars(jde), &vsop87e::sun(jde));
+///
+/// assert!((mars - vsop87a::mars(jde)).norm() < 1e-6);
+/// ```
+pub fn barycentric_to_heliocentric(
+    body: &RectangularCoordinates,
+    sun: &RectangularCoordinates,
+) -> RectangularCoordinates {
+    *body - *sun
+}
+
+/// Converts a heliocentric position into a barycentric position.
+///
+/// This is the inverse of [`barycentric_to_heliocentric()`](fn.barycentric_to_heliocentric.html):
+/// the parameters are the position of the body relative to the center of the Sun, such as the
+/// [*VSOP87A*](vsop87a/index.html) ones, and the position of the Sun relative to the barycenter of
+/// the solar system, from [`vsop87e::sun()`](vsop87e/fn.sun.html), for the same date and in the
+/// same reference frame.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{heliocentric_to_barycentric, vsop87a, vsop87e};
+///
+/// let jde = 2451545.0;
+/// let mars = heliocentric_to_barycentric(&vsop87a::mars(jde), &vsop87e::sun(jde));
+///
+/// assert!((mars - vsop87e::mars(jde)).norm() < 1e-6);
+/// ```
+pub fn heliocentric_to_barycentric(
+    body: &RectangularCoordinates,
+    sun: &RectangularCoordinates,
+) -> RectangularCoordinates {
+    *body + *sun
+}
+
+/// Converts *VSOP87* spherical coordinates from the dynamical reference frame to the FK5 system.
+///
+/// The *VSOP87* solutions are referred to the dynamical ecliptic and equinox, which differ
+/// slightly from the FK5 system used by star catalogs. This function applies the correction given
+/// by Jean Meeus in *Astronomical Algorithms*, equation 32.3: with `λ' = L − 1.397°·T −
+/// 0.00031°·T²`, where *T* is the time in Julian centuries from J2000.0,
+///
+/// - `ΔL = −0.09033" + 0.03916"·(cos λ' + sin λ')·tan B`,
+/// - `ΔB = +0.03916"·(cos λ' − sin λ')`.
+///
+/// The correction is below 0.1" for bodies close to the ecliptic, so it only matters to compare
+/// positions with FK5 or ICRS catalogs at the sub-arcsecond level. The `jde` parameter is the
+/// Julian Day (*JD*) of the equinox of the coordinates: the date itself for the *VSOP87D* and
+/// [`geocentric`](geocentric/index.html) coordinates, which are referred to the equinox of the
+/// day, and J2000.0 (2451545.0) for the *VSOP87B* coordinates. The distance is not modified.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87_to_fk5, vsop87b};
+///
+/// let coordinates = vsop87b::mars(2451545.0);
+/// let fk5 = vsop87_to_fk5(2451545.0, &coordinates);
+///
+/// let difference = (fk5.longitude() - coordinates.longitude()).to_degrees() * 3600.0;
+/// assert!(difference < 0.0 && difference > -0.1);
+/// ```
+pub fn vsop87_to_fk5(jde: f64, coordinates: &SphericalCoordinates) -> SphericalCoordinates {
+    let t = calculate_t(jde) * 10_f64;
+    let lambda = coordinates.lon - (1.397 * t + 0.000_31 * t * t).to_radians();
+    let (sin_lambda, cos_lambda) = sin_cos(lambda);
+
+    let tan_lat = tan(coordinates.lat);
+
+    let delta_lon = -0.090_33 + 0.039_16 * (cos_lambda + sin_lambda) * tan_lat;
+    let delta_lat = 0.039_16 * (cos_lambda - sin_lambda);
+
+    SphericalCoordinates {
+        lon: normalize_angle(coordinates.lon + (delta_lon / 3_600_f64).to_radians()),
+        lat: coordinates.lat + (delta_lat / 3_600_f64).to_radians(),
+        dist: coordinates.dist,
+    }
+}
+
+/// Errors returned by the fallible *VSOP87* functions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Vsop87Error {
+    /// The Julian Day is outside of the validity span of the solution.
+    OutOfRange {
+        /// The Julian Day that was requested.
+        jde: f64,
+        /// Earliest valid Julian Day.
+        min: f64,
+        /// Latest valid Julian Day.
+        max: f64,
+    },
+    /// The cargo feature of the planet is not enabled, so its coefficient tables are not
+    /// available.
+    NotEnabled {
+        /// The planet that was requested.
+        planet: Planet,
+        /// The Julian Day that was requested.
+        jde: f64,
+    },
+}
+
+impl Vsop87Error {
+    /// Checks that the given Julian Day is inside the given `(min, max)` span.
+    fn check_span(jde: f64, (min, max): (f64, f64)) -> Result<(), Self> {
+        if (min..=max).contains(&jde) {
+            Ok(())
+        } else {
+            Err(Self::OutOfRange { jde, min, max })
+        }
+    }
+}
+
+impl fmt::Display for Vsop87Error {
+    // Inlined format arguments are not available in the minimum supported Rust version.
+    #[allow(clippy::uninlined_format_args)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfRange { jde, min, max } => write!(
+                f,
+                "Julian Day {} is out of the valid range ({} to {})",
+                jde, min, max
+            ),
+            Self::NotEnabled { planet, jde } => write!(
+                f,
+                "cannot compute {:?} for Julian Day {}, enable the `{}` feature",
+                planet,
+                jde,
+                planet.feature()
+            ),
+        }
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl Error for Vsop87Error {}
+
+/// Error returned when a coefficient table does not give its reference position.
+///
+/// This is returned by [`vsop87d::verify_tables()`](vsop87d/fn.verify_tables.html), and means that
+/// the coefficients in memory have been corrupted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TableError {
+    /// The planet whose tables failed the check.
+    pub planet: Planet,
+    /// The variable whose series gave a wrong value.
+    pub variable: Variable,
+}
+
+impl fmt::Display for TableError {
+    // Inlined format arguments are not available in the minimum supported Rust version.
+    #[allow(clippy::uninlined_format_args)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the {:?} tables of {:?} do not give the reference position",
+            self.variable, self.planet
+        )
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl Error for TableError {}
+
+/// Variables of the spherical coordinates of a body.
+///
+/// With the `serde` feature, the variables are serialized by name, such as `"Longitude"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Variable {
+    /// The ecliptic longitude, in radians.
+    Longitude,
+    /// The ecliptic latitude, in radians.
+    Latitude,
+    /// The distance to the center of mass, in *AU*.
+    Distance,
+}
+
+/// Planets of the solar system with a *VSOP87* solution.
+///
+/// Every planet is always part of the enumeration, but its coefficient tables, and so its
+/// positions, are only available with its cargo feature, such as `mars`, enabled by default with
+/// `all_planets`. The functions that calculate the position of a planet given as a `Planet` panic
+/// if its feature is not enabled, which can be checked with
+/// [`is_enabled`](enum.Planet.html#method.is_enabled), except for the fallible ones, such as
+/// [`vsop87d::try_planet()`](vsop87d/fn.try_planet.html), that return
+/// [`Vsop87Error::NotEnabled`](enum.Vsop87Error.html#variant.NotEnabled).
+///
+/// With the `serde` feature, the planets are serialized by name, such as `"Mars"`, so that
+/// positions can be stored along with the planet they belong to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Planet {
+    /// The planet Mercury.
+    Mercury,
+    /// The planet Venus.
+    Venus,
+    /// The planet Earth.
+    Earth,
+    /// The planet Mars.
+    Mars,
+    /// The planet Jupiter.
+    Jupiter,
+    /// The planet Saturn.
+    Saturn,
+    /// The planet Uranus.
+    Uranus,
+    /// The planet Neptune.
+    Neptune,
+}
+
+impl Planet {
+    /// All the planets, in order of distance to the Sun.
+    pub const ALL: [Self; 8] = [
+        Self::Mercury,
+        Self::Venus,
+        Self::Earth,
+        Self::Mars,
+        Self::Jupiter,
+        Self::Saturn,
+        Self::Uranus,
+        Self::Neptune,
+    ];
+
+    /// Checks if the coefficient tables of the planet are available, that is, if its cargo
+    /// feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vsop87::Planet;
+    ///
+    /// // All the planets are enabled by default.
+    /// assert!(Planet::ALL.iter().all(|planet| planet.is_enabled()));
+    /// ```
+    pub fn is_enabled(self) -> bool {
+        match self {
+            Self::Mercury => cfg!(feature = "mercury"),
+            Self::Venus => cfg!(feature = "venus"),
+            Self::Earth => cfg!(feature = "earth"),
+            Self::Mars => cfg!(feature = "mars"),
+            Self::Jupiter => cfg!(feature = "jupiter"),
+            Self::Saturn => cfg!(feature = "saturn"),
+            Self::Uranus => cfg!(feature = "uranus"),
+            Self::Neptune => cfg!(feature = "neptune"),
+        }
+    }
+
+    /// Checks that the cargo feature of the planet is enabled, to compute it for the given Julian
+    /// Day.
+    fn check_enabled(self, jde: f64) -> Result<(), Vsop87Error> {
+        if self.is_enabled() {
+            Ok(())
+        } else {
+            Err(Vsop87Error::NotEnabled { planet: self, jde })
+        }
+    }
+
+    /// Panics because the cargo feature of the planet is not enabled.
+    #[cold]
+    #[track_caller]
+    fn not_enabled(self) -> ! {
+        panic!(
+            "the tables of {:?} are not available, enable the `{}` feature",
+            self,
+            self.feature()
+        )
+    }
+
+    /// Gets the name of the cargo feature of the planet.
+    fn feature(self) -> &'static str {
+        match self {
+            Self::Mercury => "mercury",
+            Self::Venus => "venus",
+            Self::Earth => "earth",
+            Self::Mars => "mars",
+            Self::Jupiter => "jupiter",
+            Self::Saturn => "saturn",
+            Self::Uranus => "uranus",
+            Self::Neptune => "neptune",
+        }
+    }
+
+    /// Gets the validity span of the *VSOP87* solution of the planet, as a `(min, max)` pair of
+    /// Julian Days (*JD*).
+    ///
+    /// The authors of *VSOP87* give the span over which the full series stay within 1" of the
+    /// numerical integration they were fitted to: 4,000 years before and after J2000.0 for Mercury,
+    /// Venus, the Earth and Mars, 2,000 years for Jupiter and Saturn, whose mutual perturbations
+    /// are the hardest to model, and 6,000 years for Uranus and Neptune. The precision degrades
+    /// slowly outside this span, it does not fail suddenly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vsop87::{Planet, MAX_JDE, MIN_JDE};
+    ///
+    /// assert_eq!(Planet::Mars.valid_range(), (MIN_JDE, MAX_JDE));
+    ///
+    /// // Jupiter is only valid from 1 BC to 4000 AD.
+    /// let (min, max) = Planet::Jupiter.valid_range();
+    /// assert_eq!((min, max), (1_721_045.0, 3_182_045.0));
+    /// ```
+    pub fn valid_range(self) -> (f64, f64) {
+        let millennia = match self {
+            Self::Mercury | Self::Venus | Self::Earth | Self::Mars => 4_f64,
+            Self::Jupiter | Self::Saturn => 2_f64,
+            Self::Uranus | Self::Neptune => 6_f64,
+        };
+
+        // Julian millennia, of 365,250 days, around J2000.0.
+        let span = millennia * 365_250_f64;
+        (2_451_545_f64 - span, 2_451_545_f64 + span)
+    }
+
+    /// Checks that the given Julian Day (*JD*) is inside the
+    /// [validity span](#method.valid_range) of the planet.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Vsop87Error::OutOfRange`](enum.Vsop87Error.html#variant.OutOfRange), with the
+    /// span of the planet, if `jde` is outside of it or is not a number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vsop87::{Planet, Vsop87Error};
+    ///
+    /// // 3000 BC is fine for Uranus, but not for Saturn.
+    /// assert!(Planet::Uranus.check_range(625_673.5).is_ok());
+    /// assert!(matches!(
+    ///     Planet::Saturn.check_range(625_673.5),
+    ///     Err(Vsop87Error::OutOfRange { .. })
+    /// ));
+    /// ```
+    pub fn check_range(self, jde: f64) -> Result<(), Vsop87Error> {
+        Vsop87Error::check_span(jde, self.valid_range())
+    }
+
+    /// Gets the number of terms of one of the *VSOP87D* series of the planet.
+    ///
+    /// The `order` is the power of *t* of the series, so that the order 0 of the longitude is
+    /// the *L0* series, and the order 5 is *L5*. The cost of computing a variable is roughly
+    /// proportional to the total number of terms of its series, and the truncated functions,
+    /// such as [`vsop87d::planet_truncated()`](vsop87d/fn.planet_truncated.html), skip some of
+    /// them. Orders without a series, up to 5 for the longitude and the latitude and 4 or 5 for
+    /// the distance depending on the planet, have 0 terms, and so do all the orders of a planet
+    /// whose cargo feature is not [enabled](#method.is_enabled).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vsop87::{Planet, Variable};
+    ///
+    /// assert_eq!(Planet::Mercury.term_count(Variable::Longitude, 0), 1380);
+    /// assert_eq!(Planet::Mercury.term_count(Variable::Longitude, 6), 0);
+    /// ```
+    pub fn term_count(self, variable: Variable, order: usize) -> usize {
+        if !self.is_enabled() {
+            return 0;
+        }
+
+        let tables = vsop87d::tables(self);
+        let series = match variable {
+            Variable::Longitude => tables.longitude,
+            Variable::Latitude => tables.latitude,
+            Variable::Distance => tables.distance,
+        };
+
+        series.get(order).map_or(0, |[a, _, _]| a.len())
+    }
+
+    /// Gets the approximate sidereal orbital period of the planet, in days.
+    fn sidereal_period(self) -> f64 {
+        match self {
+            Self::Mercury => 87.969,
+            Self::Venus => 224.701,
+            Self::Earth => 365.256,
+            Self::Mars => 686.980,
+            Self::Jupiter => 4_332.589,
+            Self::Saturn => 10_759.22,
+            Self::Uranus => 30_685.4,
+            Self::Neptune => 60_189.0,
+        }
+    }
+}
+
+/// One *VSOP87* series, as the `[A, B, C]` coefficient slices of its terms.
+///
+/// The three slices have the same length, and the series evaluates to `Σ A·cos(B + C·t)`, where
+/// *t* is the time in Julian millennia since J2000.0.
+pub type Series<'a> = [&'a [f64]; 3];
+
+/// Coefficient tables of a planet for a spherical *VSOP87* solution.
+///
+/// Each field holds one series per power of *t*, starting with *t⁰*, so that a variable is
+/// computed as `S0 + S1·t + S2·t² + …`. The built-in tables can be found in the
+/// [*VSOP87B*](./vsop87b/index.html) and [*VSOP87D*](./vsop87d/index.html) modules, but any set
+/// of series using the same layout can be evaluated with [`evaluate()`](fn.evaluate.html).
+#[derive(Debug, Clone, Copy)]
+pub struct PlanetTables<'a> {
+    /// Series of the ecliptic longitude (*L*), in radians.
+    pub longitude: &'a [Series<'a>],
+    /// Series of the ecliptic latitude (*B*), in radians.
+    pub latitude: &'a [Series<'a>],
+    /// Series of the distance to the center of mass (*R*), in *AU*.
+    pub distance: &'a [Series<'a>],
+}
+
+/// Calculates the spherical coordinates of a planet from its coefficient tables.
+///
+/// This is the function used by the spherical solutions, and allows evaluating custom or
+/// alternative tables with the same layout. The parameter needed is the Julian Day (*JD*) for the
+/// given date.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{evaluate, vsop87d};
+///
+/// let coordinates = evaluate(&vsop87d::JUPITER, 2232395.0);
+///
+/// assert_eq!(coordinates, vsop87d::jupiter(2232395.0));
+/// ```
+pub fn evaluate(tables: &PlanetTables<'_>, jde: f64) -> SphericalCoordinates {
+    evaluate_t(tables, calculate_t(jde))
+}
+
+/// Calculates the spherical coordinates of a planet from its coefficient tables, at the given
+/// time variable `t`.
+fn evaluate_t(tables: &PlanetTables<'_>, t: f64) -> SphericalCoordinates {
+    let l = normalize_angle(calculate_polynomial(t, tables.longitude));
+    let b = calculate_polynomial(t, tables.latitude);
+    let r = calculate_polynomial(t, tables.distance);
+
+    SphericalCoordinates {
+        lon: l,
+        lat: b,
+        dist: r,
+    }
+}
+
+/// Calculates the spherical coordinates of a planet from its coefficient tables, and their rates
+/// of change.
+///
+/// The first coordinates are the same as those of [`evaluate()`](fn.evaluate.html). The second
+/// ones hold the derivatives of the longitude and the latitude, in radians per day, and of the
+/// distance, in *AU* per day. They are computed from the analytic derivative of the series, in
+/// the same pass as the position, instead of by finite differences. The parameter needed is the
+/// Julian Day (*JD*) for the given date.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{evaluate, evaluate_state, vsop87d};
+///
+/// let (position, rates) = evaluate_state(&vsop87d::EARTH, 2451545.0);
+///
+/// assert_eq!(position, evaluate(&vsop87d::EARTH, 2451545.0));
+/// // The Earth moves by about 1° per day along its orbit.
+/// assert!((rates.longitude().to_degrees() - 1.0).abs() < 0.05);
+/// ```
+pub fn evaluate_state(
+    tables: &PlanetTables<'_>,
+    jde: f64,
+) -> (SphericalCoordinates, SphericalCoordinates) {
+    let t = calculate_t(jde);
+    let (l, l_rate) = calculate_polynomial_derivative(t, tables.longitude);
+    let (b, b_rate) = calculate_polynomial_derivative(t, tables.latitude);
+    let (r, r_rate) = calculate_polynomial_derivative(t, tables.distance);
+
+    (
+        SphericalCoordinates {
+            lon: normalize_angle(l),
+            lat: b,
+            dist: r,
+        },
+        SphericalCoordinates {
+            lon: l_rate / 365_250_f64,
+            lat: b_rate / 365_250_f64,
+            dist: r_rate / 365_250_f64,
+        },
+    )
+}
+
+/// Calculates the spherical coordinates of a planet from its coefficient tables, skipping the
+/// smaller terms.
+///
+/// This is the same as [`evaluate()`](fn.evaluate.html), but the terms whose amplitude `A` is
+/// smaller than `epsilon` (in radians for the angles, and in *AU* for the distance) are not
+/// computed. The *VSOP87* series are not strictly sorted by amplitude, so all the terms are
+/// checked, but the cosines of the skipped terms, which are most of the cost, are not evaluated.
+/// An `epsilon` of `0.0` keeps every term.
+///
+/// Each skipped term changes the result by at most its amplitude, and the skipped terms mostly
+/// cancel each other, so the error is usually a small multiple of `epsilon`. See
+/// [`vsop87d::planet_truncated()`](vsop87d/fn.planet_truncated.html) for some figures.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{evaluate, evaluate_truncated, vsop87d};
+///
+/// let full = evaluate(&vsop87d::JUPITER, 2232395.0);
+/// let truncated = evaluate_truncated(&vsop87d::JUPITER, 2232395.0, 1e-7);
+///
+/// assert!((full.distance() - truncated.distance()).abs() < 1e-5);
+/// ```
+pub fn evaluate_truncated(
+    tables: &PlanetTables<'_>,
+    jde: f64,
+    epsilon: f64,
+) -> SphericalCoordinates {
+    let t = calculate_t(jde);
 
-#[cfg(not(feature = "no_std"))]
-use std::f64::consts::PI;
+    let l = normalize_angle(calculate_polynomial_truncated(t, tables.longitude, epsilon));
+    let b = calculate_polynomial_truncated(t, tables.latitude, epsilon);
+    let r = calculate_polynomial_truncated(t, tables.distance, epsilon);
 
-/// Structure representing the keplerian elements of an orbit.
+    SphericalCoordinates {
+        lon: l,
+        lat: b,
+        dist: r,
+    }
+}
+
+/// Time of a *VSOP87* evaluation, shared by several planets.
+///
+/// It stores the Julian Day (*JD*) and the time variable `t` of the series, in Julian millennia
+/// since J2000.0, so that it is only computed once when many bodies are evaluated at the same
+/// instant, for example to draw a snapshot of the whole solar system. The methods compute the
+/// *VSOP87D* solution (heliocentric ecliptic spherical coordinates for the equinox of the day),
+/// and give exactly the same results as the functions in the [`vsop87d`](vsop87d/index.html)
+/// module. The series are evaluated with Horner's scheme, so no powers of `t` need to be stored,
+/// and the saving is small compared to the evaluation of the series themselves.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87d, Planet, TimeContext};
+///
+/// let context = TimeContext::new(2451545.0);
+/// let positions: Vec<_> = Planet::ALL.iter().map(|&planet| context.planet(planet)).collect();
+///
+/// assert_eq!(positions[2], vsop87d::earth(2451545.0));
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct KeplerianElements {
-    ecc: f64,
-    sma: f64,
-    incl: f64,
-    lan: f64,
-    lper: f64,
-    l0: f64,
+pub struct TimeContext {
+    jde: f64,
+    t: f64,
 }
 
-impl KeplerianElements {
-    /// Gets the eccentricity of the orbit (*e*).
-    ///
-    /// A number smaller to one would be a closed ellipse, while 0 would be a circle orbit. Values
-    /// higher than one would be hyperbolic orbits, that are not closed, while a 1 would be a
-    /// parabolic orbit. Negative values cannot exist.
-    pub fn eccentricity(&self) -> f64 {
-        self.ecc
+impl TimeContext {
+    /// Creates the context for the given Julian Day (*JD*).
+    pub fn new(jde: f64) -> Self {
+        Self {
+            jde,
+            t: calculate_t(jde),
+        }
     }
 
-    /// Gets the semimajor axis of an orbit (*a*), in *AU* (Astronomical units).
-    ///
-    /// This value represents the average distance from the orbiting body to the center of mass.
-    pub fn semimajor_axis(&self) -> f64 {
-        self.sma
+    /// Gets the Julian Day (*JD*) of the context.
+    pub fn jde(&self) -> f64 {
+        self.jde
     }
 
-    /// Gets the inclination of the orbit (*i*), in radians.
+    /// Gets the time variable of the series, in Julian millennia since J2000.0.
     ///
-    /// This value represents the inclination of the plane where the object is orbiting with
-    /// respect to the reference plane.
-    pub fn inclination(&self) -> f64 {
-        self.incl
+    /// It is the value returned by [`calculate_t()`](fn.calculate_t.html) for the Julian Day of
+    /// the context.
+    pub fn t(&self) -> f64 {
+        self.t
     }
 
-    /// Gets the longitude of the ascending node of the orbit (*Ω*), in radians.
-    ///
-    /// This value represents the angle in the orbit ellipse of the point where the reference plane
-    /// and the orbit plane cross when the orbiting body crosses the plane *ascending* in the orbit.
-    pub fn ascending_node(&self) -> f64 {
-        self.lan
+    /// Calculates the VSOP87D solution for the given planet.
+    pub fn planet(&self, planet: Planet) -> SphericalCoordinates {
+        match planet {
+            #[cfg(feature = "mercury")]
+            Planet::Mercury => self.mercury(),
+            #[cfg(feature = "venus")]
+            Planet::Venus => self.venus(),
+            #[cfg(feature = "earth")]
+            Planet::Earth => self.earth(),
+            #[cfg(feature = "mars")]
+            Planet::Mars => self.mars(),
+            #[cfg(feature = "jupiter")]
+            Planet::Jupiter => self.jupiter(),
+            #[cfg(feature = "saturn")]
+            Planet::Saturn => self.saturn(),
+            #[cfg(feature = "uranus")]
+            Planet::Uranus => self.uranus(),
+            #[cfg(feature = "neptune")]
+            Planet::Neptune => self.neptune(),
+            #[allow(unreachable_patterns)]
+            _ => planet.not_enabled(),
+        }
     }
 
-    /// Gets the longitude of the periapsis of the orbit (*ϖ*), in radians.
-    ///
-    /// This value represents the angle in the orbit ellipse of the nearest point of the orbit to
-    /// the center of mass of the system.
-    pub fn periapsis(&self) -> f64 {
-        self.lper
+    /// Calculates the VSOP87D solution for Mercury.
+    #[cfg(feature = "mercury")]
+    pub fn mercury(&self) -> SphericalCoordinates {
+        evaluate_t(&vsop87d::MERCURY, self.t)
     }
 
-    /// Gets the mean anomaly of the orbiting object at the given epoch.
-    ///
-    /// This value represents the angle in the orbit ellipse of the orbiting body at the given
-    /// moment.
-    pub fn mean_anomaly(&self) -> f64 {
-        self.l0
+    /// Calculates the VSOP87D solution for Venus.
+    #[cfg(feature = "venus")]
+    pub fn venus(&self) -> SphericalCoordinates {
+        evaluate_t(&vsop87d::VENUS, self.t)
     }
-}
 
-impl From<VSOP87Elements> for KeplerianElements {
-    fn from(elts: VSOP87Elements) -> Self {
-        #[cfg(feature = "no_std")]
-        {
-            let ecc = sqrt(elts.h * elts.h + elts.k * elts.k);
-            let i = acos(1_f64 - 2_f64 * (elts.p * elts.p + elts.q * elts.q));
-            let lan = atan(elts.p / elts.q);
-            let lper = asin(elts.h / ecc);
-
-            Self {
-                ecc,
-                sma: elts.a,
-                incl: i,
-                lan,
-                lper,
-                l0: elts.l,
-            }
-        }
+    /// Calculates the VSOP87D solution for Earth.
+    #[cfg(feature = "earth")]
+    pub fn earth(&self) -> SphericalCoordinates {
+        evaluate_t(&vsop87d::EARTH, self.t)
+    }
 
-        #[cfg(not(feature = "no_std"))]
-        {
-            let ecc = (elts.h * elts.h + elts.k * elts.k).sqrt();
-            let i = (1_f64 - 2_f64 * (elts.p * elts.p + elts.q * elts.q)).acos();
-            let lan = (elts.p / elts.q).atan();
-            let lper = (elts.h / ecc).asin();
-
-            Self {
-                ecc,
-                sma: elts.a,
-                incl: i,
-                lan,
-                lper,
-                l0: elts.l,
-            }
-        }
+    /// Calculates the VSOP87D solution for Mars.
+    #[cfg(feature = "mars")]
+    pub fn mars(&self) -> SphericalCoordinates {
+        evaluate_t(&vsop87d::MARS, self.t)
+    }
+
+    /// Calculates the VSOP87D solution for Jupiter.
+    #[cfg(feature = "jupiter")]
+    pub fn jupiter(&self) -> SphericalCoordinates {
+        evaluate_t(&vsop87d::JUPITER, self.t)
+    }
+
+    /// Calculates the VSOP87D solution for Saturn.
+    #[cfg(feature = "saturn")]
+    pub fn saturn(&self) -> SphericalCoordinates {
+        evaluate_t(&vsop87d::SATURN, self.t)
+    }
+
+    /// Calculates the VSOP87D solution for Uranus.
+    #[cfg(feature = "uranus")]
+    pub fn uranus(&self) -> SphericalCoordinates {
+        evaluate_t(&vsop87d::URANUS, self.t)
+    }
+
+    /// Calculates the VSOP87D solution for Neptune.
+    #[cfg(feature = "neptune")]
+    pub fn neptune(&self) -> SphericalCoordinates {
+        evaluate_t(&vsop87d::NEPTUNE, self.t)
     }
 }
 
-/// Structure representing 3 dimensional rectangular coordinates.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct RectangularCoordinates {
-    /// X coordinate.
-    pub x: f64,
-    /// Y coordinate.
-    pub y: f64,
-    /// Z coordinate.
-    pub z: f64,
+/// Calculates a variable as a polynomial in `t` whose coefficients are the given series.
+#[inline]
+fn calculate_polynomial(t: f64, series: &[Series<'_>]) -> f64 {
+    series
+        .iter()
+        .rev()
+        .fold(0_f64, |var, [a, b, c]| var * t + calculate_var(t, a, b, c))
 }
 
-/// Structure representing spherical coordinates of a body.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct SphericalCoordinates {
-    lon: f64,
-    lat: f64,
-    dist: f64,
+/// Calculates a variable as a polynomial in `t`, skipping the terms smaller than `epsilon`.
+#[inline]
+fn calculate_polynomial_truncated(t: f64, series: &[Series<'_>], epsilon: f64) -> f64 {
+    series.iter().rev().fold(0_f64, |var, [a, b, c]| {
+        var * t + calculate_var_truncated(t, a, b, c, epsilon)
+    })
 }
 
-impl SphericalCoordinates {
-    /// Gets the ecliptic longitude of the body, in radians.
-    ///
-    /// This value represents the angular distance of an object along the ecliptic plane from the
-    /// primary direction. In the case of heliocentric coordinates, it represents the *l* parameter,
-    /// in geocentric coordinates, represents the *λ* parameter.
-    pub fn longitude(&self) -> f64 {
-        self.lon
+/// Calculates a variable as a polynomial in `t` and its derivative with respect to `t`.
+///
+/// The derivative of `S0 + S1·t + S2·t² + …` is `S0' + (S1'·t + S1) + (S2'·t² + 2·S2·t) + …`,
+/// which is accumulated with the same Horner scheme as the value.
+fn calculate_polynomial_derivative(t: f64, series: &[Series<'_>]) -> (f64, f64) {
+    series
+        .iter()
+        .rev()
+        .fold((0_f64, 0_f64), |(var, derivative), [a, b, c]| {
+            (
+                var * t + calculate_var(t, a, b, c),
+                derivative * t + var + calculate_var_derivative(t, a, b, c),
+            )
+        })
+}
+
+/// Calculates rectangular coordinates from the series of *X*, *Y* and *Z*.
+fn rectangular(series: &[&[Series<'_>]; 3], jde: f64) -> RectangularCoordinates {
+    let t = calculate_t(jde);
+
+    RectangularCoordinates {
+        x: calculate_polynomial(t, series[0]),
+        y: calculate_polynomial(t, series[1]),
+        z: calculate_polynomial(t, series[2]),
     }
+}
 
-    /// Gets the ecliptic latitude of the body, in radians.
-    ///
-    /// This value represents the angular distance of an object from the ecliptic towards the north
-    /// ecliptic pole. In the case of heliocentric coordinates, it represents the *b* parameter, in
-    /// geocentric coordinates, represents the *β* parameter.
-    pub fn latitude(&self) -> f64 {
-        self.lat
+/// Calculates rectangular coordinates from the series of *X*, *Y* and *Z*, and their velocity in
+/// *AU* per day.
+fn rectangular_state(
+    series: &[&[Series<'_>]; 3],
+    jde: f64,
+) -> (RectangularCoordinates, RectangularCoordinates) {
+    let t = calculate_t(jde);
+    let (x, x_rate) = calculate_polynomial_derivative(t, series[0]);
+    let (y, y_rate) = calculate_polynomial_derivative(t, series[1]);
+    let (z, z_rate) = calculate_polynomial_derivative(t, series[2]);
+
+    (
+        RectangularCoordinates { x, y, z },
+        RectangularCoordinates {
+            x: x_rate,
+            y: y_rate,
+            z: z_rate,
+        } / 365_250_f64,
+    )
+}
+
+/// Calculates rectangular coordinates from the series of *X*, *Y* and *Z*, skipping the terms
+/// smaller than `epsilon`, in *AU*.
+fn rectangular_truncated(
+    series: &[&[Series<'_>]; 3],
+    jde: f64,
+    epsilon: f64,
+) -> RectangularCoordinates {
+    let t = calculate_t(jde);
+
+    RectangularCoordinates {
+        x: calculate_polynomial_truncated(t, series[0], epsilon),
+        y: calculate_polynomial_truncated(t, series[1], epsilon),
+        z: calculate_polynomial_truncated(t, series[2], epsilon),
     }
+}
 
-    /// Gets the distance to the center of mass, in astronomical units (*AU*).
-    ///
-    /// In the case of heliocentric coordinates, it represents the *r* parameter, in geocentric
-    /// coordinates, represents the *Δ* parameter.
-    pub fn distance(&self) -> f64 {
-        self.dist
+/// Calculates the given solution for all the planets, in the order of `Planet::ALL`, one planet
+/// per thread with the `rayon` feature.
+#[cfg(feature = "all_planets")]
+fn all_planets<C, F>(planet: F) -> [C; 8]
+where
+    C: Copy + Send,
+    F: Fn(Planet) -> C + Sync,
+{
+    let mut system = [planet(Planet::Mercury); 8];
+
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+
+        system
+            .par_iter_mut()
+            .zip(Planet::ALL.par_iter())
+            .skip(1)
+            .for_each(|(coordinates, &body)| *coordinates = planet(body));
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    {
+        for (coordinates, &body) in system.iter_mut().zip(Planet::ALL.iter()).skip(1) {
+            *coordinates = planet(body);
+        }
     }
+
+    system
 }
 
 /// Calculates the time variable for VSOP87.
+///
+/// The *VSOP87* series are evaluated with the time `t` measured in Julian millennia (365,250
+/// days) since J2000.0 (JD 2451545.0), that is, `t = (jde - 2451545.0) / 365250.0`. The parameter
+/// needed is the Julian Day (*JD*) for the given date.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::calculate_t;
+///
+/// assert_eq!(calculate_t(2451545.0), 0.0);
+/// assert_eq!(calculate_t(2816795.0), 1.0);
+/// ```
 #[inline]
-fn calculate_t(jde: f64) -> f64 {
+pub fn calculate_t(jde: f64) -> f64 {
     (jde - 2_451_545_f64) / 365_250_f64
 }
 
-/// Calculates the given variable.
+/// Calculates the sum of the terms of a *VSOP87* series.
+///
+/// A series is a sum of periodic terms `A·cos(B + C·t)`, where `t` is the time in Julian millennia
+/// since J2000.0, as returned by [`calculate_t()`](fn.calculate_t.html). The terms are given in
+/// three columns of the same length: `a` holds the amplitudes *A*, `b` the phases *B*, in radians,
+/// and `c` the frequencies *C*, in radians per Julian millennium. The term `i` is then
+/// `a[i]·cos(b[i] + c[i]·t)`. This is the layout of the built-in `[[f64; N]; 3]` coefficient
+/// tables, and of the [`Series`](type.Series.html) type.
+///
+/// A complete variable is a polynomial in `t` whose coefficients are series, `S0 + S1·t + S2·t² +
+/// …`, so this function is called once per power of `t`.
+///
+/// With the `high_precision` feature, the terms are added with compensated (Kahan–Babuška)
+/// summation, from the smallest to the largest, instead of with the AVX instructions. This removes
+/// most of the rounding error of the sum, but the difference is tiny: over the whole valid range,
+/// the *VSOP87A* coordinates change by less than 3e-14 AU (about 4 mm), far below the precision of
+/// the theory itself. It is only useful to compare results bit by bit with other implementations.
+///
+/// With the `simd` feature, on x86 processors with AVX, the cosines are computed 4 at a time with
+/// a vectorized reduction of the angle, which is as accurate as the scalar cosine for arguments
+/// `|B + C·t|` up to 1.6·10⁶ radians. Beyond that, the vector cosine would be off by about 10⁻⁹
+/// at 10⁷ radians, and by 10⁻⁶ at 10¹² radians, so the terms with larger arguments, such as the
+/// fastest terms of Mercury near the ends of the valid range, or those of custom series, are
+/// computed with the scalar cosine instead.
+///
+/// # Panics
+///
+/// Panics if `a`, `b` and `c` do not have the same length.
+///
+/// # Example
+///
+/// Evaluating a custom table with two terms:
+///
+/// ```
+/// use vsop87::{calculate_t, calculate_var};
+///
+/// const TERMS: [[f64; 2]; 3] = [
+///     [0.5, 0.25],           // A
+///     [0.0, 1.5],            // B
+///     [6283.0758, 12566.15], // C
+/// ];
+///
+/// let t = calculate_t(2451545.0);
+/// let value = calculate_var(t, &TERMS[0], &TERMS[1], &TERMS[2]);
+///
+/// assert!((value - (0.5 + 0.25 * 1.5_f64.cos())).abs() < 1e-15);
+/// ```
+#[inline]
+pub fn calculate_var(t: f64, a: &[f64], b: &[f64], c: &[f64]) -> f64 {
+    assert!(
+        a.len() == b.len() && a.len() == c.len(),
+        "the columns of a VSOP87 series must have the same length"
+    );
+
+    sum_series(t, a, b, c)
+}
+
+/// Calculates the sum of the terms of a *VSOP87* series stored in a fixed-size table.
+///
+/// This is the same as [`calculate_var()`](fn.calculate_var.html), and gives exactly the same
+/// results, but it takes a whole `[[f64; N]; 3]` table, the layout of the built-in coefficient
+/// tables, with the amplitudes, the phases and the frequencies as its three rows. The number of
+/// terms is part of the type, so the columns cannot have different lengths and nothing needs to
+/// be checked at runtime, and each table size gets its own monomorphized copy of the evaluator,
+/// where the compiler knows the number of iterations of the loop.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{calculate_t, calculate_var, calculate_var_const};
+///
+/// const TERMS: [[f64; 2]; 3] = [
+///     [0.5, 0.25],           // A
+///     [0.0, 1.5],            // B
+///     [6283.0758, 12566.15], // C
+/// ];
+///
+/// let t = calculate_t(2451545.0);
+///
+/// assert_eq!(
+///     calculate_var_const(t, &TERMS),
+///     calculate_var(t, &TERMS[0], &TERMS[1], &TERMS[2])
+/// );
+/// ```
+#[inline]
+pub fn calculate_var_const<const N: usize>(t: f64, terms: &[[f64; N]; 3]) -> f64 {
+    let [a, b, c] = terms;
+    sum_series(t, a, b, c)
+}
+
+/// Calculates the sum of the terms of a series whose columns have the same length, with the best
+/// implementation available.
 #[inline]
-fn calculate_var(t: f64, a: &[f64], b: &[f64], c: &[f64]) -> f64 {
+fn sum_series(t: f64, a: &[f64], b: &[f64], c: &[f64]) -> f64 {
     #[cfg(all(
         any(target_arch = "x86", target_arch = "x86_64"),
         feature = "simd",
-        not(feature = "no_std")
+        not(feature = "no_std"),
+        not(feature = "high_precision")
     ))]
     #[allow(unsafe_code)]
     {
@@ -295,7 +3062,8 @@ fn calculate_var(t: f64, a: &[f64], b: &[f64], c: &[f64]) -> f64 {
     #[cfg(any(
         all(not(target_arch = "x86"), not(target_arch = "x86_64")),
         not(feature = "simd"),
-        feature = "no_std"
+        feature = "no_std",
+        feature = "high_precision"
     ))]
     {
         calculate_var_fallback(t, a, b, c)
@@ -304,140 +3072,264 @@ fn calculate_var(t: f64, a: &[f64], b: &[f64], c: &[f64]) -> f64 {
 
 /// Fallback implementation of the variable calculation.
 ///
-/// Used in systems without SIMD support.
+/// Used in systems without SIMD support, and with the `high_precision` feature. The terms are
+/// roughly sorted by decreasing amplitude, so they are added in reverse order: adding the small
+/// terms to the large first one would round each of them to its last place.
 #[inline]
 fn calculate_var_fallback(t: f64, a: &[f64], b: &[f64], c: &[f64]) -> f64 {
-    #[cfg(not(feature = "no_std"))]
+    #[cfg(feature = "high_precision")]
     {
-        a.iter()
-            .zip(b)
-            .zip(c)
-            .fold(0_f64, |term, ((a, b), c)| term + a * (b + c * t).cos())
+        calculate_var_compensated(t, a, b, c)
     }
 
-    #[cfg(feature = "no_std")]
+    #[cfg(not(feature = "high_precision"))]
     {
         a.iter()
             .zip(b)
             .zip(c)
+            .rev()
             .fold(0_f64, |term, ((a, b), c)| term + a * cos(b + c * t))
     }
 }
 
+/// Calculates the given variable with compensated (Kahan–Babuška) summation.
+///
+/// The terms of the series are roughly sorted by decreasing amplitude, so they are added in
+/// reverse order, starting with the smallest ones. The rounding error of each addition is
+/// accumulated separately and added back at the end.
+#[cfg(feature = "high_precision")]
+#[inline]
+fn calculate_var_compensated(t: f64, a: &[f64], b: &[f64], c: &[f64]) -> f64 {
+    let (sum, compensation) =
+        a.iter()
+            .zip(b)
+            .zip(c)
+            .rev()
+            .fold((0_f64, 0_f64), |(sum, compensation), ((a, b), c)| {
+                let term = a * cos(b + c * t);
+
+                let new_sum = sum + term;
+                let error = if sum.abs() >= term.abs() {
+                    (sum - new_sum) + term
+                } else {
+                    (term - new_sum) + sum
+                };
+                (new_sum, compensation + error)
+            });
+
+    sum + compensation
+}
+
+/// Calculates the given variable, skipping the terms whose amplitude is smaller than `epsilon`.
+#[inline]
+fn calculate_var_truncated(t: f64, a: &[f64], b: &[f64], c: &[f64], epsilon: f64) -> f64 {
+    a.iter()
+        .zip(b)
+        .zip(c)
+        .filter(|((a, _), _)| a.abs() >= epsilon)
+        .fold(0_f64, |term, ((a, b), c)| term + a * cos(b + c * t))
+}
+
+/// Calculates the derivative of the given variable with respect to `t`.
+///
+/// Each term `A·cos(B + C·t)` has the derivative `-A·C·sin(B + C·t)`.
+#[inline]
+fn calculate_var_derivative(t: f64, a: &[f64], b: &[f64], c: &[f64]) -> f64 {
+    a.iter()
+        .zip(b)
+        .zip(c)
+        .fold(0_f64, |term, ((a, b), c)| term - a * c * sin(b + c * t))
+}
+
 /// Calculates the given variable using the AVX instruction set.
+///
+/// The terms are evaluated 4 at a time, including their cosines, which are computed in the vector
+/// registers, and accumulated in 4 separate sums. The last terms, if the length is not a multiple
+/// of 4, are evaluated one by one, and so are the groups of 4 terms with an argument larger than
+/// `MAX_ARGUMENT`, where the reduction of the vector cosine is no longer exact.
 #[target_feature(enable = "avx")]
 #[cfg(all(
     any(target_arch = "x86", target_arch = "x86_64"),
     feature = "simd",
-    not(feature = "no_std")
+    not(feature = "no_std"),
+    not(feature = "high_precision")
 ))]
-#[allow(unsafe_code)]
+#[allow(unsafe_code, clippy::too_many_lines)]
 unsafe fn calculate_var_avx(t: f64, a: &[f64], b: &[f64], c: &[f64]) -> f64 {
-    #[cfg(feature = "no_std")]
-    use core::{f64, mem};
-    #[cfg(not(feature = "no_std"))]
-    use std::{f64, mem};
-
-    #[cfg(all(feature = "no_std", target_arch = "x86_64"))]
-    use core::arch::x86_64::{_mm256_add_pd, _mm256_mul_pd, _mm256_set1_pd, _mm256_set_pd};
-    #[cfg(all(not(feature = "no_std"), target_arch = "x86_64"))]
-    use std::arch::x86_64::{_mm256_add_pd, _mm256_mul_pd, _mm256_set1_pd, _mm256_set_pd};
-
-    #[cfg(all(feature = "no_std", target_arch = "x86"))]
-    use core::arch::x86::{_mm256_add_pd, _mm256_mul_pd, _mm256_set1_pd, _mm256_set_pd};
-    #[cfg(all(not(feature = "no_std"), target_arch = "x86"))]
-    use std::arch::x86::{_mm256_add_pd, _mm256_mul_pd, _mm256_set1_pd, _mm256_set_pd};
-
-    /// Vectorizes the calculation of 4 terms at the same time.
-    unsafe fn vector_term(
-        (a1, b1, c1): (f64, f64, f64),
-        (a2, b2, c2): (f64, f64, f64),
-        (a3, b3, c3): (f64, f64, f64),
-        (a4, b4, c4): (f64, f64, f64),
-        t: f64,
-    ) -> (f64, f64, f64, f64) {
-        let a = _mm256_set_pd(a1, a2, a3, a4);
-        let b = _mm256_set_pd(b1, b2, b3, b4);
-        let c = _mm256_set_pd(c1, c2, c3, c4);
-        let t = _mm256_set1_pd(t);
-
-        // Safe because both values are created properly and checked.
-        let ct = _mm256_mul_pd(c, t);
-        // Safe because both values are created properly and checked.
-        let bct = _mm256_add_pd(b, ct);
-
-        // Safe because bct_unpacked is 4 f64 long.
-        let bct_unpacked: (f64, f64, f64, f64) = mem::transmute(bct);
-
-        // Safe because bct_unpacked is 4 f64 long, and x84/x86_64 is little endian.
-        let bct = _mm256_set_pd(
-            bct_unpacked.3.cos(),
-            bct_unpacked.2.cos(),
-            bct_unpacked.1.cos(),
-            bct_unpacked.0.cos(),
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::{
+        __m256d, _mm256_add_pd, _mm256_and_pd, _mm256_andnot_pd, _mm256_blendv_pd, _mm256_cmp_pd,
+        _mm256_floor_pd, _mm256_loadu_pd, _mm256_movemask_pd, _mm256_mul_pd, _mm256_round_pd,
+        _mm256_set1_pd, _mm256_setzero_pd, _mm256_storeu_pd, _mm256_sub_pd, _mm256_xor_pd,
+        _CMP_GT_OQ, _CMP_LT_OQ, _MM_FROUND_NO_EXC, _MM_FROUND_TO_NEAREST_INT,
+    };
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::{
+        __m256d, _mm256_add_pd, _mm256_and_pd, _mm256_andnot_pd, _mm256_blendv_pd, _mm256_cmp_pd,
+        _mm256_floor_pd, _mm256_loadu_pd, _mm256_movemask_pd, _mm256_mul_pd, _mm256_round_pd,
+        _mm256_set1_pd, _mm256_setzero_pd, _mm256_storeu_pd, _mm256_sub_pd, _mm256_xor_pd,
+        _CMP_GT_OQ, _CMP_LT_OQ, _MM_FROUND_NO_EXC, _MM_FROUND_TO_NEAREST_INT,
+    };
+
+    /// Largest argument of the vector cosine, in radians, whose reduction is still exact: up to
+    /// 2²⁰ quadrants, the products of the quadrant by the parts of `π/2` fit in an `f64`.
+    const MAX_ARGUMENT: f64 = 1.6e6;
+    /// `π/2` split in three parts of 33 bits, so that their products by the quadrant are exact.
+    const FRAC_PI_2: [f64; 3] = [
+        1.570_796_326_734_125_6,
+        6.077_100_506_303_966e-11,
+        2.022_266_248_711_166_5e-21,
+    ];
+    /// Coefficients of the polynomial for `sin(r)`, from *fdlibm*.
+    const SIN: [f64; 6] = [
+        -0.166_666_666_666_666_32,
+        0.008_333_333_333_322_49,
+        -0.000_198_412_698_298_579_5,
+        2.755_731_370_707_006_8e-6,
+        -2.505_076_025_340_686_3e-8,
+        1.589_690_995_211_55e-10,
+    ];
+    /// Coefficients of the polynomial for `cos(r)`, from *fdlibm*.
+    const COS: [f64; 6] = [
+        0.041_666_666_666_666_6,
+        -0.001_388_888_888_887_411,
+        2.480_158_728_947_673e-5,
+        -2.755_731_435_139_066_3e-7,
+        2.087_572_321_298_175e-9,
+        -1.135_964_755_778_819_5e-11,
+    ];
+
+    /// Calculates the cosine of 4 angles at the same time.
+    ///
+    /// The angles are reduced to `r` in `[-π/4, π/4]`, with `x = r + n·π/2`, using the three-part
+    /// `π/2`, which keeps the reduction exact to about 10⁻¹⁹ up to `MAX_ARGUMENT`. The sine and the
+    /// cosine of `r` are then computed with the minimax polynomials of *fdlibm*, and the one giving
+    /// `cos(x)` in the quadrant `n` is selected, with its sign. The error is within one or two
+    /// units in the last place, like the scalar cosine.
+    #[target_feature(enable = "avx")]
+    #[inline]
+    unsafe fn cos(x: __m256d) -> __m256d {
+        let q = _mm256_round_pd(
+            _mm256_mul_pd(x, _mm256_set1_pd(std::f64::consts::FRAC_2_PI)),
+            _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC,
+        );
+        let r = _mm256_sub_pd(x, _mm256_mul_pd(q, _mm256_set1_pd(FRAC_PI_2[0])));
+        let r = _mm256_sub_pd(r, _mm256_mul_pd(q, _mm256_set1_pd(FRAC_PI_2[1])));
+        let r = _mm256_sub_pd(r, _mm256_mul_pd(q, _mm256_set1_pd(FRAC_PI_2[2])));
+        let n = _mm256_sub_pd(
+            q,
+            _mm256_mul_pd(
+                _mm256_set1_pd(4_f64),
+                _mm256_floor_pd(_mm256_mul_pd(q, _mm256_set1_pd(0.25))),
+            ),
+        );
+
+        let z = _mm256_mul_pd(r, r);
+        let sin_r = polynomial(z, &SIN[1..]);
+        let sin_r = _mm256_add_pd(_mm256_set1_pd(SIN[0]), _mm256_mul_pd(z, sin_r));
+        let sin_r = _mm256_add_pd(r, _mm256_mul_pd(_mm256_mul_pd(z, r), sin_r));
+
+        let half_z = _mm256_mul_pd(_mm256_set1_pd(0.5), z);
+        let w = _mm256_sub_pd(_mm256_set1_pd(1_f64), half_z);
+        let tail = _mm256_sub_pd(_mm256_sub_pd(_mm256_set1_pd(1_f64), w), half_z);
+        let cos_poly = _mm256_mul_pd(_mm256_mul_pd(z, z), polynomial(z, &COS));
+        let cos_r = _mm256_add_pd(w, _mm256_add_pd(tail, cos_poly));
+
+        // cos(r + n·π/2) is cos(r), -sin(r), -cos(r) and sin(r) for n = 0, 1, 2 and 3.
+        let parity = _mm256_sub_pd(
+            n,
+            _mm256_mul_pd(
+                _mm256_set1_pd(2_f64),
+                _mm256_floor_pd(_mm256_mul_pd(n, _mm256_set1_pd(0.5))),
+            ),
+        );
+        let odd = _mm256_cmp_pd(parity, _mm256_set1_pd(0.5), _CMP_GT_OQ);
+        let value = _mm256_blendv_pd(cos_r, sin_r, odd);
+        let negative = _mm256_and_pd(
+            _mm256_cmp_pd(n, _mm256_set1_pd(0.5), _CMP_GT_OQ),
+            _mm256_cmp_pd(n, _mm256_set1_pd(2.5), _CMP_LT_OQ),
         );
 
-        // Safe because both values are created properly and checked.
-        let term = _mm256_mul_pd(a, bct);
-        let term_unpacked: (f64, f64, f64, f64) = mem::transmute(term);
+        _mm256_xor_pd(value, _mm256_and_pd(negative, _mm256_set1_pd(-0_f64)))
+    }
 
-        term_unpacked
+    /// Evaluates the polynomial with the given coefficients, from the lowest degree, with the
+    /// Horner scheme.
+    #[target_feature(enable = "avx")]
+    #[inline]
+    unsafe fn polynomial(z: __m256d, coefficients: &[f64]) -> __m256d {
+        coefficients
+            .iter()
+            .rev()
+            .fold(_mm256_setzero_pd(), |value, &coefficient| {
+                _mm256_add_pd(_mm256_set1_pd(coefficient), _mm256_mul_pd(z, value))
+            })
     }
 
     let iter1 = a.chunks_exact(4);
     let iter2 = b.chunks_exact(4);
     let iter3 = c.chunks_exact(4);
 
-    let remainder = match (iter1.remainder(), iter2.remainder(), iter3.remainder()) {
-        (&[a1, a2, a3], &[b1, b2, b3], &[c1, c2, c3]) => {
-            // The result is little endian in x86/x86_64.
-            let (_term4, term3, term2, term1) = vector_term(
-                (a1, b1, c1),
-                (a2, b2, c2),
-                (a3, b3, c3),
-                (f64::NAN, f64::NAN, f64::NAN),
-                t,
-            );
+    let remainder = iter1
+        .remainder()
+        .iter()
+        .zip(iter2.remainder())
+        .zip(iter3.remainder())
+        .fold(0_f64, |term, ((a, b), c)| term + a * (b + c * t).cos());
+
+    let mut large = 0_f64;
+    let t_vec = _mm256_set1_pd(t);
+    let mut sum = _mm256_setzero_pd();
+    for ((a, b), c) in iter1.zip(iter2).zip(iter3) {
+        // Safe because the chunks are 4 f64 long, and the loads do not need to be aligned.
+        let a_vec = _mm256_loadu_pd(a.as_ptr());
+        let b_vec = _mm256_loadu_pd(b.as_ptr());
+        let c_vec = _mm256_loadu_pd(c.as_ptr());
 
-            term1 + term2 + term3
+        let x = _mm256_add_pd(b_vec, _mm256_mul_pd(c_vec, t_vec));
+        let abs_x = _mm256_andnot_pd(_mm256_set1_pd(-0_f64), x);
+        if _mm256_movemask_pd(_mm256_cmp_pd(
+            abs_x,
+            _mm256_set1_pd(MAX_ARGUMENT),
+            _CMP_GT_OQ,
+        )) != 0
+        {
+            large = a
+                .iter()
+                .zip(b)
+                .zip(c)
+                .fold(large, |term, ((a, b), c)| term + a * (b + c * t).cos());
+            continue;
         }
-        (&[a1, a2], &[b1, b2], &[c1, c2]) => a1 * (b1 + c1 * t).cos() + a2 * (b2 + c2 * t).cos(),
-        (&[a], &[b], &[c]) => a * (b + c * t).cos(),
-        (&[], &[], &[]) => 0_f64,
-        _ => unreachable!(),
-    };
 
-    let res = iter1
-        .zip(iter2)
-        .zip(iter3)
-        .map(|vars| match vars {
-            ((&[a1, a2, a3, a4], &[b1, b2, b3, b4]), &[c1, c2, c3, c4]) => {
-                // The result is little endian in x86/x86_64.
-                let (term4, term3, term2, term1) =
-                    vector_term((a1, b1, c1), (a2, b2, c2), (a3, b3, c3), (a4, b4, c4), t);
+        let term = _mm256_mul_pd(a_vec, cos(x));
+        sum = _mm256_add_pd(sum, term);
+    }
 
-                term1 + term2 + term3 + term4
-            }
-            _ => unreachable!(),
-        })
-        .sum::<f64>();
+    let mut sums = [0_f64; 4];
+    // Safe because the array is 4 f64 long, and the store does not need to be aligned.
+    _mm256_storeu_pd(sums.as_mut_ptr(), sum);
 
-    res + remainder
+    sums[0] + sums[1] + sums[2] + sums[3] + large + remainder
 }
 
 /// Elements used by the VSOP87 solution. Can be converted into keplerian elements.
 ///
 /// More information can be found [here](http://totaleclipse.eu/Astronomy/VSOP87.html).
+///
+/// With the `serde` feature, the elements are serialized with the `a`, `l`, `k`, `h`, `q` and `p`
+/// fields.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VSOP87Elements {
     /// Semimajor axis in astronomical units (*AU*).
     pub a: f64,
     /// Mean longitude at epoch.
     pub l: f64,
-    /// `e * lper.cos()``, where *e* is the eccentricity and *lper* is the longitude of the
+    /// `e * lper.cos()`, where *e* is the eccentricity and *lper* is the longitude of the
     /// perihelion.
     pub k: f64,
-    /// `e * lper.sin()``, where *e* is the eccentricity and *lper* is the longitude of the
+    /// `e * lper.sin()`, where *e* is the eccentricity and *lper* is the longitude of the
     /// perihelion (*ϖ*).
     pub h: f64,
     /// `(i/2.0).sin() * lan.cos()` where *i* is inclination and *lan is the longitude of the
@@ -450,34 +3342,16 @@ pub struct VSOP87Elements {
 
 impl From<KeplerianElements> for VSOP87Elements {
     fn from(elts: KeplerianElements) -> Self {
-        #[cfg(feature = "no_std")]
-        {
-            let (lper_sin, lper_cos) = sincos(elts.lper);
-            let (lan_sin, lan_cos) = sincos(elts.lan);
-            let incl_sin = sin(elts.incl / 2.0);
-            Self {
-                a: elts.sma,
-                l: elts.l0,
-                k: elts.ecc * lper_cos,
-                h: elts.ecc * lper_sin,
-                q: incl_sin * lan_cos,
-                p: incl_sin * lan_sin,
-            }
-        }
-
-        #[cfg(not(feature = "no_std"))]
-        {
-            let (lper_sin, lper_cos) = elts.lper.sin_cos();
-            let (lan_sin, lan_cos) = elts.lan.sin_cos();
-            let incl_sin = (elts.incl / 2.0).sin();
-            Self {
-                a: elts.sma,
-                l: elts.l0,
-                k: elts.ecc * lper_cos,
-                h: elts.ecc * lper_sin,
-                q: incl_sin * lan_cos,
-                p: incl_sin * lan_sin,
-            }
+        let (lper_sin, lper_cos) = sin_cos(elts.lper);
+        let (lan_sin, lan_cos) = sin_cos(elts.lan);
+        let incl_sin = sin(elts.incl / 2.0);
+        Self {
+            a: elts.sma,
+            l: elts.l0,
+            k: elts.ecc * lper_cos,
+            h: elts.ecc * lper_sin,
+            q: incl_sin * lan_cos,
+            p: incl_sin * lan_sin,
         }
     }
 }
@@ -515,6 +3389,7 @@ impl From<KeplerianElements> for VSOP87Elements {
 /// let k_elements: KeplerianElements = vsop87_elts.into();
 /// let convert_back = VSOP87Elements::from(k_elements);
 /// ```
+#[cfg(feature = "mercury")]
 pub fn mercury(jde: f64) -> VSOP87Elements {
     let t = calculate_t(jde);
 
@@ -554,27 +3429,14 @@ pub fn mercury(jde: f64) -> VSOP87Elements {
     let p3 = calculate_var(t, &mercury::P3[0], &mercury::P3[1], &mercury::P3[2]);
     let p4 = calculate_var(t, &mercury::P4[0], &mercury::P4[1], &mercury::P4[2]);
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
+    let a = (a2 * t + a1) * t + a0;
+    let l = normalize_angle(((l3 * t + l2) * t + l1) * t + l0);
+    let k = ((((k5 * t + k4) * t + k3) * t + k2) * t + k1) * t + k0;
+    let h = ((((h5 * t + h4) * t + h3) * t + h2) * t + h1) * t + h0;
+    let q = ((((q5 * t + q4) * t + q3) * t + q2) * t + q1) * t + q0;
+    let p = (((p4 * t + p3) * t + p2) * t + p1) * t + p0;
 
-    let a = a0 + a1 * t + a2 * t2;
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3) % (2_f64 * PI);
-    let k = k0 + k1 * t + k2 * t2 + k3 * t3 + k4 * t4 + k5 * t5;
-    let h = h0 + h1 * t + h2 * t2 + h3 * t3 + h4 * t4 + h5 * t5;
-    let q = q0 + q1 * t + q2 * t2 + q3 * t3 + q4 * t4 + q5 * t5;
-    let p = p0 + p1 * t + p2 * t2 + p3 * t3 + p4 * t4;
-
-    VSOP87Elements {
-        a,
-        l: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        k,
-        h,
-        q,
-        p,
-    }
+    VSOP87Elements { a, l, k, h, q, p }
 }
 
 /// Calculates VSOP87 solution for Venus.
@@ -609,6 +3471,7 @@ pub fn mercury(jde: f64) -> VSOP87Elements {
 /// let k_elements: KeplerianElements = vsop87_elts.into();
 /// let convert_back = VSOP87Elements::from(k_elements);
 /// ```
+#[cfg(feature = "venus")]
 pub fn venus(jde: f64) -> VSOP87Elements {
     let t = calculate_t(jde);
 
@@ -648,27 +3511,14 @@ pub fn venus(jde: f64) -> VSOP87Elements {
     let p3 = calculate_var(t, &venus::P3[0], &venus::P3[1], &venus::P3[2]);
     let p4 = calculate_var(t, &venus::P4[0], &venus::P4[1], &venus::P4[2]);
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
+    let a = (a2 * t + a1) * t + a0;
+    let l = normalize_angle(((l3 * t + l2) * t + l1) * t + l0);
+    let k = ((((k5 * t + k4) * t + k3) * t + k2) * t + k1) * t + k0;
+    let h = ((((h5 * t + h4) * t + h3) * t + h2) * t + h1) * t + h0;
+    let q = ((((q5 * t + q4) * t + q3) * t + q2) * t + q1) * t + q0;
+    let p = (((p4 * t + p3) * t + p2) * t + p1) * t + p0;
 
-    let a = a0 + a1 * t + a2 * t2;
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3) % (2_f64 * PI);
-    let k = k0 + k1 * t + k2 * t2 + k3 * t3 + k4 * t4 + k5 * t5;
-    let h = h0 + h1 * t + h2 * t2 + h3 * t3 + h4 * t4 + h5 * t5;
-    let q = q0 + q1 * t + q2 * t2 + q3 * t3 + q4 * t4 + q5 * t5;
-    let p = p0 + p1 * t + p2 * t2 + p3 * t3 + p4 * t4;
-
-    VSOP87Elements {
-        a,
-        l: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        k,
-        h,
-        q,
-        p,
-    }
+    VSOP87Elements { a, l, k, h, q, p }
 }
 
 /// Calculates VSOP87 solution for Earth - Moon barycenter.
@@ -705,6 +3555,7 @@ pub fn venus(jde: f64) -> VSOP87Elements {
 /// let convert_back = VSOP87Elements::from(k_elements);
 /// ```
 #[allow(clippy::too_many_lines)]
+#[cfg(feature = "earth")]
 pub fn earth_moon(jde: f64) -> VSOP87Elements {
     let t = calculate_t(jde);
 
@@ -906,27 +3757,14 @@ pub fn earth_moon(jde: f64) -> VSOP87Elements {
         &earth_moon::P4[2],
     );
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
+    let a = (a2 * t + a1) * t + a0;
+    let l = normalize_angle(((((l5 * t + l4) * t + l3) * t + l2) * t + l1) * t + l0);
+    let k = ((((k5 * t + k4) * t + k3) * t + k2) * t + k1) * t + k0;
+    let h = ((((h5 * t + h4) * t + h3) * t + h2) * t + h1) * t + h0;
+    let q = ((((q5 * t + q4) * t + q3) * t + q2) * t + q1) * t + q0;
+    let p = (((p4 * t + p3) * t + p2) * t + p1) * t + p0;
 
-    let a = a0 + a1 * t + a2 * t2;
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let k = k0 + k1 * t + k2 * t2 + k3 * t3 + k4 * t4 + k5 * t5;
-    let h = h0 + h1 * t + h2 * t2 + h3 * t3 + h4 * t4 + h5 * t5;
-    let q = q0 + q1 * t + q2 * t2 + q3 * t3 + q4 * t4 + q5 * t5;
-    let p = p0 + p1 * t + p2 * t2 + p3 * t3 + p4 * t4;
-
-    VSOP87Elements {
-        a,
-        l: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        k,
-        h,
-        q,
-        p,
-    }
+    VSOP87Elements { a, l, k, h, q, p }
 }
 
 /// Calculates VSOP87 solution for Mars.
@@ -961,6 +3799,7 @@ pub fn earth_moon(jde: f64) -> VSOP87Elements {
 /// let k_elements: KeplerianElements = vsop87_elts.into();
 /// let convert_back = VSOP87Elements::from(k_elements);
 /// ```
+#[cfg(feature = "mars")]
 pub fn mars(jde: f64) -> VSOP87Elements {
     let t = calculate_t(jde);
 
@@ -1001,27 +3840,14 @@ pub fn mars(jde: f64) -> VSOP87Elements {
     let p2 = calculate_var(t, &mars::P2[0], &mars::P2[1], &mars::P2[2]);
     let p3 = calculate_var(t, &mars::P3[0], &mars::P3[1], &mars::P3[2]);
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
+    let a = (a2 * t + a1) * t + a0;
+    let l = normalize_angle(((((l5 * t + l4) * t + l3) * t + l2) * t + l1) * t + l0);
+    let k = ((((k5 * t + k4) * t + k3) * t + k2) * t + k1) * t + k0;
+    let h = ((((h5 * t + h4) * t + h3) * t + h2) * t + h1) * t + h0;
+    let q = ((((q5 * t + q4) * t + q3) * t + q2) * t + q1) * t + q0;
+    let p = ((p3 * t + p2) * t + p1) * t + p0;
 
-    let a = a0 + a1 * t + a2 * t2;
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let k = k0 + k1 * t + k2 * t2 + k3 * t3 + k4 * t4 + k5 * t5;
-    let h = h0 + h1 * t + h2 * t2 + h3 * t3 + h4 * t4 + h5 * t5;
-    let q = q0 + q1 * t + q2 * t2 + q3 * t3 + q4 * t4 + q5 * t5;
-    let p = p0 + p1 * t + p2 * t2 + p3 * t3;
-
-    VSOP87Elements {
-        a,
-        l: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        k,
-        h,
-        q,
-        p,
-    }
+    VSOP87Elements { a, l, k, h, q, p }
 }
 
 /// Calculates VSOP87 solution for Jupiter.
@@ -1057,6 +3883,7 @@ pub fn mars(jde: f64) -> VSOP87Elements {
 /// let k_elements: KeplerianElements = vsop87_elts.into();
 /// let convert_back = VSOP87Elements::from(k_elements);
 /// ```
+#[cfg(feature = "jupiter")]
 pub fn jupiter(jde: f64) -> VSOP87Elements {
     let t = calculate_t(jde);
 
@@ -1095,27 +3922,14 @@ pub fn jupiter(jde: f64) -> VSOP87Elements {
     let p1 = calculate_var(t, &jupiter::P1[0], &jupiter::P1[1], &jupiter::P1[2]);
     let p2 = calculate_var(t, &jupiter::P2[0], &jupiter::P2[1], &jupiter::P2[2]);
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
+    let a = ((((a5 * t + a4) * t + a3) * t + a2) * t + a1) * t + a0;
+    let l = normalize_angle(((((l5 * t + l4) * t + l3) * t + l2) * t + l1) * t + l0);
+    let k = (((k4 * t + k3) * t + k2) * t + k1) * t + k0;
+    let h = (((h4 * t + h3) * t + h2) * t + h1) * t + h0;
+    let q = ((q3 * t + q2) * t + q1) * t + q0;
+    let p = (p2 * t + p1) * t + p0;
 
-    let a = a0 + a1 * t + a2 * t2 + a3 * t3 + a4 * t4 + a5 * t5;
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let k = k0 + k1 * t + k2 * t2 + k3 * t3 + k4 * t4;
-    let h = h0 + h1 * t + h2 * t2 + h3 * t3 + h4 * t4;
-    let q = q0 + q1 * t + q2 * t2 + q3 * t3;
-    let p = p0 + p1 * t + p2 * t2;
-
-    VSOP87Elements {
-        a,
-        l: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        k,
-        h,
-        q,
-        p,
-    }
+    VSOP87Elements { a, l, k, h, q, p }
 }
 
 /// Calculates VSOP87 solution for Saturn.
@@ -1151,6 +3965,7 @@ pub fn jupiter(jde: f64) -> VSOP87Elements {
 /// let k_elements: KeplerianElements = vsop87_elts.into();
 /// let convert_back = VSOP87Elements::from(k_elements);
 /// ```
+#[cfg(feature = "saturn")]
 pub fn saturn(jde: f64) -> VSOP87Elements {
     let t = calculate_t(jde);
 
@@ -1193,27 +4008,14 @@ pub fn saturn(jde: f64) -> VSOP87Elements {
     let p2 = calculate_var(t, &saturn::P2[0], &saturn::P2[1], &saturn::P2[2]);
     let p3 = calculate_var(t, &saturn::P3[0], &saturn::P3[1], &saturn::P3[2]);
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
+    let a = ((((a5 * t + a4) * t + a3) * t + a2) * t + a1) * t + a0;
+    let l = normalize_angle(((((l5 * t + l4) * t + l3) * t + l2) * t + l1) * t + l0);
+    let k = ((((k5 * t + k4) * t + k3) * t + k2) * t + k1) * t + k0;
+    let h = ((((h5 * t + h4) * t + h3) * t + h2) * t + h1) * t + h0;
+    let q = (((q4 * t + q3) * t + q2) * t + q1) * t + q0;
+    let p = ((p3 * t + p2) * t + p1) * t + p0;
 
-    let a = a0 + a1 * t + a2 * t2 + a3 * t3 + a4 * t4 + a5 * t5;
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let k = k0 + k1 * t + k2 * t2 + k3 * t3 + k4 * t4 + k5 * t5;
-    let h = h0 + h1 * t + h2 * t2 + h3 * t3 + h4 * t4 + h5 * t5;
-    let q = q0 + q1 * t + q2 * t2 + q3 * t3 + q4 * t4;
-    let p = p0 + p1 * t + p2 * t2 + p3 * t3;
-
-    VSOP87Elements {
-        a,
-        l: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        k,
-        h,
-        q,
-        p,
-    }
+    VSOP87Elements { a, l, k, h, q, p }
 }
 
 /// Calculates VSOP87 solution for Uranus.
@@ -1249,6 +4051,7 @@ pub fn saturn(jde: f64) -> VSOP87Elements {
 /// let k_elements: KeplerianElements = vsop87_elts.into();
 /// let convert_back = VSOP87Elements::from(k_elements);
 /// ```
+#[cfg(feature = "uranus")]
 pub fn uranus(jde: f64) -> VSOP87Elements {
     let t = calculate_t(jde);
 
@@ -1287,27 +4090,14 @@ pub fn uranus(jde: f64) -> VSOP87Elements {
     let p1 = calculate_var(t, &uranus::P1[0], &uranus::P1[1], &uranus::P1[2]);
     let p2 = calculate_var(t, &uranus::P2[0], &uranus::P2[1], &uranus::P2[2]);
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
+    let a = ((((a5 * t + a4) * t + a3) * t + a2) * t + a1) * t + a0;
+    let l = normalize_angle(((((l5 * t + l4) * t + l3) * t + l2) * t + l1) * t + l0);
+    let k = (((k4 * t + k3) * t + k2) * t + k1) * t + k0;
+    let h = (((h4 * t + h3) * t + h2) * t + h1) * t + h0;
+    let q = ((q3 * t + q2) * t + q1) * t + q0;
+    let p = (p2 * t + p1) * t + p0;
 
-    let a = a0 + a1 * t + a2 * t2 + a3 * t3 + a4 * t4 + a5 * t5;
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let k = k0 + k1 * t + k2 * t2 + k3 * t3 + k4 * t4;
-    let h = h0 + h1 * t + h2 * t2 + h3 * t3 + h4 * t4;
-    let q = q0 + q1 * t + q2 * t2 + q3 * t3;
-    let p = p0 + p1 * t + p2 * t2;
-
-    VSOP87Elements {
-        a,
-        l: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        k,
-        h,
-        q,
-        p,
-    }
+    VSOP87Elements { a, l, k, h, q, p }
 }
 
 /// Calculates VSOP87 solution for Neptune.
@@ -1343,6 +4133,7 @@ pub fn uranus(jde: f64) -> VSOP87Elements {
 /// let k_elements: KeplerianElements = vsop87_elts.into();
 /// let convert_back = VSOP87Elements::from(k_elements);
 /// ```
+#[cfg(feature = "neptune")]
 pub fn neptune(jde: f64) -> VSOP87Elements {
     let t = calculate_t(jde);
 
@@ -1383,25 +4174,53 @@ pub fn neptune(jde: f64) -> VSOP87Elements {
     let p1 = calculate_var(t, &neptune::P1[0], &neptune::P1[1], &neptune::P1[2]);
     let p2 = calculate_var(t, &neptune::P2[0], &neptune::P2[1], &neptune::P2[2]);
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let a = a0 + a1 * t + a2 * t2 + a3 * t3 + a4 * t4 + a5 * t5;
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let k = k0 + k1 * t + k2 * t2 + k3 * t3 + k4 * t4 + k5 * t5;
-    let h = h0 + h1 * t + h2 * t2 + h3 * t3 + h4 * t4 + h5 * t5;
-    let q = q0 + q1 * t + q2 * t2 + q3 * t3;
-    let p = p0 + p1 * t + p2 * t2;
-
-    VSOP87Elements {
-        a,
-        l: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        k,
-        h,
-        q,
-        p,
+    let a = ((((a5 * t + a4) * t + a3) * t + a2) * t + a1) * t + a0;
+    let l = normalize_angle(((((l5 * t + l4) * t + l3) * t + l2) * t + l1) * t + l0);
+    let k = ((((k5 * t + k4) * t + k3) * t + k2) * t + k1) * t + k0;
+    let h = ((((h5 * t + h4) * t + h3) * t + h2) * t + h1) * t + h0;
+    let q = ((q3 * t + q2) * t + q1) * t + q0;
+    let p = (p2 * t + p1) * t + p0;
+
+    VSOP87Elements { a, l, k, h, q, p }
+}
+
+/// Calculates the VSOP87 orbital elements of the given planet.
+///
+/// This function calculates the VSOP87 solution (heliocentric orbital elements for the equinox
+/// J2000.0) for the given planet, by calling the function of that planet in this crate. It is
+/// useful when the planet is only known at runtime, or to iterate over all the planets, like
+/// the `planet` functions of the [`vsop87a`](vsop87a/index.html) to
+/// [`vsop87e`](vsop87e/index.html) modules. The VSOP87 solution has no elements for the Earth
+/// alone, so [`Planet::Earth`](enum.Planet.html#variant.Earth) gives those of the Earth - Moon
+/// barycenter, from [`earth_moon`](fn.earth_moon.html).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::Planet;
+///
+/// assert_eq!(vsop87::planet(Planet::Mars, 2451545.0), vsop87::mars(2451545.0));
+/// assert_eq!(vsop87::planet(Planet::Earth, 2451545.0), vsop87::earth_moon(2451545.0));
+/// ```
+pub fn planet(planet: Planet, jde: f64) -> VSOP87Elements {
+    match planet {
+        #[cfg(feature = "mercury")]
+        Planet::Mercury => mercury(jde),
+        #[cfg(feature = "venus")]
+        Planet::Venus => venus(jde),
+        #[cfg(feature = "earth")]
+        Planet::Earth => earth_moon(jde),
+        #[cfg(feature = "mars")]
+        Planet::Mars => mars(jde),
+        #[cfg(feature = "jupiter")]
+        Planet::Jupiter => jupiter(jde),
+        #[cfg(feature = "saturn")]
+        Planet::Saturn => saturn(jde),
+        #[cfg(feature = "uranus")]
+        Planet::Uranus => uranus(jde),
+        #[cfg(feature = "neptune")]
+        Planet::Neptune => neptune(jde),
+        #[allow(unreachable_patterns)]
+        _ => planet.not_enabled(),
     }
 }
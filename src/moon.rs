@@ -0,0 +1,241 @@
+//! Low-precision position of the Moon.
+//!
+//! *VSOP87* does not include the Moon, but its position is often needed together with the
+//! planets: the main *VSOP87* solution and [`vsop87a::earth_moon()`](../vsop87a/fn.earth_moon.html)
+//! give the barycenter of the Earth - Moon system, and the center of the Earth is about 4,670 km
+//! away from it, in the direction opposite to the Moon.
+//!
+//! This module computes the geocentric position of the Moon with the main terms of the
+//! *ELP-2000/82* theory, as given by Jean Meeus in *Astronomical Algorithms*, chapter 47. Only
+//! the largest terms are kept, so the results are low-precision: about 0.01° in longitude and
+//! latitude, and 50 km in distance. That is enough to move between the center of the Earth and the
+//! barycenter to better than 2 km, but not for eclipses or occultations.
+//!
+//! # Example
+//!
+//! ```
+//! use vsop87::{earth_moon_offset, moon, vsop87a};
+//!
+//! // The center of the Earth, from the position of the Earth - Moon barycenter.
+//! let jde = 2451545.0;
+//! let earth = vsop87a::earth_moon(jde) - earth_moon_offset(&moon::position(jde));
+//!
+//! assert!((earth - vsop87a::earth(jde)).norm() < 2e-8);
+//! ```
+
+use super::{cos, normalize_angle, sin, RectangularCoordinates, SphericalCoordinates, AU_KM};
+
+/// Calculates the geocentric ecliptic coordinates of the Moon.
+///
+/// The coordinates are referred to the mean ecliptic and equinox of the day, like the *VSOP87C*
+/// and *VSOP87D* solutions, and the distance between the centers of the Earth and of the Moon is
+/// in *AU*. The parameter needed is the Julian Day (*JD*) for the given date.
+///
+/// This is a truncated series, see the [module documentation](index.html) for its precision.
+///
+/// # Example
+///
+/// This is example 47.a from *Astronomical Algorithms*, for April 12th, 1992 at 0h TD, where the
+/// Moon is at a longitude of 133.162655°, a latitude of −3.229126° and 368,409.7 km:
+///
+/// ```
+/// use vsop87::moon;
+///
+/// let moon = moon::position(2448724.5);
+///
+/// assert!((moon.longitude().to_degrees() - 133.162655).abs() < 0.01);
+/// assert!((moon.latitude().to_degrees() - -3.229126).abs() < 0.01);
+/// assert!((moon.distance_km() - 368_409.7).abs() < 50.0);
+/// ```
+pub fn position(jde: f64) -> SphericalCoordinates {
+    let t = (jde - 2_451_545_f64) / 36_525_f64;
+
+    // Mean longitude of the Moon, and fundamental arguments, in degrees: mean elongation of the
+    // Moon from the Sun, mean anomalies of the Sun and of the Moon, and argument of latitude of
+    // the Moon.
+    let mean_longitude = 218.316_447_7
+        + t * (481_267.881_234_21
+            + t * (-0.001_578_6 + t * (1_f64 / 538_841_f64 - t / 65_194_000_f64)));
+    let arguments = [
+        297.850_192_1
+            + t * (445_267.111_403_4
+                + t * (-0.001_881_9 + t * (1_f64 / 545_868_f64 - t / 113_065_000_f64))),
+        357.529_109_2 + t * (35_999.050_290_9 + t * (-0.000_153_6 + t / 24_490_000_f64)),
+        134.963_396_4
+            + t * (477_198.867_505_5
+                + t * (0.008_741_4 + t * (1_f64 / 69_699_f64 - t / 14_712_000_f64))),
+        93.272_095
+            + t * (483_202.017_523_3
+                + t * (-0.003_653_9 - t * (1_f64 / 3_526_000_f64 - t / 863_310_000_f64))),
+    ];
+
+    // Eccentricity of the orbit of the Earth, which scales the terms with the anomaly of the Sun.
+    let eccentricity = 1_f64 - t * (0.002_516 + 0.000_007_4 * t);
+
+    let sum = |terms: &[([i8; 4], f64)], trigonometric: fn(f64) -> f64| {
+        terms.iter().fold(0_f64, |sum, (multiples, coefficient)| {
+            let argument = multiples
+                .iter()
+                .zip(arguments.iter())
+                .fold(0_f64, |argument, (multiple, value)| {
+                    argument + f64::from(*multiple) * value
+                });
+            let scale = match multiples[1].abs() {
+                0 => 1_f64,
+                1 => eccentricity,
+                _ => eccentricity * eccentricity,
+            };
+
+            sum + coefficient * scale * trigonometric(argument.to_radians())
+        })
+    };
+
+    // Additive terms, for the action of Venus and Jupiter, and the flattening of the Earth.
+    let a1 = (119.75 + 131.849 * t).to_radians();
+    let a2 = (53.09 + 479_264.29 * t).to_radians();
+    let a3 = (313.45 + 481_266.484 * t).to_radians();
+    let l = mean_longitude.to_radians();
+    let f = arguments[3].to_radians();
+    let m = arguments[2].to_radians();
+
+    // The longitude and latitude terms are in units of 10⁻⁶°, and the distance ones in meters.
+    let longitude = sum(&LONGITUDE_TERMS, sin)
+        + 3_958_f64 * sin(a1)
+        + 1_962_f64 * sin(l - f)
+        + 318_f64 * sin(a2);
+    let latitude = sum(&LATITUDE_TERMS, sin) - 2_235_f64 * sin(l)
+        + 382_f64 * sin(a3)
+        + 175_f64 * (sin(a1 - f) + sin(a1 + f))
+        + 127_f64 * sin(l - m)
+        - 115_f64 * sin(l + m);
+    let distance = sum(&DISTANCE_TERMS, cos);
+
+    SphericalCoordinates::new(
+        normalize_angle((mean_longitude + longitude / 1e6).to_radians()),
+        (latitude / 1e6).to_radians(),
+        (385_000.56 + distance / 1_000_f64) / AU_KM,
+    )
+}
+
+/// Calculates the position of the Moon relative to the center of the Earth, in *AU*.
+///
+/// This is the [`position()`](fn.position.html) of the Moon as rectangular coordinates, referred
+/// to the mean ecliptic and equinox of the day. The barycenter of the Earth - Moon system is at
+/// [`earth_moon_offset()`](../fn.earth_moon_offset.html) of the same position, so the center of
+/// the Earth can be derived from a barycenter position by subtracting it. For the *VSOP87A*
+/// coordinates, referred to the J2000.0 equinox, the offset must be
+/// [precessed](../struct.RectangularCoordinates.html#method.precess) first.
+///
+/// This is a truncated series, see the [module documentation](index.html) for its precision.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::moon;
+///
+/// let offset = moon::geocentric_offset(2451545.0);
+///
+/// assert!(offset.norm_km() > 356_000.0 && offset.norm_km() < 407_000.0);
+/// ```
+pub fn geocentric_offset(jde: f64) -> RectangularCoordinates {
+    position(jde).to_rectangular()
+}
+
+/// Largest periodic terms of the longitude of the Moon, in units of 10⁻⁶°.
+///
+/// Each term has the multiples of the fundamental arguments *D*, *M*, *M'* and *F*, and the
+/// coefficient of the sine of their combination.
+const LONGITUDE_TERMS: [([i8; 4], f64); 32] = [
+    ([0, 0, 1, 0], 6_288_774.0),
+    ([2, 0, -1, 0], 1_274_027.0),
+    ([2, 0, 0, 0], 658_314.0),
+    ([0, 0, 2, 0], 213_618.0),
+    ([0, 1, 0, 0], -185_116.0),
+    ([0, 0, 0, 2], -114_332.0),
+    ([2, 0, -2, 0], 58_793.0),
+    ([2, -1, -1, 0], 57_066.0),
+    ([2, 0, 1, 0], 53_322.0),
+    ([2, -1, 0, 0], 45_758.0),
+    ([0, 1, -1, 0], -40_923.0),
+    ([1, 0, 0, 0], -34_720.0),
+    ([0, 1, 1, 0], -30_383.0),
+    ([2, 0, 0, -2], 15_327.0),
+    ([0, 0, 1, 2], -12_528.0),
+    ([0, 0, 1, -2], 10_980.0),
+    ([4, 0, -1, 0], 10_675.0),
+    ([0, 0, 3, 0], 10_034.0),
+    ([4, 0, -2, 0], 8_548.0),
+    ([2, 1, -1, 0], -7_888.0),
+    ([2, 1, 0, 0], -6_766.0),
+    ([1, 0, -1, 0], -5_163.0),
+    ([1, 1, 0, 0], 4_987.0),
+    ([2, -1, 1, 0], 4_036.0),
+    ([2, 0, 2, 0], 3_994.0),
+    ([4, 0, 0, 0], 3_861.0),
+    ([2, 0, -3, 0], 3_665.0),
+    ([0, 1, -2, 0], -2_689.0),
+    ([2, 0, -1, 2], -2_602.0),
+    ([2, -1, -2, 0], 2_390.0),
+    ([1, 0, 1, 0], -2_348.0),
+    ([2, -2, 0, 0], 2_236.0),
+];
+
+/// Largest periodic terms of the distance of the Moon, in meters.
+///
+/// Each term has the multiples of the fundamental arguments *D*, *M*, *M'* and *F*, and the
+/// coefficient of the cosine of their combination.
+const DISTANCE_TERMS: [([i8; 4], f64); 26] = [
+    ([0, 0, 1, 0], -20_905_355.0),
+    ([2, 0, -1, 0], -3_699_111.0),
+    ([2, 0, 0, 0], -2_955_968.0),
+    ([0, 0, 2, 0], -569_925.0),
+    ([0, 1, 0, 0], 48_888.0),
+    ([0, 0, 0, 2], -3_149.0),
+    ([2, 0, -2, 0], 246_158.0),
+    ([2, -1, -1, 0], -152_138.0),
+    ([2, 0, 1, 0], -170_733.0),
+    ([2, -1, 0, 0], -204_586.0),
+    ([0, 1, -1, 0], -129_620.0),
+    ([1, 0, 0, 0], 108_743.0),
+    ([0, 1, 1, 0], 104_755.0),
+    ([2, 0, 0, -2], 10_321.0),
+    ([0, 0, 1, -2], 79_661.0),
+    ([4, 0, -1, 0], -34_782.0),
+    ([0, 0, 3, 0], -23_210.0),
+    ([4, 0, -2, 0], -21_636.0),
+    ([2, 1, -1, 0], 24_208.0),
+    ([2, 1, 0, 0], 30_824.0),
+    ([1, 0, -1, 0], -8_379.0),
+    ([1, 1, 0, 0], -16_675.0),
+    ([2, -1, 1, 0], -12_831.0),
+    ([2, 0, 2, 0], -10_445.0),
+    ([4, 0, 0, 0], -11_650.0),
+    ([2, 0, -3, 0], 14_403.0),
+];
+
+/// Largest periodic terms of the latitude of the Moon, in units of 10⁻⁶°.
+///
+/// Each term has the multiples of the fundamental arguments *D*, *M*, *M'* and *F*, and the
+/// coefficient of the sine of their combination.
+const LATITUDE_TERMS: [([i8; 4], f64); 20] = [
+    ([0, 0, 0, 1], 5_128_122.0),
+    ([0, 0, 1, 1], 280_602.0),
+    ([0, 0, 1, -1], 277_693.0),
+    ([2, 0, 0, -1], 173_237.0),
+    ([2, 0, -1, 1], 55_413.0),
+    ([2, 0, -1, -1], 46_271.0),
+    ([2, 0, 0, 1], 32_573.0),
+    ([0, 0, 2, 1], 17_198.0),
+    ([2, 0, 1, -1], 9_266.0),
+    ([0, 0, 2, -1], 8_822.0),
+    ([2, -1, 0, -1], 8_216.0),
+    ([2, 0, -2, -1], 4_324.0),
+    ([2, 0, 1, 1], 4_200.0),
+    ([2, 1, 0, -1], -3_359.0),
+    ([2, -1, -1, 1], 2_463.0),
+    ([2, -1, 0, 1], 2_211.0),
+    ([2, -1, -1, -1], 2_065.0),
+    ([0, 1, -1, -1], -1_870.0),
+    ([4, 0, -1, -1], 1_828.0),
+    ([0, 1, 0, 1], -1_794.0),
+];
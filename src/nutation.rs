@@ -0,0 +1,429 @@
+//! Nutation in longitude and in obliquity.
+//!
+//! The *VSOP87* solutions are referred to the mean ecliptic and equinox, which only include the
+//! slow precession of the axis of the Earth. The nutation is the periodic oscillation of the axis
+//! around that mean position, mainly caused by the Moon, with a main period of 18.6 years and an
+//! amplitude of about 17" in longitude and 9" in obliquity.
+//!
+//! To get apparent positions, the nutation in longitude (*Δψ*) is added to the ecliptic longitude,
+//! and the true obliquity, [`mean_obliquity`](../fn.mean_obliquity.html)` + Δε`, is used to
+//! convert to equatorial coordinates.
+//!
+//! # Example
+//!
+//! ```
+//! use vsop87::{mean_obliquity, nutation, vsop87d};
+//!
+//! let jde = 2446895.5;
+//! let (delta_psi, delta_epsilon) = nutation::nutation(jde);
+//!
+//! let true_obliquity = mean_obliquity(jde) + delta_epsilon;
+//! assert!((true_obliquity.to_degrees() - 23.443569).abs() < 1e-6);
+//! ```
+
+use super::{sin_cos, Model};
+
+/// Calculates the nutation in longitude (*Δψ*) and in obliquity (*Δε*), in radians.
+///
+/// It uses the complete series of the IAU 1980 theory of nutation, with its 63 terms, as given by
+/// Jean Meeus in *Astronomical Algorithms*, chapter 22. The series reproduces the theory to about
+/// 0.0003", but the theory itself differs from the modern IAU 2000A model by a few hundredths of
+/// an arcsecond, which is enough for most purposes. The IAU 2000 theory is available with
+/// [`nutation_with`](fn.nutation_with.html). The parameter needed is the Julian Day (*JD*) for the
+/// given date.
+///
+/// # Example
+///
+/// This is example 22.a from *Astronomical Algorithms*, for April 10th, 1987 at 0h TD:
+///
+/// ```
+/// use vsop87::nutation;
+///
+/// let (delta_psi, delta_epsilon) = nutation::nutation(2446895.5);
+///
+/// assert!((delta_psi.to_degrees() * 3600.0 - -3.788).abs() < 0.001);
+/// assert!((delta_epsilon.to_degrees() * 3600.0 - 9.443).abs() < 0.001);
+/// ```
+pub fn nutation(jde: f64) -> (f64, f64) {
+    let t = (jde - 2_451_545_f64) / 36_525_f64;
+
+    // Fundamental arguments, in degrees: mean elongation of the Moon from the Sun, mean anomalies
+    // of the Sun and of the Moon, argument of latitude of the Moon, and longitude of the
+    // ascending node of the mean orbit of the Moon.
+    let arguments = [
+        297.85036 + t * (445_267.111480 + t * (-0.0019142 + t / 189_474_f64)),
+        357.52772 + t * (35_999.050340 + t * (-0.0001603 - t / 300_000_f64)),
+        134.96298 + t * (477_198.867398 + t * (0.0086972 + t / 56_250_f64)),
+        93.27191 + t * (483_202.017538 + t * (-0.0036825 + t / 327_270_f64)),
+        125.04452 + t * (-1_934.136261 + t * (0.0020708 + t / 450_000_f64)),
+    ];
+
+    let (delta_psi, delta_epsilon) = TERMS.iter().fold(
+        (0_f64, 0_f64),
+        |(delta_psi, delta_epsilon), (multiples, [a, b, c, d])| {
+            let argument = multiples
+                .iter()
+                .zip(arguments.iter())
+                .fold(0_f64, |argument, (multiple, value)| {
+                    argument + f64::from(*multiple) * value
+                });
+            let (sin, cos) = sin_cos(argument.to_radians());
+
+            (
+                delta_psi + (a + b * t) * sin,
+                delta_epsilon + (c + d * t) * cos,
+            )
+        },
+    );
+
+    // The coefficients are in units of 0.0001".
+    (
+        (delta_psi / 36_000_000_f64).to_radians(),
+        (delta_epsilon / 36_000_000_f64).to_radians(),
+    )
+}
+
+/// Calculates the nutation in longitude (*Δψ*) and in obliquity (*Δε*) with the given model, in
+/// radians.
+///
+/// With [`Model::Iau1980`](../enum.Model.html), this is the same as [`nutation`](fn.nutation.html).
+/// With [`Model::Iau2006`](../enum.Model.html), it uses the IAU 2000B series, the 77 largest
+/// luni-solar terms of the IAU 2000A theory plus a fixed offset for the planetary terms, with the
+/// adjustments of the IAU 2006 precession. It reproduces IAU 2000A to about 1 mas (0.001") over
+/// 1995–2050, while the IAU 1980 theory is off by a few hundredths of an arcsecond. Use it with
+/// [`mean_obliquity_with`](../fn.mean_obliquity_with.html) and the same model.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{nutation, Model};
+///
+/// let (psi_1980, epsilon_1980) = nutation::nutation_with(2446895.5, Model::Iau1980);
+/// let (psi_2006, epsilon_2006) = nutation::nutation_with(2446895.5, Model::Iau2006);
+///
+/// assert_eq!((psi_1980, epsilon_1980), nutation::nutation(2446895.5));
+/// assert!((psi_2006 - psi_1980).to_degrees().abs() * 3600.0 < 0.05);
+/// assert!((epsilon_2006 - epsilon_1980).to_degrees().abs() * 3600.0 < 0.05);
+/// ```
+pub fn nutation_with(jde: f64, model: Model) -> (f64, f64) {
+    match model {
+        Model::Iau1980 => nutation(jde),
+        Model::Iau2006 => nutation_2000b(jde),
+    }
+}
+
+/// Calculates the IAU 2000B nutation, adjusted to the IAU 2006 precession, in radians.
+fn nutation_2000b(jde: f64) -> (f64, f64) {
+    let t = (jde - 2_451_545_f64) / 36_525_f64;
+
+    // Fundamental arguments by Simon et al. (1994), in arcseconds, in the same order as for the
+    // IAU 1980 series: D, M, M', F and Ω.
+    let arguments = [
+        1_072_260.703_69 + 1_602_961_601.209_0 * t,
+        1_287_104.793_05 + 129_596_581.048_1 * t,
+        485_868.249_036 + 1_717_915_923.217_8 * t,
+        335_779.526_232 + 1_739_527_262.847_8 * t,
+        450_160.398_036 - 6_962_890.543_1 * t,
+    ];
+
+    // The smallest terms are added first, to reduce the rounding errors.
+    let (delta_psi, delta_epsilon) = TERMS_2000B.iter().rev().fold(
+        (0_f64, 0_f64),
+        |(delta_psi, delta_epsilon), (multiples, [a, b, c, d, e, f])| {
+            let argument = multiples.iter().zip(arguments.iter()).fold(
+                0_f64,
+                |argument, (multiple, value)| {
+                    argument + f64::from(*multiple) * (value % 1_296_000_f64)
+                },
+            );
+            let (sin, cos) = sin_cos((argument / 3_600_f64).to_radians());
+
+            (
+                delta_psi + (a + b * t) * sin + c * cos,
+                delta_epsilon + (d + e * t) * cos + f * sin,
+            )
+        },
+    );
+
+    // The coefficients are in units of 0.1 µas, and the planetary terms are replaced by a fixed
+    // offset of a fraction of a mas.
+    let delta_psi = (delta_psi / 1e7 - 0.000_135) / 3_600_f64;
+    let delta_epsilon = (delta_epsilon / 1e7 + 0.000_388) / 3_600_f64;
+
+    // Adjustments for the IAU 2006 precession, from the rate of change of J2 and the new value
+    // of the obliquity at J2000.0.
+    let j2_rate = -2.7774e-6 * t;
+    (
+        (delta_psi * (1_f64 + 0.4697e-6 + j2_rate)).to_radians(),
+        (delta_epsilon * (1_f64 + j2_rate)).to_radians(),
+    )
+}
+
+/// Terms of the IAU 1980 nutation series.
+///
+/// Each term has the multiples of the fundamental arguments *D*, *M*, *M'*, *F* and *Ω*, and the
+/// coefficients `[a, b, c, d]`, in units of 0.0001", so that the term adds `(a + b·T)·sin(arg)`
+/// to *Δψ* and `(c + d·T)·cos(arg)` to *Δε*.
+const TERMS: [([i8; 5], [f64; 4]); 63] = [
+    ([0, 0, 0, 0, 1], [-171_996.0, -174.2, 92_025.0, 8.9]),
+    ([-2, 0, 0, 2, 2], [-13_187.0, -1.6, 5_736.0, -3.1]),
+    ([0, 0, 0, 2, 2], [-2_274.0, -0.2, 977.0, -0.5]),
+    ([0, 0, 0, 0, 2], [2_062.0, 0.2, -895.0, 0.5]),
+    ([0, 1, 0, 0, 0], [1_426.0, -3.4, 54.0, -0.1]),
+    ([0, 0, 1, 0, 0], [712.0, 0.1, -7.0, 0.0]),
+    ([-2, 1, 0, 2, 2], [-517.0, 1.2, 224.0, -0.6]),
+    ([0, 0, 0, 2, 1], [-386.0, -0.4, 200.0, 0.0]),
+    ([0, 0, 1, 2, 2], [-301.0, 0.0, 129.0, -0.1]),
+    ([-2, -1, 0, 2, 2], [217.0, -0.5, -95.0, 0.3]),
+    ([-2, 0, 1, 0, 0], [-158.0, 0.0, 0.0, 0.0]),
+    ([-2, 0, 0, 2, 1], [129.0, 0.1, -70.0, 0.0]),
+    ([0, 0, -1, 2, 2], [123.0, 0.0, -53.0, 0.0]),
+    ([2, 0, 0, 0, 0], [63.0, 0.0, 0.0, 0.0]),
+    ([0, 0, 1, 0, 1], [63.0, 0.1, -33.0, 0.0]),
+    ([2, 0, -1, 2, 2], [-59.0, 0.0, 26.0, 0.0]),
+    ([0, 0, -1, 0, 1], [-58.0, -0.1, 32.0, 0.0]),
+    ([0, 0, 1, 2, 1], [-51.0, 0.0, 27.0, 0.0]),
+    ([-2, 0, 2, 0, 0], [48.0, 0.0, 0.0, 0.0]),
+    ([0, 0, -2, 2, 1], [46.0, 0.0, -24.0, 0.0]),
+    ([2, 0, 0, 2, 2], [-38.0, 0.0, 16.0, 0.0]),
+    ([0, 0, 2, 2, 2], [-31.0, 0.0, 13.0, 0.0]),
+    ([0, 0, 2, 0, 0], [29.0, 0.0, 0.0, 0.0]),
+    ([-2, 0, 1, 2, 2], [29.0, 0.0, -12.0, 0.0]),
+    ([0, 0, 0, 2, 0], [26.0, 0.0, 0.0, 0.0]),
+    ([-2, 0, 0, 2, 0], [-22.0, 0.0, 0.0, 0.0]),
+    ([0, 0, -1, 2, 1], [21.0, 0.0, -10.0, 0.0]),
+    ([0, 2, 0, 0, 0], [17.0, -0.1, 0.0, 0.0]),
+    ([2, 0, -1, 0, 1], [16.0, 0.0, -8.0, 0.0]),
+    ([-2, 2, 0, 2, 2], [-16.0, 0.1, 7.0, 0.0]),
+    ([0, 1, 0, 0, 1], [-15.0, 0.0, 9.0, 0.0]),
+    ([-2, 0, 1, 0, 1], [-13.0, 0.0, 7.0, 0.0]),
+    ([0, -1, 0, 0, 1], [-12.0, 0.0, 6.0, 0.0]),
+    ([0, 0, 2, -2, 0], [11.0, 0.0, 0.0, 0.0]),
+    ([2, 0, -1, 2, 1], [-10.0, 0.0, 5.0, 0.0]),
+    ([2, 0, 1, 2, 2], [-8.0, 0.0, 3.0, 0.0]),
+    ([0, 1, 0, 2, 2], [7.0, 0.0, -3.0, 0.0]),
+    ([-2, 1, 1, 0, 0], [-7.0, 0.0, 0.0, 0.0]),
+    ([0, -1, 0, 2, 2], [-7.0, 0.0, 3.0, 0.0]),
+    ([2, 0, 0, 2, 1], [-7.0, 0.0, 3.0, 0.0]),
+    ([2, 0, 1, 0, 0], [6.0, 0.0, 0.0, 0.0]),
+    ([-2, 0, 2, 2, 2], [6.0, 0.0, -3.0, 0.0]),
+    ([-2, 0, 1, 2, 1], [6.0, 0.0, -3.0, 0.0]),
+    ([2, 0, -2, 0, 1], [-6.0, 0.0, 3.0, 0.0]),
+    ([2, 0, 0, 0, 1], [-6.0, 0.0, 3.0, 0.0]),
+    ([0, -1, 1, 0, 0], [5.0, 0.0, 0.0, 0.0]),
+    ([-2, -1, 0, 2, 1], [-5.0, 0.0, 3.0, 0.0]),
+    ([-2, 0, 0, 0, 1], [-5.0, 0.0, 3.0, 0.0]),
+    ([0, 0, 2, 2, 1], [-5.0, 0.0, 3.0, 0.0]),
+    ([-2, 0, 2, 0, 1], [4.0, 0.0, 0.0, 0.0]),
+    ([-2, 1, 0, 2, 1], [4.0, 0.0, 0.0, 0.0]),
+    ([0, 0, 1, -2, 0], [4.0, 0.0, 0.0, 0.0]),
+    ([-1, 0, 1, 0, 0], [-4.0, 0.0, 0.0, 0.0]),
+    ([-2, 1, 0, 0, 0], [-4.0, 0.0, 0.0, 0.0]),
+    ([1, 0, 0, 0, 0], [-4.0, 0.0, 0.0, 0.0]),
+    ([0, 0, 1, 2, 0], [3.0, 0.0, 0.0, 0.0]),
+    ([0, 0, -2, 2, 2], [-3.0, 0.0, 0.0, 0.0]),
+    ([-1, -1, 1, 0, 0], [-3.0, 0.0, 0.0, 0.0]),
+    ([0, 1, 1, 0, 0], [-3.0, 0.0, 0.0, 0.0]),
+    ([0, -1, 1, 2, 2], [-3.0, 0.0, 0.0, 0.0]),
+    ([2, -1, -1, 2, 2], [-3.0, 0.0, 0.0, 0.0]),
+    ([0, 0, 3, 2, 2], [-3.0, 0.0, 0.0, 0.0]),
+    ([2, -1, 0, 2, 2], [-3.0, 0.0, 0.0, 0.0]),
+];
+
+/// Luni-solar terms of the IAU 2000B nutation series.
+///
+/// Each term has the multiples of the fundamental arguments *D*, *M*, *M'*, *F* and *Ω*, and the
+/// coefficients `[a, b, c, d, e, f]`, in units of 0.1 µas, so that the term adds
+/// `(a + b·T)·sin(arg) + c·cos(arg)` to *Δψ* and `(d + e·T)·cos(arg) + f·sin(arg)` to *Δε*.
+const TERMS_2000B: [([i8; 5], [f64; 6]); 77] = [
+    (
+        [0, 0, 0, 0, 1],
+        [
+            -172_064_161.0,
+            -174_666.0,
+            33_386.0,
+            92_052_331.0,
+            9_086.0,
+            15_377.0,
+        ],
+    ),
+    (
+        [-2, 0, 0, 2, 2],
+        [
+            -13_170_906.0,
+            -1_675.0,
+            -13_696.0,
+            5_730_336.0,
+            -3_015.0,
+            -4_587.0,
+        ],
+    ),
+    (
+        [0, 0, 0, 2, 2],
+        [-2_276_413.0, -234.0, 2_796.0, 978_459.0, -485.0, 1_374.0],
+    ),
+    (
+        [0, 0, 0, 0, 2],
+        [2_074_554.0, 207.0, -698.0, -897_492.0, 470.0, -291.0],
+    ),
+    (
+        [0, 1, 0, 0, 0],
+        [1_475_877.0, -3_633.0, 11_817.0, 73_871.0, -184.0, -1_924.0],
+    ),
+    (
+        [-2, 1, 0, 2, 2],
+        [-516_821.0, 1_226.0, -524.0, 224_386.0, -677.0, -174.0],
+    ),
+    (
+        [0, 0, 1, 0, 0],
+        [711_159.0, 73.0, -872.0, -6_750.0, 0.0, 358.0],
+    ),
+    (
+        [0, 0, 0, 2, 1],
+        [-387_298.0, -367.0, 380.0, 200_728.0, 18.0, 318.0],
+    ),
+    (
+        [0, 0, 1, 2, 2],
+        [-301_461.0, -36.0, 816.0, 129_025.0, -63.0, 367.0],
+    ),
+    (
+        [-2, -1, 0, 2, 2],
+        [215_829.0, -494.0, 111.0, -95_929.0, 299.0, 132.0],
+    ),
+    (
+        [-2, 0, 0, 2, 1],
+        [128_227.0, 137.0, 181.0, -68_982.0, -9.0, 39.0],
+    ),
+    (
+        [0, 0, -1, 2, 2],
+        [123_457.0, 11.0, 19.0, -53_311.0, 32.0, -4.0],
+    ),
+    (
+        [2, 0, -1, 0, 0],
+        [156_994.0, 10.0, -168.0, -1_235.0, 0.0, 82.0],
+    ),
+    (
+        [0, 0, 1, 0, 1],
+        [63_110.0, 63.0, 27.0, -33_228.0, 0.0, -9.0],
+    ),
+    (
+        [0, 0, -1, 0, 1],
+        [-57_976.0, -63.0, -189.0, 31_429.0, 0.0, -75.0],
+    ),
+    (
+        [2, 0, -1, 2, 2],
+        [-59_641.0, -11.0, 149.0, 25_543.0, -11.0, 66.0],
+    ),
+    (
+        [0, 0, 1, 2, 1],
+        [-51_613.0, -42.0, 129.0, 26_366.0, 0.0, 78.0],
+    ),
+    (
+        [0, 0, -2, 2, 1],
+        [45_893.0, 50.0, 31.0, -24_236.0, -10.0, 20.0],
+    ),
+    (
+        [2, 0, 0, 0, 0],
+        [63_384.0, 11.0, -150.0, -1_220.0, 0.0, 29.0],
+    ),
+    (
+        [2, 0, 0, 2, 2],
+        [-38_571.0, -1.0, 158.0, 16_452.0, -11.0, 68.0],
+    ),
+    ([-2, -2, 0, 2, 2], [32_481.0, 0.0, 0.0, -13_870.0, 0.0, 0.0]),
+    ([2, 0, -2, 0, 0], [-47_722.0, 0.0, -18.0, 477.0, 0.0, -25.0]),
+    (
+        [0, 0, 2, 2, 2],
+        [-31_046.0, -1.0, 131.0, 13_238.0, -11.0, 59.0],
+    ),
+    (
+        [-2, 0, 1, 2, 2],
+        [28_593.0, 0.0, -1.0, -12_338.0, 10.0, -3.0],
+    ),
+    (
+        [0, 0, -1, 2, 1],
+        [20_441.0, 21.0, 10.0, -10_758.0, 0.0, -3.0],
+    ),
+    ([0, 0, 2, 0, 0], [29_243.0, 0.0, -74.0, -609.0, 0.0, 13.0]),
+    ([0, 0, 0, 2, 0], [25_887.0, 0.0, -66.0, -550.0, 0.0, 11.0]),
+    (
+        [0, 1, 0, 0, 1],
+        [-14_053.0, -25.0, 79.0, 8_551.0, -2.0, -45.0],
+    ),
+    (
+        [2, 0, -1, 0, 1],
+        [15_164.0, 10.0, 11.0, -8_001.0, 0.0, -1.0],
+    ),
+    (
+        [-2, 2, 0, 2, 2],
+        [-15_794.0, 72.0, -16.0, 6_850.0, -42.0, -5.0],
+    ),
+    ([2, 0, 0, -2, 0], [21_783.0, 0.0, 13.0, -167.0, 0.0, 13.0]),
+    (
+        [-2, 0, 1, 0, 1],
+        [-12_873.0, -10.0, -37.0, 6_953.0, 0.0, -14.0],
+    ),
+    (
+        [0, -1, 0, 0, 1],
+        [-12_654.0, 11.0, 63.0, 6_415.0, 0.0, 26.0],
+    ),
+    ([2, 0, -1, 2, 1], [-10_204.0, 0.0, 25.0, 5_222.0, 0.0, 15.0]),
+    ([0, 2, 0, 0, 0], [16_707.0, -85.0, -10.0, 168.0, -1.0, 10.0]),
+    ([2, 0, 1, 2, 2], [-7_691.0, 0.0, 44.0, 3_268.0, 0.0, 19.0]),
+    ([0, 0, -2, 2, 0], [-11_024.0, 0.0, -14.0, 104.0, 0.0, 2.0]),
+    (
+        [0, 1, 0, 2, 2],
+        [7_566.0, -21.0, -11.0, -3_250.0, 0.0, -5.0],
+    ),
+    ([2, 0, 0, 2, 1], [-6_637.0, -11.0, 25.0, 3_353.0, 0.0, 14.0]),
+    ([0, -1, 0, 2, 2], [-7_141.0, 21.0, 8.0, 3_070.0, 0.0, 4.0]),
+    ([2, 0, 0, 0, 1], [-6_302.0, -11.0, 2.0, 3_272.0, 0.0, 4.0]),
+    ([-2, 0, 1, 2, 1], [5_800.0, 10.0, 2.0, -3_045.0, 0.0, -1.0]),
+    ([-2, 0, 2, 2, 2], [6_443.0, 0.0, -7.0, -2_768.0, 0.0, -4.0]),
+    (
+        [2, 0, -2, 0, 1],
+        [-5_774.0, -11.0, -15.0, 3_041.0, 0.0, -5.0],
+    ),
+    ([0, 0, 2, 2, 1], [-5_350.0, 0.0, 21.0, 2_695.0, 0.0, 12.0]),
+    (
+        [-2, -1, 0, 2, 1],
+        [-4_752.0, -11.0, -3.0, 2_719.0, 0.0, -3.0],
+    ),
+    (
+        [-2, 0, 0, 0, 1],
+        [-4_940.0, -11.0, -21.0, 2_720.0, 0.0, -9.0],
+    ),
+    ([2, -1, -1, 0, 0], [7_350.0, 0.0, -8.0, -51.0, 0.0, 4.0]),
+    ([-2, 0, 2, 0, 1], [4_065.0, 0.0, 6.0, -2_206.0, 0.0, 1.0]),
+    ([2, 0, 1, 0, 0], [6_579.0, 0.0, -24.0, -199.0, 0.0, 2.0]),
+    ([-2, 1, 0, 2, 1], [3_579.0, 0.0, 5.0, -1_900.0, 0.0, 1.0]),
+    ([0, -1, 1, 0, 0], [4_725.0, 0.0, -6.0, -41.0, 0.0, 3.0]),
+    ([0, 0, -2, 2, 2], [-3_075.0, 0.0, -2.0, 1_313.0, 0.0, -1.0]),
+    ([0, 0, 3, 2, 2], [-2_904.0, 0.0, 15.0, 1_233.0, 0.0, 7.0]),
+    ([2, -1, 0, 0, 0], [4_348.0, 0.0, -10.0, -81.0, 0.0, 2.0]),
+    ([0, -1, 1, 2, 2], [-2_878.0, 0.0, 8.0, 1_232.0, 0.0, 4.0]),
+    ([1, 0, 0, 0, 0], [-4_230.0, 0.0, 5.0, -20.0, 0.0, -2.0]),
+    ([2, -1, -1, 2, 2], [-2_819.0, 0.0, 7.0, 1_207.0, 0.0, 3.0]),
+    ([0, 0, -1, 2, 0], [-4_056.0, 0.0, 5.0, 40.0, 0.0, -2.0]),
+    ([2, -1, 0, 2, 2], [-2_647.0, 0.0, 11.0, 1_129.0, 0.0, 5.0]),
+    ([0, 0, -2, 0, 1], [-2_294.0, 0.0, -10.0, 1_266.0, 0.0, -4.0]),
+    ([0, 1, 1, 2, 2], [2_481.0, 0.0, -7.0, -1_062.0, 0.0, -3.0]),
+    ([0, 0, 2, 0, 1], [2_179.0, 0.0, -2.0, -1_129.0, 0.0, -2.0]),
+    ([1, 1, -1, 0, 0], [3_276.0, 0.0, 1.0, -9.0, 0.0, 0.0]),
+    ([0, 1, 1, 0, 0], [-3_389.0, 0.0, 5.0, 35.0, 0.0, -2.0]),
+    ([0, 0, 1, 2, 0], [3_339.0, 0.0, -13.0, -107.0, 0.0, 1.0]),
+    ([-2, 0, -1, 2, 1], [-1_987.0, 0.0, -6.0, 1_073.0, 0.0, -2.0]),
+    ([0, 0, 1, 0, 2], [-1_981.0, 0.0, 0.0, 854.0, 0.0, 0.0]),
+    (
+        [1, 0, -1, 0, 0],
+        [4_026.0, 0.0, -353.0, -553.0, 0.0, -139.0],
+    ),
+    ([1, 0, 0, 2, 2], [1_660.0, 0.0, -5.0, -710.0, 0.0, -2.0]),
+    ([4, 0, -1, 2, 2], [-1_521.0, 0.0, 9.0, 647.0, 0.0, 4.0]),
+    ([1, 1, -1, 0, 1], [1_314.0, 0.0, 0.0, -700.0, 0.0, 0.0]),
+    ([-2, -2, 0, 2, 1], [-1_283.0, 0.0, 0.0, 672.0, 0.0, 0.0]),
+    ([2, 0, 1, 2, 1], [-1_331.0, 0.0, 8.0, 663.0, 0.0, 4.0]),
+    ([2, 0, -2, 2, 2], [1_383.0, 0.0, -2.0, -594.0, 0.0, -2.0]),
+    ([0, 0, -1, 0, 2], [1_405.0, 0.0, 4.0, -610.0, 0.0, 2.0]),
+    ([-2, 1, 1, 2, 2], [1_290.0, 0.0, 0.0, -556.0, 0.0, 0.0]),
+];
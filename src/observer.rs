@@ -0,0 +1,222 @@
+//! Rising, transit and setting times of the planets for an observer.
+//!
+//! The functions in this module compute when a planet crosses the horizon and the local meridian,
+//! with the iterative method described by Jean Meeus in *Astronomical Algorithms*, chapter 15.
+//! The apparent right ascension and declination of the planet are computed at three times of the
+//! day, and interpolated to follow its motion during the day. Only available with the `earth`
+//! feature.
+//!
+//! # Example
+//!
+//! ```
+//! use vsop87::{julian_day, observer, Planet};
+//!
+//! // Jupiter from Madrid, on January 1st, 2000.
+//! let midnight = julian_day(2000, 1, 1.0);
+//! let latitude = 40.4168_f64.to_radians();
+//! let longitude = -3.7038_f64.to_radians();
+//!
+//! let (rise, transit, set) =
+//!     observer::rise_transit_set(Planet::Jupiter, midnight, latitude, longitude);
+//!
+//! assert!(rise.unwrap() < transit);
+//! assert!((midnight..midnight + 1.0).contains(&transit));
+//! assert!(set.is_some());
+//! ```
+
+use super::{
+    acos, apparent_sidereal_time, asin, delta_t, geocentric, mean_obliquity, normalize_angle,
+    normalize_angle_signed, nutation, sin_cos, ut_to_tt, Planet,
+};
+
+#[cfg(feature = "no_std")]
+use core::f64::consts::PI;
+
+#[cfg(not(feature = "no_std"))]
+use std::f64::consts::PI;
+
+/// Standard altitude of the planets at rising and setting, in degrees.
+///
+/// It is the apparent altitude of the center of the planet when it touches the horizon: the
+/// atmospheric refraction lifts objects at the horizon by about 34', so a planet is seen rising
+/// when it is still 0°34' below the geometric horizon.
+pub const STANDARD_ALTITUDE: f64 = -0.5667;
+
+/// Rotation of the Earth relative to the equinox, in radians per day of Universal Time.
+const SIDEREAL_RATE: f64 = 2_f64 * PI * 1.002_737_909_35;
+
+/// Maximum number of corrections of each time.
+const MAX_ITERATIONS: u8 = 10;
+
+/// Precision of the times, in days.
+const PRECISION_DAYS: f64 = 1e-7;
+
+/// Calculates the rising, transit and setting times of a planet.
+///
+/// The parameters are the Julian Day (*JD*) of 0h Universal Time (*UT*) of the date, and the
+/// geographic latitude and longitude of the observer, in radians, with the longitude positive
+/// east of Greenwich. The result is a `(rise, transit, set)` tuple of Julian Days, in *UT*, all of
+/// them between `jd_midnight` and `jd_midnight + 1`, so the rising can happen after the transit
+/// or the setting of the same date. The rising and the setting are `None` when the planet does not
+/// cross the horizon that day: it is circumpolar, or it never rises.
+///
+/// The planet rises and sets when the center of its disk is at the
+/// [`STANDARD_ALTITUDE`](constant.STANDARD_ALTITUDE.html), which assumes a standard atmospheric
+/// refraction of 34' at the horizon, and an observer at sea level with a flat horizon. The
+/// semidiameter and the parallax of the planets are below 1', and are neglected, but the actual
+/// refraction can change the times by a few minutes, so the results should not be trusted to
+/// better than about a minute. The transit is the upper culmination, when the planet crosses the
+/// meridian to the south (or to the north, in the southern hemisphere), and it does not depend on
+/// the refraction.
+///
+/// The apparent right ascension and declination of the planet are computed with
+/// [`geocentric::apparent_equatorial()`](../geocentric/fn.apparent_equatorial.html) at 0h, 12h
+/// and 24h *UT*, using [`delta_t()`](../fn.delta_t.html) to get the *TT* of those times, and are
+/// interpolated between them. The sidereal time is the apparent one, including the nutation.
+///
+/// # Example
+///
+/// This is example 15.a from *Astronomical Algorithms*: Venus from Boston, on March 20th, 1988.
+/// It rose at 12h25m, transited at 19h41m and set at 2h55m *UT*:
+///
+/// ```
+/// use vsop87::{julian_day, observer, Planet};
+///
+/// let midnight = julian_day(1988, 3, 20.0);
+/// let latitude = 42.3333_f64.to_radians();
+/// let longitude = -71.0833_f64.to_radians();
+///
+/// let (rise, transit, set) =
+///     observer::rise_transit_set(Planet::Venus, midnight, latitude, longitude);
+///
+/// assert!((rise.unwrap() - midnight - 0.51766).abs() < 1e-3);
+/// assert!((transit - midnight - 0.81980).abs() < 1e-3);
+/// assert!((set.unwrap() - midnight - 0.12130).abs() < 1e-3);
+/// ```
+pub fn rise_transit_set(
+    planet: Planet,
+    jd_midnight: f64,
+    lat: f64,
+    lon: f64,
+) -> (Option<f64>, f64, Option<f64>) {
+    let year = 2_000_f64 + (jd_midnight - 2_451_545_f64) / 365.25;
+    let delta_t = delta_t(year);
+
+    let jde = ut_to_tt(jd_midnight, delta_t);
+    let (delta_psi, delta_epsilon) = nutation::nutation(jde);
+    let sidereal_time =
+        apparent_sidereal_time(jd_midnight, delta_psi, mean_obliquity(jde) + delta_epsilon);
+
+    let position = |fraction: f64| {
+        let coordinates =
+            geocentric::apparent_equatorial(planet, ut_to_tt(jd_midnight + fraction, delta_t));
+        (coordinates.right_ascension(), coordinates.declination())
+    };
+    let interpolation = Interpolation::new([position(0_f64), position(0.5), position(1_f64)]);
+
+    let (sin_lat, cos_lat) = sin_cos(lat);
+    let altitude = STANDARD_ALTITUDE.to_radians();
+
+    // Hour angle, altitude and declination of the planet at the fraction `m` of the day.
+    let horizontal = |m: f64| {
+        let (right_ascension, declination) = interpolation.at(m);
+        let hour_angle =
+            normalize_angle_signed(sidereal_time + SIDEREAL_RATE * m + lon - right_ascension);
+        let (sin_dec, cos_dec) = sin_cos(declination);
+        let (_, cos_hour_angle) = sin_cos(hour_angle);
+
+        (
+            hour_angle,
+            asin(sin_lat * sin_dec + cos_lat * cos_dec * cos_hour_angle),
+            declination,
+        )
+    };
+
+    // First approximations, with the position of the planet at 12h.
+    let (right_ascension, declination) = interpolation.at(0.5);
+    let transit = normalize_angle(right_ascension - lon - sidereal_time) / (2_f64 * PI);
+    let transit = refine(transit, |m| -horizontal(m).0 / (2_f64 * PI));
+
+    let (sin_dec, cos_dec) = sin_cos(declination);
+    let (sin_altitude, _) = sin_cos(altitude);
+    let cos_hour_angle = (sin_altitude - sin_lat * sin_dec) / (cos_lat * cos_dec);
+    if !(-1_f64..=1_f64).contains(&cos_hour_angle) {
+        return (None, jd_midnight + transit, None);
+    }
+
+    let half_arc = acos(cos_hour_angle) / (2_f64 * PI);
+    let correction = |m: f64| {
+        let (hour_angle, h, declination) = horizontal(m);
+        let (sin_hour_angle, _) = sin_cos(hour_angle);
+        let (_, cos_dec) = sin_cos(declination);
+
+        (h - altitude) / (2_f64 * PI * cos_dec * cos_lat * sin_hour_angle)
+    };
+    let rise = refine(normalize_fraction(transit - half_arc), correction);
+    let set = refine(normalize_fraction(transit + half_arc), correction);
+
+    (
+        Some(jd_midnight + rise),
+        jd_midnight + transit,
+        Some(jd_midnight + set),
+    )
+}
+
+/// Quadratic interpolation of the right ascension and declination at 0h, 12h and 24h.
+#[derive(Debug, Clone, Copy)]
+struct Interpolation {
+    values: [(f64, f64); 3],
+}
+
+impl Interpolation {
+    /// Creates the interpolation, unwrapping the right ascensions around the one at 12h.
+    fn new(values: [(f64, f64); 3]) -> Self {
+        let middle = values[1].0;
+        let unwrap = |(right_ascension, declination): (f64, f64)| {
+            (
+                middle + normalize_angle_signed(right_ascension - middle),
+                declination,
+            )
+        };
+
+        Self {
+            values: [unwrap(values[0]), values[1], unwrap(values[2])],
+        }
+    }
+
+    /// Interpolates the right ascension and the declination at the fraction `m` of the day.
+    fn at(&self, m: f64) -> (f64, f64) {
+        // Interpolation factor, in half days from 12h.
+        let n = 2_f64 * m - 1_f64;
+        let interpolate = |y1: f64, y2: f64, y3: f64| {
+            let a = y2 - y1;
+            let b = y3 - y2;
+            y2 + n / 2_f64 * (a + b + n * (b - a))
+        };
+
+        let [(ra1, dec1), (ra2, dec2), (ra3, dec3)] = self.values;
+        (interpolate(ra1, ra2, ra3), interpolate(dec1, dec2, dec3))
+    }
+}
+
+/// Refines a time, as a fraction of the day, by adding the given correction until it converges.
+fn refine<F>(mut m: f64, correction: F) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    for _ in 0..MAX_ITERATIONS {
+        let delta = correction(m);
+        m = normalize_fraction(m + delta);
+
+        if delta.abs() < PRECISION_DAYS {
+            break;
+        }
+    }
+
+    m
+}
+
+/// Normalizes a fraction of the day into the `[0, 1)` range.
+fn normalize_fraction(m: f64) -> f64 {
+    normalize_angle(m * 2_f64 * PI) / (2_f64 * PI)
+}
@@ -0,0 +1,184 @@
+//! Physical ephemeris of the planets: the sub-Earth and sub-solar points.
+//!
+//! The sub-Earth point of a planet is the point of its surface at the center of its disk, as seen
+//! from the Earth, and the sub-solar point is the one where the Sun is at the zenith. Their
+//! coordinates on the planet tell which side of it is seen from the Earth, and which side is lit,
+//! and depend on the orientation of the planet, given by its rotation model.
+//!
+//! The functions in this module take the rotation elements of the planet as defined by the IAU
+//! Working Group on Cartographic Coordinates and Rotational Elements:
+//!
+//! - The right ascension *α₀* and the declination *δ₀* of the north pole of rotation of the
+//!   planet, referred to the ICRF equator and equinox (J2000.0), in radians. The north pole is
+//!   the one on the north side of the invariable plane of the solar system.
+//! - The angle *W* of the prime meridian, measured eastwards along the equator of the planet from
+//!   its ascending node on the ICRF equator, given as `W = W₀ + Ẇ·d`, where *d* is the time in
+//!   days since J2000.0, with *W₀* in radians and *Ẇ* in radians per day. *Ẇ* is negative for the
+//!   planets with a retrograde rotation, Venus and Uranus.
+//!
+//! The IAU gives *α₀* and *δ₀* as slow functions of time, which should be evaluated by the
+//! caller at the date of interest. The positions are computed from the *VSOP87A* solutions,
+//! corrected for light-time, and rotated to the equatorial frame of J2000.0, which matches the
+//! ICRF to better than 0.1". Only available with the `earth` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use vsop87::{physical, Planet};
+//!
+//! // IAU 2015 rotation model of Jupiter, with the System III prime meridian.
+//! let jde = 2451545.0;
+//! let (longitude, latitude) = physical::sub_earth(
+//!     Planet::Jupiter,
+//!     jde,
+//!     268.056595_f64.to_radians(),
+//!     64.495303_f64.to_radians(),
+//!     284.95_f64.to_radians(),
+//!     870.536_f64.to_radians(),
+//! );
+//!
+//! // The Earth is always within a few degrees of the equator of Jupiter.
+//! assert!(latitude.to_degrees().abs() < 4.0);
+//! assert!(longitude >= 0.0 && longitude < 2.0 * std::f64::consts::PI);
+//! ```
+
+use super::{
+    asin, atan2, geocentric, mean_obliquity, normalize_angle, sin_cos, vsop87a, Planet,
+    RectangularCoordinates,
+};
+
+/// Calculates the planetographic coordinates of the sub-Earth point of a planet.
+///
+/// The parameters are the planet, the Julian Day (*JD*) of the observation, and the rotation
+/// elements of the planet, as described in the [module documentation](index.html): `pole_ra`
+/// and `pole_dec` are *α₀* and *δ₀*, and `w0` and `w_dot` give the angle of the prime meridian.
+/// The planet is seen where it was when the light left it, so its position and its rotation are
+/// computed one light-time before `jde`.
+///
+/// The result is the `(longitude, latitude)` of the sub-Earth point, in radians. Following the
+/// IAU convention for planetographic coordinates, the longitude increases in the direction
+/// opposite to the rotation: towards the west for the planets with a prograde rotation, and
+/// towards the east for Venus and Uranus. It is in the `[0, 2π)` range. The latitude is the
+/// planetocentric one, measured from the center of the planet, which differs from the
+/// planetographic latitude of a flattened planet by at most a few degrees.
+///
+/// # Example
+///
+/// This is example 42.a from *Astronomical Algorithms*, by Jean Meeus: Mars on November 9th, 1992
+/// at 0h TD, when the longitude of the central meridian was 111.55°, and the latitude of the
+/// sub-Earth point +12.44°. The book uses an older rotation model than the IAU 2015 one below:
+///
+/// ```
+/// use vsop87::{physical, Planet};
+///
+/// let jde = 2448935.5;
+/// let t = (jde - 2451545.0) / 36525.0;
+/// let (longitude, latitude) = physical::sub_earth(
+///     Planet::Mars,
+///     jde,
+///     (317.68143 - 0.1061 * t).to_radians(),
+///     (52.88650 - 0.0609 * t).to_radians(),
+///     176.630_f64.to_radians(),
+///     350.89198226_f64.to_radians(),
+/// );
+///
+/// assert!((longitude.to_degrees() - 111.55).abs() < 0.5);
+/// assert!((latitude.to_degrees() - 12.44).abs() < 0.1);
+/// ```
+pub fn sub_earth(
+    planet: Planet,
+    jde: f64,
+    pole_ra: f64,
+    pole_dec: f64,
+    w0: f64,
+    w_dot: f64,
+) -> (f64, f64) {
+    let emission = jde - geocentric::distance_light_time(planet, jde);
+    let towards_earth = vsop87a::earth(jde) - vsop87a::planet(planet, emission);
+
+    planetographic(&towards_earth, emission, pole_ra, pole_dec, w0, w_dot)
+}
+
+/// Calculates the planetographic coordinates of the sub-solar point of a planet.
+///
+/// This is the same as [`sub_earth()`](fn.sub_earth.html), but for the point of the planet where
+/// the Sun is at the zenith, at the time the light seen from the Earth at `jde` left the planet.
+/// The difference between both points shows how much of the night side of the planet can be
+/// seen from the Earth.
+///
+/// # Example
+///
+/// This is example 42.a from *Astronomical Algorithms*: the latitude of the sub-solar point of
+/// Mars was −2.76°:
+///
+/// ```
+/// use vsop87::{physical, Planet};
+///
+/// let jde = 2448935.5;
+/// let t = (jde - 2451545.0) / 36525.0;
+/// let (_, latitude) = physical::sub_solar(
+///     Planet::Mars,
+///     jde,
+///     (317.68143 - 0.1061 * t).to_radians(),
+///     (52.88650 - 0.0609 * t).to_radians(),
+///     176.630_f64.to_radians(),
+///     350.89198226_f64.to_radians(),
+/// );
+///
+/// assert!((latitude.to_degrees() - -2.76).abs() < 0.1);
+/// ```
+pub fn sub_solar(
+    planet: Planet,
+    jde: f64,
+    pole_ra: f64,
+    pole_dec: f64,
+    w0: f64,
+    w_dot: f64,
+) -> (f64, f64) {
+    let emission = jde - geocentric::distance_light_time(planet, jde);
+    let towards_sun = vsop87a::planet(planet, emission) * -1_f64;
+
+    planetographic(&towards_sun, emission, pole_ra, pole_dec, w0, w_dot)
+}
+
+/// Calculates the planetographic longitude and the planetocentric latitude of the point of a
+/// planet in the given direction, from *VSOP87A* coordinates.
+fn planetographic(
+    direction: &RectangularCoordinates,
+    jde: f64,
+    pole_ra: f64,
+    pole_dec: f64,
+    w0: f64,
+    w_dot: f64,
+) -> (f64, f64) {
+    let direction = direction.to_equatorial_frame(mean_obliquity(2_451_545_f64));
+    let direction = direction / direction.norm();
+
+    // The north pole, the ascending node of the equator of the planet on the ICRF equator, and
+    // the point of the equator of the planet 90° east of the node.
+    let (sin_ra, cos_ra) = sin_cos(pole_ra);
+    let (sin_dec, cos_dec) = sin_cos(pole_dec);
+    let pole = RectangularCoordinates {
+        x: cos_dec * cos_ra,
+        y: cos_dec * sin_ra,
+        z: sin_dec,
+    };
+    let node = RectangularCoordinates {
+        x: -sin_ra,
+        y: cos_ra,
+        z: 0_f64,
+    };
+    let east = pole.cross(&node);
+
+    let latitude = asin(direction.dot(&pole).clamp(-1_f64, 1_f64));
+    let from_node = atan2(direction.dot(&east), direction.dot(&node));
+    let meridian = w0 + w_dot * (jde - 2_451_545_f64);
+
+    let longitude = if w_dot < 0_f64 {
+        from_node - meridian
+    } else {
+        meridian - from_node
+    };
+
+    (normalize_angle(longitude), latitude)
+}
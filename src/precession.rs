@@ -0,0 +1,89 @@
+//! Precession of the ecliptic coordinates between different equinoxes.
+//!
+//! The *VSOP87A*, *VSOP87B* and *VSOP87E* solutions are referred to the mean ecliptic and equinox
+//! of J2000.0, while the *VSOP87C* and *VSOP87D* solutions are referred to the mean ecliptic and
+//! equinox of the day. This module contains the rotation that converts ecliptic coordinates from
+//! one of these frames to the other, or between any two dates.
+//!
+//! # Example
+//!
+//! Rotating a *VSOP87A* position from the J2000.0 frame to the frame of the day gives the
+//! *VSOP87C* position:
+//!
+//! ```
+//! use vsop87::{vsop87a, vsop87c};
+//!
+//! let jde = 2415020.0;
+//! let precessed = vsop87a::mars(jde).precess(2451545.0, jde);
+//! let expected = vsop87c::mars(jde);
+//!
+//! assert!((precessed - expected).norm() < 1e-5);
+//! ```
+
+use super::sin_cos;
+
+/// Calculates the rotation matrix for the precession of ecliptic coordinates.
+///
+/// The returned matrix converts rectangular ecliptic coordinates referred to the mean ecliptic and
+/// equinox of `jd_from` into coordinates referred to the mean ecliptic and equinox of `jd_to`, when
+/// multiplied by the column vector `[x, y, z]`. Both dates are Julian Days (*JD*). The matrix is
+/// orthogonal, so its transpose performs the inverse rotation.
+///
+/// It uses the rigorous method for the precession of ecliptic coordinates described by Jean Meeus
+/// in *Astronomical Algorithms*, chapter 21, with the IAU 1976 precession angles. The rotation is
+/// accurate to a fraction of an arcsecond for some centuries around J2000, and degrades slowly
+/// for dates far from it.
+pub fn ecliptic_precession_matrix(jd_from: f64, jd_to: f64) -> [[f64; 3]; 3] {
+    let big_t = (jd_from - 2_451_545_f64) / 36_525_f64;
+    let t = (jd_to - jd_from) / 36_525_f64;
+
+    // Angles in arcseconds: the inclination of the ecliptic of the day on the original ecliptic,
+    // the longitude of the node of that ecliptic, and the general precession in longitude.
+    let eta = ((47.0029 - 0.06603 * big_t + 0.000598 * big_t * big_t) * t
+        + (-0.03302 + 0.000598 * big_t) * t * t
+        + 0.000060 * t * t * t)
+        / 3_600_f64;
+    let pi = 174.876384
+        + (3289.4789 * big_t + 0.60622 * big_t * big_t - (869.8089 + 0.50491 * big_t) * t
+            + 0.03536 * t * t)
+            / 3_600_f64;
+    let p = ((5029.0966 + 2.22226 * big_t - 0.000042 * big_t * big_t) * t
+        + (1.11113 - 0.000042 * big_t) * t * t
+        - 0.000006 * t * t * t)
+        / 3_600_f64;
+
+    // The rotation is R3(-(Π + p)) · R1(η) · R3(Π).
+    multiply(
+        &multiply(
+            &rotation_z(-(pi + p).to_radians()),
+            &rotation_x(eta.to_radians()),
+        ),
+        &rotation_z(pi.to_radians()),
+    )
+}
+
+/// Calculates the matrix that rotates the reference frame by the given angle around the *X* axis.
+fn rotation_x(angle: f64) -> [[f64; 3]; 3] {
+    let (sin, cos) = sin_cos(angle);
+
+    [[1_f64, 0_f64, 0_f64], [0_f64, cos, sin], [0_f64, -sin, cos]]
+}
+
+/// Calculates the matrix that rotates the reference frame by the given angle around the *Z* axis.
+fn rotation_z(angle: f64) -> [[f64; 3]; 3] {
+    let (sin, cos) = sin_cos(angle);
+
+    [[cos, sin, 0_f64], [-sin, cos, 0_f64], [0_f64, 0_f64, 1_f64]]
+}
+
+/// Multiplies two 3×3 matrices.
+fn multiply(a: &[[f64; 3]; 3], b: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let mut result = [[0_f64; 3]; 3];
+    for (i, row) in result.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = a[i][0] * b[0][j] + a[i][1] * b[1][j] + a[i][2] * b[2][j];
+        }
+    }
+
+    result
+}
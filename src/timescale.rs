@@ -0,0 +1,397 @@
+//! Conversions between time scales.
+//!
+//! The *VSOP87* solutions take the time as a Julian Ephemeris Day (*JDE*), that is, a Julian Day
+//! in Terrestrial Time (*TT*). Civil times are based on Universal Time (*UT*), which follows the
+//! rotation of the Earth, so they must be shifted by *ΔT = TT − UT* before being used. The
+//! sidereal time, which measures that rotation relative to the equinox, is also computed from *UT*.
+//!
+//! Strictly speaking, *VSOP87* is expressed in Barycentric Dynamical Time (*TDB*), which differs
+//! from *TT* by periodic terms below 2 milliseconds. In that time, the Moon moves about 2 meters,
+//! and the planets much less, so the *TT* Julian Ephemeris Day can be used directly: the
+//! [`tt_to_tdb`](fn.tt_to_tdb.html) conversion is only needed to compare with other ephemerides at
+//! that level.
+
+use super::{cos, normalize_angle, sin};
+
+/// Evaluates the polynomial with the given coefficients, starting with the constant term.
+#[inline]
+fn polynomial(x: f64, coefficients: &[f64]) -> f64 {
+    coefficients
+        .iter()
+        .rev()
+        .fold(0_f64, |value, coefficient| value * x + coefficient)
+}
+
+/// Estimates *ΔT = TT − UT*, in seconds, for the given year.
+///
+/// The estimate uses the polynomial expressions by Fred Espenak and Jean Meeus, published in the
+/// *Five Millennium Canon of Solar Eclipses*. The `year` is a decimal astronomical year, so that
+/// `2000.5` is the middle of the year 2000. The value is only an estimate: it is measured with
+/// increasing uncertainty in the past, and it cannot be predicted with accuracy in the future.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::delta_t;
+///
+/// let delta_t = delta_t(2000.0);
+///
+/// assert!(delta_t > 63.8 && delta_t < 63.9);
+/// ```
+pub fn delta_t(year: f64) -> f64 {
+    if !(-500_f64..2_150_f64).contains(&year) {
+        let u = (year - 1_820_f64) / 100_f64;
+        -20_f64 + 32_f64 * u * u
+    } else if year < 500_f64 {
+        polynomial(
+            year / 100_f64,
+            &[
+                10583.6,
+                -1014.41,
+                33.78311,
+                -5.952053,
+                -0.1798452,
+                0.022174192,
+                0.0090316521,
+            ],
+        )
+    } else if year < 1_600_f64 {
+        polynomial(
+            (year - 1_000_f64) / 100_f64,
+            &[
+                1574.2,
+                -556.01,
+                71.23472,
+                0.319781,
+                -0.8503463,
+                -0.005050998,
+                0.0083572073,
+            ],
+        )
+    } else if year < 1_700_f64 {
+        polynomial(
+            year - 1_600_f64,
+            &[120_f64, -0.9808, -0.01532, 1_f64 / 7129_f64],
+        )
+    } else if year < 1_800_f64 {
+        polynomial(
+            year - 1_700_f64,
+            &[8.83, 0.1603, -0.0059285, 0.00013336, -1_f64 / 1174000_f64],
+        )
+    } else if year < 1_860_f64 {
+        polynomial(
+            year - 1_800_f64,
+            &[
+                13.72,
+                -0.332447,
+                0.0068612,
+                0.0041116,
+                -0.00037436,
+                0.0000121272,
+                -0.0000001699,
+                0.000000000875,
+            ],
+        )
+    } else if year < 1_900_f64 {
+        polynomial(
+            year - 1_860_f64,
+            &[
+                7.62,
+                0.5737,
+                -0.251754,
+                0.01680668,
+                -0.0004473624,
+                1_f64 / 233174_f64,
+            ],
+        )
+    } else if year < 1_920_f64 {
+        polynomial(
+            year - 1_900_f64,
+            &[-2.79, 1.494119, -0.0598939, 0.0061966, -0.000197],
+        )
+    } else if year < 1_941_f64 {
+        polynomial(year - 1_920_f64, &[21.20, 0.84493, -0.076100, 0.0020936])
+    } else if year < 1_961_f64 {
+        polynomial(
+            year - 1_950_f64,
+            &[29.07, 0.407, -1_f64 / 233_f64, 1_f64 / 2547_f64],
+        )
+    } else if year < 1_986_f64 {
+        polynomial(
+            year - 1_975_f64,
+            &[45.45, 1.067, -1_f64 / 260_f64, -1_f64 / 718_f64],
+        )
+    } else if year < 2_005_f64 {
+        polynomial(
+            year - 2_000_f64,
+            &[
+                63.86,
+                0.3345,
+                -0.060374,
+                0.0017275,
+                0.000651814,
+                0.00002373599,
+            ],
+        )
+    } else if year < 2_050_f64 {
+        polynomial(year - 2_000_f64, &[62.92, 0.32217, 0.005589])
+    } else {
+        let u = (year - 1_820_f64) / 100_f64;
+        -20_f64 + 32_f64 * u * u - 0.5628 * (2_150_f64 - year)
+    }
+}
+
+/// Converts a Julian Day in Universal Time (*UT*) into a Julian Ephemeris Day (*TT*).
+///
+/// The `delta_t_seconds` parameter is *ΔT = TT − UT*, in seconds. It can be taken from published
+/// tables, or estimated with [`delta_t`](fn.delta_t.html). The result can be directly used as the
+/// `jde` parameter of the *VSOP87* functions.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{delta_t, julian_day, ut_to_tt, vsop87d};
+///
+/// // December 19th, 1399 at noon, civil time.
+/// let jd_ut = julian_day(1399, 12, 19.5);
+/// let jde = ut_to_tt(jd_ut, delta_t(1399.97));
+///
+/// assert!(jde - jd_ut > 0.003 && jde - jd_ut < 0.004);
+///
+/// let coordinates = vsop87d::jupiter(jde);
+/// ```
+pub fn ut_to_tt(jd_ut: f64, delta_t_seconds: f64) -> f64 {
+    jd_ut + delta_t_seconds / 86_400_f64
+}
+
+/// Converts a Julian Ephemeris Day (*TT*) into a Julian Day in Universal Time (*UT*).
+///
+/// This is the inverse of [`ut_to_tt`](fn.ut_to_tt.html), with the same `delta_t_seconds`
+/// parameter, *ΔT = TT − UT*, in seconds. *ΔT* changes by less than a second per year, so the
+/// estimate of [`delta_t`](fn.delta_t.html) for the year of the *TT* date can be used.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{tt_to_ut, ut_to_tt};
+///
+/// assert_eq!(tt_to_ut(ut_to_tt(2451545.0, 64.0), 64.0), 2451545.0);
+/// ```
+pub fn tt_to_ut(jde: f64, delta_t_seconds: f64) -> f64 {
+    jde - delta_t_seconds / 86_400_f64
+}
+
+/// Converts a Julian Ephemeris Day in Terrestrial Time (*TT*) into Barycentric Dynamical Time
+/// (*TDB*).
+///
+/// The difference, *TDB − TT*, is periodic, and comes from the motion of the Earth in the
+/// gravitational field of the Sun and the planets. It is computed with the two main terms given in
+/// the *USNO Circular 179*, `0.001657·sin(g) + 0.000022·sin(L − Lⱼ)` seconds, where *g* is the mean
+/// anomaly of the Earth and *L − Lⱼ* the difference of the mean longitudes of the Earth and
+/// Jupiter. The error is of about 10 microseconds between 1600 and 2200.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::tt_to_tdb;
+///
+/// let difference = (tt_to_tdb(2451545.0) - 2451545.0) * 86400.0;
+///
+/// assert!(difference.abs() < 0.002);
+/// ```
+pub fn tt_to_tdb(jde: f64) -> f64 {
+    jde + tdb_minus_tt(jde) / 86_400_f64
+}
+
+/// Converts a Julian Ephemeris Day in Barycentric Dynamical Time (*TDB*) into Terrestrial Time
+/// (*TT*).
+///
+/// This is the inverse of [`tt_to_tdb`](fn.tt_to_tdb.html). The difference between the time scales
+/// changes too slowly for the date it is evaluated at to matter.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{tdb_to_tt, tt_to_tdb};
+///
+/// assert!((tdb_to_tt(tt_to_tdb(2451545.0)) - 2451545.0).abs() < 1e-12);
+/// ```
+pub fn tdb_to_tt(jd_tdb: f64) -> f64 {
+    jd_tdb - tdb_minus_tt(jd_tdb) / 86_400_f64
+}
+
+/// Calculates *TDB − TT*, in seconds, for the given Julian Day.
+fn tdb_minus_tt(jd: f64) -> f64 {
+    let days = jd - 2_451_545_f64;
+    let g = (357.53 + 0.985_600_28 * days).to_radians();
+    let l = (246.11 + 0.902_517_92 * days).to_radians();
+
+    0.001_657 * sin(g) + 0.000_022 * sin(l)
+}
+
+/// Converts a `chrono` date and time in *UTC* into a Julian Ephemeris Day (*TT*).
+///
+/// This takes care of the conversion to a Julian Day, whose days start at noon, and of the shift
+/// to Terrestrial Time. The `delta_t_seconds` parameter is *ΔT = TT − UT*, in seconds, like in
+/// [`ut_to_tt`](fn.ut_to_tt.html), or `None` to estimate it with [`delta_t`](fn.delta_t.html).
+/// The difference between *UTC* and *UT*, below 0.9 seconds, is neglected. Only available with
+/// the `chrono` feature.
+///
+/// Note that `chrono` uses the proleptic Gregorian calendar, so dates before October 15th, 1582
+/// are not the Julian calendar dates given by historical records and used by
+/// [`julian_day`](fn.julian_day.html).
+///
+/// # Example
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use vsop87::{jde_from_chrono, ut_to_tt};
+///
+/// let datetime = Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap();
+///
+/// assert_eq!(jde_from_chrono(&datetime, Some(0.0)), 2451545.0);
+/// assert_eq!(jde_from_chrono(&datetime, Some(64.0)), ut_to_tt(2451545.0, 64.0));
+/// ```
+#[cfg(feature = "chrono")]
+pub fn jde_from_chrono(
+    datetime: &chrono::DateTime<chrono::Utc>,
+    delta_t_seconds: Option<f64>,
+) -> f64 {
+    utc_to_tt(
+        jd_from_unix(datetime.timestamp(), datetime.timestamp_subsec_nanos()),
+        delta_t_seconds,
+    )
+}
+
+/// Converts a `time` date and time into a Julian Ephemeris Day (*TT*).
+///
+/// The date and time can have any *UTC* offset, and is converted like in
+/// [`jde_from_chrono`](fn.jde_from_chrono.html): `delta_t_seconds` is *ΔT = TT − UT*, in seconds,
+/// or `None` to estimate it with [`delta_t`](fn.delta_t.html). Like `chrono`, `time` uses the
+/// proleptic Gregorian calendar. Only available with the `time` feature.
+///
+/// # Example
+///
+/// ```
+/// use time::{Date, Month, UtcOffset};
+/// use vsop87::jde_from_time;
+///
+/// // January 1st, 2000, at noon in UTC, given in Central European Time.
+/// let datetime = Date::from_calendar_date(2000, Month::January, 1)
+///     .unwrap()
+///     .with_hms(13, 0, 0)
+///     .unwrap()
+///     .assume_offset(UtcOffset::from_hms(1, 0, 0).unwrap());
+/// let delta_t = (jde_from_time(&datetime, None) - 2451545.0) * 86400.0;
+///
+/// // The estimated ΔT was about 64 seconds in 2000.
+/// assert!(delta_t > 63.8 && delta_t < 63.9);
+/// ```
+#[cfg(feature = "time")]
+pub fn jde_from_time(datetime: &time::OffsetDateTime, delta_t_seconds: Option<f64>) -> f64 {
+    utc_to_tt(
+        jd_from_unix(datetime.unix_timestamp(), datetime.nanosecond()),
+        delta_t_seconds,
+    )
+}
+
+/// Converts a Unix timestamp into a Julian Day, in the same time scale.
+#[cfg(any(feature = "chrono", feature = "time"))]
+#[allow(clippy::cast_precision_loss)]
+fn jd_from_unix(seconds: i64, nanoseconds: u32) -> f64 {
+    // The Unix epoch is January 1st, 1970 at midnight. The whole days are counted apart from the
+    // seconds of the day, so that no precision is lost before the conversion to `f64`.
+    let days = seconds.div_euclid(86_400) as f64;
+    let seconds = seconds.rem_euclid(86_400) as f64 + f64::from(nanoseconds) * 1e-9;
+
+    2_440_587.5 + days + seconds / 86_400_f64
+}
+
+/// Converts a Julian Day in *UTC* into a Julian Ephemeris Day, estimating *ΔT* if not given.
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn utc_to_tt(jd_utc: f64, delta_t_seconds: Option<f64>) -> f64 {
+    let delta_t_seconds =
+        delta_t_seconds.unwrap_or_else(|| delta_t(2_000_f64 + (jd_utc - 2_451_545_f64) / 365.25));
+
+    ut_to_tt(jd_utc, delta_t_seconds)
+}
+
+/// Calculates the Greenwich mean sidereal time, in radians.
+///
+/// The parameter is the Julian Day in Universal Time (*UT*), and the result is normalized into the
+/// `[0, 2π)` range. It uses the IAU 1982 expression given by Jean Meeus in *Astronomical
+/// Algorithms*, chapter 12, valid for any instant of the day.
+///
+/// # Example
+///
+/// This is example 12.a from *Astronomical Algorithms*, for April 10th, 1987 at 0h UT, where the
+/// mean sidereal time is 13h10m46.3668s:
+///
+/// ```
+/// use vsop87::greenwich_mean_sidereal_time;
+///
+/// let sidereal_time = greenwich_mean_sidereal_time(2446895.5);
+///
+/// assert!((sidereal_time.to_degrees() - 197.693195).abs() < 1e-6);
+/// ```
+pub fn greenwich_mean_sidereal_time(jd_ut: f64) -> f64 {
+    let days = jd_ut - 2_451_545_f64;
+    let t = days / 36_525_f64;
+    let degrees = 280.46061837
+        + 360.98564736629 * days
+        + polynomial(t, &[0_f64, 0_f64, 0.000387933, -1_f64 / 38_710_000_f64]);
+
+    normalize_angle(degrees.to_radians())
+}
+
+/// Calculates the Greenwich apparent sidereal time, in radians.
+///
+/// It is the [mean sidereal time](fn.greenwich_mean_sidereal_time.html) corrected by the equation
+/// of the equinoxes, `Δψ·cos(ε)`, where `nutation_longitude` is the nutation in longitude *Δψ*, in
+/// radians, and `obliquity` is the true obliquity of the ecliptic *ε*, in radians. Both can be
+/// computed with the [`nutation`](nutation/index.html) module and
+/// [`mean_obliquity()`](fn.mean_obliquity.html), for the Julian Ephemeris Day of the same instant.
+/// The correction is always below 1.2 seconds of time. The result is normalized into the
+/// `[0, 2π)` range.
+///
+/// # Example
+///
+/// This is example 12.a from *Astronomical Algorithms*, where the apparent sidereal time is
+/// 13h10m46.1351s:
+///
+/// ```
+/// use vsop87::apparent_sidereal_time;
+///
+/// let nutation_longitude = (-3.788_f64 / 3_600.0).to_radians();
+/// let obliquity = 23.4435694_f64.to_radians();
+/// let sidereal_time = apparent_sidereal_time(2446895.5, nutation_longitude, obliquity);
+///
+/// assert!((sidereal_time.to_degrees() - 197.692230).abs() < 1e-6);
+/// ```
+pub fn apparent_sidereal_time(jd_ut: f64, nutation_longitude: f64, obliquity: f64) -> f64 {
+    let equation_of_the_equinoxes = nutation_longitude * cos(obliquity);
+
+    normalize_angle(greenwich_mean_sidereal_time(jd_ut) + equation_of_the_equinoxes)
+}
+
+/// Calculates the local sidereal time from the Greenwich sidereal time, in radians.
+///
+/// The parameters are the Greenwich sidereal time, mean or apparent, and the geographic longitude
+/// of the observer, both in radians, with the longitude positive east of Greenwich. The result is
+/// normalized into the `[0, 2π)` range.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{greenwich_mean_sidereal_time, local_sidereal_time};
+///
+/// let greenwich = greenwich_mean_sidereal_time(2451545.0);
+///
+/// // Six hours of longitude west of Greenwich.
+/// let local = local_sidereal_time(greenwich, -std::f64::consts::FRAC_PI_2);
+///
+/// assert!((local - (greenwich - std::f64::consts::FRAC_PI_2)).abs() < 1e-12);
+/// ```
+pub fn local_sidereal_time(greenwich_sidereal_time: f64, longitude_east: f64) -> f64 {
+    normalize_angle(greenwich_sidereal_time + longitude_east)
+}
@@ -0,0 +1,156 @@
+//! Topocentric ecliptic coordinates, as seen by an observer on the surface of the Earth.
+//!
+//! The [geocentric](../geocentric/index.html) positions are referred to the center of the Earth.
+//! An observer on its surface is displaced from it by up to one Earth radius, which shifts the
+//! apparent direction of a planet by its parallax: 8.794" at 1 *AU*, and inversely proportional to
+//! the distance. This module corrects the apparent geocentric coordinates for that displacement,
+//! using the equatorial radius and the flattening of the Earth. Only available with the `earth`
+//! feature.
+//!
+//! The position of the observer depends on the rotation of the Earth, so the functions need the
+//! local sidereal time, that can be computed with
+//! [`local_mean_sidereal_time_from_ut`](fn.local_mean_sidereal_time_from_ut.html).
+//!
+//! # Example
+//!
+//! ```
+//! use vsop87::{delta_t, topocentric, ut_to_tt, Planet};
+//!
+//! // Mars seen from Palomar Observatory on August 28th, 2003, at 3h17m UT.
+//! let jd = 2452879.63681;
+//! let jde = ut_to_tt(jd, delta_t(2003.66));
+//! let latitude = 33.356111_f64.to_radians();
+//! let longitude = -116.8625_f64.to_radians();
+//! let sidereal_time = topocentric::local_mean_sidereal_time_from_ut(jd, longitude);
+//!
+//! let coordinates = topocentric::planet(Planet::Mars, jde, latitude, 1706.0, sidereal_time);
+//!
+//! assert!(coordinates.distance() > 0.3727 && coordinates.distance() < 0.3728);
+//! ```
+
+use super::{
+    atan2, geocentric, greenwich_mean_sidereal_time, mean_obliquity, nutation, sin_cos, Planet,
+    RectangularCoordinates, SphericalCoordinates, AU_KM,
+};
+
+/// Equatorial radius of the Earth, in kilometers.
+pub const EARTH_EQUATORIAL_RADIUS: f64 = 6_378.14;
+
+/// Flattening of the Earth, `(a − b) / a`.
+pub const EARTH_FLATTENING: f64 = 1_f64 / 298.257;
+
+/// Calculates the apparent topocentric ecliptic coordinates of a planet.
+///
+/// This function calculates the [apparent geocentric](../geocentric/fn.apparent.html) position of
+/// the planet, and subtracts the position of the observer, at the given geographic latitude, in
+/// radians, and elevation above sea level, in meters. The result is referred to the ecliptic and
+/// equinox of the day, with the distance to the observer in *AU*.
+///
+/// The `local_sidereal_time` parameter is the local sidereal time of the observer, in radians,
+/// which encodes both its longitude and the rotation of the Earth at that moment. It should be
+/// computed from the Universal Time (*UT*) of the observation, not from the Julian Ephemeris Day
+/// (*JDE*) used for the planet, for example with
+/// [`local_mean_sidereal_time_from_ut`](fn.local_mean_sidereal_time_from_ut.html). Using the mean
+/// instead of the apparent sidereal time makes no practical difference here.
+///
+/// The correction is largest for the nearest planets: it reaches about 24" for Mars at its closest
+/// approach, while it is always below 0.4" for Neptune.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{angular_separation, geocentric, topocentric, Planet};
+///
+/// let jde = 2452880.0;
+/// let geocentric = geocentric::apparent(Planet::Venus, jde);
+/// let topocentric = topocentric::planet(Planet::Venus, jde, 0.7, 0.0, 1.0);
+///
+/// // Venus was at about 1.7 AU, so the parallax is at most 5".
+/// let shift = angular_separation(&geocentric, &topocentric).to_degrees() * 3600.0;
+/// assert!(shift < 5.5);
+/// ```
+pub fn planet(
+    planet: Planet,
+    jde: f64,
+    observer_lat: f64,
+    elevation_m: f64,
+    local_sidereal_time: f64,
+) -> SphericalCoordinates {
+    let (_, delta_epsilon) = nutation::nutation(jde);
+    let obliquity = mean_obliquity(jde) + delta_epsilon;
+
+    let observer = observer_position(observer_lat, elevation_m, local_sidereal_time)
+        .to_equatorial_frame(-obliquity);
+
+    (geocentric::apparent(planet, jde).to_rectangular() - observer).to_spherical()
+}
+
+/// Calculates the position of an observer relative to the center of the Earth.
+///
+/// The position is given in rectangular equatorial coordinates for the equinox of the day, in
+/// *AU*: the *X* axis points to the equinox, and the *Z* axis to the north celestial pole. The
+/// parameters are the geographic latitude of the observer, in radians, its elevation above sea
+/// level, in meters, and its local sidereal time, in radians. The shape of the Earth is modelled
+/// as an ellipsoid, as described by Jean Meeus in *Astronomical Algorithms*, chapter 11.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::topocentric;
+///
+/// // At the equator, the observer is one equatorial radius away from the center of the Earth.
+/// let position = topocentric::observer_position(0.0, 0.0, 0.0);
+///
+/// assert!((position.x * vsop87::AU_KM - topocentric::EARTH_EQUATORIAL_RADIUS).abs() < 1e-6);
+/// assert!(position.y.abs() < 1e-15 && position.z.abs() < 1e-15);
+/// ```
+pub fn observer_position(
+    observer_lat: f64,
+    elevation_m: f64,
+    local_sidereal_time: f64,
+) -> RectangularCoordinates {
+    let axis_ratio = 1_f64 - EARTH_FLATTENING;
+    let (sin_lat, cos_lat) = sin_cos(observer_lat);
+
+    let u = atan2(axis_ratio * sin_lat, cos_lat);
+
+    // Distances to the axis of rotation and to the equatorial plane, in Earth radii.
+    let elevation = elevation_m / (EARTH_EQUATORIAL_RADIUS * 1_000_f64);
+    let (sin_u, cos_u) = sin_cos(u);
+    let rho_sin_phi = axis_ratio * sin_u + elevation * sin_lat;
+    let rho_cos_phi = cos_u + elevation * cos_lat;
+
+    let radius = EARTH_EQUATORIAL_RADIUS / AU_KM;
+    let (sin_theta, cos_theta) = sin_cos(local_sidereal_time);
+
+    RectangularCoordinates {
+        x: radius * rho_cos_phi * cos_theta,
+        y: radius * rho_cos_phi * sin_theta,
+        z: radius * rho_sin_phi,
+    }
+}
+
+/// Calculates the local mean sidereal time, in radians.
+///
+/// The parameters are the Julian Day in Universal Time (*UT*) and the geographic longitude of the
+/// observer, in radians, positive east of Greenwich. It is the
+/// [Greenwich mean sidereal time](../fn.greenwich_mean_sidereal_time.html) plus the longitude,
+/// normalized into the `[0, 2π)` range, as computed by
+/// [`local_sidereal_time`](../fn.local_sidereal_time.html) from any Greenwich sidereal time.
+///
+/// # Example
+///
+/// This is example 12.a from *Astronomical Algorithms*, for April 10th, 1987 at 0h UT, at
+/// Greenwich:
+///
+/// ```
+/// use vsop87::topocentric;
+///
+/// let sidereal_time = topocentric::local_mean_sidereal_time_from_ut(2446895.5, 0.0);
+///
+/// // 13h10m46.3668s
+/// assert!((sidereal_time.to_degrees() - 197.693195).abs() < 1e-6);
+/// ```
+pub fn local_mean_sidereal_time_from_ut(jd_ut: f64, longitude: f64) -> f64 {
+    super::local_sidereal_time(greenwich_mean_sidereal_time(jd_ut), longitude)
+}
@@ -0,0 +1,99 @@
+//! Marker types of the *VSOP87* solutions, for code generic over the solution.
+
+use super::{
+    vsop87a, vsop87b, vsop87c, vsop87d, vsop87e, Planet, RectangularCoordinates,
+    SphericalCoordinates,
+};
+
+/// A *VSOP87* solution, which computes the position of a planet at a given date.
+///
+/// Each solution is implemented by a marker type, and has the same meaning as the `planet()`
+/// function of its module. This allows writing code that is generic over the solution, and only
+/// depends on the coordinates it returns.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87b, vsop87d, Planet, SphericalCoordinates, Vsop87B, Vsop87D, Vsop87Variant};
+///
+/// // Distance traveled in longitude by a planet in a day, with any of the spherical solutions.
+/// fn daily_motion<V: Vsop87Variant<Output = SphericalCoordinates>>(planet: Planet) -> f64 {
+///     V::compute(planet, 2451546.0).longitude() - V::compute(planet, 2451545.0).longitude()
+/// }
+///
+/// let motion = vsop87d::venus(2451546.0).longitude() - vsop87d::venus(2451545.0).longitude();
+/// assert_eq!(daily_motion::<Vsop87D>(Planet::Venus), motion);
+/// assert_eq!(Vsop87B::compute(Planet::Venus, 2451545.0), vsop87b::venus(2451545.0));
+/// ```
+pub trait Vsop87Variant {
+    /// The coordinates returned by the solution.
+    type Output;
+
+    /// Calculates the position of the given planet at the given Julian Day (*JD*).
+    fn compute(planet: Planet, jde: f64) -> Self::Output;
+}
+
+/// The [*VSOP87A*](vsop87a/index.html) solution: heliocentric ecliptic rectangular coordinates
+/// for the equinox J2000.0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Vsop87A;
+
+impl Vsop87Variant for Vsop87A {
+    type Output = RectangularCoordinates;
+
+    fn compute(planet: Planet, jde: f64) -> Self::Output {
+        vsop87a::planet(planet, jde)
+    }
+}
+
+/// The [*VSOP87B*](vsop87b/index.html) solution: heliocentric ecliptic spherical coordinates for
+/// the equinox J2000.0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Vsop87B;
+
+impl Vsop87Variant for Vsop87B {
+    type Output = SphericalCoordinates;
+
+    fn compute(planet: Planet, jde: f64) -> Self::Output {
+        vsop87b::planet(planet, jde)
+    }
+}
+
+/// The [*VSOP87C*](vsop87c/index.html) solution: heliocentric ecliptic rectangular coordinates
+/// for the equinox of the day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Vsop87C;
+
+impl Vsop87Variant for Vsop87C {
+    type Output = RectangularCoordinates;
+
+    fn compute(planet: Planet, jde: f64) -> Self::Output {
+        vsop87c::planet(planet, jde)
+    }
+}
+
+/// The [*VSOP87D*](vsop87d/index.html) solution: heliocentric ecliptic spherical coordinates for
+/// the equinox of the day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Vsop87D;
+
+impl Vsop87Variant for Vsop87D {
+    type Output = SphericalCoordinates;
+
+    fn compute(planet: Planet, jde: f64) -> Self::Output {
+        vsop87d::planet(planet, jde)
+    }
+}
+
+/// The [*VSOP87E*](vsop87e/index.html) solution: barycentric ecliptic rectangular coordinates for
+/// the equinox J2000.0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Vsop87E;
+
+impl Vsop87Variant for Vsop87E {
+    type Output = RectangularCoordinates;
+
+    fn compute(planet: Planet, jde: f64) -> Self::Output {
+        vsop87e::planet(planet, jde)
+    }
+}
@@ -19,17 +19,29 @@
 //! assert!(coordinates.y > -0.1502242200 && coordinates.y < -0.1502242198);
 //! assert!(coordinates.z > 0.023618 && coordinates.z < 0.023622);
 
+#[cfg(feature = "earth")]
 mod earth;
+#[cfg(feature = "earth")]
 mod earth_moon;
+#[cfg(feature = "jupiter")]
 mod jupiter;
+#[cfg(feature = "mars")]
 mod mars;
+#[cfg(feature = "mercury")]
 mod mercury;
+#[cfg(feature = "neptune")]
 mod neptune;
+#[cfg(feature = "saturn")]
 mod saturn;
+#[cfg(feature = "uranus")]
 mod uranus;
+#[cfg(feature = "venus")]
 mod venus;
 
-use super::{calculate_t, calculate_var, RectangularCoordinates};
+use super::{
+    calculate_polynomial_derivative, calculate_t, calculate_var, rectangular, rectangular_state,
+    rectangular_truncated, Planet, RectangularCoordinates, Series,
+};
 
 /// Calculates VSOP87A solution for Mercury.
 ///
@@ -54,6 +66,7 @@ use super::{calculate_t, calculate_var, RectangularCoordinates};
 /// assert!(coordinates.y > -0.1502242200 && coordinates.y < -0.1502242198);
 /// assert!(coordinates.z > 0.023618 && coordinates.z < 0.023622);
 /// ```
+#[cfg(feature = "mercury")]
 pub fn mercury(jde: f64) -> RectangularCoordinates {
     let t = calculate_t(jde);
 
@@ -77,15 +90,9 @@ pub fn mercury(jde: f64) -> RectangularCoordinates {
     let z3 = calculate_var(t, &mercury::Z3[0], &mercury::Z3[1], &mercury::Z3[2]);
     let z4 = calculate_var(t, &mercury::Z4[0], &mercury::Z4[1], &mercury::Z4[2]);
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4 + x5 * t5;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4 + y5 * t5;
-    let z = z0 + z1 * t + z2 * t2 + z3 * t3 + z4 * t4;
+    let x = ((((x5 * t + x4) * t + x3) * t + x2) * t + x1) * t + x0;
+    let y = ((((y5 * t + y4) * t + y3) * t + y2) * t + y1) * t + y0;
+    let z = (((z4 * t + z3) * t + z2) * t + z1) * t + z0;
 
     RectangularCoordinates { x, y, z }
 }
@@ -113,6 +120,7 @@ pub fn mercury(jde: f64) -> RectangularCoordinates {
 /// assert!(coordinates.y > -0.2753592312 && coordinates.y < -0.2753592310);
 /// assert!(coordinates.z > 0.035785 && coordinates.z < 0.035789);
 /// ```
+#[cfg(feature = "venus")]
 pub fn venus(jde: f64) -> RectangularCoordinates {
     let t = calculate_t(jde);
 
@@ -136,15 +144,9 @@ pub fn venus(jde: f64) -> RectangularCoordinates {
     let z3 = calculate_var(t, &venus::Z3[0], &venus::Z3[1], &venus::Z3[2]);
     let z4 = calculate_var(t, &venus::Z4[0], &venus::Z4[1], &venus::Z4[2]);
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4 + x5 * t5;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4 + y5 * t5;
-    let z = z0 + z1 * t + z2 * t2 + z3 * t3 + z4 * t4;
+    let x = ((((x5 * t + x4) * t + x3) * t + x2) * t + x1) * t + x0;
+    let y = ((((y5 * t + y4) * t + y3) * t + y2) * t + y1) * t + y0;
+    let z = (((z4 * t + z3) * t + z2) * t + z1) * t + z0;
 
     RectangularCoordinates { x, y, z }
 }
@@ -172,6 +174,7 @@ pub fn venus(jde: f64) -> RectangularCoordinates {
 /// assert!(coordinates.y > 0.9603579953 && coordinates.y < 0.9603579955);
 /// assert!(coordinates.z > 0.000645 && coordinates.z < 0.000649);
 /// ```
+#[cfg(feature = "earth")]
 pub fn earth(jde: f64) -> RectangularCoordinates {
     let t = calculate_t(jde);
 
@@ -195,15 +198,9 @@ pub fn earth(jde: f64) -> RectangularCoordinates {
     let z3 = calculate_var(t, &earth::Z3[0], &earth::Z3[1], &earth::Z3[2]);
     let z4 = calculate_var(t, &earth::Z4[0], &earth::Z4[1], &earth::Z4[2]);
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4 + x5 * t5;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4 + y5 * t5;
-    let z = z0 + z1 * t + z2 * t2 + z3 * t3 + z4 * t4;
+    let x = ((((x5 * t + x4) * t + x3) * t + x2) * t + x1) * t + x0;
+    let y = ((((y5 * t + y4) * t + y3) * t + y2) * t + y1) * t + y0;
+    let z = (((z4 * t + z3) * t + z2) * t + z1) * t + z0;
 
     RectangularCoordinates { x, y, z }
 }
@@ -232,6 +229,7 @@ pub fn earth(jde: f64) -> RectangularCoordinates {
 /// assert!(coordinates.z > 0.001703 && coordinates.z < 0.001707);
 /// ```
 #[allow(clippy::too_many_lines)]
+#[cfg(feature = "earth")]
 pub fn earth_moon(jde: f64) -> RectangularCoordinates {
     let t = calculate_t(jde);
 
@@ -340,15 +338,9 @@ pub fn earth_moon(jde: f64) -> RectangularCoordinates {
         &earth_moon::Z4[2],
     );
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4 + x5 * t5;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4 + y5 * t5;
-    let z = z0 + z1 * t + z2 * t2 + z3 * t3 + z4 * t4;
+    let x = ((((x5 * t + x4) * t + x3) * t + x2) * t + x1) * t + x0;
+    let y = ((((y5 * t + y4) * t + y3) * t + y2) * t + y1) * t + y0;
+    let z = (((z4 * t + z3) * t + z2) * t + z1) * t + z0;
 
     RectangularCoordinates { x, y, z }
 }
@@ -376,6 +368,7 @@ pub fn earth_moon(jde: f64) -> RectangularCoordinates {
 /// assert!(coordinates.y > -0.0543839268 && coordinates.y < -0.0543839266);
 /// assert!(coordinates.z > -0.037103 && coordinates.z < -0.037099);
 /// ```
+#[cfg(feature = "mars")]
 pub fn mars(jde: f64) -> RectangularCoordinates {
     let t = calculate_t(jde);
 
@@ -399,15 +392,9 @@ pub fn mars(jde: f64) -> RectangularCoordinates {
     let z3 = calculate_var(t, &mars::Z3[0], &mars::Z3[1], &mars::Z3[2]);
     let z4 = calculate_var(t, &mars::Z4[0], &mars::Z4[1], &mars::Z4[2]);
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4 + x5 * t5;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4 + y5 * t5;
-    let z = z0 + z1 * t + z2 * t2 + z3 * t3 + z4 * t4;
+    let x = ((((x5 * t + x4) * t + x3) * t + x2) * t + x1) * t + x0;
+    let y = ((((y5 * t + y4) * t + y3) * t + y2) * t + y1) * t + y0;
+    let z = (((z4 * t + z3) * t + z2) * t + z1) * t + z0;
 
     RectangularCoordinates { x, y, z }
 }
@@ -435,6 +422,7 @@ pub fn mars(jde: f64) -> RectangularCoordinates {
 /// assert!(coordinates.y > 2.9385810076 && coordinates.y < 2.9385810078);
 /// assert!(coordinates.z > -0.101786 && coordinates.z < -0.101782);
 /// ```
+#[cfg(feature = "jupiter")]
 pub fn jupiter(jde: f64) -> RectangularCoordinates {
     let t = calculate_t(jde);
 
@@ -458,15 +446,9 @@ pub fn jupiter(jde: f64) -> RectangularCoordinates {
     let z3 = calculate_var(t, &jupiter::Z3[0], &jupiter::Z3[1], &jupiter::Z3[2]);
     let z4 = calculate_var(t, &jupiter::Z4[0], &jupiter::Z4[1], &jupiter::Z4[2]);
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4 + x5 * t5;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4 + y5 * t5;
-    let z = z0 + z1 * t + z2 * t2 + z3 * t3 + z4 * t4;
+    let x = ((((x5 * t + x4) * t + x3) * t + x2) * t + x1) * t + x0;
+    let y = ((((y5 * t + y4) * t + y3) * t + y2) * t + y1) * t + y0;
+    let z = (((z4 * t + z3) * t + z2) * t + z1) * t + z0;
 
     RectangularCoordinates { x, y, z }
 }
@@ -494,6 +476,7 @@ pub fn jupiter(jde: f64) -> RectangularCoordinates {
 /// assert!(coordinates.y > -5.8435867017 && coordinates.y < -5.8435867015);
 /// assert!(coordinates.z > 0.416558 && coordinates.z < 0.416562);
 /// ```
+#[cfg(feature = "saturn")]
 pub fn saturn(jde: f64) -> RectangularCoordinates {
     let t = calculate_t(jde);
 
@@ -517,15 +500,9 @@ pub fn saturn(jde: f64) -> RectangularCoordinates {
     let z3 = calculate_var(t, &saturn::Z3[0], &saturn::Z3[1], &saturn::Z3[2]);
     let z4 = calculate_var(t, &saturn::Z4[0], &saturn::Z4[1], &saturn::Z4[2]);
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4 + x5 * t5;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4 + y5 * t5;
-    let z = z0 + z1 * t + z2 * t2 + z3 * t3 + z4 * t4;
+    let x = ((((x5 * t + x4) * t + x3) * t + x2) * t + x1) * t + x0;
+    let y = ((((y5 * t + y4) * t + y3) * t + y2) * t + y1) * t + y0;
+    let z = (((z4 * t + z3) * t + z2) * t + z1) * t + z0;
 
     RectangularCoordinates { x, y, z }
 }
@@ -553,6 +530,7 @@ pub fn saturn(jde: f64) -> RectangularCoordinates {
 /// assert!(coordinates.y > 15.7711888604 && coordinates.y < 15.7711888606);
 /// assert!(coordinates.z > 0.191480 && coordinates.z < 0.191484);
 /// ```
+#[cfg(feature = "uranus")]
 pub fn uranus(jde: f64) -> RectangularCoordinates {
     let t = calculate_t(jde);
 
@@ -572,14 +550,9 @@ pub fn uranus(jde: f64) -> RectangularCoordinates {
     let z1 = calculate_var(t, &uranus::Z1[0], &uranus::Z1[1], &uranus::Z1[2]);
     let z2 = calculate_var(t, &uranus::Z2[0], &uranus::Z2[1], &uranus::Z2[2]);
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4;
-    let z = z0 + z1 * t + z2 * t2;
+    let x = (((x4 * t + x3) * t + x2) * t + x1) * t + x0;
+    let y = (((y4 * t + y3) * t + y2) * t + y1) * t + y0;
+    let z = (z2 * t + z1) * t + z0;
 
     RectangularCoordinates { x, y, z }
 }
@@ -607,6 +580,7 @@ pub fn uranus(jde: f64) -> RectangularCoordinates {
 /// assert!(coordinates.y > -17.6514428047 && coordinates.y < -17.6514428045);
 /// assert!(coordinates.z > 0.929722 && coordinates.z < 0.929726);
 /// ```
+#[cfg(feature = "neptune")]
 pub fn neptune(jde: f64) -> RectangularCoordinates {
     let t = calculate_t(jde);
 
@@ -626,14 +600,466 @@ pub fn neptune(jde: f64) -> RectangularCoordinates {
     let z1 = calculate_var(t, &neptune::Z1[0], &neptune::Z1[1], &neptune::Z1[2]);
     let z2 = calculate_var(t, &neptune::Z2[0], &neptune::Z2[1], &neptune::Z2[2]);
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4;
-    let z = z0 + z1 * t + z2 * t2;
+    let x = (((x4 * t + x3) * t + x2) * t + x1) * t + x0;
+    let y = (((y4 * t + y3) * t + y2) * t + y1) * t + y0;
+    let z = (z2 * t + z1) * t + z0;
 
     RectangularCoordinates { x, y, z }
 }
+
+/// Calculates VSOP87A solution for the given planet.
+///
+/// This function calculates the VSOP87A solution (heliocentric ecliptic rectangular coordinates for
+/// the equinox J2000.0) for the given planet, by calling the function of that planet in this
+/// module. It is useful when the planet is only known at runtime, or to iterate over all the
+/// planets.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87a, Planet};
+///
+/// assert_eq!(vsop87a::planet(Planet::Mars, 2451545.0), vsop87a::mars(2451545.0));
+/// ```
+pub fn planet(planet: Planet, jde: f64) -> RectangularCoordinates {
+    match planet {
+        #[cfg(feature = "mercury")]
+        Planet::Mercury => mercury(jde),
+        #[cfg(feature = "venus")]
+        Planet::Venus => venus(jde),
+        #[cfg(feature = "earth")]
+        Planet::Earth => earth(jde),
+        #[cfg(feature = "mars")]
+        Planet::Mars => mars(jde),
+        #[cfg(feature = "jupiter")]
+        Planet::Jupiter => jupiter(jde),
+        #[cfg(feature = "saturn")]
+        Planet::Saturn => saturn(jde),
+        #[cfg(feature = "uranus")]
+        Planet::Uranus => uranus(jde),
+        #[cfg(feature = "neptune")]
+        Planet::Neptune => neptune(jde),
+        #[allow(unreachable_patterns)]
+        _ => planet.not_enabled(),
+    }
+}
+
+/// Calculates VSOP87A solution for all the planets at once.
+///
+/// The coordinates are returned in the order of
+/// [`Planet::ALL`](../enum.Planet.html#associatedconstant.ALL), by distance to the Sun: Mercury,
+/// Venus, Earth, Mars, Jupiter, Saturn, Uranus and Neptune. With the `rayon` feature, each planet
+/// is computed in a thread of the global [*rayon*](https://docs.rs/rayon) thread pool, which makes
+/// it suitable to refresh a whole planetarium every frame. Without it, the planets are computed one
+/// after the other, and the coordinates are the same in both cases. Only available with the
+/// `all_planets` feature.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87a, Planet};
+///
+/// let planets = vsop87a::all_planets(2451545.0);
+///
+/// assert_eq!(planets[Planet::Jupiter as usize], vsop87a::jupiter(2451545.0));
+/// ```
+#[cfg(feature = "all_planets")]
+pub fn all_planets(jde: f64) -> [RectangularCoordinates; 8] {
+    super::all_planets(|body| planet(body, jde))
+}
+
+/// Calculates the heliocentric velocity of the given planet, in *AU* per day.
+///
+/// The velocity is computed from the analytic derivative of the VSOP87A series with respect to
+/// time, instead of by finite differences: each term `A·cos(B + C·t)` is differentiated into
+/// `-A·C·sin(B + C·t)`, and the result is divided by the 365,250 days of a Julian millennium,
+/// the unit of `t`. The components are referred to the ecliptic and equinox of J2000.0, like the
+/// [`planet`](fn.planet.html) positions. The parameter needed is the Julian Day (*JD*) for the
+/// given date.
+///
+/// # Example
+///
+/// Close to the perihelion, at the beginning of January, the Earth moves at about 0.0175 *AU* per
+/// day, or 30.3 km/s:
+///
+/// ```
+/// use vsop87::{vsop87a, Planet};
+///
+/// let velocity = vsop87a::velocity(Planet::Earth, 2451545.0);
+///
+/// assert!(velocity.norm() > 0.0174 && velocity.norm() < 0.0176);
+/// ```
+pub fn velocity(planet: Planet, jde: f64) -> RectangularCoordinates {
+    let [x, y, z] = tables(planet);
+    let t = calculate_t(jde);
+
+    RectangularCoordinates {
+        x: calculate_polynomial_derivative(t, x).1,
+        y: calculate_polynomial_derivative(t, y).1,
+        z: calculate_polynomial_derivative(t, z).1,
+    } / 365_250_f64
+}
+
+/// Calculates the heliocentric position and velocity of the given planet.
+///
+/// This returns the same position as [`planet`](fn.planet.html), in *AU*, and the same velocity
+/// as [`velocity`](fn.velocity.html), in *AU* per day, computed in a single pass over the series.
+/// Together they form the state vector of the planet, referred to the ecliptic and equinox of
+/// J2000.0, as needed to propagate its orbit. The parameter needed is the Julian Day (*JD*) for
+/// the given date.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87a, Planet};
+///
+/// let (position, velocity) = vsop87a::state(Planet::Mars, 2451545.0);
+///
+/// assert_eq!(position, vsop87a::mars(2451545.0));
+/// assert!((velocity - vsop87a::velocity(Planet::Mars, 2451545.0)).norm() < 1e-15);
+/// ```
+pub fn state(planet: Planet, jde: f64) -> (RectangularCoordinates, RectangularCoordinates) {
+    rectangular_state(tables(planet), jde)
+}
+
+/// Calculates VSOP87A solution for the given planet, skipping the terms smaller than `epsilon`.
+///
+/// This trades precision for speed, like
+/// [`vsop87d::planet_truncated()`](../vsop87d/fn.planet_truncated.html), which gives some figures.
+/// The terms whose amplitude is smaller than `epsilon`, in *AU*, are not computed, and an
+/// `epsilon` of `0.0` keeps every term. The error on each coordinate is usually a small multiple
+/// of `epsilon`.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87a, Planet};
+///
+/// let full = vsop87a::planet(Planet::Jupiter, 2451545.0);
+/// let truncated = vsop87a::planet_truncated(Planet::Jupiter, 2451545.0, 1e-7);
+///
+/// assert!((full - truncated).norm() < 1e-5);
+/// ```
+pub fn planet_truncated(planet: Planet, jde: f64, epsilon: f64) -> RectangularCoordinates {
+    rectangular_truncated(tables(planet), jde, epsilon)
+}
+
+/// Calculates VSOP87A solution for the given planet on many dates.
+///
+/// This function calculates the VSOP87A solution of the given planet for each Julian Day (*JD*) in
+/// `jdes`, and returns the coordinates in a vector, in the same order. The coefficient tables of
+/// the planet are looked up once for the whole batch, and the output vector is allocated once.
+/// Only available with the standard library: see [`planet_into`](fn.planet_into.html) otherwise.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87a, Planet};
+///
+/// let jdes = [2451545.0, 2451546.0, 2451547.0];
+/// let track = vsop87a::planet_many(Planet::Earth, &jdes);
+///
+/// assert_eq!(track.len(), 3);
+/// assert_eq!(track[1], vsop87a::earth(2451546.0));
+/// ```
+#[cfg(not(feature = "no_std"))]
+pub fn planet_many(planet: Planet, jdes: &[f64]) -> Vec<RectangularCoordinates> {
+    let tables = tables(planet);
+    jdes.iter().map(|&jde| rectangular(tables, jde)).collect()
+}
+
+/// Calculates VSOP87A solution for the given planet on many dates, into a caller-provided buffer.
+///
+/// This is the allocation-free version of [`planet_many`](fn.planet_many.html), also available
+/// with the `no_std` feature. The coordinates for `jdes[i]` are written to `output[i]`, and every
+/// element of `output` is overwritten.
+///
+/// # Panics
+///
+/// Panics if `jdes` and `output` have different lengths, before writing anything.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87a, Planet};
+///
+/// let jdes = [2451545.0, 2451546.0];
+/// let mut output = [vsop87a::mars(0.0); 2];
+/// vsop87a::planet_into(Planet::Mars, &jdes, &mut output);
+///
+/// assert_eq!(output[0], vsop87a::mars(2451545.0));
+/// assert_eq!(output[1], vsop87a::mars(2451546.0));
+/// ```
+pub fn planet_into(planet: Planet, jdes: &[f64], output: &mut [RectangularCoordinates]) {
+    assert_eq!(
+        jdes.len(),
+        output.len(),
+        "the input dates and the output buffer must have the same length"
+    );
+
+    let tables = tables(planet);
+    for (coordinates, &jde) in output.iter_mut().zip(jdes) {
+        *coordinates = rectangular(tables, jde);
+    }
+}
+
+/// Calculates the heliocentric speed of the given planet, in km/s.
+///
+/// This is the norm of the [`velocity`](fn.velocity.html), converted from *AU* per day with
+/// [`AU_KM`](../constant.AU_KM.html). The parameter needed is the Julian Day (*JD*) for the given
+/// date.
+///
+/// # Example
+///
+/// The Earth moves at about 30 km/s around the Sun, slightly faster at the beginning of January:
+///
+/// ```
+/// use vsop87::{vsop87a, Planet};
+///
+/// let speed = vsop87a::speed_km_s(Planet::Earth, 2451545.0);
+///
+/// assert!(speed > 30.2 && speed < 30.4);
+/// ```
+pub fn speed_km_s(planet: Planet, jde: f64) -> f64 {
+    velocity(planet, jde).norm_km() / 86_400_f64
+}
+
+/// Gets the series of the *X*, *Y* and *Z* coordinates of the given planet.
+fn tables(planet: Planet) -> &'static [&'static [Series<'static>]; 3] {
+    match planet {
+        #[cfg(feature = "mercury")]
+        Planet::Mercury => &MERCURY,
+        #[cfg(feature = "venus")]
+        Planet::Venus => &VENUS,
+        #[cfg(feature = "earth")]
+        Planet::Earth => &EARTH,
+        #[cfg(feature = "mars")]
+        Planet::Mars => &MARS,
+        #[cfg(feature = "jupiter")]
+        Planet::Jupiter => &JUPITER,
+        #[cfg(feature = "saturn")]
+        Planet::Saturn => &SATURN,
+        #[cfg(feature = "uranus")]
+        Planet::Uranus => &URANUS,
+        #[cfg(feature = "neptune")]
+        Planet::Neptune => &NEPTUNE,
+        #[allow(unreachable_patterns)]
+        _ => planet.not_enabled(),
+    }
+}
+
+/// Series of the *X*, *Y* and *Z* coordinates of Mercury.
+#[cfg(feature = "mercury")]
+static MERCURY: [&[Series<'static>]; 3] = [
+    &[
+        [&mercury::X0[0], &mercury::X0[1], &mercury::X0[2]],
+        [&mercury::X1[0], &mercury::X1[1], &mercury::X1[2]],
+        [&mercury::X2[0], &mercury::X2[1], &mercury::X2[2]],
+        [&mercury::X3[0], &mercury::X3[1], &mercury::X3[2]],
+        [&mercury::X4[0], &mercury::X4[1], &mercury::X4[2]],
+        [&mercury::X5[0], &mercury::X5[1], &mercury::X5[2]],
+    ],
+    &[
+        [&mercury::Y0[0], &mercury::Y0[1], &mercury::Y0[2]],
+        [&mercury::Y1[0], &mercury::Y1[1], &mercury::Y1[2]],
+        [&mercury::Y2[0], &mercury::Y2[1], &mercury::Y2[2]],
+        [&mercury::Y3[0], &mercury::Y3[1], &mercury::Y3[2]],
+        [&mercury::Y4[0], &mercury::Y4[1], &mercury::Y4[2]],
+        [&mercury::Y5[0], &mercury::Y5[1], &mercury::Y5[2]],
+    ],
+    &[
+        [&mercury::Z0[0], &mercury::Z0[1], &mercury::Z0[2]],
+        [&mercury::Z1[0], &mercury::Z1[1], &mercury::Z1[2]],
+        [&mercury::Z2[0], &mercury::Z2[1], &mercury::Z2[2]],
+        [&mercury::Z3[0], &mercury::Z3[1], &mercury::Z3[2]],
+        [&mercury::Z4[0], &mercury::Z4[1], &mercury::Z4[2]],
+    ],
+];
+
+/// Series of the *X*, *Y* and *Z* coordinates of Venus.
+#[cfg(feature = "venus")]
+static VENUS: [&[Series<'static>]; 3] = [
+    &[
+        [&venus::X0[0], &venus::X0[1], &venus::X0[2]],
+        [&venus::X1[0], &venus::X1[1], &venus::X1[2]],
+        [&venus::X2[0], &venus::X2[1], &venus::X2[2]],
+        [&venus::X3[0], &venus::X3[1], &venus::X3[2]],
+        [&venus::X4[0], &venus::X4[1], &venus::X4[2]],
+        [&venus::X5[0], &venus::X5[1], &venus::X5[2]],
+    ],
+    &[
+        [&venus::Y0[0], &venus::Y0[1], &venus::Y0[2]],
+        [&venus::Y1[0], &venus::Y1[1], &venus::Y1[2]],
+        [&venus::Y2[0], &venus::Y2[1], &venus::Y2[2]],
+        [&venus::Y3[0], &venus::Y3[1], &venus::Y3[2]],
+        [&venus::Y4[0], &venus::Y4[1], &venus::Y4[2]],
+        [&venus::Y5[0], &venus::Y5[1], &venus::Y5[2]],
+    ],
+    &[
+        [&venus::Z0[0], &venus::Z0[1], &venus::Z0[2]],
+        [&venus::Z1[0], &venus::Z1[1], &venus::Z1[2]],
+        [&venus::Z2[0], &venus::Z2[1], &venus::Z2[2]],
+        [&venus::Z3[0], &venus::Z3[1], &venus::Z3[2]],
+        [&venus::Z4[0], &venus::Z4[1], &venus::Z4[2]],
+    ],
+];
+
+/// Series of the *X*, *Y* and *Z* coordinates of Earth.
+#[cfg(feature = "earth")]
+static EARTH: [&[Series<'static>]; 3] = [
+    &[
+        [&earth::X0[0], &earth::X0[1], &earth::X0[2]],
+        [&earth::X1[0], &earth::X1[1], &earth::X1[2]],
+        [&earth::X2[0], &earth::X2[1], &earth::X2[2]],
+        [&earth::X3[0], &earth::X3[1], &earth::X3[2]],
+        [&earth::X4[0], &earth::X4[1], &earth::X4[2]],
+        [&earth::X5[0], &earth::X5[1], &earth::X5[2]],
+    ],
+    &[
+        [&earth::Y0[0], &earth::Y0[1], &earth::Y0[2]],
+        [&earth::Y1[0], &earth::Y1[1], &earth::Y1[2]],
+        [&earth::Y2[0], &earth::Y2[1], &earth::Y2[2]],
+        [&earth::Y3[0], &earth::Y3[1], &earth::Y3[2]],
+        [&earth::Y4[0], &earth::Y4[1], &earth::Y4[2]],
+        [&earth::Y5[0], &earth::Y5[1], &earth::Y5[2]],
+    ],
+    &[
+        [&earth::Z0[0], &earth::Z0[1], &earth::Z0[2]],
+        [&earth::Z1[0], &earth::Z1[1], &earth::Z1[2]],
+        [&earth::Z2[0], &earth::Z2[1], &earth::Z2[2]],
+        [&earth::Z3[0], &earth::Z3[1], &earth::Z3[2]],
+        [&earth::Z4[0], &earth::Z4[1], &earth::Z4[2]],
+    ],
+];
+
+/// Series of the *X*, *Y* and *Z* coordinates of Mars.
+#[cfg(feature = "mars")]
+static MARS: [&[Series<'static>]; 3] = [
+    &[
+        [&mars::X0[0], &mars::X0[1], &mars::X0[2]],
+        [&mars::X1[0], &mars::X1[1], &mars::X1[2]],
+        [&mars::X2[0], &mars::X2[1], &mars::X2[2]],
+        [&mars::X3[0], &mars::X3[1], &mars::X3[2]],
+        [&mars::X4[0], &mars::X4[1], &mars::X4[2]],
+        [&mars::X5[0], &mars::X5[1], &mars::X5[2]],
+    ],
+    &[
+        [&mars::Y0[0], &mars::Y0[1], &mars::Y0[2]],
+        [&mars::Y1[0], &mars::Y1[1], &mars::Y1[2]],
+        [&mars::Y2[0], &mars::Y2[1], &mars::Y2[2]],
+        [&mars::Y3[0], &mars::Y3[1], &mars::Y3[2]],
+        [&mars::Y4[0], &mars::Y4[1], &mars::Y4[2]],
+        [&mars::Y5[0], &mars::Y5[1], &mars::Y5[2]],
+    ],
+    &[
+        [&mars::Z0[0], &mars::Z0[1], &mars::Z0[2]],
+        [&mars::Z1[0], &mars::Z1[1], &mars::Z1[2]],
+        [&mars::Z2[0], &mars::Z2[1], &mars::Z2[2]],
+        [&mars::Z3[0], &mars::Z3[1], &mars::Z3[2]],
+        [&mars::Z4[0], &mars::Z4[1], &mars::Z4[2]],
+    ],
+];
+
+/// Series of the *X*, *Y* and *Z* coordinates of Jupiter.
+#[cfg(feature = "jupiter")]
+static JUPITER: [&[Series<'static>]; 3] = [
+    &[
+        [&jupiter::X0[0], &jupiter::X0[1], &jupiter::X0[2]],
+        [&jupiter::X1[0], &jupiter::X1[1], &jupiter::X1[2]],
+        [&jupiter::X2[0], &jupiter::X2[1], &jupiter::X2[2]],
+        [&jupiter::X3[0], &jupiter::X3[1], &jupiter::X3[2]],
+        [&jupiter::X4[0], &jupiter::X4[1], &jupiter::X4[2]],
+        [&jupiter::X5[0], &jupiter::X5[1], &jupiter::X5[2]],
+    ],
+    &[
+        [&jupiter::Y0[0], &jupiter::Y0[1], &jupiter::Y0[2]],
+        [&jupiter::Y1[0], &jupiter::Y1[1], &jupiter::Y1[2]],
+        [&jupiter::Y2[0], &jupiter::Y2[1], &jupiter::Y2[2]],
+        [&jupiter::Y3[0], &jupiter::Y3[1], &jupiter::Y3[2]],
+        [&jupiter::Y4[0], &jupiter::Y4[1], &jupiter::Y4[2]],
+        [&jupiter::Y5[0], &jupiter::Y5[1], &jupiter::Y5[2]],
+    ],
+    &[
+        [&jupiter::Z0[0], &jupiter::Z0[1], &jupiter::Z0[2]],
+        [&jupiter::Z1[0], &jupiter::Z1[1], &jupiter::Z1[2]],
+        [&jupiter::Z2[0], &jupiter::Z2[1], &jupiter::Z2[2]],
+        [&jupiter::Z3[0], &jupiter::Z3[1], &jupiter::Z3[2]],
+        [&jupiter::Z4[0], &jupiter::Z4[1], &jupiter::Z4[2]],
+    ],
+];
+
+/// Series of the *X*, *Y* and *Z* coordinates of Saturn.
+#[cfg(feature = "saturn")]
+static SATURN: [&[Series<'static>]; 3] = [
+    &[
+        [&saturn::X0[0], &saturn::X0[1], &saturn::X0[2]],
+        [&saturn::X1[0], &saturn::X1[1], &saturn::X1[2]],
+        [&saturn::X2[0], &saturn::X2[1], &saturn::X2[2]],
+        [&saturn::X3[0], &saturn::X3[1], &saturn::X3[2]],
+        [&saturn::X4[0], &saturn::X4[1], &saturn::X4[2]],
+        [&saturn::X5[0], &saturn::X5[1], &saturn::X5[2]],
+    ],
+    &[
+        [&saturn::Y0[0], &saturn::Y0[1], &saturn::Y0[2]],
+        [&saturn::Y1[0], &saturn::Y1[1], &saturn::Y1[2]],
+        [&saturn::Y2[0], &saturn::Y2[1], &saturn::Y2[2]],
+        [&saturn::Y3[0], &saturn::Y3[1], &saturn::Y3[2]],
+        [&saturn::Y4[0], &saturn::Y4[1], &saturn::Y4[2]],
+        [&saturn::Y5[0], &saturn::Y5[1], &saturn::Y5[2]],
+    ],
+    &[
+        [&saturn::Z0[0], &saturn::Z0[1], &saturn::Z0[2]],
+        [&saturn::Z1[0], &saturn::Z1[1], &saturn::Z1[2]],
+        [&saturn::Z2[0], &saturn::Z2[1], &saturn::Z2[2]],
+        [&saturn::Z3[0], &saturn::Z3[1], &saturn::Z3[2]],
+        [&saturn::Z4[0], &saturn::Z4[1], &saturn::Z4[2]],
+    ],
+];
+
+/// Series of the *X*, *Y* and *Z* coordinates of Uranus.
+#[cfg(feature = "uranus")]
+static URANUS: [&[Series<'static>]; 3] = [
+    &[
+        [&uranus::X0[0], &uranus::X0[1], &uranus::X0[2]],
+        [&uranus::X1[0], &uranus::X1[1], &uranus::X1[2]],
+        [&uranus::X2[0], &uranus::X2[1], &uranus::X2[2]],
+        [&uranus::X3[0], &uranus::X3[1], &uranus::X3[2]],
+        [&uranus::X4[0], &uranus::X4[1], &uranus::X4[2]],
+    ],
+    &[
+        [&uranus::Y0[0], &uranus::Y0[1], &uranus::Y0[2]],
+        [&uranus::Y1[0], &uranus::Y1[1], &uranus::Y1[2]],
+        [&uranus::Y2[0], &uranus::Y2[1], &uranus::Y2[2]],
+        [&uranus::Y3[0], &uranus::Y3[1], &uranus::Y3[2]],
+        [&uranus::Y4[0], &uranus::Y4[1], &uranus::Y4[2]],
+    ],
+    &[
+        [&uranus::Z0[0], &uranus::Z0[1], &uranus::Z0[2]],
+        [&uranus::Z1[0], &uranus::Z1[1], &uranus::Z1[2]],
+        [&uranus::Z2[0], &uranus::Z2[1], &uranus::Z2[2]],
+    ],
+];
+
+/// Series of the *X*, *Y* and *Z* coordinates of Neptune.
+#[cfg(feature = "neptune")]
+static NEPTUNE: [&[Series<'static>]; 3] = [
+    &[
+        [&neptune::X0[0], &neptune::X0[1], &neptune::X0[2]],
+        [&neptune::X1[0], &neptune::X1[1], &neptune::X1[2]],
+        [&neptune::X2[0], &neptune::X2[1], &neptune::X2[2]],
+        [&neptune::X3[0], &neptune::X3[1], &neptune::X3[2]],
+        [&neptune::X4[0], &neptune::X4[1], &neptune::X4[2]],
+    ],
+    &[
+        [&neptune::Y0[0], &neptune::Y0[1], &neptune::Y0[2]],
+        [&neptune::Y1[0], &neptune::Y1[1], &neptune::Y1[2]],
+        [&neptune::Y2[0], &neptune::Y2[1], &neptune::Y2[2]],
+        [&neptune::Y3[0], &neptune::Y3[1], &neptune::Y3[2]],
+        [&neptune::Y4[0], &neptune::Y4[1], &neptune::Y4[2]],
+    ],
+    &[
+        [&neptune::Z0[0], &neptune::Z0[1], &neptune::Z0[2]],
+        [&neptune::Z1[0], &neptune::Z1[1], &neptune::Z1[2]],
+        [&neptune::Z2[0], &neptune::Z2[1], &neptune::Z2[2]],
+    ],
+];
@@ -18,20 +18,241 @@
 //! assert!(coordinates.latitude() > 0.0004381094 && coordinates.latitude() < 0.0004381096);
 //! assert!(coordinates.distance() > 0.9832270 && coordinates.distance() < 0.9832278);
 
+#[cfg(feature = "earth")]
 mod earth;
+#[cfg(feature = "jupiter")]
 mod jupiter;
+#[cfg(feature = "mars")]
 mod mars;
+#[cfg(feature = "mercury")]
 mod mercury;
+#[cfg(feature = "neptune")]
 mod neptune;
+#[cfg(feature = "saturn")]
 mod saturn;
+#[cfg(feature = "uranus")]
 mod uranus;
+#[cfg(feature = "venus")]
 mod venus;
 
-use super::{calculate_t, calculate_var, SphericalCoordinates};
-#[cfg(feature = "no_std")]
-use core::f64::consts::PI;
-#[cfg(not(feature = "no_std"))]
-use std::f64::consts::PI;
+use super::{
+    evaluate, evaluate_state, evaluate_truncated, Planet, PlanetTables, SphericalCoordinates,
+};
+
+/// Coefficient tables of the VSOP87B solution for Mercury.
+#[cfg(feature = "mercury")]
+pub static MERCURY: PlanetTables<'static> = PlanetTables {
+    longitude: &[
+        [&mercury::L0[0], &mercury::L0[1], &mercury::L0[2]],
+        [&mercury::L1[0], &mercury::L1[1], &mercury::L1[2]],
+        [&mercury::L2[0], &mercury::L2[1], &mercury::L2[2]],
+        [&mercury::L3[0], &mercury::L3[1], &mercury::L3[2]],
+        [&mercury::L4[0], &mercury::L4[1], &mercury::L4[2]],
+        [&mercury::L5[0], &mercury::L5[1], &mercury::L5[2]],
+    ],
+    latitude: &[
+        [&mercury::B0[0], &mercury::B0[1], &mercury::B0[2]],
+        [&mercury::B1[0], &mercury::B1[1], &mercury::B1[2]],
+        [&mercury::B2[0], &mercury::B2[1], &mercury::B2[2]],
+        [&mercury::B3[0], &mercury::B3[1], &mercury::B3[2]],
+        [&mercury::B4[0], &mercury::B4[1], &mercury::B4[2]],
+        [&mercury::B5[0], &mercury::B5[1], &mercury::B5[2]],
+    ],
+    distance: &[
+        [&mercury::R0[0], &mercury::R0[1], &mercury::R0[2]],
+        [&mercury::R1[0], &mercury::R1[1], &mercury::R1[2]],
+        [&mercury::R2[0], &mercury::R2[1], &mercury::R2[2]],
+        [&mercury::R3[0], &mercury::R3[1], &mercury::R3[2]],
+        [&mercury::R4[0], &mercury::R4[1], &mercury::R4[2]],
+    ],
+};
+
+/// Coefficient tables of the VSOP87B solution for Venus.
+#[cfg(feature = "venus")]
+pub static VENUS: PlanetTables<'static> = PlanetTables {
+    longitude: &[
+        [&venus::L0[0], &venus::L0[1], &venus::L0[2]],
+        [&venus::L1[0], &venus::L1[1], &venus::L1[2]],
+        [&venus::L2[0], &venus::L2[1], &venus::L2[2]],
+        [&venus::L3[0], &venus::L3[1], &venus::L3[2]],
+        [&venus::L4[0], &venus::L4[1], &venus::L4[2]],
+        [&venus::L5[0], &venus::L5[1], &venus::L5[2]],
+    ],
+    latitude: &[
+        [&venus::B0[0], &venus::B0[1], &venus::B0[2]],
+        [&venus::B1[0], &venus::B1[1], &venus::B1[2]],
+        [&venus::B2[0], &venus::B2[1], &venus::B2[2]],
+        [&venus::B3[0], &venus::B3[1], &venus::B3[2]],
+        [&venus::B4[0], &venus::B4[1], &venus::B4[2]],
+        [&venus::B5[0], &venus::B5[1], &venus::B5[2]],
+    ],
+    distance: &[
+        [&venus::R0[0], &venus::R0[1], &venus::R0[2]],
+        [&venus::R1[0], &venus::R1[1], &venus::R1[2]],
+        [&venus::R2[0], &venus::R2[1], &venus::R2[2]],
+        [&venus::R3[0], &venus::R3[1], &venus::R3[2]],
+        [&venus::R4[0], &venus::R4[1], &venus::R4[2]],
+    ],
+};
+
+/// Coefficient tables of the VSOP87B solution for Earth.
+#[cfg(feature = "earth")]
+pub static EARTH: PlanetTables<'static> = PlanetTables {
+    longitude: &[
+        [&earth::L0[0], &earth::L0[1], &earth::L0[2]],
+        [&earth::L1[0], &earth::L1[1], &earth::L1[2]],
+        [&earth::L2[0], &earth::L2[1], &earth::L2[2]],
+        [&earth::L3[0], &earth::L3[1], &earth::L3[2]],
+        [&earth::L4[0], &earth::L4[1], &earth::L4[2]],
+        [&earth::L5[0], &earth::L5[1], &earth::L5[2]],
+    ],
+    latitude: &[
+        [&earth::B0[0], &earth::B0[1], &earth::B0[2]],
+        [&earth::B1[0], &earth::B1[1], &earth::B1[2]],
+        [&earth::B2[0], &earth::B2[1], &earth::B2[2]],
+        [&earth::B3[0], &earth::B3[1], &earth::B3[2]],
+        [&earth::B4[0], &earth::B4[1], &earth::B4[2]],
+        [&earth::B5[0], &earth::B5[1], &earth::B5[2]],
+    ],
+    distance: &[
+        [&earth::R0[0], &earth::R0[1], &earth::R0[2]],
+        [&earth::R1[0], &earth::R1[1], &earth::R1[2]],
+        [&earth::R2[0], &earth::R2[1], &earth::R2[2]],
+        [&earth::R3[0], &earth::R3[1], &earth::R3[2]],
+        [&earth::R4[0], &earth::R4[1], &earth::R4[2]],
+    ],
+};
+
+/// Coefficient tables of the VSOP87B solution for Mars.
+#[cfg(feature = "mars")]
+pub static MARS: PlanetTables<'static> = PlanetTables {
+    longitude: &[
+        [&mars::L0[0], &mars::L0[1], &mars::L0[2]],
+        [&mars::L1[0], &mars::L1[1], &mars::L1[2]],
+        [&mars::L2[0], &mars::L2[1], &mars::L2[2]],
+        [&mars::L3[0], &mars::L3[1], &mars::L3[2]],
+        [&mars::L4[0], &mars::L4[1], &mars::L4[2]],
+        [&mars::L5[0], &mars::L5[1], &mars::L5[2]],
+    ],
+    latitude: &[
+        [&mars::B0[0], &mars::B0[1], &mars::B0[2]],
+        [&mars::B1[0], &mars::B1[1], &mars::B1[2]],
+        [&mars::B2[0], &mars::B2[1], &mars::B2[2]],
+        [&mars::B3[0], &mars::B3[1], &mars::B3[2]],
+        [&mars::B4[0], &mars::B4[1], &mars::B4[2]],
+        [&mars::B5[0], &mars::B5[1], &mars::B5[2]],
+    ],
+    distance: &[
+        [&mars::R0[0], &mars::R0[1], &mars::R0[2]],
+        [&mars::R1[0], &mars::R1[1], &mars::R1[2]],
+        [&mars::R2[0], &mars::R2[1], &mars::R2[2]],
+        [&mars::R3[0], &mars::R3[1], &mars::R3[2]],
+        [&mars::R4[0], &mars::R4[1], &mars::R4[2]],
+    ],
+};
+
+/// Coefficient tables of the VSOP87B solution for Jupiter.
+#[cfg(feature = "jupiter")]
+pub static JUPITER: PlanetTables<'static> = PlanetTables {
+    longitude: &[
+        [&jupiter::L0[0], &jupiter::L0[1], &jupiter::L0[2]],
+        [&jupiter::L1[0], &jupiter::L1[1], &jupiter::L1[2]],
+        [&jupiter::L2[0], &jupiter::L2[1], &jupiter::L2[2]],
+        [&jupiter::L3[0], &jupiter::L3[1], &jupiter::L3[2]],
+        [&jupiter::L4[0], &jupiter::L4[1], &jupiter::L4[2]],
+        [&jupiter::L5[0], &jupiter::L5[1], &jupiter::L5[2]],
+    ],
+    latitude: &[
+        [&jupiter::B0[0], &jupiter::B0[1], &jupiter::B0[2]],
+        [&jupiter::B1[0], &jupiter::B1[1], &jupiter::B1[2]],
+        [&jupiter::B2[0], &jupiter::B2[1], &jupiter::B2[2]],
+        [&jupiter::B3[0], &jupiter::B3[1], &jupiter::B3[2]],
+        [&jupiter::B4[0], &jupiter::B4[1], &jupiter::B4[2]],
+        [&jupiter::B5[0], &jupiter::B5[1], &jupiter::B5[2]],
+    ],
+    distance: &[
+        [&jupiter::R0[0], &jupiter::R0[1], &jupiter::R0[2]],
+        [&jupiter::R1[0], &jupiter::R1[1], &jupiter::R1[2]],
+        [&jupiter::R2[0], &jupiter::R2[1], &jupiter::R2[2]],
+        [&jupiter::R3[0], &jupiter::R3[1], &jupiter::R3[2]],
+        [&jupiter::R4[0], &jupiter::R4[1], &jupiter::R4[2]],
+    ],
+};
+
+/// Coefficient tables of the VSOP87B solution for Saturn.
+#[cfg(feature = "saturn")]
+pub static SATURN: PlanetTables<'static> = PlanetTables {
+    longitude: &[
+        [&saturn::L0[0], &saturn::L0[1], &saturn::L0[2]],
+        [&saturn::L1[0], &saturn::L1[1], &saturn::L1[2]],
+        [&saturn::L2[0], &saturn::L2[1], &saturn::L2[2]],
+        [&saturn::L3[0], &saturn::L3[1], &saturn::L3[2]],
+        [&saturn::L4[0], &saturn::L4[1], &saturn::L4[2]],
+        [&saturn::L5[0], &saturn::L5[1], &saturn::L5[2]],
+    ],
+    latitude: &[
+        [&saturn::B0[0], &saturn::B0[1], &saturn::B0[2]],
+        [&saturn::B1[0], &saturn::B1[1], &saturn::B1[2]],
+        [&saturn::B2[0], &saturn::B2[1], &saturn::B2[2]],
+        [&saturn::B3[0], &saturn::B3[1], &saturn::B3[2]],
+        [&saturn::B4[0], &saturn::B4[1], &saturn::B4[2]],
+        [&saturn::B5[0], &saturn::B5[1], &saturn::B5[2]],
+    ],
+    distance: &[
+        [&saturn::R0[0], &saturn::R0[1], &saturn::R0[2]],
+        [&saturn::R1[0], &saturn::R1[1], &saturn::R1[2]],
+        [&saturn::R2[0], &saturn::R2[1], &saturn::R2[2]],
+        [&saturn::R3[0], &saturn::R3[1], &saturn::R3[2]],
+        [&saturn::R4[0], &saturn::R4[1], &saturn::R4[2]],
+    ],
+};
+
+/// Coefficient tables of the VSOP87B solution for Uranus.
+#[cfg(feature = "uranus")]
+pub static URANUS: PlanetTables<'static> = PlanetTables {
+    longitude: &[
+        [&uranus::L0[0], &uranus::L0[1], &uranus::L0[2]],
+        [&uranus::L1[0], &uranus::L1[1], &uranus::L1[2]],
+        [&uranus::L2[0], &uranus::L2[1], &uranus::L2[2]],
+        [&uranus::L3[0], &uranus::L3[1], &uranus::L3[2]],
+        [&uranus::L4[0], &uranus::L4[1], &uranus::L4[2]],
+    ],
+    latitude: &[
+        [&uranus::B0[0], &uranus::B0[1], &uranus::B0[2]],
+        [&uranus::B1[0], &uranus::B1[1], &uranus::B1[2]],
+        [&uranus::B2[0], &uranus::B2[1], &uranus::B2[2]],
+        [&uranus::B3[0], &uranus::B3[1], &uranus::B3[2]],
+    ],
+    distance: &[
+        [&uranus::R0[0], &uranus::R0[1], &uranus::R0[2]],
+        [&uranus::R1[0], &uranus::R1[1], &uranus::R1[2]],
+        [&uranus::R2[0], &uranus::R2[1], &uranus::R2[2]],
+        [&uranus::R3[0], &uranus::R3[1], &uranus::R3[2]],
+    ],
+};
+
+/// Coefficient tables of the VSOP87B solution for Neptune.
+#[cfg(feature = "neptune")]
+pub static NEPTUNE: PlanetTables<'static> = PlanetTables {
+    longitude: &[
+        [&neptune::L0[0], &neptune::L0[1], &neptune::L0[2]],
+        [&neptune::L1[0], &neptune::L1[1], &neptune::L1[2]],
+        [&neptune::L2[0], &neptune::L2[1], &neptune::L2[2]],
+        [&neptune::L3[0], &neptune::L3[1], &neptune::L3[2]],
+    ],
+    latitude: &[
+        [&neptune::B0[0], &neptune::B0[1], &neptune::B0[2]],
+        [&neptune::B1[0], &neptune::B1[1], &neptune::B1[2]],
+        [&neptune::B2[0], &neptune::B2[1], &neptune::B2[2]],
+        [&neptune::B3[0], &neptune::B3[1], &neptune::B3[2]],
+    ],
+    distance: &[
+        [&neptune::R0[0], &neptune::R0[1], &neptune::R0[2]],
+        [&neptune::R1[0], &neptune::R1[1], &neptune::R1[2]],
+        [&neptune::R2[0], &neptune::R2[1], &neptune::R2[2]],
+        [&neptune::R3[0], &neptune::R3[1], &neptune::R3[2]],
+    ],
+};
 
 /// Calculates VSOP87B solution for Mercury.
 ///
@@ -56,44 +277,9 @@ use std::f64::consts::PI;
 /// assert!(coordinates.latitude() > -0.0527573412 && coordinates.latitude() < -0.0527573410);
 /// assert!(coordinates.distance() > 0.4664711 && coordinates.distance() < 0.4664719);
 /// ```
+#[cfg(feature = "mercury")]
 pub fn mercury(jde: f64) -> SphericalCoordinates {
-    let t = calculate_t(jde);
-
-    let l0 = calculate_var(t, &mercury::L0[0], &mercury::L0[1], &mercury::L0[2]);
-    let l1 = calculate_var(t, &mercury::L1[0], &mercury::L1[1], &mercury::L1[2]);
-    let l2 = calculate_var(t, &mercury::L2[0], &mercury::L2[1], &mercury::L2[2]);
-    let l3 = calculate_var(t, &mercury::L3[0], &mercury::L3[1], &mercury::L3[2]);
-    let l4 = calculate_var(t, &mercury::L4[0], &mercury::L4[1], &mercury::L4[2]);
-    let l5 = calculate_var(t, &mercury::L5[0], &mercury::L5[1], &mercury::L5[2]);
-
-    let b0 = calculate_var(t, &mercury::B0[0], &mercury::B0[1], &mercury::B0[2]);
-    let b1 = calculate_var(t, &mercury::B1[0], &mercury::B1[1], &mercury::B1[2]);
-    let b2 = calculate_var(t, &mercury::B2[0], &mercury::B2[1], &mercury::B2[2]);
-    let b3 = calculate_var(t, &mercury::B3[0], &mercury::B3[1], &mercury::B3[2]);
-    let b4 = calculate_var(t, &mercury::B4[0], &mercury::B4[1], &mercury::B4[2]);
-    let b5 = calculate_var(t, &mercury::B5[0], &mercury::B5[1], &mercury::B5[2]);
-
-    let r0 = calculate_var(t, &mercury::R0[0], &mercury::R0[1], &mercury::R0[2]);
-    let r1 = calculate_var(t, &mercury::R1[0], &mercury::R1[1], &mercury::R1[2]);
-    let r2 = calculate_var(t, &mercury::R2[0], &mercury::R2[1], &mercury::R2[2]);
-    let r3 = calculate_var(t, &mercury::R3[0], &mercury::R3[1], &mercury::R3[2]);
-    let r4 = calculate_var(t, &mercury::R4[0], &mercury::R4[1], &mercury::R4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let b = b0 + b1 * t + b2 * t2 + b3 * t3 + b4 * t4 + b5 * t5;
-    let r = r0 + r1 * t + r2 * t2 + r3 * t3 + r4 * t4;
-
-    SphericalCoordinates {
-        lon: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        lat: b,
-        dist: r,
-    }
+    evaluate(&MERCURY, jde)
 }
 
 /// Calculates VSOP87B solution for Venus.
@@ -119,44 +305,9 @@ pub fn mercury(jde: f64) -> SphericalCoordinates {
 /// assert!(coordinates.latitude() > -0.0591709805 && coordinates.latitude() < -0.0591709803);
 /// assert!(coordinates.distance() > 0.7274715 && coordinates.distance() < 0.7274723);
 /// ```
+#[cfg(feature = "venus")]
 pub fn venus(jde: f64) -> SphericalCoordinates {
-    let t = calculate_t(jde);
-
-    let l0 = calculate_var(t, &venus::L0[0], &venus::L0[1], &venus::L0[2]);
-    let l1 = calculate_var(t, &venus::L1[0], &venus::L1[1], &venus::L1[2]);
-    let l2 = calculate_var(t, &venus::L2[0], &venus::L2[1], &venus::L2[2]);
-    let l3 = calculate_var(t, &venus::L3[0], &venus::L3[1], &venus::L3[2]);
-    let l4 = calculate_var(t, &venus::L4[0], &venus::L4[1], &venus::L4[2]);
-    let l5 = calculate_var(t, &venus::L5[0], &venus::L5[1], &venus::L5[2]);
-
-    let b0 = calculate_var(t, &venus::B0[0], &venus::B0[1], &venus::B0[2]);
-    let b1 = calculate_var(t, &venus::B1[0], &venus::B1[1], &venus::B1[2]);
-    let b2 = calculate_var(t, &venus::B2[0], &venus::B2[1], &venus::B2[2]);
-    let b3 = calculate_var(t, &venus::B3[0], &venus::B3[1], &venus::B3[2]);
-    let b4 = calculate_var(t, &venus::B4[0], &venus::B4[1], &venus::B4[2]);
-    let b5 = calculate_var(t, &venus::B5[0], &venus::B5[1], &venus::B5[2]);
-
-    let r0 = calculate_var(t, &venus::R0[0], &venus::R0[1], &venus::R0[2]);
-    let r1 = calculate_var(t, &venus::R1[0], &venus::R1[1], &venus::R1[2]);
-    let r2 = calculate_var(t, &venus::R2[0], &venus::R2[1], &venus::R2[2]);
-    let r3 = calculate_var(t, &venus::R3[0], &venus::R3[1], &venus::R3[2]);
-    let r4 = calculate_var(t, &venus::R4[0], &venus::R4[1], &venus::R4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let b = b0 + b1 * t + b2 * t2 + b3 * t3 + b4 * t4 + b5 * t5;
-    let r = r0 + r1 * t + r2 * t2 + r3 * t3 + r4 * t4;
-
-    SphericalCoordinates {
-        lon: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        lat: b,
-        dist: r,
-    }
+    evaluate(&VENUS, jde)
 }
 
 /// Calculates VSOP87B solution for Earth.
@@ -182,44 +333,9 @@ pub fn venus(jde: f64) -> SphericalCoordinates {
 /// assert!(coordinates.latitude() > 0.0004381094 && coordinates.latitude() < 0.0004381096);
 /// assert!(coordinates.distance() > 0.9832270 && coordinates.distance() < 0.9832278);
 /// ```
+#[cfg(feature = "earth")]
 pub fn earth(jde: f64) -> SphericalCoordinates {
-    let t = calculate_t(jde);
-
-    let l0 = calculate_var(t, &earth::L0[0], &earth::L0[1], &earth::L0[2]);
-    let l1 = calculate_var(t, &earth::L1[0], &earth::L1[1], &earth::L1[2]);
-    let l2 = calculate_var(t, &earth::L2[0], &earth::L2[1], &earth::L2[2]);
-    let l3 = calculate_var(t, &earth::L3[0], &earth::L3[1], &earth::L3[2]);
-    let l4 = calculate_var(t, &earth::L4[0], &earth::L4[1], &earth::L4[2]);
-    let l5 = calculate_var(t, &earth::L5[0], &earth::L5[1], &earth::L5[2]);
-
-    let b0 = calculate_var(t, &earth::B0[0], &earth::B0[1], &earth::B0[2]);
-    let b1 = calculate_var(t, &earth::B1[0], &earth::B1[1], &earth::B1[2]);
-    let b2 = calculate_var(t, &earth::B2[0], &earth::B2[1], &earth::B2[2]);
-    let b3 = calculate_var(t, &earth::B3[0], &earth::B3[1], &earth::B3[2]);
-    let b4 = calculate_var(t, &earth::B4[0], &earth::B4[1], &earth::B4[2]);
-    let b5 = calculate_var(t, &earth::B5[0], &earth::B5[1], &earth::B5[2]);
-
-    let r0 = calculate_var(t, &earth::R0[0], &earth::R0[1], &earth::R0[2]);
-    let r1 = calculate_var(t, &earth::R1[0], &earth::R1[1], &earth::R1[2]);
-    let r2 = calculate_var(t, &earth::R2[0], &earth::R2[1], &earth::R2[2]);
-    let r3 = calculate_var(t, &earth::R3[0], &earth::R3[1], &earth::R3[2]);
-    let r4 = calculate_var(t, &earth::R4[0], &earth::R4[1], &earth::R4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let b = b0 + b1 * t + b2 * t2 + b3 * t3 + b4 * t4 + b5 * t5;
-    let r = r0 + r1 * t + r2 * t2 + r3 * t3 + r4 * t4;
-
-    SphericalCoordinates {
-        lon: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        lat: b,
-        dist: r,
-    }
+    evaluate(&EARTH, jde)
 }
 
 /// Calculates VSOP87B solution for Mars.
@@ -245,44 +361,9 @@ pub fn earth(jde: f64) -> SphericalCoordinates {
 /// assert!(coordinates.latitude() > 0.0280781216 && coordinates.latitude() < 0.0280781218);
 /// assert!(coordinates.distance() > 1.6584693 && coordinates.distance() < 1.6584701);
 /// ```
+#[cfg(feature = "mars")]
 pub fn mars(jde: f64) -> SphericalCoordinates {
-    let t = calculate_t(jde);
-
-    let l0 = calculate_var(t, &mars::L0[0], &mars::L0[1], &mars::L0[2]);
-    let l1 = calculate_var(t, &mars::L1[0], &mars::L1[1], &mars::L1[2]);
-    let l2 = calculate_var(t, &mars::L2[0], &mars::L2[1], &mars::L2[2]);
-    let l3 = calculate_var(t, &mars::L3[0], &mars::L3[1], &mars::L3[2]);
-    let l4 = calculate_var(t, &mars::L4[0], &mars::L4[1], &mars::L4[2]);
-    let l5 = calculate_var(t, &mars::L5[0], &mars::L5[1], &mars::L5[2]);
-
-    let b0 = calculate_var(t, &mars::B0[0], &mars::B0[1], &mars::B0[2]);
-    let b1 = calculate_var(t, &mars::B1[0], &mars::B1[1], &mars::B1[2]);
-    let b2 = calculate_var(t, &mars::B2[0], &mars::B2[1], &mars::B2[2]);
-    let b3 = calculate_var(t, &mars::B3[0], &mars::B3[1], &mars::B3[2]);
-    let b4 = calculate_var(t, &mars::B4[0], &mars::B4[1], &mars::B4[2]);
-    let b5 = calculate_var(t, &mars::B5[0], &mars::B5[1], &mars::B5[2]);
-
-    let r0 = calculate_var(t, &mars::R0[0], &mars::R0[1], &mars::R0[2]);
-    let r1 = calculate_var(t, &mars::R1[0], &mars::R1[1], &mars::R1[2]);
-    let r2 = calculate_var(t, &mars::R2[0], &mars::R2[1], &mars::R2[2]);
-    let r3 = calculate_var(t, &mars::R3[0], &mars::R3[1], &mars::R3[2]);
-    let r4 = calculate_var(t, &mars::R4[0], &mars::R4[1], &mars::R4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let b = b0 + b1 * t + b2 * t2 + b3 * t3 + b4 * t4 + b5 * t5;
-    let r = r0 + r1 * t + r2 * t2 + r3 * t3 + r4 * t4;
-
-    SphericalCoordinates {
-        lon: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        lat: b,
-        dist: r,
-    }
+    evaluate(&MARS, jde)
 }
 
 /// Calculates VSOP87B solution for Jupiter.
@@ -308,44 +389,9 @@ pub fn mars(jde: f64) -> SphericalCoordinates {
 /// assert!(coordinates.latitude() > 0.0145957281 && coordinates.latitude() < 0.0145957283);
 /// assert!(coordinates.distance() > 5.3439451 && coordinates.distance() < 5.3439459);
 /// ```
+#[cfg(feature = "jupiter")]
 pub fn jupiter(jde: f64) -> SphericalCoordinates {
-    let t = calculate_t(jde);
-
-    let l0 = calculate_var(t, &jupiter::L0[0], &jupiter::L0[1], &jupiter::L0[2]);
-    let l1 = calculate_var(t, &jupiter::L1[0], &jupiter::L1[1], &jupiter::L1[2]);
-    let l2 = calculate_var(t, &jupiter::L2[0], &jupiter::L2[1], &jupiter::L2[2]);
-    let l3 = calculate_var(t, &jupiter::L3[0], &jupiter::L3[1], &jupiter::L3[2]);
-    let l4 = calculate_var(t, &jupiter::L4[0], &jupiter::L4[1], &jupiter::L4[2]);
-    let l5 = calculate_var(t, &jupiter::L5[0], &jupiter::L5[1], &jupiter::L5[2]);
-
-    let b0 = calculate_var(t, &jupiter::B0[0], &jupiter::B0[1], &jupiter::B0[2]);
-    let b1 = calculate_var(t, &jupiter::B1[0], &jupiter::B1[1], &jupiter::B1[2]);
-    let b2 = calculate_var(t, &jupiter::B2[0], &jupiter::B2[1], &jupiter::B2[2]);
-    let b3 = calculate_var(t, &jupiter::B3[0], &jupiter::B3[1], &jupiter::B3[2]);
-    let b4 = calculate_var(t, &jupiter::B4[0], &jupiter::B4[1], &jupiter::B4[2]);
-    let b5 = calculate_var(t, &jupiter::B5[0], &jupiter::B5[1], &jupiter::B5[2]);
-
-    let r0 = calculate_var(t, &jupiter::R0[0], &jupiter::R0[1], &jupiter::R0[2]);
-    let r1 = calculate_var(t, &jupiter::R1[0], &jupiter::R1[1], &jupiter::R1[2]);
-    let r2 = calculate_var(t, &jupiter::R2[0], &jupiter::R2[1], &jupiter::R2[2]);
-    let r3 = calculate_var(t, &jupiter::R3[0], &jupiter::R3[1], &jupiter::R3[2]);
-    let r4 = calculate_var(t, &jupiter::R4[0], &jupiter::R4[1], &jupiter::R4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let b = b0 + b1 * t + b2 * t2 + b3 * t3 + b4 * t4 + b5 * t5;
-    let r = r0 + r1 * t + r2 * t2 + r3 * t3 + r4 * t4;
-
-    SphericalCoordinates {
-        lon: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        lat: b,
-        dist: r,
-    }
+    evaluate(&JUPITER, jde)
 }
 
 /// Calculates VSOP87B solution for Saturn.
@@ -371,44 +417,9 @@ pub fn jupiter(jde: f64) -> SphericalCoordinates {
 /// assert!(coordinates.latitude() > -0.0369435534 && coordinates.latitude() < -0.0369435532);
 /// assert!(coordinates.distance() > 9.0669210 && coordinates.distance() < 9.0669218);
 /// ```
+#[cfg(feature = "saturn")]
 pub fn saturn(jde: f64) -> SphericalCoordinates {
-    let t = calculate_t(jde);
-
-    let l0 = calculate_var(t, &saturn::L0[0], &saturn::L0[1], &saturn::L0[2]);
-    let l1 = calculate_var(t, &saturn::L1[0], &saturn::L1[1], &saturn::L1[2]);
-    let l2 = calculate_var(t, &saturn::L2[0], &saturn::L2[1], &saturn::L2[2]);
-    let l3 = calculate_var(t, &saturn::L3[0], &saturn::L3[1], &saturn::L3[2]);
-    let l4 = calculate_var(t, &saturn::L4[0], &saturn::L4[1], &saturn::L4[2]);
-    let l5 = calculate_var(t, &saturn::L5[0], &saturn::L5[1], &saturn::L5[2]);
-
-    let b0 = calculate_var(t, &saturn::B0[0], &saturn::B0[1], &saturn::B0[2]);
-    let b1 = calculate_var(t, &saturn::B1[0], &saturn::B1[1], &saturn::B1[2]);
-    let b2 = calculate_var(t, &saturn::B2[0], &saturn::B2[1], &saturn::B2[2]);
-    let b3 = calculate_var(t, &saturn::B3[0], &saturn::B3[1], &saturn::B3[2]);
-    let b4 = calculate_var(t, &saturn::B4[0], &saturn::B4[1], &saturn::B4[2]);
-    let b5 = calculate_var(t, &saturn::B5[0], &saturn::B5[1], &saturn::B5[2]);
-
-    let r0 = calculate_var(t, &saturn::R0[0], &saturn::R0[1], &saturn::R0[2]);
-    let r1 = calculate_var(t, &saturn::R1[0], &saturn::R1[1], &saturn::R1[2]);
-    let r2 = calculate_var(t, &saturn::R2[0], &saturn::R2[1], &saturn::R2[2]);
-    let r3 = calculate_var(t, &saturn::R3[0], &saturn::R3[1], &saturn::R3[2]);
-    let r4 = calculate_var(t, &saturn::R4[0], &saturn::R4[1], &saturn::R4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t3 * t;
-    let t5 = t4 * t;
-
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let b = b0 + b1 * t + b2 * t2 + b3 * t3 + b4 * t4 + b5 * t5;
-    let r = r0 + r1 * t + r2 * t2 + r3 * t3 + r4 * t4;
-
-    SphericalCoordinates {
-        lon: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        lat: b,
-        dist: r,
-    }
+    evaluate(&SATURN, jde)
 }
 
 /// Calculates VSOP87B solution for Uranus.
@@ -434,39 +445,9 @@ pub fn saturn(jde: f64) -> SphericalCoordinates {
 /// assert!(coordinates.latitude() > -0.0033027750 && coordinates.latitude() < -0.0033027748);
 /// assert!(coordinates.distance() > 19.2694309 && coordinates.distance() < 19.2694317);
 /// ```
+#[cfg(feature = "uranus")]
 pub fn uranus(jde: f64) -> SphericalCoordinates {
-    let t = calculate_t(jde);
-
-    let l0 = calculate_var(t, &uranus::L0[0], &uranus::L0[1], &uranus::L0[2]);
-    let l1 = calculate_var(t, &uranus::L1[0], &uranus::L1[1], &uranus::L1[2]);
-    let l2 = calculate_var(t, &uranus::L2[0], &uranus::L2[1], &uranus::L2[2]);
-    let l3 = calculate_var(t, &uranus::L3[0], &uranus::L3[1], &uranus::L3[2]);
-    let l4 = calculate_var(t, &uranus::L4[0], &uranus::L4[1], &uranus::L4[2]);
-
-    let b0 = calculate_var(t, &uranus::B0[0], &uranus::B0[1], &uranus::B0[2]);
-    let b1 = calculate_var(t, &uranus::B1[0], &uranus::B1[1], &uranus::B1[2]);
-    let b2 = calculate_var(t, &uranus::B2[0], &uranus::B2[1], &uranus::B2[2]);
-    let b3 = calculate_var(t, &uranus::B3[0], &uranus::B3[1], &uranus::B3[2]);
-
-    let r0 = calculate_var(t, &uranus::R0[0], &uranus::R0[1], &uranus::R0[2]);
-    let r1 = calculate_var(t, &uranus::R1[0], &uranus::R1[1], &uranus::R1[2]);
-    let r2 = calculate_var(t, &uranus::R2[0], &uranus::R2[1], &uranus::R2[2]);
-    let r3 = calculate_var(t, &uranus::R3[0], &uranus::R3[1], &uranus::R3[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4) % (2_f64 * PI);
-    let b = b0 + b1 * t + b2 * t2 + b3 * t3;
-    let r = r0 + r1 * t + r2 * t2 + r3 * t3;
-
-    SphericalCoordinates {
-        lon: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        lat: b,
-        dist: r,
-    }
+    evaluate(&URANUS, jde)
 }
 
 /// Calculates VSOP87B solution for Neptune.
@@ -492,35 +473,219 @@ pub fn uranus(jde: f64) -> SphericalCoordinates {
 /// assert!(coordinates.latitude() > 0.0306777429 && coordinates.latitude() < 0.0306777431);
 /// assert!(coordinates.distance() > 30.3109111 && coordinates.distance() < 30.3109119);
 /// ```
+#[cfg(feature = "neptune")]
 pub fn neptune(jde: f64) -> SphericalCoordinates {
-    let t = calculate_t(jde);
+    evaluate(&NEPTUNE, jde)
+}
+
+/// Calculates VSOP87B solution for the given planet.
+///
+/// This function calculates the VSOP87B solution (heliocentric ecliptic spherical coordinates for
+/// the equinox J2000.0) for the given planet, by calling the function of that planet in this
+/// module. It is useful when the planet is only known at runtime, or to iterate over all the
+/// planets.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87b, Planet};
+///
+/// assert_eq!(vsop87b::planet(Planet::Mars, 2451545.0), vsop87b::mars(2451545.0));
+/// ```
+pub fn planet(planet: Planet, jde: f64) -> SphericalCoordinates {
+    match planet {
+        #[cfg(feature = "mercury")]
+        Planet::Mercury => mercury(jde),
+        #[cfg(feature = "venus")]
+        Planet::Venus => venus(jde),
+        #[cfg(feature = "earth")]
+        Planet::Earth => earth(jde),
+        #[cfg(feature = "mars")]
+        Planet::Mars => mars(jde),
+        #[cfg(feature = "jupiter")]
+        Planet::Jupiter => jupiter(jde),
+        #[cfg(feature = "saturn")]
+        Planet::Saturn => saturn(jde),
+        #[cfg(feature = "uranus")]
+        Planet::Uranus => uranus(jde),
+        #[cfg(feature = "neptune")]
+        Planet::Neptune => neptune(jde),
+        #[allow(unreachable_patterns)]
+        _ => planet.not_enabled(),
+    }
+}
 
-    let l0 = calculate_var(t, &neptune::L0[0], &neptune::L0[1], &neptune::L0[2]);
-    let l1 = calculate_var(t, &neptune::L1[0], &neptune::L1[1], &neptune::L1[2]);
-    let l2 = calculate_var(t, &neptune::L2[0], &neptune::L2[1], &neptune::L2[2]);
-    let l3 = calculate_var(t, &neptune::L3[0], &neptune::L3[1], &neptune::L3[2]);
+/// Calculates VSOP87B solution for all the planets at once.
+///
+/// The coordinates are returned in the order of
+/// [`Planet::ALL`](../enum.Planet.html#associatedconstant.ALL), by distance to the Sun: Mercury,
+/// Venus, Earth, Mars, Jupiter, Saturn, Uranus and Neptune. With the `rayon` feature, each planet
+/// is computed in a thread of the global [*rayon*](https://docs.rs/rayon) thread pool, which makes
+/// it suitable to refresh a whole planetarium every frame. Without it, the planets are computed one
+/// after the other, and the coordinates are the same in both cases. Only available with the
+/// `all_planets` feature.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87b, Planet};
+///
+/// let planets = vsop87b::all_planets(2451545.0);
+///
+/// assert_eq!(planets[Planet::Saturn as usize], vsop87b::saturn(2451545.0));
+/// ```
+#[cfg(feature = "all_planets")]
+pub fn all_planets(jde: f64) -> [SphericalCoordinates; 8] {
+    super::all_planets(|body| planet(body, jde))
+}
 
-    let b0 = calculate_var(t, &neptune::B0[0], &neptune::B0[1], &neptune::B0[2]);
-    let b1 = calculate_var(t, &neptune::B1[0], &neptune::B1[1], &neptune::B1[2]);
-    let b2 = calculate_var(t, &neptune::B2[0], &neptune::B2[1], &neptune::B2[2]);
-    let b3 = calculate_var(t, &neptune::B3[0], &neptune::B3[1], &neptune::B3[2]);
+/// Calculates VSOP87B solution for the given planet, in single precision.
+///
+/// The solution is computed in double precision, and the resulting coordinates are then converted
+/// to `f32`, so that the large terms of the series do not lose precision. The angles are precise to
+/// about 2·10⁻⁷ radians (0.05"), and the distance has a relative precision of about 6·10⁻⁸.
+///
+/// The Julian Day (*JD*) is still an `f64`: an `f32` can only represent Julian Days around the
+/// current epoch with a resolution of a quarter of a day.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87b, Planet};
+///
+/// let coordinates = vsop87b::planet_f32(Planet::Earth, 2451545.0);
+///
+/// assert!(coordinates.distance() > 0.9833 && coordinates.distance() < 0.9834);
+/// ```
+pub fn planet_f32(planet: Planet, jde: f64) -> SphericalCoordinates<f32> {
+    self::planet(planet, jde).to_f32()
+}
 
-    let r0 = calculate_var(t, &neptune::R0[0], &neptune::R0[1], &neptune::R0[2]);
-    let r1 = calculate_var(t, &neptune::R1[0], &neptune::R1[1], &neptune::R1[2]);
-    let r2 = calculate_var(t, &neptune::R2[0], &neptune::R2[1], &neptune::R2[2]);
-    let r3 = calculate_var(t, &neptune::R3[0], &neptune::R3[1], &neptune::R3[2]);
+/// Calculates the VSOP87B position of the given planet, and its rates of change.
+///
+/// This returns the same coordinates as [`planet`](fn.planet.html), and the derivatives of the
+/// longitude and the latitude, in radians per day, and of the distance, in *AU* per day, as given
+/// by [`evaluate_state()`](../fn.evaluate_state.html). They are computed in a single pass from
+/// the analytic derivative of the series, without finite differences. The parameter needed is the
+/// Julian Day (*JD*) for the given date.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87b, Planet};
+///
+/// let (position, rates) = vsop87b::state(Planet::Mars, 2451545.0);
+///
+/// assert_eq!(position, vsop87b::mars(2451545.0));
+/// // Close to its perihelion, Mars moves by about 0.63° per day along its orbit.
+/// assert!((rates.longitude().to_degrees() - 0.63).abs() < 0.01);
+/// ```
+pub fn state(planet: Planet, jde: f64) -> (SphericalCoordinates, SphericalCoordinates) {
+    evaluate_state(tables(planet), jde)
+}
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
+/// Calculates VSOP87B solution for the given planet, skipping the terms smaller than `epsilon`.
+///
+/// This trades precision for speed, like
+/// [`vsop87d::planet_truncated()`](../vsop87d/fn.planet_truncated.html), which gives some figures.
+/// The terms whose amplitude is smaller than `epsilon` (in radians for the longitude and latitude,
+/// and in *AU* for the distance) are not computed. See
+/// [`evaluate_truncated()`](../fn.evaluate_truncated.html) for details.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87b, Planet};
+///
+/// let full = vsop87b::planet(Planet::Mars, 2451545.0);
+/// let truncated = vsop87b::planet_truncated(Planet::Mars, 2451545.0, 1e-7);
+///
+/// assert!((full.longitude() - truncated.longitude()).abs() < 1e-5);
+/// assert!((full.latitude() - truncated.latitude()).abs() < 1e-5);
+/// ```
+pub fn planet_truncated(planet: Planet, jde: f64, epsilon: f64) -> SphericalCoordinates {
+    evaluate_truncated(tables(planet), jde, epsilon)
+}
+
+/// Calculates VSOP87B solution for the given planet on many dates.
+///
+/// This function calculates the VSOP87B solution of the given planet for each Julian Day (*JD*) in
+/// `jdes`, and returns the coordinates in a vector, in the same order. The coefficient tables of
+/// the planet are looked up once for the whole batch, and the output vector is allocated once.
+/// Only available with the standard library: see [`planet_into`](fn.planet_into.html) otherwise.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87b, Planet};
+///
+/// let jdes = [2451545.0, 2451546.0, 2451547.0];
+/// let track = vsop87b::planet_many(Planet::Earth, &jdes);
+///
+/// assert_eq!(track.len(), 3);
+/// assert_eq!(track[1], vsop87b::earth(2451546.0));
+/// ```
+#[cfg(not(feature = "no_std"))]
+pub fn planet_many(planet: Planet, jdes: &[f64]) -> Vec<SphericalCoordinates> {
+    let tables = tables(planet);
+    jdes.iter().map(|&jde| evaluate(tables, jde)).collect()
+}
 
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3) % (2_f64 * PI);
-    let b = b0 + b1 * t + b2 * t2 + b3 * t3;
-    let r = r0 + r1 * t + r2 * t2 + r3 * t3;
+/// Calculates VSOP87B solution for the given planet on many dates, into a caller-provided buffer.
+///
+/// This is the allocation-free version of [`planet_many`](fn.planet_many.html), also available
+/// with the `no_std` feature. The coordinates for `jdes[i]` are written to `output[i]`, and every
+/// element of `output` is overwritten.
+///
+/// # Panics
+///
+/// Panics if `jdes` and `output` have different lengths, before writing anything.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87b, Planet};
+///
+/// let jdes = [2451545.0, 2451546.0];
+/// let mut output = [vsop87b::mars(0.0); 2];
+/// vsop87b::planet_into(Planet::Mars, &jdes, &mut output);
+///
+/// assert_eq!(output[0], vsop87b::mars(2451545.0));
+/// assert_eq!(output[1], vsop87b::mars(2451546.0));
+/// ```
+pub fn planet_into(planet: Planet, jdes: &[f64], output: &mut [SphericalCoordinates]) {
+    assert_eq!(
+        jdes.len(),
+        output.len(),
+        "the input dates and the output buffer must have the same length"
+    );
+
+    let tables = tables(planet);
+    for (coordinates, &jde) in output.iter_mut().zip(jdes) {
+        *coordinates = evaluate(tables, jde);
+    }
+}
 
-    SphericalCoordinates {
-        lon: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        lat: b,
-        dist: r,
+/// Gets the VSOP87B coefficient tables of the given planet.
+fn tables(planet: Planet) -> &'static PlanetTables<'static> {
+    match planet {
+        #[cfg(feature = "mercury")]
+        Planet::Mercury => &MERCURY,
+        #[cfg(feature = "venus")]
+        Planet::Venus => &VENUS,
+        #[cfg(feature = "earth")]
+        Planet::Earth => &EARTH,
+        #[cfg(feature = "mars")]
+        Planet::Mars => &MARS,
+        #[cfg(feature = "jupiter")]
+        Planet::Jupiter => &JUPITER,
+        #[cfg(feature = "saturn")]
+        Planet::Saturn => &SATURN,
+        #[cfg(feature = "uranus")]
+        Planet::Uranus => &URANUS,
+        #[cfg(feature = "neptune")]
+        Planet::Neptune => &NEPTUNE,
+        #[allow(unreachable_patterns)]
+        _ => planet.not_enabled(),
     }
 }
@@ -19,21 +19,251 @@
 //! assert!(coordinates.distance() > 5.44915664 && coordinates.distance() < 5.44915740);
 //! ```
 
+#[cfg(feature = "earth")]
 mod earth;
+#[cfg(feature = "jupiter")]
 mod jupiter;
+#[cfg(feature = "mars")]
 mod mars;
+#[cfg(feature = "mercury")]
 mod mercury;
+#[cfg(feature = "neptune")]
 mod neptune;
+#[cfg(feature = "saturn")]
 mod saturn;
+#[cfg(feature = "uranus")]
 mod uranus;
+#[cfg(feature = "venus")]
 mod venus;
 
-use super::{calculate_t, calculate_var, SphericalCoordinates};
+#[cfg(feature = "all_planets")]
+use super::TimeContext;
+#[cfg(feature = "earth")]
+use super::{earth_moon_offset, geocentric};
+use super::{
+    evaluate, evaluate_state, evaluate_truncated, Planet, PlanetTables, SphericalCoordinates,
+    TableError, Variable, Vsop87Error,
+};
 
-#[cfg(feature = "no_std")]
-use core::f64::consts::PI;
-#[cfg(not(feature = "no_std"))]
-use std::f64::consts::PI;
+/// Coefficient tables of the VSOP87D solution for Mercury.
+#[cfg(feature = "mercury")]
+pub static MERCURY: PlanetTables<'static> = PlanetTables {
+    longitude: &[
+        [&mercury::L0[0], &mercury::L0[1], &mercury::L0[2]],
+        [&mercury::L1[0], &mercury::L1[1], &mercury::L1[2]],
+        [&mercury::L2[0], &mercury::L2[1], &mercury::L2[2]],
+        [&mercury::L3[0], &mercury::L3[1], &mercury::L3[2]],
+        [&mercury::L4[0], &mercury::L4[1], &mercury::L4[2]],
+        [&mercury::L5[0], &mercury::L5[1], &mercury::L5[2]],
+    ],
+    latitude: &[
+        [&mercury::B0[0], &mercury::B0[1], &mercury::B0[2]],
+        [&mercury::B1[0], &mercury::B1[1], &mercury::B1[2]],
+        [&mercury::B2[0], &mercury::B2[1], &mercury::B2[2]],
+        [&mercury::B3[0], &mercury::B3[1], &mercury::B3[2]],
+        [&mercury::B4[0], &mercury::B4[1], &mercury::B4[2]],
+        [&mercury::B5[0], &mercury::B5[1], &mercury::B5[2]],
+    ],
+    distance: &[
+        [&mercury::R0[0], &mercury::R0[1], &mercury::R0[2]],
+        [&mercury::R1[0], &mercury::R1[1], &mercury::R1[2]],
+        [&mercury::R2[0], &mercury::R2[1], &mercury::R2[2]],
+        [&mercury::R3[0], &mercury::R3[1], &mercury::R3[2]],
+        [&mercury::R4[0], &mercury::R4[1], &mercury::R4[2]],
+    ],
+};
+
+/// Coefficient tables of the VSOP87D solution for Venus.
+#[cfg(feature = "venus")]
+pub static VENUS: PlanetTables<'static> = PlanetTables {
+    longitude: &[
+        [&venus::L0[0], &venus::L0[1], &venus::L0[2]],
+        [&venus::L1[0], &venus::L1[1], &venus::L1[2]],
+        [&venus::L2[0], &venus::L2[1], &venus::L2[2]],
+        [&venus::L3[0], &venus::L3[1], &venus::L3[2]],
+        [&venus::L4[0], &venus::L4[1], &venus::L4[2]],
+        [&venus::L5[0], &venus::L5[1], &venus::L5[2]],
+    ],
+    latitude: &[
+        [&venus::B0[0], &venus::B0[1], &venus::B0[2]],
+        [&venus::B1[0], &venus::B1[1], &venus::B1[2]],
+        [&venus::B2[0], &venus::B2[1], &venus::B2[2]],
+        [&venus::B3[0], &venus::B3[1], &venus::B3[2]],
+        [&venus::B4[0], &venus::B4[1], &venus::B4[2]],
+        [&venus::B5[0], &venus::B5[1], &venus::B5[2]],
+    ],
+    distance: &[
+        [&venus::R0[0], &venus::R0[1], &venus::R0[2]],
+        [&venus::R1[0], &venus::R1[1], &venus::R1[2]],
+        [&venus::R2[0], &venus::R2[1], &venus::R2[2]],
+        [&venus::R3[0], &venus::R3[1], &venus::R3[2]],
+        [&venus::R4[0], &venus::R4[1], &venus::R4[2]],
+    ],
+};
+
+/// Coefficient tables of the VSOP87D solution for Earth.
+#[cfg(feature = "earth")]
+pub static EARTH: PlanetTables<'static> = PlanetTables {
+    longitude: &[
+        [&earth::L0[0], &earth::L0[1], &earth::L0[2]],
+        [&earth::L1[0], &earth::L1[1], &earth::L1[2]],
+        [&earth::L2[0], &earth::L2[1], &earth::L2[2]],
+        [&earth::L3[0], &earth::L3[1], &earth::L3[2]],
+        [&earth::L4[0], &earth::L4[1], &earth::L4[2]],
+        [&earth::L5[0], &earth::L5[1], &earth::L5[2]],
+    ],
+    latitude: &[
+        [&earth::B0[0], &earth::B0[1], &earth::B0[2]],
+        [&earth::B1[0], &earth::B1[1], &earth::B1[2]],
+        [&earth::B2[0], &earth::B2[1], &earth::B2[2]],
+        [&earth::B3[0], &earth::B3[1], &earth::B3[2]],
+        [&earth::B4[0], &earth::B4[1], &earth::B4[2]],
+    ],
+    distance: &[
+        [&earth::R0[0], &earth::R0[1], &earth::R0[2]],
+        [&earth::R1[0], &earth::R1[1], &earth::R1[2]],
+        [&earth::R2[0], &earth::R2[1], &earth::R2[2]],
+        [&earth::R3[0], &earth::R3[1], &earth::R3[2]],
+        [&earth::R4[0], &earth::R4[1], &earth::R4[2]],
+    ],
+};
+
+/// Coefficient tables of the VSOP87D solution for Mars.
+#[cfg(feature = "mars")]
+pub static MARS: PlanetTables<'static> = PlanetTables {
+    longitude: &[
+        [&mars::L0[0], &mars::L0[1], &mars::L0[2]],
+        [&mars::L1[0], &mars::L1[1], &mars::L1[2]],
+        [&mars::L2[0], &mars::L2[1], &mars::L2[2]],
+        [&mars::L3[0], &mars::L3[1], &mars::L3[2]],
+        [&mars::L4[0], &mars::L4[1], &mars::L4[2]],
+        [&mars::L5[0], &mars::L5[1], &mars::L5[2]],
+    ],
+    latitude: &[
+        [&mars::B0[0], &mars::B0[1], &mars::B0[2]],
+        [&mars::B1[0], &mars::B1[1], &mars::B1[2]],
+        [&mars::B2[0], &mars::B2[1], &mars::B2[2]],
+        [&mars::B3[0], &mars::B3[1], &mars::B3[2]],
+        [&mars::B4[0], &mars::B4[1], &mars::B4[2]],
+        [&mars::B5[0], &mars::B5[1], &mars::B5[2]],
+    ],
+    distance: &[
+        [&mars::R0[0], &mars::R0[1], &mars::R0[2]],
+        [&mars::R1[0], &mars::R1[1], &mars::R1[2]],
+        [&mars::R2[0], &mars::R2[1], &mars::R2[2]],
+        [&mars::R3[0], &mars::R3[1], &mars::R3[2]],
+        [&mars::R4[0], &mars::R4[1], &mars::R4[2]],
+    ],
+};
+
+/// Coefficient tables of the VSOP87D solution for Jupiter.
+#[cfg(feature = "jupiter")]
+pub static JUPITER: PlanetTables<'static> = PlanetTables {
+    longitude: &[
+        [&jupiter::L0[0], &jupiter::L0[1], &jupiter::L0[2]],
+        [&jupiter::L1[0], &jupiter::L1[1], &jupiter::L1[2]],
+        [&jupiter::L2[0], &jupiter::L2[1], &jupiter::L2[2]],
+        [&jupiter::L3[0], &jupiter::L3[1], &jupiter::L3[2]],
+        [&jupiter::L4[0], &jupiter::L4[1], &jupiter::L4[2]],
+        [&jupiter::L5[0], &jupiter::L5[1], &jupiter::L5[2]],
+    ],
+    latitude: &[
+        [&jupiter::B0[0], &jupiter::B0[1], &jupiter::B0[2]],
+        [&jupiter::B1[0], &jupiter::B1[1], &jupiter::B1[2]],
+        [&jupiter::B2[0], &jupiter::B2[1], &jupiter::B2[2]],
+        [&jupiter::B3[0], &jupiter::B3[1], &jupiter::B3[2]],
+        [&jupiter::B4[0], &jupiter::B4[1], &jupiter::B4[2]],
+        [&jupiter::B5[0], &jupiter::B5[1], &jupiter::B5[2]],
+    ],
+    distance: &[
+        [&jupiter::R0[0], &jupiter::R0[1], &jupiter::R0[2]],
+        [&jupiter::R1[0], &jupiter::R1[1], &jupiter::R1[2]],
+        [&jupiter::R2[0], &jupiter::R2[1], &jupiter::R2[2]],
+        [&jupiter::R3[0], &jupiter::R3[1], &jupiter::R3[2]],
+        [&jupiter::R4[0], &jupiter::R4[1], &jupiter::R4[2]],
+    ],
+};
+
+/// Coefficient tables of the VSOP87D solution for Saturn.
+#[cfg(feature = "saturn")]
+pub static SATURN: PlanetTables<'static> = PlanetTables {
+    longitude: &[
+        [&saturn::L0[0], &saturn::L0[1], &saturn::L0[2]],
+        [&saturn::L1[0], &saturn::L1[1], &saturn::L1[2]],
+        [&saturn::L2[0], &saturn::L2[1], &saturn::L2[2]],
+        [&saturn::L3[0], &saturn::L3[1], &saturn::L3[2]],
+        [&saturn::L4[0], &saturn::L4[1], &saturn::L4[2]],
+        [&saturn::L5[0], &saturn::L5[1], &saturn::L5[2]],
+    ],
+    latitude: &[
+        [&saturn::B0[0], &saturn::B0[1], &saturn::B0[2]],
+        [&saturn::B1[0], &saturn::B1[1], &saturn::B1[2]],
+        [&saturn::B2[0], &saturn::B2[1], &saturn::B2[2]],
+        [&saturn::B3[0], &saturn::B3[1], &saturn::B3[2]],
+        [&saturn::B4[0], &saturn::B4[1], &saturn::B4[2]],
+        [&saturn::B5[0], &saturn::B5[1], &saturn::B5[2]],
+    ],
+    distance: &[
+        [&saturn::R0[0], &saturn::R0[1], &saturn::R0[2]],
+        [&saturn::R1[0], &saturn::R1[1], &saturn::R1[2]],
+        [&saturn::R2[0], &saturn::R2[1], &saturn::R2[2]],
+        [&saturn::R3[0], &saturn::R3[1], &saturn::R3[2]],
+        [&saturn::R4[0], &saturn::R4[1], &saturn::R4[2]],
+    ],
+};
+
+/// Coefficient tables of the VSOP87D solution for Uranus.
+#[cfg(feature = "uranus")]
+pub static URANUS: PlanetTables<'static> = PlanetTables {
+    longitude: &[
+        [&uranus::L0[0], &uranus::L0[1], &uranus::L0[2]],
+        [&uranus::L1[0], &uranus::L1[1], &uranus::L1[2]],
+        [&uranus::L2[0], &uranus::L2[1], &uranus::L2[2]],
+        [&uranus::L3[0], &uranus::L3[1], &uranus::L3[2]],
+        [&uranus::L4[0], &uranus::L4[1], &uranus::L4[2]],
+        [&uranus::L5[0], &uranus::L5[1], &uranus::L5[2]],
+    ],
+    latitude: &[
+        [&uranus::B0[0], &uranus::B0[1], &uranus::B0[2]],
+        [&uranus::B1[0], &uranus::B1[1], &uranus::B1[2]],
+        [&uranus::B2[0], &uranus::B2[1], &uranus::B2[2]],
+        [&uranus::B3[0], &uranus::B3[1], &uranus::B3[2]],
+        [&uranus::B4[0], &uranus::B4[1], &uranus::B4[2]],
+    ],
+    distance: &[
+        [&uranus::R0[0], &uranus::R0[1], &uranus::R0[2]],
+        [&uranus::R1[0], &uranus::R1[1], &uranus::R1[2]],
+        [&uranus::R2[0], &uranus::R2[1], &uranus::R2[2]],
+        [&uranus::R3[0], &uranus::R3[1], &uranus::R3[2]],
+    ],
+};
+
+/// Coefficient tables of the VSOP87D solution for Neptune.
+#[cfg(feature = "neptune")]
+pub static NEPTUNE: PlanetTables<'static> = PlanetTables {
+    longitude: &[
+        [&neptune::L0[0], &neptune::L0[1], &neptune::L0[2]],
+        [&neptune::L1[0], &neptune::L1[1], &neptune::L1[2]],
+        [&neptune::L2[0], &neptune::L2[1], &neptune::L2[2]],
+        [&neptune::L3[0], &neptune::L3[1], &neptune::L3[2]],
+        [&neptune::L4[0], &neptune::L4[1], &neptune::L4[2]],
+        [&neptune::L5[0], &neptune::L5[1], &neptune::L5[2]],
+    ],
+    latitude: &[
+        [&neptune::B0[0], &neptune::B0[1], &neptune::B0[2]],
+        [&neptune::B1[0], &neptune::B1[1], &neptune::B1[2]],
+        [&neptune::B2[0], &neptune::B2[1], &neptune::B2[2]],
+        [&neptune::B3[0], &neptune::B3[1], &neptune::B3[2]],
+        [&neptune::B4[0], &neptune::B4[1], &neptune::B4[2]],
+        [&neptune::B5[0], &neptune::B5[1], &neptune::B5[2]],
+    ],
+    distance: &[
+        [&neptune::R0[0], &neptune::R0[1], &neptune::R0[2]],
+        [&neptune::R1[0], &neptune::R1[1], &neptune::R1[2]],
+        [&neptune::R2[0], &neptune::R2[1], &neptune::R2[2]],
+        [&neptune::R3[0], &neptune::R3[1], &neptune::R3[2]],
+    ],
+};
 
 /// Calculates VSOP87D solution for Mercury.
 ///
@@ -58,44 +288,9 @@ use std::f64::consts::PI;
 /// assert!(coordinates.latitude() > 0.1168184803 && coordinates.latitude() < 0.1168184805);
 /// assert!(coordinates.distance() > 0.32339057 && coordinates.distance() < 0.32339133);
 /// ```
+#[cfg(feature = "mercury")]
 pub fn mercury(jde: f64) -> SphericalCoordinates {
-    let t = calculate_t(jde);
-
-    let l0 = calculate_var(t, &mercury::L0[0], &mercury::L0[1], &mercury::L0[2]);
-    let l1 = calculate_var(t, &mercury::L1[0], &mercury::L1[1], &mercury::L1[2]);
-    let l2 = calculate_var(t, &mercury::L2[0], &mercury::L2[1], &mercury::L2[2]);
-    let l3 = calculate_var(t, &mercury::L3[0], &mercury::L3[1], &mercury::L3[2]);
-    let l4 = calculate_var(t, &mercury::L4[0], &mercury::L4[1], &mercury::L4[2]);
-    let l5 = calculate_var(t, &mercury::L5[0], &mercury::L5[1], &mercury::L5[2]);
-
-    let b0 = calculate_var(t, &mercury::B0[0], &mercury::B0[1], &mercury::B0[2]);
-    let b1 = calculate_var(t, &mercury::B1[0], &mercury::B1[1], &mercury::B1[2]);
-    let b2 = calculate_var(t, &mercury::B2[0], &mercury::B2[1], &mercury::B2[2]);
-    let b3 = calculate_var(t, &mercury::B3[0], &mercury::B3[1], &mercury::B3[2]);
-    let b4 = calculate_var(t, &mercury::B4[0], &mercury::B4[1], &mercury::B4[2]);
-    let b5 = calculate_var(t, &mercury::B5[0], &mercury::B5[1], &mercury::B5[2]);
-
-    let r0 = calculate_var(t, &mercury::R0[0], &mercury::R0[1], &mercury::R0[2]);
-    let r1 = calculate_var(t, &mercury::R1[0], &mercury::R1[1], &mercury::R1[2]);
-    let r2 = calculate_var(t, &mercury::R2[0], &mercury::R2[1], &mercury::R2[2]);
-    let r3 = calculate_var(t, &mercury::R3[0], &mercury::R3[1], &mercury::R3[2]);
-    let r4 = calculate_var(t, &mercury::R4[0], &mercury::R4[1], &mercury::R4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let b = b0 + b1 * t + b2 * t2 + b3 * t3 + b4 * t4 + b5 * t5;
-    let r = r0 + r1 * t + r2 * t2 + r3 * t3 + r4 * t4;
-
-    SphericalCoordinates {
-        lon: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        lat: b,
-        dist: r,
-    }
+    evaluate(&MERCURY, jde)
 }
 
 /// Calculates VSOP87D solution for Venus.
@@ -121,44 +316,9 @@ pub fn mercury(jde: f64) -> SphericalCoordinates {
 /// assert!(coordinates.latitude() > -0.0455979905 && coordinates.latitude() < -0.0455979903);
 /// assert!(coordinates.distance() > 0.72834037 && coordinates.distance() < 0.72834113);
 /// ```
+#[cfg(feature = "venus")]
 pub fn venus(jde: f64) -> SphericalCoordinates {
-    let t = calculate_t(jde);
-
-    let l0 = calculate_var(t, &venus::L0[0], &venus::L0[1], &venus::L0[2]);
-    let l1 = calculate_var(t, &venus::L1[0], &venus::L1[1], &venus::L1[2]);
-    let l2 = calculate_var(t, &venus::L2[0], &venus::L2[1], &venus::L2[2]);
-    let l3 = calculate_var(t, &venus::L3[0], &venus::L3[1], &venus::L3[2]);
-    let l4 = calculate_var(t, &venus::L4[0], &venus::L4[1], &venus::L4[2]);
-    let l5 = calculate_var(t, &venus::L5[0], &venus::L5[1], &venus::L5[2]);
-
-    let b0 = calculate_var(t, &venus::B0[0], &venus::B0[1], &venus::B0[2]);
-    let b1 = calculate_var(t, &venus::B1[0], &venus::B1[1], &venus::B1[2]);
-    let b2 = calculate_var(t, &venus::B2[0], &venus::B2[1], &venus::B2[2]);
-    let b3 = calculate_var(t, &venus::B3[0], &venus::B3[1], &venus::B3[2]);
-    let b4 = calculate_var(t, &venus::B4[0], &venus::B4[1], &venus::B4[2]);
-    let b5 = calculate_var(t, &venus::B5[0], &venus::B5[1], &venus::B5[2]);
-
-    let r0 = calculate_var(t, &venus::R0[0], &venus::R0[1], &venus::R0[2]);
-    let r1 = calculate_var(t, &venus::R1[0], &venus::R1[1], &venus::R1[2]);
-    let r2 = calculate_var(t, &venus::R2[0], &venus::R2[1], &venus::R2[2]);
-    let r3 = calculate_var(t, &venus::R3[0], &venus::R3[1], &venus::R3[2]);
-    let r4 = calculate_var(t, &venus::R4[0], &venus::R4[1], &venus::R4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let b = b0 + b1 * t + b2 * t2 + b3 * t3 + b4 * t4 + b5 * t5;
-    let r = r0 + r1 * t + r2 * t2 + r3 * t3 + r4 * t4;
-
-    SphericalCoordinates {
-        lon: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        lat: b,
-        dist: r,
-    }
+    evaluate(&VENUS, jde)
 }
 
 /// Calculates VSOP87D solution for Earth.
@@ -169,6 +329,10 @@ pub fn venus(jde: f64) -> SphericalCoordinates {
 /// are the spherical coordinates of the planet, with the Sun in the center and the ecliptic plane
 /// as reference `latitude = 0`.
 ///
+/// These are the coordinates of the center of the Earth, not of the barycenter of the Earth - Moon
+/// system, which is about 4,670 km away, in the direction of the Moon. See
+/// [`earth_moon_barycenter`](fn.earth_moon_barycenter.html).
+///
 /// # Example
 ///
 /// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
@@ -184,43 +348,44 @@ pub fn venus(jde: f64) -> SphericalCoordinates {
 /// assert!(coordinates.latitude() > -0.0000016360 && coordinates.latitude() < -0.0000016358);
 /// assert!(coordinates.distance() > 0.98312506 && coordinates.distance() < 0.98312582);
 /// ```
+#[cfg(feature = "earth")]
 pub fn earth(jde: f64) -> SphericalCoordinates {
-    let t = calculate_t(jde);
-
-    let l0 = calculate_var(t, &earth::L0[0], &earth::L0[1], &earth::L0[2]);
-    let l1 = calculate_var(t, &earth::L1[0], &earth::L1[1], &earth::L1[2]);
-    let l2 = calculate_var(t, &earth::L2[0], &earth::L2[1], &earth::L2[2]);
-    let l3 = calculate_var(t, &earth::L3[0], &earth::L3[1], &earth::L3[2]);
-    let l4 = calculate_var(t, &earth::L4[0], &earth::L4[1], &earth::L4[2]);
-    let l5 = calculate_var(t, &earth::L5[0], &earth::L5[1], &earth::L5[2]);
-
-    let b0 = calculate_var(t, &earth::B0[0], &earth::B0[1], &earth::B0[2]);
-    let b1 = calculate_var(t, &earth::B1[0], &earth::B1[1], &earth::B1[2]);
-    let b2 = calculate_var(t, &earth::B2[0], &earth::B2[1], &earth::B2[2]);
-    let b3 = calculate_var(t, &earth::B3[0], &earth::B3[1], &earth::B3[2]);
-    let b4 = calculate_var(t, &earth::B4[0], &earth::B4[1], &earth::B4[2]);
-
-    let r0 = calculate_var(t, &earth::R0[0], &earth::R0[1], &earth::R0[2]);
-    let r1 = calculate_var(t, &earth::R1[0], &earth::R1[1], &earth::R1[2]);
-    let r2 = calculate_var(t, &earth::R2[0], &earth::R2[1], &earth::R2[2]);
-    let r3 = calculate_var(t, &earth::R3[0], &earth::R3[1], &earth::R3[2]);
-    let r4 = calculate_var(t, &earth::R4[0], &earth::R4[1], &earth::R4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let b = b0 + b1 * t + b2 * t2 + b3 * t3 + b4 * t4;
-    let r = r0 + r1 * t + r2 * t2 + r3 * t3 + r4 * t4;
-
-    SphericalCoordinates {
-        lon: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        lat: b,
-        dist: r,
-    }
+    evaluate(&EARTH, jde)
+}
+
+/// Calculates the position of the Earth - Moon barycenter, in VSOP87D coordinates.
+///
+/// The *VSOP87D* solution for the [Earth](fn.earth.html) is the position of its center. This
+/// function shifts it towards the Moon by the
+/// [`earth_moon_offset()`](../fn.earth_moon_offset.html), to get the heliocentric position of the
+/// barycenter of the Earth - Moon system, for the equinox of the day. The parameters needed are
+/// the Julian Day (*JD*) for the given date, and the geocentric ecliptic coordinates of the Moon
+/// for the equinox of the day at that date, with the distance in *AU*. Only available with the
+/// `earth` feature.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{angular_separation, vsop87d, SphericalCoordinates};
+///
+/// // Geocentric position of the Moon on April 12th, 1992, from example 47.a of *Astronomical
+/// // Algorithms*, by Jean Meeus.
+/// let moon = SphericalCoordinates::new(
+///     133.162655_f64.to_radians(),
+///     -3.229126_f64.to_radians(),
+///     368_409.7 / 149_597_870.7,
+/// );
+///
+/// let earth = vsop87d::earth(2448724.5);
+/// let barycenter = vsop87d::earth_moon_barycenter(2448724.5, &moon);
+///
+/// // The barycenter is a few arcseconds away from the Earth, as seen from the Sun.
+/// let separation = angular_separation(&earth, &barycenter).to_degrees() * 3600.0;
+/// assert!(separation > 1.0 && separation < 6.5);
+/// ```
+#[cfg(feature = "earth")]
+pub fn earth_moon_barycenter(jde: f64, moon: &SphericalCoordinates) -> SphericalCoordinates {
+    (earth(jde).to_rectangular() + earth_moon_offset(moon)).to_spherical()
 }
 
 /// Calculates VSOP87D solution for Mars.
@@ -246,44 +411,9 @@ pub fn earth(jde: f64) -> SphericalCoordinates {
 /// assert!(coordinates.latitude() > 0.0066676097 && coordinates.latitude() < 0.0066676099);
 /// assert!(coordinates.distance() > 1.51236189 && coordinates.distance() < 1.51236265);
 /// ```
+#[cfg(feature = "mars")]
 pub fn mars(jde: f64) -> SphericalCoordinates {
-    let t = calculate_t(jde);
-
-    let l0 = calculate_var(t, &mars::L0[0], &mars::L0[1], &mars::L0[2]);
-    let l1 = calculate_var(t, &mars::L1[0], &mars::L1[1], &mars::L1[2]);
-    let l2 = calculate_var(t, &mars::L2[0], &mars::L2[1], &mars::L2[2]);
-    let l3 = calculate_var(t, &mars::L3[0], &mars::L3[1], &mars::L3[2]);
-    let l4 = calculate_var(t, &mars::L4[0], &mars::L4[1], &mars::L4[2]);
-    let l5 = calculate_var(t, &mars::L5[0], &mars::L5[1], &mars::L5[2]);
-
-    let b0 = calculate_var(t, &mars::B0[0], &mars::B0[1], &mars::B0[2]);
-    let b1 = calculate_var(t, &mars::B1[0], &mars::B1[1], &mars::B1[2]);
-    let b2 = calculate_var(t, &mars::B2[0], &mars::B2[1], &mars::B2[2]);
-    let b3 = calculate_var(t, &mars::B3[0], &mars::B3[1], &mars::B3[2]);
-    let b4 = calculate_var(t, &mars::B4[0], &mars::B4[1], &mars::B4[2]);
-    let b5 = calculate_var(t, &mars::B5[0], &mars::B5[1], &mars::B5[2]);
-
-    let r0 = calculate_var(t, &mars::R0[0], &mars::R0[1], &mars::R0[2]);
-    let r1 = calculate_var(t, &mars::R1[0], &mars::R1[1], &mars::R1[2]);
-    let r2 = calculate_var(t, &mars::R2[0], &mars::R2[1], &mars::R2[2]);
-    let r3 = calculate_var(t, &mars::R3[0], &mars::R3[1], &mars::R3[2]);
-    let r4 = calculate_var(t, &mars::R4[0], &mars::R4[1], &mars::R4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let b = b0 + b1 * t + b2 * t2 + b3 * t3 + b4 * t4 + b5 * t5;
-    let r = r0 + r1 * t + r2 * t2 + r3 * t3 + r4 * t4;
-
-    SphericalCoordinates {
-        lon: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        lat: b,
-        dist: r,
-    }
+    evaluate(&MARS, jde)
 }
 
 /// Calculates VSOP87D solution for Jupiter.
@@ -309,44 +439,9 @@ pub fn mars(jde: f64) -> SphericalCoordinates {
 /// assert!(coordinates.latitude() > 0.0231157946 && coordinates.latitude() < 0.0231157948);
 /// assert!(coordinates.distance() > 5.44915664 && coordinates.distance() < 5.44915740);
 /// ```
+#[cfg(feature = "jupiter")]
 pub fn jupiter(jde: f64) -> SphericalCoordinates {
-    let t = calculate_t(jde);
-
-    let l0 = calculate_var(t, &jupiter::L0[0], &jupiter::L0[1], &jupiter::L0[2]);
-    let l1 = calculate_var(t, &jupiter::L1[0], &jupiter::L1[1], &jupiter::L1[2]);
-    let l2 = calculate_var(t, &jupiter::L2[0], &jupiter::L2[1], &jupiter::L2[2]);
-    let l3 = calculate_var(t, &jupiter::L3[0], &jupiter::L3[1], &jupiter::L3[2]);
-    let l4 = calculate_var(t, &jupiter::L4[0], &jupiter::L4[1], &jupiter::L4[2]);
-    let l5 = calculate_var(t, &jupiter::L5[0], &jupiter::L5[1], &jupiter::L5[2]);
-
-    let b0 = calculate_var(t, &jupiter::B0[0], &jupiter::B0[1], &jupiter::B0[2]);
-    let b1 = calculate_var(t, &jupiter::B1[0], &jupiter::B1[1], &jupiter::B1[2]);
-    let b2 = calculate_var(t, &jupiter::B2[0], &jupiter::B2[1], &jupiter::B2[2]);
-    let b3 = calculate_var(t, &jupiter::B3[0], &jupiter::B3[1], &jupiter::B3[2]);
-    let b4 = calculate_var(t, &jupiter::B4[0], &jupiter::B4[1], &jupiter::B4[2]);
-    let b5 = calculate_var(t, &jupiter::B5[0], &jupiter::B5[1], &jupiter::B5[2]);
-
-    let r0 = calculate_var(t, &jupiter::R0[0], &jupiter::R0[1], &jupiter::R0[2]);
-    let r1 = calculate_var(t, &jupiter::R1[0], &jupiter::R1[1], &jupiter::R1[2]);
-    let r2 = calculate_var(t, &jupiter::R2[0], &jupiter::R2[1], &jupiter::R2[2]);
-    let r3 = calculate_var(t, &jupiter::R3[0], &jupiter::R3[1], &jupiter::R3[2]);
-    let r4 = calculate_var(t, &jupiter::R4[0], &jupiter::R4[1], &jupiter::R4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let b = b0 + b1 * t + b2 * t2 + b3 * t3 + b4 * t4 + b5 * t5;
-    let r = r0 + r1 * t + r2 * t2 + r3 * t3 + r4 * t4;
-
-    SphericalCoordinates {
-        lon: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        lat: b,
-        dist: r,
-    }
+    evaluate(&JUPITER, jde)
 }
 
 /// Calculates VSOP87D solution for Saturn.
@@ -372,44 +467,9 @@ pub fn jupiter(jde: f64) -> SphericalCoordinates {
 /// assert!(coordinates.latitude() > 0.0178533696 && coordinates.latitude() < 0.0178533698);
 /// assert!(coordinates.distance() > 9.18575957 && coordinates.distance() < 9.18576033);
 /// ```
+#[cfg(feature = "saturn")]
 pub fn saturn(jde: f64) -> SphericalCoordinates {
-    let t = calculate_t(jde);
-
-    let l0 = calculate_var(t, &saturn::L0[0], &saturn::L0[1], &saturn::L0[2]);
-    let l1 = calculate_var(t, &saturn::L1[0], &saturn::L1[1], &saturn::L1[2]);
-    let l2 = calculate_var(t, &saturn::L2[0], &saturn::L2[1], &saturn::L2[2]);
-    let l3 = calculate_var(t, &saturn::L3[0], &saturn::L3[1], &saturn::L3[2]);
-    let l4 = calculate_var(t, &saturn::L4[0], &saturn::L4[1], &saturn::L4[2]);
-    let l5 = calculate_var(t, &saturn::L5[0], &saturn::L5[1], &saturn::L5[2]);
-
-    let b0 = calculate_var(t, &saturn::B0[0], &saturn::B0[1], &saturn::B0[2]);
-    let b1 = calculate_var(t, &saturn::B1[0], &saturn::B1[1], &saturn::B1[2]);
-    let b2 = calculate_var(t, &saturn::B2[0], &saturn::B2[1], &saturn::B2[2]);
-    let b3 = calculate_var(t, &saturn::B3[0], &saturn::B3[1], &saturn::B3[2]);
-    let b4 = calculate_var(t, &saturn::B4[0], &saturn::B4[1], &saturn::B4[2]);
-    let b5 = calculate_var(t, &saturn::B5[0], &saturn::B5[1], &saturn::B5[2]);
-
-    let r0 = calculate_var(t, &saturn::R0[0], &saturn::R0[1], &saturn::R0[2]);
-    let r1 = calculate_var(t, &saturn::R1[0], &saturn::R1[1], &saturn::R1[2]);
-    let r2 = calculate_var(t, &saturn::R2[0], &saturn::R2[1], &saturn::R2[2]);
-    let r3 = calculate_var(t, &saturn::R3[0], &saturn::R3[1], &saturn::R3[2]);
-    let r4 = calculate_var(t, &saturn::R4[0], &saturn::R4[1], &saturn::R4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let b = b0 + b1 * t + b2 * t2 + b3 * t3 + b4 * t4 + b5 * t5;
-    let r = r0 + r1 * t + r2 * t2 + r3 * t3 + r4 * t4;
-
-    SphericalCoordinates {
-        lon: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        lat: b,
-        dist: r,
-    }
+    evaluate(&SATURN, jde)
 }
 
 /// Calculates VSOP87D solution for Uranus.
@@ -435,42 +495,9 @@ pub fn saturn(jde: f64) -> SphericalCoordinates {
 /// assert!(coordinates.latitude() > 0.0088045917 && coordinates.latitude() < 0.0088045919);
 /// assert!(coordinates.distance() > 18.58414975 && coordinates.distance() < 18.58415051);
 /// ```
+#[cfg(feature = "uranus")]
 pub fn uranus(jde: f64) -> SphericalCoordinates {
-    let t = calculate_t(jde);
-
-    let l0 = calculate_var(t, &uranus::L0[0], &uranus::L0[1], &uranus::L0[2]);
-    let l1 = calculate_var(t, &uranus::L1[0], &uranus::L1[1], &uranus::L1[2]);
-    let l2 = calculate_var(t, &uranus::L2[0], &uranus::L2[1], &uranus::L2[2]);
-    let l3 = calculate_var(t, &uranus::L3[0], &uranus::L3[1], &uranus::L3[2]);
-    let l4 = calculate_var(t, &uranus::L4[0], &uranus::L4[1], &uranus::L4[2]);
-    let l5 = calculate_var(t, &uranus::L5[0], &uranus::L5[1], &uranus::L5[2]);
-
-    let b0 = calculate_var(t, &uranus::B0[0], &uranus::B0[1], &uranus::B0[2]);
-    let b1 = calculate_var(t, &uranus::B1[0], &uranus::B1[1], &uranus::B1[2]);
-    let b2 = calculate_var(t, &uranus::B2[0], &uranus::B2[1], &uranus::B2[2]);
-    let b3 = calculate_var(t, &uranus::B3[0], &uranus::B3[1], &uranus::B3[2]);
-    let b4 = calculate_var(t, &uranus::B4[0], &uranus::B4[1], &uranus::B4[2]);
-
-    let r0 = calculate_var(t, &uranus::R0[0], &uranus::R0[1], &uranus::R0[2]);
-    let r1 = calculate_var(t, &uranus::R1[0], &uranus::R1[1], &uranus::R1[2]);
-    let r2 = calculate_var(t, &uranus::R2[0], &uranus::R2[1], &uranus::R2[2]);
-    let r3 = calculate_var(t, &uranus::R3[0], &uranus::R3[1], &uranus::R3[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let b = b0 + b1 * t + b2 * t2 + b3 * t3 + b4 * t4;
-    let r = r0 + r1 * t + r2 * t2 + r3 * t3;
-
-    SphericalCoordinates {
-        lon: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        lat: b,
-        dist: r,
-    }
+    evaluate(&URANUS, jde)
 }
 
 /// Calculates VSOP87D solution for Neptune.
@@ -496,41 +523,694 @@ pub fn uranus(jde: f64) -> SphericalCoordinates {
 /// assert!(coordinates.latitude() > 0.0027498092 && coordinates.latitude() < 0.0027498094);
 /// assert!(coordinates.distance() > 30.06536898 && coordinates.distance() < 30.06536974);
 /// ```
+#[cfg(feature = "neptune")]
 pub fn neptune(jde: f64) -> SphericalCoordinates {
-    let t = calculate_t(jde);
-
-    let l0 = calculate_var(t, &neptune::L0[0], &neptune::L0[1], &neptune::L0[2]);
-    let l1 = calculate_var(t, &neptune::L1[0], &neptune::L1[1], &neptune::L1[2]);
-    let l2 = calculate_var(t, &neptune::L2[0], &neptune::L2[1], &neptune::L2[2]);
-    let l3 = calculate_var(t, &neptune::L3[0], &neptune::L3[1], &neptune::L3[2]);
-    let l4 = calculate_var(t, &neptune::L4[0], &neptune::L4[1], &neptune::L4[2]);
-    let l5 = calculate_var(t, &neptune::L5[0], &neptune::L5[1], &neptune::L5[2]);
-
-    let b0 = calculate_var(t, &neptune::B0[0], &neptune::B0[1], &neptune::B0[2]);
-    let b1 = calculate_var(t, &neptune::B1[0], &neptune::B1[1], &neptune::B1[2]);
-    let b2 = calculate_var(t, &neptune::B2[0], &neptune::B2[1], &neptune::B2[2]);
-    let b3 = calculate_var(t, &neptune::B3[0], &neptune::B3[1], &neptune::B3[2]);
-    let b4 = calculate_var(t, &neptune::B4[0], &neptune::B4[1], &neptune::B4[2]);
-    let b5 = calculate_var(t, &neptune::B5[0], &neptune::B5[1], &neptune::B5[2]);
-
-    let r0 = calculate_var(t, &neptune::R0[0], &neptune::R0[1], &neptune::R0[2]);
-    let r1 = calculate_var(t, &neptune::R1[0], &neptune::R1[1], &neptune::R1[2]);
-    let r2 = calculate_var(t, &neptune::R2[0], &neptune::R2[1], &neptune::R2[2]);
-    let r3 = calculate_var(t, &neptune::R3[0], &neptune::R3[1], &neptune::R3[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let b = b0 + b1 * t + b2 * t2 + b3 * t3 + b4 * t4 + b5 * t5;
-    let r = r0 + r1 * t + r2 * t2 + r3 * t3;
-
-    SphericalCoordinates {
-        lon: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        lat: b,
-        dist: r,
+    evaluate(&NEPTUNE, jde)
+}
+
+/// Calculates VSOP87D solution for the given planet.
+///
+/// This function calculates the VSOP87D solution (heliocentric ecliptic spherical coordinates for
+/// the equinox of the day) for the given planet, by calling the function of that planet in this
+/// module. It is useful when the planet is only known at runtime, or to iterate over all the
+/// planets.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87d, Planet};
+///
+/// assert_eq!(vsop87d::planet(Planet::Mars, 2451545.0), vsop87d::mars(2451545.0));
+/// ```
+pub fn planet(planet: Planet, jde: f64) -> SphericalCoordinates {
+    match planet {
+        #[cfg(feature = "mercury")]
+        Planet::Mercury => mercury(jde),
+        #[cfg(feature = "venus")]
+        Planet::Venus => venus(jde),
+        #[cfg(feature = "earth")]
+        Planet::Earth => earth(jde),
+        #[cfg(feature = "mars")]
+        Planet::Mars => mars(jde),
+        #[cfg(feature = "jupiter")]
+        Planet::Jupiter => jupiter(jde),
+        #[cfg(feature = "saturn")]
+        Planet::Saturn => saturn(jde),
+        #[cfg(feature = "uranus")]
+        Planet::Uranus => uranus(jde),
+        #[cfg(feature = "neptune")]
+        Planet::Neptune => neptune(jde),
+        #[allow(unreachable_patterns)]
+        _ => planet.not_enabled(),
+    }
+}
+
+/// Calculates VSOP87D solution for all the planets at once.
+///
+/// The coordinates are returned in the order of
+/// [`Planet::ALL`](../enum.Planet.html#associatedconstant.ALL), by distance to the Sun: Mercury,
+/// Venus, Earth, Mars, Jupiter, Saturn, Uranus and Neptune. The time variable is computed once for
+/// all of them, with a [`TimeContext`](../struct.TimeContext.html). With the `rayon` feature, each
+/// planet is computed in a thread of the global [*rayon*](https://docs.rs/rayon) thread pool, which
+/// makes it suitable to refresh a whole planetarium every frame. Without it, the planets are
+/// computed one after the other, and the coordinates are the same in both cases. Only available
+/// with the `all_planets` feature.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87d, Planet};
+///
+/// let planets = vsop87d::all_planets(2451545.0);
+///
+/// assert_eq!(planets[Planet::Mars as usize], vsop87d::mars(2451545.0));
+/// ```
+#[cfg(feature = "all_planets")]
+pub fn all_planets(jde: f64) -> [SphericalCoordinates; 8] {
+    let context = TimeContext::new(jde);
+
+    super::all_planets(|body| context.planet(body))
+}
+
+/// Calculates VSOP87D solution for the given planet, checking the date.
+///
+/// This is the same as [`planet`](fn.planet.html), but it returns
+/// [`Vsop87Error::OutOfRange`](../enum.Vsop87Error.html#variant.OutOfRange) if the Julian Day
+/// (*JD*) is outside of the [validity span](../enum.Planet.html#method.valid_range) of the planet,
+/// instead of silently returning imprecise coordinates. A NaN Julian Day is also rejected, and so
+/// is a planet whose cargo feature is not enabled, instead of panicking.
+///
+/// # Errors
+///
+/// Returns [`Vsop87Error::NotEnabled`](../enum.Vsop87Error.html#variant.NotEnabled) if the
+/// feature of the planet is not enabled, or
+/// [`Vsop87Error::OutOfRange`](../enum.Vsop87Error.html#variant.OutOfRange) if `jde` is outside
+/// of the span given by [`Planet::valid_range()`](../enum.Planet.html#method.valid_range), such
+/// as 990545.0 to 3912545.0 (J2000.0 ± 4,000 Julian years) for Mars.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87d, Planet, Vsop87Error};
+///
+/// assert!(vsop87d::try_planet(Planet::Mars, 2451545.0).is_ok());
+///
+/// let error = vsop87d::try_planet(Planet::Mars, 5000000.0).unwrap_err();
+/// assert!(matches!(error, Vsop87Error::OutOfRange { .. }));
+/// ```
+pub fn try_planet(planet: Planet, jde: f64) -> Result<SphericalCoordinates, Vsop87Error> {
+    planet.check_enabled(jde)?;
+    planet.check_range(jde)?;
+
+    Ok(self::planet(planet, jde))
+}
+
+/// Calculates VSOP87D solution for the given planet, in single precision.
+///
+/// The solution is computed in double precision, and the resulting coordinates are then converted
+/// to `f32`, so that the large terms of the series do not lose precision. The angles are precise to
+/// about 2·10⁻⁷ radians (0.05"), and the distance has a relative precision of about 6·10⁻⁸.
+///
+/// The Julian Day (*JD*) is still an `f64`: an `f32` can only represent Julian Days around the
+/// current epoch with a resolution of a quarter of a day.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87d, Planet};
+///
+/// let coordinates = vsop87d::planet_f32(Planet::Earth, 2451545.0);
+///
+/// assert!(coordinates.distance() > 0.9833 && coordinates.distance() < 0.9834);
+/// ```
+pub fn planet_f32(planet: Planet, jde: f64) -> SphericalCoordinates<f32> {
+    self::planet(planet, jde).to_f32()
+}
+
+/// Calculates the VSOP87D position of the given planet, and its rates of change.
+///
+/// This returns the same coordinates as [`planet`](fn.planet.html), and the derivatives of the
+/// longitude and the latitude, in radians per day, and of the distance, in *AU* per day, as given
+/// by [`evaluate_state()`](../fn.evaluate_state.html). They are computed in a single pass from
+/// the analytic derivative of the series, without finite differences. The parameter needed is the
+/// Julian Day (*JD*) for the given date.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87d, Planet};
+///
+/// let (position, rates) = vsop87d::state(Planet::Mars, 2451545.0);
+///
+/// assert_eq!(position, vsop87d::mars(2451545.0));
+/// // Close to its perihelion, Mars moves by about 0.63° per day along its orbit.
+/// assert!((rates.longitude().to_degrees() - 0.63).abs() < 0.01);
+/// ```
+pub fn state(planet: Planet, jde: f64) -> (SphericalCoordinates, SphericalCoordinates) {
+    evaluate_state(tables(planet), jde)
+}
+
+/// Calculates VSOP87D solution for the given planet, skipping the terms smaller than `epsilon`.
+///
+/// This trades precision for speed, which can be useful for real-time applications that do not
+/// need the full precision of the solution. The terms whose amplitude is smaller than `epsilon`
+/// (in radians for the longitude and latitude, and in *AU* for the distance) are not computed. See
+/// [`evaluate_truncated()`](../fn.evaluate_truncated.html) for details.
+///
+/// For the 8 planets, over 1,000 years before and after J2000, the maximum errors are:
+///
+/// | `epsilon` | Terms kept     | Angles | Distance (relative) | Speed-up |
+/// |-----------|----------------|--------|---------------------|----------|
+/// | `1e-6`    | 1,996 / 31,498 | 3.4"   | 1.7·10⁻⁵            | ~20×     |
+/// | `1e-7`    | 5,269 / 31,498 | 0.46"  | 3.1·10⁻⁶            | ~10×     |
+/// | `1e-8`    | 13,327 / 31,498| 0.07"  | 4.7·10⁻⁷            | ~3.5×    |
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87d, Planet};
+///
+/// let full = vsop87d::planet(Planet::Mars, 2451545.0);
+/// let truncated = vsop87d::planet_truncated(Planet::Mars, 2451545.0, 1e-7);
+///
+/// assert!((full.longitude() - truncated.longitude()).abs() < 1e-5);
+/// assert!((full.latitude() - truncated.latitude()).abs() < 1e-5);
+/// ```
+pub fn planet_truncated(planet: Planet, jde: f64, epsilon: f64) -> SphericalCoordinates {
+    evaluate_truncated(tables(planet), jde, epsilon)
+}
+
+/// Calculates VSOP87D solution for the given planet on many dates.
+///
+/// This function calculates the VSOP87D solution of the given planet for each Julian Day (*JD*) in
+/// `jdes`, and returns the coordinates in a vector, in the same order. The coefficient tables of
+/// the planet are looked up once for the whole batch, and the output vector is allocated once.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87d, Planet};
+///
+/// let jdes = [2451545.0, 2451546.0, 2451547.0];
+/// let track = vsop87d::planet_many(Planet::Earth, &jdes);
+///
+/// assert_eq!(track.len(), 3);
+/// assert_eq!(track[1], vsop87d::earth(2451546.0));
+/// ```
+#[cfg(not(feature = "no_std"))]
+pub fn planet_many(planet: Planet, jdes: &[f64]) -> Vec<SphericalCoordinates> {
+    let tables = tables(planet);
+    jdes.iter().map(|&jde| evaluate(tables, jde)).collect()
+}
+
+/// Calculates VSOP87D solution for the given planet on many dates, in parallel.
+///
+/// With the `rayon` feature, the dates are split among the threads of the global
+/// [*rayon*](https://docs.rs/rayon) thread pool. Without it, this is the same as
+/// [`planet_many`](fn.planet_many.html). The coordinates are returned in the same order as the
+/// dates, and are the same in both cases. Only available with the standard library.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87d, Planet};
+///
+/// let jdes: Vec<f64> = (0..1_000).map(|day| 2451545.0 + f64::from(day)).collect();
+/// let track = vsop87d::planet_many_par(Planet::Mars, &jdes);
+///
+/// assert_eq!(track.len(), 1_000);
+/// assert_eq!(track[500], vsop87d::mars(2452045.0));
+/// ```
+#[cfg(not(feature = "no_std"))]
+pub fn planet_many_par(planet: Planet, jdes: &[f64]) -> Vec<SphericalCoordinates> {
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+
+        let tables = tables(planet);
+        jdes.par_iter().map(|&jde| evaluate(tables, jde)).collect()
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    {
+        planet_many(planet, jdes)
+    }
+}
+
+/// Calculates VSOP87D solution for the given planet on many dates, into a caller-provided buffer.
+///
+/// This is the allocation-free version of [`planet_many`](fn.planet_many.html), also available
+/// with the `no_std` feature. The coordinates for `jdes[i]` are written to `output[i]`, so the
+/// buffer can live on the stack or in a `static`, and be reused for every batch on targets without
+/// an allocator. Every element of `output` is overwritten, so its initial values do not matter.
+///
+/// # Panics
+///
+/// Panics if `jdes` and `output` have different lengths, before writing anything. To compute a
+/// batch shorter than the buffer, pass a sub-slice of it, such as `&mut output[..jdes.len()]`.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87d, Planet};
+///
+/// let jdes = [2451545.0, 2451546.0];
+/// let mut output = [vsop87d::mars(0.0); 2];
+/// vsop87d::planet_into(Planet::Mars, &jdes, &mut output);
+///
+/// assert_eq!(output[0], vsop87d::mars(2451545.0));
+/// assert_eq!(output[1], vsop87d::mars(2451546.0));
+/// ```
+pub fn planet_into(planet: Planet, jdes: &[f64], output: &mut [SphericalCoordinates]) {
+    assert_eq!(
+        jdes.len(),
+        output.len(),
+        "the input dates and the output buffer must have the same length"
+    );
+
+    let tables = tables(planet);
+    for (coordinates, &jde) in output.iter_mut().zip(jdes) {
+        *coordinates = evaluate(tables, jde);
+    }
+}
+
+/// Iterator over the VSOP87D positions of a planet at evenly spaced dates.
+///
+/// It yields `(jde, coordinates)` pairs, computing each position when it is requested. It is
+/// created with the [`ephemeris`](fn.ephemeris.html) and
+/// [`ephemeris_range`](fn.ephemeris_range.html) functions.
+#[derive(Debug, Clone, Copy)]
+pub struct Ephemeris {
+    tables: &'static PlanetTables<'static>,
+    start: f64,
+    step: f64,
+    end: Option<f64>,
+    steps: f64,
+}
+
+impl Ephemeris {
+    /// Creates an iterator over the VSOP87D positions of a planet between two dates.
+    ///
+    /// This is the same as [`ephemeris_range`](fn.ephemeris_range.html): the iterator yields the
+    /// Julian Day (*JD*) and the position of the planet every `step_days` days, from `start_jde`
+    /// to the last date that is not past `end_jde`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vsop87::{vsop87d, Planet};
+    ///
+    /// let mut ephemeris = vsop87d::Ephemeris::new(Planet::Saturn, 2451545.0, 2451910.0, 7.0);
+    ///
+    /// assert_eq!(ephemeris.next(), Some((2451545.0, vsop87d::saturn(2451545.0))));
+    /// assert_eq!(ephemeris.count(), 52);
+    /// ```
+    pub fn new(planet: Planet, start_jde: f64, end_jde: f64, step_days: f64) -> Self {
+        ephemeris_range(planet, start_jde, end_jde, step_days)
+    }
+}
+
+// The iterator is small and cheap to copy, and `Copy` is required for all the public types.
+#[allow(clippy::copy_iterator)]
+impl Iterator for Ephemeris {
+    type Item = (f64, SphericalCoordinates);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // The date is computed from the start, so that rounding errors do not accumulate.
+        let jde = self.start + self.steps * self.step;
+        if !jde.is_finite() || self.step == 0_f64 {
+            return None;
+        }
+        if let Some(end) = self.end {
+            if !end.is_finite() || (jde - end) * self.step > 0_f64 {
+                return None;
+            }
+        }
+
+        self.steps += 1_f64;
+        Some((jde, evaluate(self.tables, jde)))
+    }
+}
+
+/// Creates an endless iterator over the VSOP87D positions of a planet.
+///
+/// The iterator yields the Julian Day (*JD*) and the position of the planet, starting at
+/// `start_jde` and advancing `step_days` days each time. The step can be negative to go back in
+/// time. The positions are computed lazily, so the iterator can be used for arbitrarily long
+/// spans of time without allocating memory.
+///
+/// The iterator is empty if `start_jde` or `step_days` is not a finite number, or if the step is
+/// zero, as it would otherwise never advance.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87d, Planet};
+///
+/// for (jde, coordinates) in vsop87d::ephemeris(Planet::Mars, 2451545.0, 10.0).take(3) {
+///     assert_eq!(coordinates, vsop87d::mars(jde));
+/// }
+/// ```
+pub fn ephemeris(planet: Planet, start_jde: f64, step_days: f64) -> Ephemeris {
+    Ephemeris {
+        tables: tables(planet),
+        start: start_jde,
+        step: step_days,
+        end: None,
+        steps: 0_f64,
+    }
+}
+
+/// Creates an iterator over the VSOP87D positions of a planet between two dates.
+///
+/// This is the same as [`ephemeris`](fn.ephemeris.html), but the iterator stops after the last
+/// date that is not past `end_jde`. Both ends are included if the span is a multiple of the step.
+/// With a negative step, `end_jde` must be before `start_jde`.
+///
+/// The iterator is empty if any of the dates or the step is not a finite number, if the step is
+/// zero, or if it goes away from `end_jde`, the same cases rejected by `export::write_csv`.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87d, Planet};
+///
+/// let positions: Vec<_> = vsop87d::ephemeris_range(Planet::Venus, 2451545.0, 2451555.0, 2.5)
+///     .collect();
+///
+/// assert_eq!(positions.len(), 5);
+/// assert_eq!(positions[4].0, 2451555.0);
+/// ```
+pub fn ephemeris_range(planet: Planet, start_jde: f64, end_jde: f64, step_days: f64) -> Ephemeris {
+    Ephemeris {
+        end: Some(end_jde),
+        ..ephemeris(planet, start_jde, step_days)
     }
 }
+
+/// Gets the VSOP87D coefficient tables of the given planet.
+pub(crate) fn tables(planet: Planet) -> &'static PlanetTables<'static> {
+    match planet {
+        #[cfg(feature = "mercury")]
+        Planet::Mercury => &MERCURY,
+        #[cfg(feature = "venus")]
+        Planet::Venus => &VENUS,
+        #[cfg(feature = "earth")]
+        Planet::Earth => &EARTH,
+        #[cfg(feature = "mars")]
+        Planet::Mars => &MARS,
+        #[cfg(feature = "jupiter")]
+        Planet::Jupiter => &JUPITER,
+        #[cfg(feature = "saturn")]
+        Planet::Saturn => &SATURN,
+        #[cfg(feature = "uranus")]
+        Planet::Uranus => &URANUS,
+        #[cfg(feature = "neptune")]
+        Planet::Neptune => &NEPTUNE,
+        #[allow(unreachable_patterns)]
+        _ => planet.not_enabled(),
+    }
+}
+
+/// Julian Day of the reference positions of the tables, December 31st, 1099 (*t* = −0.9).
+///
+/// It is far enough from J2000.0 for every power of *t* to contribute to the result.
+const REFERENCE_JDE: f64 = 2_122_820.0;
+
+/// Largest difference allowed with the reference positions, in radians or *AU*.
+///
+/// The positions computed with the standard library and with `libm` differ by up to 5·10⁻¹¹.
+const REFERENCE_TOLERANCE: f64 = 1e-9;
+
+/// Reference positions of the planets at [`REFERENCE_JDE`], as `[L, B, R]`.
+static REFERENCE_POSITIONS: &[(Planet, [f64; 3])] = &[
+    #[cfg(feature = "mercury")]
+    (
+        Planet::Mercury,
+        [
+            5.012_839_776_406_359,
+            -0.114_327_580_783_207_2,
+            0.435_206_323_731_461,
+        ],
+    ),
+    #[cfg(feature = "venus")]
+    (
+        Planet::Venus,
+        [
+            3.314_539_929_509_045_6,
+            0.050_501_605_252_590_24,
+            0.721_581_978_543_226_6,
+        ],
+    ),
+    #[cfg(feature = "earth")]
+    (
+        Planet::Earth,
+        [
+            1.636_719_362_319_212_2,
+            -3.129_157_612_663_597_4e-6,
+            0.983_033_181_684_093_9,
+        ],
+    ),
+    #[cfg(feature = "mars")]
+    (
+        Planet::Mars,
+        [
+            2.736_710_434_419_095,
+            0.029_552_271_923_718_162,
+            1.657_100_229_733_569_7,
+        ],
+    ),
+    #[cfg(feature = "jupiter")]
+    (
+        Planet::Jupiter,
+        [
+            1.269_506_654_629_509_5,
+            -0.007_533_573_999_799_246,
+            5.119_358_816_527_165,
+        ],
+    ),
+    #[cfg(feature = "saturn")]
+    (
+        Planet::Saturn,
+        [
+            3.557_010_806_871_183,
+            0.043_537_113_880_927_56,
+            9.866_993_583_371_736,
+        ],
+    ),
+    #[cfg(feature = "uranus")]
+    (
+        Planet::Uranus,
+        [
+            0.700_722_622_432_465_4,
+            -0.006_561_061_138_682_47,
+            19.561_207_778_734_197,
+        ],
+    ),
+    #[cfg(feature = "neptune")]
+    (
+        Planet::Neptune,
+        [
+            2.212_498_826_651_259_5,
+            0.002_749_809_277_059_874,
+            30.065_369_322_627_404,
+        ],
+    ),
+];
+
+/// Verifies that the VSOP87D coefficient tables of every planet are intact.
+///
+/// Each planet is evaluated at a reference date, and the result is compared with a position
+/// stored separately from the tables. This detects the corruption of the tables in memory, for
+/// example by hardware faults in long-running embedded devices, and can be called at startup by
+/// applications that need to trust the dataset. Only the planets enabled by the features are
+/// checked.
+///
+/// The positions must match within 10⁻⁹ radians or *AU*, to allow for the small differences of the
+/// trigonometric functions between platforms, so a change in the last digits of the smallest terms
+/// can go unnoticed.
+///
+/// # Errors
+///
+/// Returns a [`TableError`](../struct.TableError.html) with the first planet and variable whose
+/// series do not give the reference value.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::vsop87d;
+///
+/// assert!(vsop87d::verify_tables().is_ok());
+/// ```
+pub fn verify_tables() -> Result<(), TableError> {
+    REFERENCE_POSITIONS
+        .iter()
+        .try_for_each(|&(planet, _)| verify_planet_tables(planet, tables(planet)))
+}
+
+/// Verifies the given coefficient tables against the reference position of the planet.
+///
+/// This is the check of [`verify_tables()`](fn.verify_tables.html) for a single planet, and can be
+/// used with a copy of the VSOP87D tables, for example one loaded into another memory region.
+///
+/// # Errors
+///
+/// Returns a [`TableError`](../struct.TableError.html) with the first variable whose series do not
+/// give the reference value.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87d, Planet};
+///
+/// assert!(vsop87d::verify_planet_tables(Planet::Mars, &vsop87d::MARS).is_ok());
+/// assert!(vsop87d::verify_planet_tables(Planet::Mars, &vsop87d::VENUS).is_err());
+/// ```
+pub fn verify_planet_tables(planet: Planet, tables: &PlanetTables<'_>) -> Result<(), TableError> {
+    let coordinates = evaluate(tables, REFERENCE_JDE);
+
+    REFERENCE_POSITIONS
+        .iter()
+        .filter(|&&(reference, _)| reference == planet)
+        .try_for_each(|&(_, [l, b, r])| {
+            let reference = SphericalCoordinates::new(l, b, r);
+            [Variable::Longitude, Variable::Latitude, Variable::Distance]
+                .iter()
+                .find(|&&variable| {
+                    (coordinates[variable] - reference[variable]).abs() > REFERENCE_TOLERANCE
+                })
+                .map_or(Ok(()), |&variable| Err(TableError { planet, variable }))
+        })
+}
+
+/// Finds the next time a planet crosses the ecliptic plane.
+///
+/// This function searches forward from the given Julian Day (*JD*) for the first time the
+/// heliocentric latitude of the planet passes through zero, going from south to north if
+/// `ascending` is `true` (the ascending node), or from north to south otherwise (the descending
+/// node). It returns the Julian Day of the crossing, that can then be used to get the
+/// heliocentric longitude of the node.
+///
+/// The search steps through the orbit until it brackets the crossing, and then bisects the
+/// interval down to a precision of about a millisecond. Every planet crosses each of its nodes
+/// once per revolution, so the search is limited to two sidereal periods, and `None` is returned
+/// if no crossing is found in that span (for example, if `start_jde` is not finite).
+///
+/// # Example
+///
+/// Here we calculate when Mars crossed its ascending node after January 1st, 2000, and check that
+/// it is on the ecliptic plane at that moment.
+///
+/// ```
+/// use vsop87::{vsop87d, Planet};
+///
+/// let jde = vsop87d::find_node_crossing(Planet::Mars, 2451545.0, true).unwrap();
+/// let coordinates = vsop87d::mars(jde);
+///
+/// assert!(jde > 2451545.0 && jde < 2451545.0 + 687.0);
+/// assert!(coordinates.latitude().abs() < 1e-9);
+///
+/// assert_eq!(vsop87d::find_node_crossing(Planet::Mars, f64::NAN, true), None);
+/// ```
+pub fn find_node_crossing(body: Planet, start_jde: f64, ascending: bool) -> Option<f64> {
+    let tables = tables(body);
+    let max_span = 2_f64 * body.sidereal_period();
+    let step = body.sidereal_period() / 64_f64;
+    let crosses = |from: f64, to: f64| {
+        if ascending {
+            from < 0_f64 && to >= 0_f64
+        } else {
+            from > 0_f64 && to <= 0_f64
+        }
+    };
+
+    let mut start = start_jde;
+    let mut start_lat = evaluate(tables, start).latitude();
+    while start - start_jde < max_span {
+        let mut end = start + step;
+        let end_lat = evaluate(tables, end).latitude();
+
+        if crosses(start_lat, end_lat) {
+            while end - start > 1e-8 {
+                let middle = start + (end - start) / 2_f64;
+                let middle_lat = evaluate(tables, middle).latitude();
+
+                if crosses(start_lat, middle_lat) {
+                    end = middle;
+                } else {
+                    start = middle;
+                    start_lat = middle_lat;
+                }
+            }
+
+            return Some(start + (end - start) / 2_f64);
+        }
+
+        start = end;
+        start_lat = end_lat;
+    }
+
+    None
+}
+
+/// Calculates the window of dates around the given one in which a planet is observable.
+///
+/// A planet is considered observable while its elongation (the angle between the Sun and the
+/// planet, as seen from the Earth) is at least `min_elongation_deg` degrees. This function scans
+/// outward from `near_jde`, in steps of one day, until the elongation drops below that threshold
+/// on both sides, and then refines both ends of the window. It returns the Julian Days of the
+/// start and the end of the window.
+///
+/// If the planet is not observable at `near_jde` (or if the body is the Earth itself), the
+/// returned window is empty, `(near_jde, near_jde)`. The scan is limited to 1,000 days on each
+/// side, which covers the synodic period of every planet, so a planet that is always observable
+/// with the given threshold gets a window 2,000 days long. Only available with the `earth` feature.
+///
+/// # Example
+///
+/// Venus reached its greatest eastern elongation (46°) in March 24th, 2020. Here we check when
+/// it was more than 20° away from the Sun around that date.
+///
+/// ```
+/// use vsop87::{vsop87d, Planet};
+///
+/// let (start, end) = vsop87d::visibility_window(Planet::Venus, 2458932.5, 20.0);
+///
+/// assert!(start < 2458932.5 && end > 2458932.5);
+/// assert!(end - start > 200.0 && end - start < 300.0);
+/// ```
+#[cfg(feature = "earth")]
+pub fn visibility_window(body: Planet, near_jde: f64, min_elongation_deg: f64) -> (f64, f64) {
+    /// Maximum number of days to scan on each side of the given date.
+    const MAX_DAYS: u16 = 1_000;
+
+    let min_elongation = min_elongation_deg.to_radians();
+    let observable = |jde: f64| geocentric::elongation(body, jde) >= min_elongation;
+
+    if !observable(near_jde) {
+        return (near_jde, near_jde);
+    }
+
+    let find_edge = |direction: f64| {
+        let mut inside = near_jde;
+        for day in 1..=MAX_DAYS {
+            let jde = near_jde + direction * f64::from(day);
+            if !observable(jde) {
+                let mut outside = jde;
+                while (outside - inside).abs() > 1e-6 {
+                    let middle = inside + (outside - inside) / 2_f64;
+                    if observable(middle) {
+                        inside = middle;
+                    } else {
+                        outside = middle;
+                    }
+                }
+                return inside;
+            }
+            inside = jde;
+        }
+        inside
+    };
+
+    (find_edge(-1_f64), find_edge(1_f64))
+}
@@ -21,17 +21,28 @@
 //! assert!(coordinates.y > -0.0027881716 && coordinates.y < -0.0027881714);
 //! assert!(coordinates.z > 0.0002041 && coordinates.z < 0.0002081);
 
+#[cfg(feature = "earth")]
 mod earth;
+#[cfg(feature = "jupiter")]
 mod jupiter;
+#[cfg(feature = "mars")]
 mod mars;
+#[cfg(feature = "mercury")]
 mod mercury;
+#[cfg(feature = "neptune")]
 mod neptune;
+#[cfg(feature = "saturn")]
 mod saturn;
 mod sun;
+#[cfg(feature = "uranus")]
 mod uranus;
+#[cfg(feature = "venus")]
 mod venus;
 
-use super::{calculate_t, calculate_var, RectangularCoordinates};
+use super::{
+    barycentric_to_heliocentric, calculate_t, calculate_var, rectangular, rectangular_state,
+    rectangular_truncated, Planet, RectangularCoordinates, Series,
+};
 
 /// Calculates VSOP87E solution for the Sun.
 ///
@@ -79,15 +90,9 @@ pub fn sun(jde: f64) -> RectangularCoordinates {
     let z3 = calculate_var(t, &sun::Z3[0], &sun::Z3[1], &sun::Z3[2]);
     let z4 = calculate_var(t, &sun::Z4[0], &sun::Z4[1], &sun::Z4[2]);
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4 + x5 * t5;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4 + y5 * t5;
-    let z = z0 + z1 * t + z2 * t2 + z3 * t3 + z4 * t4;
+    let x = ((((x5 * t + x4) * t + x3) * t + x2) * t + x1) * t + x0;
+    let y = ((((y5 * t + y4) * t + y3) * t + y2) * t + y1) * t + y0;
+    let z = (((z4 * t + z3) * t + z2) * t + z1) * t + z0;
 
     RectangularCoordinates { x, y, z }
 }
@@ -115,6 +120,7 @@ pub fn sun(jde: f64) -> RectangularCoordinates {
 /// assert!(coordinates.y > -0.1438666202 && coordinates.y < -0.1438666200);
 /// assert!(coordinates.z > 0.0235142 && coordinates.z < 0.0235182);
 /// ```
+#[cfg(feature = "mercury")]
 pub fn mercury(jde: f64) -> RectangularCoordinates {
     let t = calculate_t(jde);
 
@@ -138,15 +144,9 @@ pub fn mercury(jde: f64) -> RectangularCoordinates {
     let z3 = calculate_var(t, &mercury::Z3[0], &mercury::Z3[1], &mercury::Z3[2]);
     let z4 = calculate_var(t, &mercury::Z4[0], &mercury::Z4[1], &mercury::Z4[2]);
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4 + x5 * t5;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4 + y5 * t5;
-    let z = z0 + z1 * t + z2 * t2 + z3 * t3 + z4 * t4;
+    let x = ((((x5 * t + x4) * t + x3) * t + x2) * t + x1) * t + x0;
+    let y = ((((y5 * t + y4) * t + y3) * t + y2) * t + y1) * t + y0;
+    let z = (((z4 * t + z3) * t + z2) * t + z1) * t + z0;
 
     RectangularCoordinates { x, y, z }
 }
@@ -174,6 +174,7 @@ pub fn mercury(jde: f64) -> RectangularCoordinates {
 /// assert!(coordinates.y > 0.3900421674 && coordinates.y < 0.3900421676);
 /// assert!(coordinates.z > 0.0397542 && coordinates.z < 0.0397582);
 /// ```
+#[cfg(feature = "venus")]
 pub fn venus(jde: f64) -> RectangularCoordinates {
     let t = calculate_t(jde);
 
@@ -197,15 +198,9 @@ pub fn venus(jde: f64) -> RectangularCoordinates {
     let z3 = calculate_var(t, &venus::Z3[0], &venus::Z3[1], &venus::Z3[2]);
     let z4 = calculate_var(t, &venus::Z4[0], &venus::Z4[1], &venus::Z4[2]);
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4 + x5 * t5;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4 + y5 * t5;
-    let z = z0 + z1 * t + z2 * t2 + z3 * t3 + z4 * t4;
+    let x = ((((x5 * t + x4) * t + x3) * t + x2) * t + x1) * t + x0;
+    let y = ((((y5 * t + y4) * t + y3) * t + y2) * t + y1) * t + y0;
+    let z = (((z4 * t + z3) * t + z2) * t + z1) * t + z0;
 
     RectangularCoordinates { x, y, z }
 }
@@ -233,6 +228,7 @@ pub fn venus(jde: f64) -> RectangularCoordinates {
 /// assert!(coordinates.y > 0.9651943804 && coordinates.y < 0.9651943806);
 /// assert!(coordinates.z > 0.0007705 && coordinates.z < 0.0007745);
 /// ```
+#[cfg(feature = "earth")]
 pub fn earth(jde: f64) -> RectangularCoordinates {
     let t = calculate_t(jde);
 
@@ -256,15 +252,9 @@ pub fn earth(jde: f64) -> RectangularCoordinates {
     let z3 = calculate_var(t, &earth::Z3[0], &earth::Z3[1], &earth::Z3[2]);
     let z4 = calculate_var(t, &earth::Z4[0], &earth::Z4[1], &earth::Z4[2]);
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4 + x5 * t5;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4 + y5 * t5;
-    let z = z0 + z1 * t + z2 * t2 + z3 * t3 + z4 * t4;
+    let x = ((((x5 * t + x4) * t + x3) * t + x2) * t + x1) * t + x0;
+    let y = ((((y5 * t + y4) * t + y3) * t + y2) * t + y1) * t + y0;
+    let z = (((z4 * t + z3) * t + z2) * t + z1) * t + z0;
 
     RectangularCoordinates { x, y, z }
 }
@@ -292,6 +282,7 @@ pub fn earth(jde: f64) -> RectangularCoordinates {
 /// assert!(coordinates.y > 1.4065798250 && coordinates.y < 1.4065798252);
 /// assert!(coordinates.z > 0.0502476 && coordinates.z < 0.0502516);
 /// ```
+#[cfg(feature = "mars")]
 pub fn mars(jde: f64) -> RectangularCoordinates {
     let t = calculate_t(jde);
 
@@ -315,15 +306,9 @@ pub fn mars(jde: f64) -> RectangularCoordinates {
     let z3 = calculate_var(t, &mars::Z3[0], &mars::Z3[1], &mars::Z3[2]);
     let z4 = calculate_var(t, &mars::Z4[0], &mars::Z4[1], &mars::Z4[2]);
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4 + x5 * t5;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4 + y5 * t5;
-    let z = z0 + z1 * t + z2 * t2 + z3 * t3 + z4 * t4;
+    let x = ((((x5 * t + x4) * t + x3) * t + x2) * t + x1) * t + x0;
+    let y = ((((y5 * t + y4) * t + y3) * t + y2) * t + y1) * t + y0;
+    let z = (((z4 * t + z3) * t + z2) * t + z1) * t + z0;
 
     RectangularCoordinates { x, y, z }
 }
@@ -351,6 +336,7 @@ pub fn mars(jde: f64) -> RectangularCoordinates {
 /// assert!(coordinates.y > -1.9854861384 && coordinates.y < -1.9854861382);
 /// assert!(coordinates.z > -0.0959289 && coordinates.z < -0.0959249);
 /// ```
+#[cfg(feature = "jupiter")]
 pub fn jupiter(jde: f64) -> RectangularCoordinates {
     let t = calculate_t(jde);
 
@@ -374,15 +360,9 @@ pub fn jupiter(jde: f64) -> RectangularCoordinates {
     let z3 = calculate_var(t, &jupiter::Z3[0], &jupiter::Z3[1], &jupiter::Z3[2]);
     let z4 = calculate_var(t, &jupiter::Z4[0], &jupiter::Z4[1], &jupiter::Z4[2]);
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4 + x5 * t5;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4 + y5 * t5;
-    let z = z0 + z1 * t + z2 * t2 + z3 * t3 + z4 * t4;
+    let x = ((((x5 * t + x4) * t + x3) * t + x2) * t + x1) * t + x0;
+    let y = ((((y5 * t + y4) * t + y3) * t + y2) * t + y1) * t + y0;
+    let z = (((z4 * t + z3) * t + z2) * t + z1) * t + z0;
 
     RectangularCoordinates { x, y, z }
 }
@@ -410,6 +390,7 @@ pub fn jupiter(jde: f64) -> RectangularCoordinates {
 /// assert!(coordinates.y > -10.0240954526 && coordinates.y < -10.0240954524);
 /// assert!(coordinates.z > 0.1345888 && coordinates.z < 0.1345928);
 /// ```
+#[cfg(feature = "saturn")]
 pub fn saturn(jde: f64) -> RectangularCoordinates {
     let t = calculate_t(jde);
 
@@ -433,15 +414,9 @@ pub fn saturn(jde: f64) -> RectangularCoordinates {
     let z3 = calculate_var(t, &saturn::Z3[0], &saturn::Z3[1], &saturn::Z3[2]);
     let z4 = calculate_var(t, &saturn::Z4[0], &saturn::Z4[1], &saturn::Z4[2]);
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4 + x5 * t5;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4 + y5 * t5;
-    let z = z0 + z1 * t + z2 * t2 + z3 * t3 + z4 * t4;
+    let x = ((((x5 * t + x4) * t + x3) * t + x2) * t + x1) * t + x0;
+    let y = ((((y5 * t + y4) * t + y3) * t + y2) * t + y1) * t + y0;
+    let z = (((z4 * t + z3) * t + z2) * t + z1) * t + z0;
 
     RectangularCoordinates { x, y, z }
 }
@@ -469,6 +444,7 @@ pub fn saturn(jde: f64) -> RectangularCoordinates {
 /// assert!(coordinates.y > -5.1666300881 && coordinates.y < -5.1666300879);
 /// assert!(coordinates.z > 0.2124594 && coordinates.z < 0.2124634);
 /// ```
+#[cfg(feature = "uranus")]
 pub fn uranus(jde: f64) -> RectangularCoordinates {
     let t = calculate_t(jde);
 
@@ -488,14 +464,9 @@ pub fn uranus(jde: f64) -> RectangularCoordinates {
     let z1 = calculate_var(t, &uranus::Z1[0], &uranus::Z1[1], &uranus::Z1[2]);
     let z2 = calculate_var(t, &uranus::Z2[0], &uranus::Z2[1], &uranus::Z2[2]);
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4;
-    let z = z0 + z1 * t + z2 * t2;
+    let x = (((x4 * t + x3) * t + x2) * t + x1) * t + x0;
+    let y = (((y4 * t + y3) * t + y2) * t + y1) * t + y0;
+    let z = (z2 * t + z1) * t + z0;
 
     RectangularCoordinates { x, y, z }
 }
@@ -523,6 +494,7 @@ pub fn uranus(jde: f64) -> RectangularCoordinates {
 /// assert!(coordinates.y > -2.0298541973 && coordinates.y < -2.0298541971);
 /// assert!(coordinates.z > -0.6440972 && coordinates.z < -0.6440932);
 /// ```
+#[cfg(feature = "neptune")]
 pub fn neptune(jde: f64) -> RectangularCoordinates {
     let t = calculate_t(jde);
 
@@ -542,14 +514,515 @@ pub fn neptune(jde: f64) -> RectangularCoordinates {
     let z1 = calculate_var(t, &neptune::Z1[0], &neptune::Z1[1], &neptune::Z1[2]);
     let z2 = calculate_var(t, &neptune::Z2[0], &neptune::Z2[1], &neptune::Z2[2]);
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4;
-    let z = z0 + z1 * t + z2 * t2;
+    let x = (((x4 * t + x3) * t + x2) * t + x1) * t + x0;
+    let y = (((y4 * t + y3) * t + y2) * t + y1) * t + y0;
+    let z = (z2 * t + z1) * t + z0;
 
     RectangularCoordinates { x, y, z }
 }
+
+/// Calculates VSOP87E solution for the given planet.
+///
+/// This function calculates the VSOP87E solution (barycentric ecliptic rectangular coordinates for
+/// the equinox J2000.0) for the given planet, by calling the function of that planet in this
+/// module. It is useful when the planet is only known at runtime, or to iterate over all the
+/// planets.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87e, Planet};
+///
+/// assert_eq!(vsop87e::planet(Planet::Mars, 2451545.0), vsop87e::mars(2451545.0));
+/// ```
+pub fn planet(planet: Planet, jde: f64) -> RectangularCoordinates {
+    match planet {
+        #[cfg(feature = "mercury")]
+        Planet::Mercury => mercury(jde),
+        #[cfg(feature = "venus")]
+        Planet::Venus => venus(jde),
+        #[cfg(feature = "earth")]
+        Planet::Earth => earth(jde),
+        #[cfg(feature = "mars")]
+        Planet::Mars => mars(jde),
+        #[cfg(feature = "jupiter")]
+        Planet::Jupiter => jupiter(jde),
+        #[cfg(feature = "saturn")]
+        Planet::Saturn => saturn(jde),
+        #[cfg(feature = "uranus")]
+        Planet::Uranus => uranus(jde),
+        #[cfg(feature = "neptune")]
+        Planet::Neptune => neptune(jde),
+        #[allow(unreachable_patterns)]
+        _ => planet.not_enabled(),
+    }
+}
+
+/// Calculates VSOP87E solution for all the planets at once.
+///
+/// The coordinates are returned in the order of
+/// [`Planet::ALL`](../enum.Planet.html#associatedconstant.ALL), by distance to the Sun: Mercury,
+/// Venus, Earth, Mars, Jupiter, Saturn, Uranus and Neptune. With the `rayon` feature, each planet
+/// is computed in a thread of the global [*rayon*](https://docs.rs/rayon) thread pool, which makes
+/// it suitable to refresh a whole planetarium every frame. Without it, the planets are computed one
+/// after the other, and the coordinates are the same in both cases. Only available with the
+/// `all_planets` feature.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87e, Planet};
+///
+/// let planets = vsop87e::all_planets(2451545.0);
+///
+/// assert_eq!(planets[Planet::Saturn as usize], vsop87e::saturn(2451545.0));
+/// ```
+#[cfg(feature = "all_planets")]
+pub fn all_planets(jde: f64) -> [RectangularCoordinates; 8] {
+    super::all_planets(|body| planet(body, jde))
+}
+
+/// Calculates the heliocentric position of the given planet from its VSOP87E solution.
+///
+/// The barycentric position of the planet is converted to a heliocentric position by subtracting
+/// the barycentric position of the [Sun](fn.sun.html) at the same date, so the result is referred
+/// to the ecliptic and equinox of J2000.0, with the Sun in the center, like the
+/// [*VSOP87A*](../vsop87a/index.html) solution. Both solutions are independent fits, so they agree
+/// to a few 10⁻⁷ *AU* for some centuries around J2000, and drift slowly apart far from it. The
+/// parameter needed is the Julian Day (*JD*) for the given date.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87a, vsop87e, Planet};
+///
+/// let heliocentric = vsop87e::heliocentric(Planet::Jupiter, 2451545.0);
+/// let vsop87a = vsop87a::jupiter(2451545.0);
+///
+/// assert!((heliocentric - vsop87a).norm() < 1e-7);
+/// ```
+pub fn heliocentric(planet: Planet, jde: f64) -> RectangularCoordinates {
+    barycentric_to_heliocentric(&self::planet(planet, jde), &sun(jde))
+}
+
+/// Calculates the offset of the Sun's center from the barycenter of the solar system.
+///
+/// This is the barycentric position of the Sun in VSOP87E, in *AU*: the vector that goes from the
+/// barycenter of the solar system to the center of the Sun. The barycenter, seen from the Sun, is
+/// at the opposite vector. The parameter needed is the Julian Day (*JD*) for the given date.
+///
+/// The offset is mainly caused by Jupiter and Saturn, and its magnitude peaks at around 0.01 *AU*
+/// (a bit more than two solar radii) when the giant planets are aligned, which is useful to
+/// visualize the wobble of the Sun.
+///
+/// # Example
+///
+/// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get how far
+/// the Sun was from the barycenter of the solar system. In this case, in January 1st, 2000.
+///
+/// ```
+/// use vsop87::vsop87e;
+///
+/// let offset = vsop87e::sun_barycenter_offset(2451545.0);
+/// let distance = (offset.x * offset.x + offset.y * offset.y + offset.z * offset.z).sqrt();
+///
+/// assert!(distance > 0.0076 && distance < 0.0077);
+/// ```
+pub fn sun_barycenter_offset(jde: f64) -> RectangularCoordinates {
+    sun(jde)
+}
+
+/// Calculates the barycentric position and velocity of the given planet.
+///
+/// This returns the same position as [`planet`](fn.planet.html), in *AU*, and the velocity of the
+/// planet, in *AU* per day, computed in a single pass from the analytic derivative of the series.
+/// Together they form the state vector of the planet, referred to the ecliptic and equinox J2000.0.
+/// The parameter needed is the Julian Day (*JD*) for the given date.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87e, Planet};
+///
+/// let (position, velocity) = vsop87e::state(Planet::Mars, 2451545.0);
+///
+/// assert_eq!(position, vsop87e::mars(2451545.0));
+/// // Mars moves at about 26.5 km/s around the Sun.
+/// assert!((velocity.norm_km() / 86400.0 - 26.5).abs() < 0.5);
+/// ```
+pub fn state(planet: Planet, jde: f64) -> (RectangularCoordinates, RectangularCoordinates) {
+    rectangular_state(tables(planet), jde)
+}
+
+/// Calculates the barycentric position and velocity of the Sun.
+///
+/// This returns the same position as [`sun`](fn.sun.html), in *AU*, and the velocity of the Sun
+/// around the barycenter of the solar system, in *AU* per day, from the analytic derivative of
+/// the series. With [`state`](fn.state.html), it gives the barycentric state vectors of every body
+/// of the solution, referred to the ecliptic and equinox J2000.0, which can be compared with
+/// those of a JPL ephemeris once rotated to the equator with
+/// [`to_equatorial_frame`](../struct.RectangularCoordinates.html#method.to_equatorial_frame).
+/// The parameter needed is the Julian Day (*JD*) for the given date.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::vsop87e;
+///
+/// let (position, velocity) = vsop87e::sun_state(2451545.0);
+///
+/// assert_eq!(position, vsop87e::sun(2451545.0));
+/// // The Sun moves at about 13 m/s around the barycenter, mostly pulled by Jupiter.
+/// assert!((velocity.norm_km() / 86400.0 - 0.013).abs() < 0.003);
+/// ```
+pub fn sun_state(jde: f64) -> (RectangularCoordinates, RectangularCoordinates) {
+    rectangular_state(&SUN, jde)
+}
+
+/// Calculates VSOP87E solution for the given planet, skipping the terms smaller than `epsilon`.
+///
+/// This trades precision for speed, like
+/// [`vsop87d::planet_truncated()`](../vsop87d/fn.planet_truncated.html), which gives some figures.
+/// The terms whose amplitude is smaller than `epsilon`, in *AU*, are not computed, and an
+/// `epsilon` of `0.0` keeps every term. The error on each coordinate is usually a small multiple
+/// of `epsilon`.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87e, Planet};
+///
+/// let full = vsop87e::planet(Planet::Jupiter, 2451545.0);
+/// let truncated = vsop87e::planet_truncated(Planet::Jupiter, 2451545.0, 1e-7);
+///
+/// assert!((full - truncated).norm() < 1e-5);
+/// ```
+pub fn planet_truncated(planet: Planet, jde: f64, epsilon: f64) -> RectangularCoordinates {
+    rectangular_truncated(tables(planet), jde, epsilon)
+}
+
+/// Calculates VSOP87E solution for the given planet on many dates.
+///
+/// This function calculates the VSOP87E solution of the given planet for each Julian Day (*JD*) in
+/// `jdes`, and returns the coordinates in a vector, in the same order. The coefficient tables of
+/// the planet are looked up once for the whole batch, and the output vector is allocated once.
+/// Only available with the standard library: see [`planet_into`](fn.planet_into.html) otherwise.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87e, Planet};
+///
+/// let jdes = [2451545.0, 2451546.0, 2451547.0];
+/// let track = vsop87e::planet_many(Planet::Earth, &jdes);
+///
+/// assert_eq!(track.len(), 3);
+/// assert_eq!(track[1], vsop87e::earth(2451546.0));
+/// ```
+#[cfg(not(feature = "no_std"))]
+pub fn planet_many(planet: Planet, jdes: &[f64]) -> Vec<RectangularCoordinates> {
+    let tables = tables(planet);
+    jdes.iter().map(|&jde| rectangular(tables, jde)).collect()
+}
+
+/// Calculates VSOP87E solution for the given planet on many dates, into a caller-provided buffer.
+///
+/// This is the allocation-free version of [`planet_many`](fn.planet_many.html), also available
+/// with the `no_std` feature. The coordinates for `jdes[i]` are written to `output[i]`, and every
+/// element of `output` is overwritten.
+///
+/// # Panics
+///
+/// Panics if `jdes` and `output` have different lengths, before writing anything.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87e, Planet};
+///
+/// let jdes = [2451545.0, 2451546.0];
+/// let mut output = [vsop87e::mars(0.0); 2];
+/// vsop87e::planet_into(Planet::Mars, &jdes, &mut output);
+///
+/// assert_eq!(output[0], vsop87e::mars(2451545.0));
+/// assert_eq!(output[1], vsop87e::mars(2451546.0));
+/// ```
+pub fn planet_into(planet: Planet, jdes: &[f64], output: &mut [RectangularCoordinates]) {
+    assert_eq!(
+        jdes.len(),
+        output.len(),
+        "the input dates and the output buffer must have the same length"
+    );
+
+    let tables = tables(planet);
+    for (coordinates, &jde) in output.iter_mut().zip(jdes) {
+        *coordinates = rectangular(tables, jde);
+    }
+}
+
+/// Gets the series of the *X*, *Y* and *Z* coordinates of the given planet.
+fn tables(planet: Planet) -> &'static [&'static [Series<'static>]; 3] {
+    match planet {
+        #[cfg(feature = "mercury")]
+        Planet::Mercury => &MERCURY,
+        #[cfg(feature = "venus")]
+        Planet::Venus => &VENUS,
+        #[cfg(feature = "earth")]
+        Planet::Earth => &EARTH,
+        #[cfg(feature = "mars")]
+        Planet::Mars => &MARS,
+        #[cfg(feature = "jupiter")]
+        Planet::Jupiter => &JUPITER,
+        #[cfg(feature = "saturn")]
+        Planet::Saturn => &SATURN,
+        #[cfg(feature = "uranus")]
+        Planet::Uranus => &URANUS,
+        #[cfg(feature = "neptune")]
+        Planet::Neptune => &NEPTUNE,
+        #[allow(unreachable_patterns)]
+        _ => planet.not_enabled(),
+    }
+}
+
+/// Series of the *X*, *Y* and *Z* coordinates of Mercury.
+#[cfg(feature = "mercury")]
+static MERCURY: [&[Series<'static>]; 3] = [
+    &[
+        [&mercury::X0[0], &mercury::X0[1], &mercury::X0[2]],
+        [&mercury::X1[0], &mercury::X1[1], &mercury::X1[2]],
+        [&mercury::X2[0], &mercury::X2[1], &mercury::X2[2]],
+        [&mercury::X3[0], &mercury::X3[1], &mercury::X3[2]],
+        [&mercury::X4[0], &mercury::X4[1], &mercury::X4[2]],
+        [&mercury::X5[0], &mercury::X5[1], &mercury::X5[2]],
+    ],
+    &[
+        [&mercury::Y0[0], &mercury::Y0[1], &mercury::Y0[2]],
+        [&mercury::Y1[0], &mercury::Y1[1], &mercury::Y1[2]],
+        [&mercury::Y2[0], &mercury::Y2[1], &mercury::Y2[2]],
+        [&mercury::Y3[0], &mercury::Y3[1], &mercury::Y3[2]],
+        [&mercury::Y4[0], &mercury::Y4[1], &mercury::Y4[2]],
+        [&mercury::Y5[0], &mercury::Y5[1], &mercury::Y5[2]],
+    ],
+    &[
+        [&mercury::Z0[0], &mercury::Z0[1], &mercury::Z0[2]],
+        [&mercury::Z1[0], &mercury::Z1[1], &mercury::Z1[2]],
+        [&mercury::Z2[0], &mercury::Z2[1], &mercury::Z2[2]],
+        [&mercury::Z3[0], &mercury::Z3[1], &mercury::Z3[2]],
+        [&mercury::Z4[0], &mercury::Z4[1], &mercury::Z4[2]],
+    ],
+];
+
+/// Series of the *X*, *Y* and *Z* coordinates of Venus.
+#[cfg(feature = "venus")]
+static VENUS: [&[Series<'static>]; 3] = [
+    &[
+        [&venus::X0[0], &venus::X0[1], &venus::X0[2]],
+        [&venus::X1[0], &venus::X1[1], &venus::X1[2]],
+        [&venus::X2[0], &venus::X2[1], &venus::X2[2]],
+        [&venus::X3[0], &venus::X3[1], &venus::X3[2]],
+        [&venus::X4[0], &venus::X4[1], &venus::X4[2]],
+        [&venus::X5[0], &venus::X5[1], &venus::X5[2]],
+    ],
+    &[
+        [&venus::Y0[0], &venus::Y0[1], &venus::Y0[2]],
+        [&venus::Y1[0], &venus::Y1[1], &venus::Y1[2]],
+        [&venus::Y2[0], &venus::Y2[1], &venus::Y2[2]],
+        [&venus::Y3[0], &venus::Y3[1], &venus::Y3[2]],
+        [&venus::Y4[0], &venus::Y4[1], &venus::Y4[2]],
+        [&venus::Y5[0], &venus::Y5[1], &venus::Y5[2]],
+    ],
+    &[
+        [&venus::Z0[0], &venus::Z0[1], &venus::Z0[2]],
+        [&venus::Z1[0], &venus::Z1[1], &venus::Z1[2]],
+        [&venus::Z2[0], &venus::Z2[1], &venus::Z2[2]],
+        [&venus::Z3[0], &venus::Z3[1], &venus::Z3[2]],
+        [&venus::Z4[0], &venus::Z4[1], &venus::Z4[2]],
+    ],
+];
+
+/// Series of the *X*, *Y* and *Z* coordinates of Earth.
+#[cfg(feature = "earth")]
+static EARTH: [&[Series<'static>]; 3] = [
+    &[
+        [&earth::X0[0], &earth::X0[1], &earth::X0[2]],
+        [&earth::X1[0], &earth::X1[1], &earth::X1[2]],
+        [&earth::X2[0], &earth::X2[1], &earth::X2[2]],
+        [&earth::X3[0], &earth::X3[1], &earth::X3[2]],
+        [&earth::X4[0], &earth::X4[1], &earth::X4[2]],
+        [&earth::X5[0], &earth::X5[1], &earth::X5[2]],
+    ],
+    &[
+        [&earth::Y0[0], &earth::Y0[1], &earth::Y0[2]],
+        [&earth::Y1[0], &earth::Y1[1], &earth::Y1[2]],
+        [&earth::Y2[0], &earth::Y2[1], &earth::Y2[2]],
+        [&earth::Y3[0], &earth::Y3[1], &earth::Y3[2]],
+        [&earth::Y4[0], &earth::Y4[1], &earth::Y4[2]],
+        [&earth::Y5[0], &earth::Y5[1], &earth::Y5[2]],
+    ],
+    &[
+        [&earth::Z0[0], &earth::Z0[1], &earth::Z0[2]],
+        [&earth::Z1[0], &earth::Z1[1], &earth::Z1[2]],
+        [&earth::Z2[0], &earth::Z2[1], &earth::Z2[2]],
+        [&earth::Z3[0], &earth::Z3[1], &earth::Z3[2]],
+        [&earth::Z4[0], &earth::Z4[1], &earth::Z4[2]],
+    ],
+];
+
+/// Series of the *X*, *Y* and *Z* coordinates of Mars.
+#[cfg(feature = "mars")]
+static MARS: [&[Series<'static>]; 3] = [
+    &[
+        [&mars::X0[0], &mars::X0[1], &mars::X0[2]],
+        [&mars::X1[0], &mars::X1[1], &mars::X1[2]],
+        [&mars::X2[0], &mars::X2[1], &mars::X2[2]],
+        [&mars::X3[0], &mars::X3[1], &mars::X3[2]],
+        [&mars::X4[0], &mars::X4[1], &mars::X4[2]],
+        [&mars::X5[0], &mars::X5[1], &mars::X5[2]],
+    ],
+    &[
+        [&mars::Y0[0], &mars::Y0[1], &mars::Y0[2]],
+        [&mars::Y1[0], &mars::Y1[1], &mars::Y1[2]],
+        [&mars::Y2[0], &mars::Y2[1], &mars::Y2[2]],
+        [&mars::Y3[0], &mars::Y3[1], &mars::Y3[2]],
+        [&mars::Y4[0], &mars::Y4[1], &mars::Y4[2]],
+        [&mars::Y5[0], &mars::Y5[1], &mars::Y5[2]],
+    ],
+    &[
+        [&mars::Z0[0], &mars::Z0[1], &mars::Z0[2]],
+        [&mars::Z1[0], &mars::Z1[1], &mars::Z1[2]],
+        [&mars::Z2[0], &mars::Z2[1], &mars::Z2[2]],
+        [&mars::Z3[0], &mars::Z3[1], &mars::Z3[2]],
+        [&mars::Z4[0], &mars::Z4[1], &mars::Z4[2]],
+    ],
+];
+
+/// Series of the *X*, *Y* and *Z* coordinates of Jupiter.
+#[cfg(feature = "jupiter")]
+static JUPITER: [&[Series<'static>]; 3] = [
+    &[
+        [&jupiter::X0[0], &jupiter::X0[1], &jupiter::X0[2]],
+        [&jupiter::X1[0], &jupiter::X1[1], &jupiter::X1[2]],
+        [&jupiter::X2[0], &jupiter::X2[1], &jupiter::X2[2]],
+        [&jupiter::X3[0], &jupiter::X3[1], &jupiter::X3[2]],
+        [&jupiter::X4[0], &jupiter::X4[1], &jupiter::X4[2]],
+        [&jupiter::X5[0], &jupiter::X5[1], &jupiter::X5[2]],
+    ],
+    &[
+        [&jupiter::Y0[0], &jupiter::Y0[1], &jupiter::Y0[2]],
+        [&jupiter::Y1[0], &jupiter::Y1[1], &jupiter::Y1[2]],
+        [&jupiter::Y2[0], &jupiter::Y2[1], &jupiter::Y2[2]],
+        [&jupiter::Y3[0], &jupiter::Y3[1], &jupiter::Y3[2]],
+        [&jupiter::Y4[0], &jupiter::Y4[1], &jupiter::Y4[2]],
+        [&jupiter::Y5[0], &jupiter::Y5[1], &jupiter::Y5[2]],
+    ],
+    &[
+        [&jupiter::Z0[0], &jupiter::Z0[1], &jupiter::Z0[2]],
+        [&jupiter::Z1[0], &jupiter::Z1[1], &jupiter::Z1[2]],
+        [&jupiter::Z2[0], &jupiter::Z2[1], &jupiter::Z2[2]],
+        [&jupiter::Z3[0], &jupiter::Z3[1], &jupiter::Z3[2]],
+        [&jupiter::Z4[0], &jupiter::Z4[1], &jupiter::Z4[2]],
+    ],
+];
+
+/// Series of the *X*, *Y* and *Z* coordinates of Saturn.
+#[cfg(feature = "saturn")]
+static SATURN: [&[Series<'static>]; 3] = [
+    &[
+        [&saturn::X0[0], &saturn::X0[1], &saturn::X0[2]],
+        [&saturn::X1[0], &saturn::X1[1], &saturn::X1[2]],
+        [&saturn::X2[0], &saturn::X2[1], &saturn::X2[2]],
+        [&saturn::X3[0], &saturn::X3[1], &saturn::X3[2]],
+        [&saturn::X4[0], &saturn::X4[1], &saturn::X4[2]],
+        [&saturn::X5[0], &saturn::X5[1], &saturn::X5[2]],
+    ],
+    &[
+        [&saturn::Y0[0], &saturn::Y0[1], &saturn::Y0[2]],
+        [&saturn::Y1[0], &saturn::Y1[1], &saturn::Y1[2]],
+        [&saturn::Y2[0], &saturn::Y2[1], &saturn::Y2[2]],
+        [&saturn::Y3[0], &saturn::Y3[1], &saturn::Y3[2]],
+        [&saturn::Y4[0], &saturn::Y4[1], &saturn::Y4[2]],
+        [&saturn::Y5[0], &saturn::Y5[1], &saturn::Y5[2]],
+    ],
+    &[
+        [&saturn::Z0[0], &saturn::Z0[1], &saturn::Z0[2]],
+        [&saturn::Z1[0], &saturn::Z1[1], &saturn::Z1[2]],
+        [&saturn::Z2[0], &saturn::Z2[1], &saturn::Z2[2]],
+        [&saturn::Z3[0], &saturn::Z3[1], &saturn::Z3[2]],
+        [&saturn::Z4[0], &saturn::Z4[1], &saturn::Z4[2]],
+    ],
+];
+
+/// Series of the *X*, *Y* and *Z* coordinates of Uranus.
+#[cfg(feature = "uranus")]
+static URANUS: [&[Series<'static>]; 3] = [
+    &[
+        [&uranus::X0[0], &uranus::X0[1], &uranus::X0[2]],
+        [&uranus::X1[0], &uranus::X1[1], &uranus::X1[2]],
+        [&uranus::X2[0], &uranus::X2[1], &uranus::X2[2]],
+        [&uranus::X3[0], &uranus::X3[1], &uranus::X3[2]],
+        [&uranus::X4[0], &uranus::X4[1], &uranus::X4[2]],
+    ],
+    &[
+        [&uranus::Y0[0], &uranus::Y0[1], &uranus::Y0[2]],
+        [&uranus::Y1[0], &uranus::Y1[1], &uranus::Y1[2]],
+        [&uranus::Y2[0], &uranus::Y2[1], &uranus::Y2[2]],
+        [&uranus::Y3[0], &uranus::Y3[1], &uranus::Y3[2]],
+        [&uranus::Y4[0], &uranus::Y4[1], &uranus::Y4[2]],
+    ],
+    &[
+        [&uranus::Z0[0], &uranus::Z0[1], &uranus::Z0[2]],
+        [&uranus::Z1[0], &uranus::Z1[1], &uranus::Z1[2]],
+        [&uranus::Z2[0], &uranus::Z2[1], &uranus::Z2[2]],
+    ],
+];
+
+/// Series of the *X*, *Y* and *Z* coordinates of Neptune.
+#[cfg(feature = "neptune")]
+static NEPTUNE: [&[Series<'static>]; 3] = [
+    &[
+        [&neptune::X0[0], &neptune::X0[1], &neptune::X0[2]],
+        [&neptune::X1[0], &neptune::X1[1], &neptune::X1[2]],
+        [&neptune::X2[0], &neptune::X2[1], &neptune::X2[2]],
+        [&neptune::X3[0], &neptune::X3[1], &neptune::X3[2]],
+        [&neptune::X4[0], &neptune::X4[1], &neptune::X4[2]],
+    ],
+    &[
+        [&neptune::Y0[0], &neptune::Y0[1], &neptune::Y0[2]],
+        [&neptune::Y1[0], &neptune::Y1[1], &neptune::Y1[2]],
+        [&neptune::Y2[0], &neptune::Y2[1], &neptune::Y2[2]],
+        [&neptune::Y3[0], &neptune::Y3[1], &neptune::Y3[2]],
+        [&neptune::Y4[0], &neptune::Y4[1], &neptune::Y4[2]],
+    ],
+    &[
+        [&neptune::Z0[0], &neptune::Z0[1], &neptune::Z0[2]],
+        [&neptune::Z1[0], &neptune::Z1[1], &neptune::Z1[2]],
+        [&neptune::Z2[0], &neptune::Z2[1], &neptune::Z2[2]],
+    ],
+];
+
+/// Series of the *X*, *Y* and *Z* coordinates of the Sun.
+static SUN: [&[Series<'static>]; 3] = [
+    &[
+        [&sun::X0[0], &sun::X0[1], &sun::X0[2]],
+        [&sun::X1[0], &sun::X1[1], &sun::X1[2]],
+        [&sun::X2[0], &sun::X2[1], &sun::X2[2]],
+        [&sun::X3[0], &sun::X3[1], &sun::X3[2]],
+        [&sun::X4[0], &sun::X4[1], &sun::X4[2]],
+        [&sun::X5[0], &sun::X5[1], &sun::X5[2]],
+    ],
+    &[
+        [&sun::Y0[0], &sun::Y0[1], &sun::Y0[2]],
+        [&sun::Y1[0], &sun::Y1[1], &sun::Y1[2]],
+        [&sun::Y2[0], &sun::Y2[1], &sun::Y2[2]],
+        [&sun::Y3[0], &sun::Y3[1], &sun::Y3[2]],
+        [&sun::Y4[0], &sun::Y4[1], &sun::Y4[2]],
+        [&sun::Y5[0], &sun::Y5[1], &sun::Y5[2]],
+    ],
+    &[
+        [&sun::Z0[0], &sun::Z0[1], &sun::Z0[2]],
+        [&sun::Z1[0], &sun::Z1[1], &sun::Z1[2]],
+        [&sun::Z2[0], &sun::Z2[1], &sun::Z2[2]],
+        [&sun::Z3[0], &sun::Z3[1], &sun::Z3[2]],
+        [&sun::Z4[0], &sun::Z4[1], &sun::Z4[2]],
+    ],
+];
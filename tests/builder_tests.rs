@@ -0,0 +1,77 @@
+#![cfg(feature = "earth")]
+#![cfg_attr(not(feature = "all_planets"), allow(unused_imports))]
+
+extern crate vsop87;
+use vsop87::*;
+
+#[cfg(all(feature = "mercury", feature = "mars", feature = "neptune"))]
+#[test]
+fn it_builder_default() {
+    // Without options, the builder gives the geometric geocentric position.
+    for &planet in &[Planet::Mercury, Planet::Mars, Planet::Neptune] {
+        assert_eq!(
+            EphemerisBuilder::new(planet).compute(2451545.0),
+            geocentric::planet(planet, 2451545.0)
+        );
+    }
+}
+
+#[cfg(feature = "venus")]
+#[test]
+fn it_builder_corrections() {
+    let jde = 2448976.5;
+    let builder = EphemerisBuilder::new(Planet::Venus);
+
+    assert_eq!(
+        builder.light_time().compute(jde),
+        geocentric::apparent_planet(Planet::Venus, jde)
+    );
+    assert_eq!(
+        builder.light_time().aberration().compute(jde),
+        geocentric::aberration(jde, &geocentric::apparent_planet(Planet::Venus, jde))
+    );
+    assert_eq!(
+        builder.apparent().compute(jde),
+        geocentric::apparent(Planet::Venus, jde)
+    );
+
+    // The order of the calls does not matter.
+    assert_eq!(
+        builder.nutation().aberration().light_time(),
+        builder.apparent()
+    );
+
+    // The nutation only changes the longitude.
+    let geometric = builder.compute(jde);
+    let nutated = builder.nutation().compute(jde);
+    let (delta_psi, _) = nutation::nutation(jde);
+    assert!((nutated.longitude() - geometric.longitude() - delta_psi).abs() < 1e-15);
+    assert_eq!(nutated.latitude(), geometric.latitude());
+}
+
+#[cfg(feature = "mars")]
+#[test]
+fn it_builder_topocentric() {
+    // Mars from Palomar Observatory on August 28th, 2003 at 3h17m UT.
+    let jd = 2452879.63681;
+    let jde = ut_to_tt(jd, delta_t(2003.66));
+    let latitude = (33.0 + 21.0 / 60.0 + 22.0 / 3600.0_f64).to_radians();
+    let longitude = -(7.0 + 47.0 / 60.0 + 27.0 / 3600.0_f64).to_radians() * 15.0;
+
+    let topocentric = EphemerisBuilder::new(Planet::Mars)
+        .apparent()
+        .topocentric(latitude, longitude, 1706.0)
+        .compute(jde);
+
+    // The builder computes the sidereal time from its own estimate of ΔT, so the sidereal times
+    // differ by a fraction of a second.
+    let sidereal_time = topocentric::local_mean_sidereal_time_from_ut(jd, longitude);
+    let expected = topocentric::planet(Planet::Mars, jde, latitude, 1706.0, sidereal_time);
+    assert!(angular_separation(&topocentric, &expected).to_degrees() * 3600.0 < 0.01);
+    assert!((topocentric.distance() - expected.distance()).abs() < 1e-9);
+
+    // The parallax of Mars at its closest approach is about 23".
+    let geocentric = geocentric::apparent(Planet::Mars, jde);
+    let shift = angular_separation(&topocentric, &geocentric).to_degrees() * 3600.0;
+    assert!(shift > 5.0 && shift < 24.0);
+}
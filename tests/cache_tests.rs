@@ -0,0 +1,91 @@
+#![cfg(not(feature = "no_std"))]
+
+extern crate vsop87;
+use vsop87::*;
+
+#[cfg(all(
+    feature = "venus",
+    feature = "mars",
+    feature = "jupiter",
+    feature = "saturn"
+))]
+#[test]
+fn it_position_cache() {
+    let step = 1.0 / 1440.0;
+    let mut cache: PositionCache = PositionCache::new(step, 4);
+    assert!(cache.is_empty());
+
+    // Two lookups within the same minute compute the position once.
+    let first = cache.get(Planet::Jupiter, 2451545.0 + 0.2 * step);
+    let second = cache.get(Planet::Jupiter, 2451545.0 - 0.3 * step);
+    assert_eq!(first, second);
+    assert_eq!(first, vsop87d::jupiter(2451545.0));
+    assert_eq!((cache.hits(), cache.misses()), (1, 1));
+    assert_eq!(cache.len(), 1);
+
+    // Another minute, or another planet, are different entries.
+    let next = cache.get(Planet::Jupiter, 2451545.0 + step);
+    assert_ne!(next, first);
+    cache.get(Planet::Saturn, 2451545.0);
+    assert_eq!((cache.hits(), cache.misses()), (1, 3));
+
+    // The least recently used entry is dropped when the cache is full.
+    cache.get(Planet::Mars, 2451545.0);
+    cache.get(Planet::Jupiter, 2451545.0);
+    cache.get(Planet::Venus, 2451545.0);
+    assert_eq!(cache.len(), 4);
+    assert_eq!((cache.hits(), cache.misses()), (2, 5));
+    cache.get(Planet::Jupiter, 2451545.0);
+    assert_eq!((cache.hits(), cache.misses()), (3, 5));
+    cache.get(Planet::Jupiter, 2451545.0 + step);
+    assert_eq!((cache.hits(), cache.misses()), (3, 6));
+
+    cache.clear();
+    assert!(cache.is_empty());
+    assert_eq!((cache.hits(), cache.misses()), (0, 0));
+}
+
+#[cfg(feature = "earth")]
+#[test]
+fn it_position_cache_variant() {
+    let mut cache = PositionCache::<Vsop87A>::new(0.5, 2);
+    assert_eq!(cache.step_days(), 0.5);
+
+    let position = cache.get(Planet::Earth, 2451545.1);
+    assert_eq!(position, vsop87a::earth(2451545.0));
+
+    // Without capacity, nothing is stored.
+    let mut cache = PositionCache::<Vsop87A>::new(0.5, 0);
+    cache.get(Planet::Earth, 2451545.1);
+    cache.get(Planet::Earth, 2451545.1);
+    assert_eq!((cache.hits(), cache.misses()), (0, 2));
+}
+
+#[test]
+#[should_panic]
+fn it_position_cache_step() {
+    let _ = PositionCache::<Vsop87D>::new(0.0, 4);
+}
+
+#[cfg(feature = "earth")]
+#[test]
+fn it_position_cache_non_finite() {
+    let mut cache: PositionCache = PositionCache::new(1.0, 4);
+
+    // Dates that are not numbers are not rounded to a step, nor stored.
+    assert!(cache.get(Planet::Earth, f64::NAN).longitude().is_nan());
+    assert!(cache.get(Planet::Earth, f64::INFINITY).longitude().is_nan());
+    assert!(cache.is_empty());
+    assert_eq!((cache.hits(), cache.misses()), (0, 2));
+
+    // Nor are dates too far away to be rounded.
+    let _ = cache.get(Planet::Earth, 1e300);
+    assert!(cache.is_empty());
+    assert_eq!((cache.hits(), cache.misses()), (0, 3));
+}
+
+#[test]
+#[should_panic]
+fn it_position_cache_infinite_step() {
+    let _: PositionCache = PositionCache::new(f64::INFINITY, 4);
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "chrono")]
+
+extern crate vsop87;
+use chrono::{TimeZone, Utc};
+use vsop87::*;
+
+#[test]
+fn it_jde_from_chrono() {
+    // The Julian Day starts at noon, so midnight is half a day before.
+    let midnight = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap();
+    assert_eq!(jde_from_chrono(&midnight, Some(0.0)), 2451544.5);
+
+    // Dates before the Unix epoch.
+    let datetime = Utc.with_ymd_and_hms(1957, 10, 4, 19, 26, 24).unwrap();
+    assert!((jde_from_chrono(&datetime, Some(0.0)) - 2436116.31).abs() < 1e-9);
+
+    // The fraction of a second is kept.
+    let datetime = Utc.timestamp_opt(946_728_000, 500_000_000).unwrap();
+    let jde = jde_from_chrono(&datetime, Some(0.0));
+    assert!(((jde - 2451545.0) * 86_400.0 - 0.5).abs() < 1e-4);
+
+    // Without ΔT, it is estimated for the date.
+    let datetime = Utc.with_ymd_and_hms(2003, 8, 28, 3, 17, 0).unwrap();
+    let jd = julian_day(2003, 8, 28.0 + (3.0 + 17.0 / 60.0) / 24.0);
+    let delta_t = (jde_from_chrono(&datetime, None) - jd) * 86_400.0;
+    assert!((delta_t - vsop87::delta_t(2003.66)).abs() < 0.01);
+
+    // Dates before the Gregorian reform are in the proleptic Gregorian calendar, ten days ahead
+    // of the Julian calendar used by `julian_day()` at the time.
+    let datetime = Utc.with_ymd_and_hms(1582, 10, 14, 12, 0, 0).unwrap();
+    assert_eq!(
+        jde_from_chrono(&datetime, Some(0.0)),
+        julian_day(1582, 10, 4.5)
+    );
+}
@@ -0,0 +1,165 @@
+extern crate vsop87;
+use vsop87::*;
+
+#[test]
+fn it_mean_obliquity() {
+    // J2000: 23°26'21.448".
+    assert!((mean_obliquity(2451545.0).to_degrees() - 23.4392911).abs() < 1e-7);
+
+    // Meeus, Astronomical Algorithms, example 22.a: 23°26'27.407".
+    assert!((mean_obliquity(2446895.5).to_degrees() - 23.440946).abs() < 1e-6);
+
+    // The obliquity is currently decreasing.
+    assert!(mean_obliquity(2488069.5) < mean_obliquity(2451545.0));
+}
+
+#[test]
+fn it_mean_obliquity_models() {
+    let difference = |jde| {
+        (mean_obliquity_with(jde, Model::Iau1980) - mean_obliquity_with(jde, Model::Iau2006))
+            .to_degrees()
+            * 3600.0
+    };
+
+    // J2000: 23°26'21.448" for IAU 1980, and 23°26'21.406" for IAU 2006.
+    let iau_1980 = mean_obliquity_with(2451545.0, Model::Iau1980);
+    assert!((iau_1980.to_degrees() * 3600.0 - 84381.448).abs() < 1e-9);
+    assert!((difference(2451545.0) - 0.042).abs() < 1e-9);
+
+    // The rates differ by 0.0218" per century, but the higher order terms compensate it a
+    // thousand years away: 0.0387" in 3000 and -0.0246" in 1000.
+    assert!((difference(2816795.0) - 0.0387).abs() < 1e-4);
+    assert!((difference(2086295.0) + 0.0246).abs() < 1e-4);
+
+    // Both agree with the default expression, by J. Laskar, close to J2000.
+    for &jde in [2378496.5, 2451545.0, 2524594.5].iter() {
+        for &model in [Model::Iau1980, Model::Iau2006].iter() {
+            let error = (mean_obliquity_with(jde, model) - mean_obliquity(jde)).to_degrees();
+            assert!(error.abs() * 3600.0 < 0.1);
+        }
+    }
+}
+
+#[cfg(feature = "mars")]
+#[test]
+fn it_to_equatorial() {
+    // Meeus, Astronomical Algorithms, example 13.a: Pollux.
+    let pollux =
+        SphericalCoordinates::new(113.215630_f64.to_radians(), 6.684170_f64.to_radians(), 1.0);
+    let equatorial = pollux.to_equatorial(23.4392911_f64.to_radians());
+
+    assert!((equatorial.right_ascension().to_degrees() - 116.328942).abs() < 1e-6);
+    assert!((equatorial.declination().to_degrees() - 28.026183).abs() < 1e-6);
+    assert_eq!(equatorial.distance(), 1.0);
+
+    // With no obliquity, the equatorial coordinates are the ecliptic ones.
+    let coordinates = vsop87d::mars(2451545.0);
+    let equatorial = coordinates.to_equatorial(0.0);
+
+    assert!((equatorial.right_ascension() - coordinates.longitude()).abs() < 1e-12);
+    assert!((equatorial.declination() - coordinates.latitude()).abs() < 1e-12);
+
+    // The right ascension is normalized into [0, 2π).
+    let coordinates = SphericalCoordinates::new(359_f64.to_radians(), -0.5, 1.0);
+    let equatorial = coordinates.to_equatorial(mean_obliquity(2451545.0));
+
+    assert!(equatorial.right_ascension() >= 0.0);
+    assert!(equatorial.right_ascension() < 2.0 * std::f64::consts::PI);
+}
+
+#[test]
+fn it_degree_accessors() {
+    let pollux =
+        SphericalCoordinates::new(113.215630_f64.to_radians(), 6.684170_f64.to_radians(), 1.0);
+    let equatorial = pollux.to_equatorial(23.4392911_f64.to_radians());
+
+    assert_eq!(
+        equatorial.right_ascension_deg(),
+        equatorial.right_ascension().to_degrees()
+    );
+    assert_eq!(
+        equatorial.declination_deg(),
+        equatorial.declination().to_degrees()
+    );
+    assert!((equatorial.right_ascension_hours() * 15.0 - 116.328942).abs() < 1e-6);
+    assert!((equatorial.right_ascension_hours() - 7.755263).abs() < 1e-6);
+}
+
+#[cfg(feature = "mars")]
+#[test]
+fn it_to_equatorial_frame() {
+    let obliquity = 23.4392911_f64.to_radians();
+
+    // A point on the ecliptic: sin(δ) = sin(ε)·sin(λ) and tan(α) = cos(ε)·tan(λ).
+    let longitude = 60_f64.to_radians();
+    let point = RectangularCoordinates {
+        x: longitude.cos(),
+        y: longitude.sin(),
+        z: 0.0,
+    };
+    let rotated = point.to_equatorial_frame(obliquity);
+    assert_eq!(rotated.x, point.x);
+    assert!((rotated.z - obliquity.sin() * longitude.sin()).abs() < 1e-15);
+    assert!((rotated.y / rotated.x - obliquity.cos() * longitude.tan()).abs() < 1e-15);
+
+    // It agrees with the spherical conversion.
+    let pollux =
+        SphericalCoordinates::new(113.215630_f64.to_radians(), 6.684170_f64.to_radians(), 1.0);
+    let expected = pollux.to_equatorial(obliquity);
+    let rotated = pollux
+        .to_rectangular()
+        .to_equatorial_frame(obliquity)
+        .to_spherical();
+    assert!((rotated.longitude() - expected.right_ascension()).abs() < 1e-12);
+    assert!((rotated.latitude() - expected.declination()).abs() < 1e-12);
+
+    // Rotating back by the opposite angle gives the original coordinates.
+    let mars = vsop87c::mars(2451545.0);
+    let back = mars
+        .to_equatorial_frame(obliquity)
+        .to_equatorial_frame(-obliquity);
+    assert!((back - mars).norm() < 1e-15);
+}
+
+#[cfg(feature = "jupiter")]
+#[test]
+fn it_to_galactic() {
+    // The galactic center, Sagittarius A*, in J2000 equatorial coordinates.
+    let center =
+        SphericalCoordinates::new(266.40500_f64.to_radians(), -28.93617_f64.to_radians(), 1.0);
+    let galactic = center.to_galactic(0.0);
+    let longitude = galactic.galactic_longitude().to_degrees();
+    assert!(!(0.01..=359.99).contains(&longitude));
+    assert!(galactic.galactic_latitude().to_degrees().abs() < 0.01);
+    assert_eq!(galactic.distance(), 1.0);
+
+    // The north celestial pole is at the galactic longitude l = 122.93192°.
+    let pole = SphericalCoordinates::new(0.0, 90_f64.to_radians(), 1.0).to_galactic(0.0);
+    assert!((pole.galactic_longitude().to_degrees() - 122.93192).abs() < 1e-6);
+    assert!((pole.galactic_latitude().to_degrees() - 27.12825).abs() < 1e-6);
+
+    // Going through the ecliptic gives the same result as going through the equator.
+    let obliquity = mean_obliquity(2451545.0);
+    let jupiter = vsop87b::jupiter(2451545.0);
+    assert_eq!(
+        jupiter.to_galactic(obliquity),
+        jupiter.to_equatorial(obliquity).to_galactic()
+    );
+}
+
+#[cfg(all(feature = "venus", feature = "earth"))]
+#[test]
+fn it_from_ecliptic() {
+    let jde = 2448976.5;
+    let venus = vsop87d::venus(jde);
+    let equatorial = EquatorialCoordinates::from_ecliptic(&venus, jde);
+
+    assert_eq!(equatorial, venus.to_equatorial(mean_obliquity(jde)));
+
+    // Example 33.a from Astronomical Algorithms, without the nutation: the mean right ascension
+    // of Venus differs from the apparent one by less than 2 seconds.
+    let apparent = geocentric::apparent_planet(Planet::Venus, jde);
+    let equatorial = EquatorialCoordinates::from_ecliptic(&apparent, jde);
+    assert!((equatorial.right_ascension_hours() - 21.078182).abs() < 2.0 / 3600.0);
+    assert!((equatorial.declination_deg() - -18.888011).abs() < 0.01);
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "earth")]
+#![cfg_attr(not(feature = "all_planets"), allow(unused_imports))]
+
+extern crate vsop87;
+use vsop87::*;
+
+#[cfg(all(
+    feature = "mercury",
+    feature = "venus",
+    feature = "mars",
+    feature = "jupiter",
+    feature = "neptune"
+))]
+#[test]
+fn it_next_opposition() {
+    // Mars, August 28th, 2003 at 17:56 UT.
+    let mars = events::next_opposition(Planet::Mars, 2452700.5).unwrap();
+    assert!((mars - 2452880.248).abs() < 0.01);
+
+    // Jupiter, November 28th, 2000.
+    let jupiter = events::next_opposition(Planet::Jupiter, 2451545.0).unwrap();
+    assert!(jupiter > 2451876.5 && jupiter < 2451877.5);
+
+    // Neptune, July 27th, 2000.
+    let neptune = events::next_opposition(Planet::Neptune, 2451545.0).unwrap();
+    assert!(neptune > 2451752.5 && neptune < 2451753.5);
+
+    // At opposition, the planet is opposite to the Sun, but for its latitude of -6.6°.
+    let elongation = geocentric::elongation(Planet::Mars, mars).to_degrees();
+    assert!((elongation - (180.0 - 6.6)).abs() < 0.2);
+
+    // The search only looks forward.
+    let next = events::next_opposition(Planet::Mars, mars + 1.0).unwrap();
+    assert!(next - mars > 700.0 && next - mars < 900.0);
+
+    assert_eq!(events::next_opposition(Planet::Mercury, 2451545.0), None);
+    assert_eq!(events::next_opposition(Planet::Venus, 2451545.0), None);
+    assert_eq!(events::next_opposition(Planet::Earth, 2451545.0), None);
+}
+
+#[cfg(all(feature = "mercury", feature = "venus", feature = "saturn"))]
+#[test]
+fn it_next_conjunction() {
+    // Inferior conjunction of Venus, during its transit of June 8th, 2004, at 8:43 UT.
+    let inferior = events::next_conjunction(Planet::Venus, 2453100.5).unwrap();
+    assert!((inferior - 2453164.864).abs() < 0.01);
+    assert!(geocentric::elongation(Planet::Venus, inferior).to_degrees() < 1.0);
+
+    // Superior conjunction of Venus, March 31st, 2005.
+    let superior = events::next_conjunction(Planet::Venus, inferior + 1.0).unwrap();
+    assert!(superior > 2453460.5 && superior < 2453461.5);
+
+    // Superior conjunction of Mercury, January 15th, 2000.
+    let mercury = events::next_conjunction(Planet::Mercury, 2451545.0).unwrap();
+    assert!(mercury > 2451558.5 && mercury < 2451560.5);
+
+    // Conjunction of Saturn, May 10th, 2000.
+    let saturn = events::next_conjunction(Planet::Saturn, 2451545.0).unwrap();
+    assert!(saturn > 2451674.5 && saturn < 2451675.5);
+
+    assert_eq!(events::next_conjunction(Planet::Earth, 2451545.0), None);
+}
+
+#[cfg(all(feature = "mercury", feature = "mars"))]
+#[test]
+fn it_next_station() {
+    // Mars was retrograde from September 9th, 2020 at 22:22 UT to November 14th, 2020 at 0:36 UT.
+    let (retrograde, station) = events::next_station(Planet::Mars, 2459000.5).unwrap();
+    assert_eq!(station, events::Station::Retrograde);
+    assert!((retrograde - 2459102.43).abs() < 0.1);
+
+    let (direct, station) = events::next_station(Planet::Mars, retrograde + 1.0).unwrap();
+    assert_eq!(station, events::Station::Direct);
+    assert!((direct - 2459167.53).abs() < 0.1);
+
+    // Between both stations, the opposition of October 13th, 2020.
+    let opposition = events::next_opposition(Planet::Mars, retrograde).unwrap();
+    assert!(opposition > retrograde && opposition < direct);
+
+    // Mercury started its retrograde motion on February 21st, 2000.
+    let (mercury, station) = events::next_station(Planet::Mercury, 2451545.0).unwrap();
+    assert_eq!(station, events::Station::Retrograde);
+    assert!(mercury > 2451595.5 && mercury < 2451596.5);
+
+    assert_eq!(events::next_station(Planet::Earth, 2451545.0), None);
+}
@@ -0,0 +1,82 @@
+#![cfg(not(feature = "no_std"))]
+#![cfg_attr(not(feature = "all_planets"), allow(unused_imports))]
+
+extern crate vsop87;
+use vsop87::*;
+
+use std::io::ErrorKind;
+
+#[cfg(feature = "jupiter")]
+#[test]
+fn it_write_csv() {
+    let mut buffer = Vec::new();
+    export::write_csv(&mut buffer, Planet::Jupiter, 2451545.0, 2451645.0, 25.0).unwrap();
+    let csv = String::from_utf8(buffer).unwrap();
+
+    let mut lines = csv.lines();
+    assert_eq!(
+        lines.next(),
+        Some("jd,longitude_deg,latitude_deg,distance_au")
+    );
+
+    let rows: Vec<Vec<f64>> = lines
+        .map(|line| {
+            line.split(',')
+                .map(|value| value.parse().unwrap())
+                .collect()
+        })
+        .collect();
+    assert_eq!(rows.len(), 5);
+    for (i, row) in rows.iter().enumerate() {
+        let jd = 2451545.0 + 25.0 * i as f64;
+        let coordinates = vsop87d::jupiter(jd);
+
+        assert_eq!(
+            row.as_slice(),
+            &[
+                jd,
+                coordinates.longitude_deg(),
+                coordinates.latitude_deg(),
+                coordinates.distance()
+            ]
+        );
+    }
+    assert!(csv.ends_with('\n'));
+
+    // Backwards.
+    let mut buffer = Vec::new();
+    export::write_csv(&mut buffer, Planet::Jupiter, 2451645.0, 2451545.0, -50.0).unwrap();
+    let csv = String::from_utf8(buffer).unwrap();
+    assert_eq!(csv.lines().count(), 4);
+    assert!(csv.lines().nth(3).unwrap().starts_with("2451545,"));
+}
+
+#[cfg(feature = "mars")]
+#[test]
+fn it_write_csv_invalid_step() {
+    for &step in [0.0, -1.0, f64::NAN, f64::INFINITY, f64::NEG_INFINITY].iter() {
+        let mut buffer = Vec::new();
+        let error =
+            export::write_csv(&mut buffer, Planet::Mars, 2451545.0, 2451555.0, step).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+        assert!(buffer.is_empty());
+    }
+
+    // Non-finite dates.
+    for &(start_jd, end_jd) in [
+        (f64::NAN, 2451555.0),
+        (2451545.0, f64::NAN),
+        (f64::NEG_INFINITY, 2451555.0),
+        (2451545.0, f64::INFINITY),
+    ]
+    .iter()
+    {
+        let mut buffer = Vec::new();
+        let error =
+            export::write_csv(&mut buffer, Planet::Mars, start_jd, end_jd, 1.0).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+        assert!(buffer.is_empty());
+    }
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "ffi")]
+
+extern crate vsop87;
+use vsop87::*;
+
+use std::ptr;
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_ffi_planet() {
+    let (mut lon, mut lat, mut dist) = (0.0, 0.0, 0.0);
+
+    for (index, &planet) in Planet::ALL.iter().enumerate() {
+        let status =
+            unsafe { ffi::vsop87d_planet(index as i32, 2451545.0, &mut lon, &mut lat, &mut dist) };
+        assert_eq!(status, ffi::VSOP87_OK);
+        assert_eq!(
+            (lon, lat, dist),
+            vsop87d::planet(planet, 2451545.0).as_tuple()
+        );
+
+        let status =
+            unsafe { ffi::vsop87a_planet(index as i32, 2451545.0, &mut lon, &mut lat, &mut dist) };
+        assert_eq!(status, ffi::VSOP87_OK);
+        assert_eq!(
+            (lon, lat, dist),
+            vsop87a::planet(planet, 2451545.0).as_tuple()
+        );
+    }
+
+    let status = unsafe { ffi::vsop87d_earth(2415020.0, &mut lon, &mut lat, &mut dist) };
+    assert_eq!(status, ffi::VSOP87_OK);
+    assert_eq!((lon, lat, dist), vsop87d::earth(2415020.0).as_tuple());
+
+    let status = unsafe { ffi::vsop87d_neptune(2415020.0, &mut lon, &mut lat, &mut dist) };
+    assert_eq!(status, ffi::VSOP87_OK);
+    assert_eq!((lon, lat, dist), vsop87d::neptune(2415020.0).as_tuple());
+}
+
+#[test]
+fn it_ffi_errors() {
+    let (mut lon, mut lat, mut dist) = (1.0, 2.0, 3.0);
+
+    for &index in [-1, 8, i32::MAX].iter() {
+        let status =
+            unsafe { ffi::vsop87d_planet(index, 2451545.0, &mut lon, &mut lat, &mut dist) };
+        assert_eq!(status, ffi::VSOP87_INVALID_PLANET);
+    }
+
+    let status = unsafe { ffi::vsop87d_planet(3, 2451545.0, &mut lon, ptr::null_mut(), &mut dist) };
+    assert_eq!(status, ffi::VSOP87_NULL_POINTER);
+    let status = unsafe { ffi::vsop87d_mars(2451545.0, ptr::null_mut(), &mut lat, &mut dist) };
+    assert_eq!(status, ffi::VSOP87_NULL_POINTER);
+
+    // Nothing is written on errors.
+    assert_eq!((lon, lat, dist), (1.0, 2.0, 3.0));
+}
@@ -0,0 +1,303 @@
+#![cfg(feature = "earth")]
+
+extern crate vsop87;
+use vsop87::*;
+
+#[cfg(all(feature = "venus", feature = "mars"))]
+#[test]
+fn it_planet() {
+    // Closest approach of Mars, 2003-08-27 09:51 UT, at 0.372719 AU.
+    let mars = geocentric::planet(Planet::Mars, 2452878.91);
+    assert!((mars.distance() - 0.372719).abs() < 1e-5);
+    assert!(mars.longitude() >= 0.0 && mars.longitude() < 2.0 * std::f64::consts::PI);
+
+    // The distance is at its minimum around that time.
+    assert!(geocentric::planet(Planet::Mars, 2452877.91).distance() > mars.distance());
+    assert!(geocentric::planet(Planet::Mars, 2452879.91).distance() > mars.distance());
+
+    // Opposition of Mars, 2003-08-28 17:56 UT: the geocentric longitude of Mars is the
+    // heliocentric longitude of the Earth.
+    let jde = 2452880.25;
+    let mars = geocentric::planet(Planet::Mars, jde);
+    assert!((mars.longitude() - vsop87d::earth(jde).longitude()).abs() < 0.001);
+
+    // Meeus, Astronomical Algorithms, example 33.a: geometric distance of Venus on
+    // 1992-12-20 0h TD, before the light-time correction.
+    let venus = geocentric::planet(Planet::Venus, 2448976.5);
+    assert!((venus.distance() - 0.910845).abs() < 2e-6);
+    assert!((venus.longitude().to_degrees() - 313.08).abs() < 0.01);
+    assert!((venus.latitude().to_degrees() - -2.085).abs() < 0.001);
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_apparent_planet() {
+    let jde = 2448976.5;
+
+    for &planet in &[
+        Planet::Mercury,
+        Planet::Venus,
+        Planet::Mars,
+        Planet::Jupiter,
+        Planet::Saturn,
+        Planet::Uranus,
+        Planet::Neptune,
+    ] {
+        let geometric = geocentric::planet(planet, jde);
+        let apparent = geocentric::apparent_planet(planet, jde);
+
+        // The planet is seen where it was when the light left it.
+        let light_time = 0.0057755183 * apparent.distance();
+        let retarded = vsop87c::planet(planet, jde - light_time);
+        let earth = vsop87c::earth(jde);
+        let distance = ((retarded.x - earth.x).powi(2)
+            + (retarded.y - earth.y).powi(2)
+            + (retarded.z - earth.z).powi(2))
+        .sqrt();
+        assert!((distance - apparent.distance()).abs() < 1e-9);
+
+        // The planets move less than a minute of arc during the light-time.
+        let shift = (apparent.longitude() - geometric.longitude()).abs();
+        assert!(shift > 0.0 && shift < 1_f64.to_radians() / 60.0);
+        assert!((apparent.latitude() - geometric.latitude()).abs() < 1_f64.to_radians() / 60.0);
+    }
+
+    // Meeus, Astronomical Algorithms, example 33.a.
+    let venus = geocentric::apparent_planet(Planet::Venus, jde);
+    assert!((venus.longitude().to_degrees() - 313.08102).abs() < 0.001);
+    assert!((venus.latitude().to_degrees() - -2.08474).abs() < 0.001);
+    assert!((venus.distance() - 0.910947).abs() < 1e-6);
+}
+
+#[cfg(all(feature = "mercury", feature = "venus", feature = "mars"))]
+#[test]
+fn it_elongation() {
+    // Greatest eastern elongation of Mercury, 18.2°, on 2020-02-10.
+    let jde = 2458889.5;
+    let elongation = geocentric::elongation(Planet::Mercury, jde);
+    assert!((elongation.to_degrees() - 18.2).abs() < 0.1);
+    assert!(geocentric::elongation(Planet::Mercury, jde - 3.0) < elongation);
+    assert!(geocentric::elongation(Planet::Mercury, jde + 3.0) < elongation);
+
+    // Greatest eastern elongation of Venus, 46.1°, on 2020-03-24.
+    let jde = 2458932.5;
+    let elongation = geocentric::elongation(Planet::Venus, jde);
+    assert!((elongation.to_degrees() - 46.1).abs() < 0.1);
+    assert!(geocentric::elongation(Planet::Venus, jde - 5.0) < elongation);
+    assert!(geocentric::elongation(Planet::Venus, jde + 5.0) < elongation);
+
+    // Opposition of Mars, 2003-08-28. The elongation is not exactly 180° because Mars was out of
+    // the ecliptic.
+    let jde = 2452880.25;
+    let elongation = geocentric::elongation(Planet::Mars, jde);
+    let latitude = geocentric::planet(Planet::Mars, jde).latitude().abs();
+    assert!((elongation - (std::f64::consts::PI - latitude)).abs() < 1e-3);
+}
+
+#[cfg(all(feature = "venus", feature = "jupiter"))]
+#[test]
+fn it_phase() {
+    // Venus goes through all its phases during its synodic period of 584 days.
+    let (mut min, mut max) = (1.0_f64, 0.0_f64);
+    for day in 0..584 {
+        let jde = 2458849.5 + f64::from(day);
+        let fraction = geocentric::illuminated_fraction(Planet::Venus, jde);
+        let phase_angle = geocentric::phase_angle(Planet::Venus, jde);
+
+        assert!((0.0..=std::f64::consts::PI).contains(&phase_angle));
+        assert!((fraction - (1.0 + phase_angle.cos()) / 2.0).abs() < 1e-12);
+
+        min = min.min(fraction);
+        max = max.max(fraction);
+    }
+    assert!(min < 0.01);
+    assert!(max > 0.99);
+
+    // Jupiter is always seen almost fully lit.
+    for month in 0..144 {
+        let jde = 2451545.0 + 30.0 * f64::from(month);
+        let fraction = geocentric::illuminated_fraction(Planet::Jupiter, jde);
+
+        assert!(fraction > 0.98 && fraction <= 1.0);
+        assert!(geocentric::phase_angle(Planet::Jupiter, jde).to_degrees() < 12.0);
+    }
+}
+
+#[test]
+fn it_aberration() {
+    let mut max_displacement = 0_f64;
+    for i in 0..73 {
+        let jde = 2451545.0 + f64::from(i) * 5.0;
+        let sun = vsop87d::earth(jde).longitude() + std::f64::consts::PI;
+
+        for j in 0..36 {
+            let object = SphericalCoordinates::new(f64::from(j) * 10_f64.to_radians(), 0.1, 1.0);
+            let apparent = geocentric::aberration(jde, &object);
+
+            let displacement = angular_separation(&object, &apparent).to_degrees() * 3600.0;
+            max_displacement = max_displacement.max(displacement);
+        }
+
+        // Towards the apex of the motion of the Earth, 90° west of the Sun, the displacement
+        // vanishes.
+        let apex = SphericalCoordinates::new(sun - std::f64::consts::FRAC_PI_2, 0.0, 1.0);
+        let apparent = geocentric::aberration(jde, &apex);
+        assert!(angular_separation(&apex, &apparent).to_degrees() * 3600.0 < 0.5);
+
+        // In the direction of the Sun, it is about κ.
+        let conjunction = SphericalCoordinates::new(sun, 0.0, 1.0);
+        let apparent = geocentric::aberration(jde, &conjunction);
+        let displacement = angular_separation(&conjunction, &apparent).to_degrees() * 3600.0;
+        assert!((displacement - geocentric::ABERRATION_CONSTANT).abs() < 0.5);
+    }
+
+    assert!(max_displacement > 20.0 && max_displacement < 21.0);
+}
+
+#[cfg(feature = "venus")]
+#[test]
+fn it_apparent() {
+    // Meeus, Astronomical Algorithms, example 33.a: Venus on December 20th, 1992 at 0h TD. The
+    // apparent position is α = 21h04m41.454s, δ = -18°53'16.84".
+    let jde = 2448976.5;
+    let (_, delta_epsilon) = nutation::nutation(jde);
+    let equatorial =
+        geocentric::apparent(Planet::Venus, jde).to_equatorial(mean_obliquity(jde) + delta_epsilon);
+
+    let right_ascension = 21.0 + 4.0 / 60.0 + 41.454 / 3600.0;
+    let declination = -(18.0 + 53.0 / 60.0 + 16.84 / 3600.0);
+
+    assert!((equatorial.right_ascension_hours() - right_ascension).abs() * 15.0 * 3600.0 < 0.2);
+    assert!((equatorial.declination_deg() - declination).abs() * 3600.0 < 0.2);
+}
+
+#[test]
+fn it_sun() {
+    // Around January 1st, the Sun is at a longitude of about 280°, and close to the perihelion.
+    let sun = geocentric::sun(2451545.0);
+    assert!((sun.longitude().to_degrees() - 280.37).abs() < 0.01);
+    assert!((sun.distance() - 0.98333).abs() < 1e-5);
+
+    // It is the opposite of the heliocentric position of the Earth.
+    for i in 0..12 {
+        let jde = 2451545.0 + f64::from(i) * 30.0;
+        let sun = geocentric::sun(jde);
+        let earth = vsop87d::earth(jde);
+
+        let separation = angular_separation(&sun, &earth).to_degrees();
+        assert!((separation - 180.0).abs() < 1e-5);
+        assert_eq!(sun.distance(), earth.distance());
+        assert!((0.0..2.0 * std::f64::consts::PI).contains(&sun.longitude()));
+    }
+
+    // Meeus, Astronomical Algorithms, example 25.b: the apparent longitude is 199°54'21.82", with
+    // the truncated series used in the book.
+    let apparent = geocentric::apparent_sun(2448908.5);
+    assert!((apparent.longitude().to_degrees() - 199.906061).abs() < 1e-4);
+    assert!((apparent.latitude() - geocentric::sun(2448908.5).latitude()).abs() < 1e-10);
+
+    // The apparent Sun is about 20.5" behind, but for the nutation.
+    let (delta_psi, _) = nutation::nutation(2451545.0);
+    let apparent = geocentric::apparent_sun(2451545.0);
+    let difference = (apparent.longitude() - sun.longitude() - delta_psi).to_degrees() * 3600.0;
+    assert!((difference - -20.5 / sun.distance()).abs() < 0.5);
+}
+
+#[cfg(all(feature = "venus", feature = "saturn"))]
+#[test]
+fn it_apparent_equatorial() {
+    // Meeus, Astronomical Algorithms, example 33.a: Venus on December 20th, 1992 at 0h TD, at
+    // α = 21h04m41.454s and δ = -18°53'16.84".
+    let venus = geocentric::apparent_equatorial(Planet::Venus, 2448976.5);
+    let right_ascension = (21.0 + 4.0 / 60.0 + 41.454 / 3600.0) * 15.0;
+    let declination = -(18.0 + 53.0 / 60.0 + 16.84 / 3600.0);
+
+    assert!((venus.right_ascension_deg() - right_ascension).abs() * 3600.0 < 2.0);
+    assert!((venus.declination_deg() - declination).abs() * 3600.0 < 2.0);
+    assert!((venus.distance() - 0.910947).abs() < 1e-5);
+
+    // It is the apparent ecliptic position, converted with the true obliquity.
+    let jde = 2459205.25;
+    let obliquity = mean_obliquity(jde) + nutation::nutation(jde).1;
+    assert_eq!(
+        geocentric::apparent_equatorial(Planet::Saturn, jde),
+        geocentric::apparent(Planet::Saturn, jde).to_equatorial(obliquity)
+    );
+}
+
+#[cfg(all(feature = "mars", feature = "jupiter"))]
+#[test]
+fn it_distance() {
+    assert_eq!(geocentric::distance(Planet::Earth, 2451545.0), 0.0);
+    assert_eq!(
+        geocentric::distance_light_time(Planet::Earth, 2451545.0),
+        0.0
+    );
+
+    // It is the distance of the spherical coordinates.
+    let jupiter = geocentric::planet(Planet::Jupiter, 2451545.0);
+    assert!((geocentric::distance(Planet::Jupiter, 2451545.0) - jupiter.distance()).abs() < 1e-14);
+
+    // Mars ranges between about 0.37 AU, at a close opposition, and 2.67 AU, at a conjunction
+    // close to its aphelion.
+    let (mut min, mut max) = (f64::MAX, 0.0_f64);
+    for day in 0..(17 * 365) {
+        let distance = geocentric::distance(Planet::Mars, 2451545.0 + f64::from(day));
+        min = min.min(distance);
+        max = max.max(distance);
+    }
+    assert!(min > 0.37 && min < 0.38);
+    assert!(max > 2.6 && max < 2.68);
+
+    // The light-time is the distance divided by the speed of light.
+    let distance = geocentric::distance(Planet::Mars, 2451545.0);
+    let light_time = geocentric::distance_light_time(Planet::Mars, 2451545.0);
+    assert!((light_time * SPEED_OF_LIGHT_AU_PER_DAY - distance).abs() < 1e-15);
+}
+
+#[test]
+fn it_equation_of_time() {
+    // Example 28.a from Astronomical Algorithms: 13m42.6s.
+    assert!((geocentric::equation_of_time(2448908.5) - 13.710).abs() < 0.005);
+
+    // Finds the day the equation of time changes sign between the given days of 2024.
+    let crossing = |(first, last): (f64, f64)| {
+        let mut day = first;
+        while day < last {
+            let jde = julian_day(2024, 1, day);
+            if geocentric::equation_of_time(jde).signum()
+                != geocentric::equation_of_time(jde + 1.0).signum()
+            {
+                return day;
+            }
+            day += 1.0;
+        }
+        panic!("no zero crossing between the given days");
+    };
+
+    // Mid-April, and mid-June.
+    assert!((crossing((91.0, 121.0)) - 106.0).abs() < 2.0);
+    assert!((crossing((152.0, 182.0)) - 165.0).abs() < 2.0);
+
+    // The maximum, of about 16.4 minutes, is at the beginning of November.
+    let (day, maximum) = (280_i32..340)
+        .map(|day| {
+            let minutes = geocentric::equation_of_time(julian_day(2024, 1, f64::from(day)));
+            (day, minutes)
+        })
+        .fold((0, f64::MIN), |best, current| {
+            if current.1 > best.1 {
+                current
+            } else {
+                best
+            }
+        });
+    assert!((maximum - 16.4).abs() < 0.1);
+    assert!((day - 308).abs() < 3);
+
+    // The minimum, of about −14.2 minutes, is in mid-February.
+    let minimum = (30..60)
+        .map(|day| geocentric::equation_of_time(julian_day(2024, 1, f64::from(day))))
+        .fold(f64::MAX, f64::min);
+    assert!((minimum - -14.2).abs() < 0.1);
+}
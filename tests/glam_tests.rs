@@ -0,0 +1,34 @@
+#![cfg(feature = "glam")]
+
+extern crate vsop87;
+use vsop87::*;
+
+#[cfg(feature = "jupiter")]
+#[test]
+fn it_glam() {
+    let coordinates = vsop87a::jupiter(2451545.0);
+
+    let vector = glam::DVec3::from(coordinates);
+    assert_eq!(vector.x, coordinates.x);
+    assert_eq!(vector.y, coordinates.y);
+    assert_eq!(vector.z, coordinates.z);
+    assert_eq!(RectangularCoordinates::from(vector), coordinates);
+
+    let single: glam::Vec3 = coordinates.into();
+    assert_eq!(single.x, coordinates.x as f32);
+    assert_eq!(single.y, coordinates.y as f32);
+    assert_eq!(single.z, coordinates.z as f32);
+
+    let back = RectangularCoordinates::from(single);
+    assert!((back - coordinates).norm() < 1e-6);
+}
+
+#[cfg(feature = "jupiter")]
+#[test]
+fn it_glam_translation_matrix() {
+    let coordinates = vsop87a::jupiter(2451545.0);
+    let matrix = glam::DMat4::from_cols_array_2d(&coordinates.to_translation_matrix_scaled(10.0));
+
+    let position = matrix.transform_point3(glam::DVec3::ZERO);
+    assert_eq!(position, glam::DVec3::from(coordinates) * 10.0);
+}
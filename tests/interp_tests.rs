@@ -0,0 +1,51 @@
+#![cfg(not(feature = "no_std"))]
+#![cfg_attr(not(feature = "all_planets"), allow(unused_imports))]
+
+extern crate vsop87;
+use vsop87::*;
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_cheb_fit() {
+    // A 30-day window of each planet, including Mercury, the fastest one.
+    for &planet in &Planet::ALL {
+        let fit = interp::ChebFit::new(planet, 2451545.0, 2451575.0, 16);
+        assert_eq!(fit.planet(), planet);
+        assert_eq!((fit.start(), fit.end()), (2451545.0, 2451575.0));
+        assert_eq!(fit.degree(), 16);
+
+        for step in 0..=120 {
+            let jde = 2451545.0 + f64::from(step) * 0.25;
+            let fitted = fit.at(jde);
+            let direct = vsop87d::planet(planet, jde);
+
+            assert!(angular_separation(&fitted, &direct).to_degrees() * 3600.0 < 1e-3);
+            assert!((fitted.distance() - direct.distance()).abs() < 1e-9);
+            assert!((0.0..2.0 * std::f64::consts::PI).contains(&fitted.longitude()));
+        }
+    }
+}
+
+#[cfg(feature = "earth")]
+#[test]
+fn it_cheb_fit_wrap() {
+    // The longitude of the Earth goes through 0 around the September equinox.
+    let fit = interp::ChebFit::new(Planet::Earth, 2451790.0, 2451800.0, 12);
+    let mut crossed = false;
+    for step in 0..=100 {
+        let jde = 2451790.0 + f64::from(step) * 0.1;
+        let fitted = fit.at(jde);
+        let direct = vsop87d::earth(jde);
+
+        assert!(angular_separation(&fitted, &direct).to_degrees() * 3600.0 < 1e-3);
+        crossed |= direct.longitude() > 6.0;
+    }
+    assert!(crossed);
+}
+
+#[cfg(feature = "mars")]
+#[test]
+#[should_panic(expected = "the end of the window must be after its start")]
+fn it_cheb_fit_empty_window() {
+    let _ = interp::ChebFit::new(Planet::Mars, 2451545.0, 2451545.0, 8);
+}
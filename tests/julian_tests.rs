@@ -0,0 +1,68 @@
+extern crate vsop87;
+use vsop87::*;
+
+#[test]
+fn it_julian_day() {
+    // Examples from Meeus, Astronomical Algorithms, chapter 7.
+    assert_eq!(julian_day(2000, 1, 1.5), 2451545.0);
+    assert_eq!(julian_day(1999, 1, 1.0), 2451179.5);
+    assert_eq!(julian_day(1987, 1, 27.0), 2446822.5);
+    assert_eq!(julian_day(1987, 6, 19.5), 2446966.0);
+    assert_eq!(julian_day(1988, 1, 27.0), 2447187.5);
+    assert_eq!(julian_day(1988, 6, 19.5), 2447332.0);
+    assert_eq!(julian_day(1900, 1, 1.0), 2415020.5);
+    assert_eq!(julian_day(1600, 1, 1.0), 2305447.5);
+    assert_eq!(julian_day(1600, 12, 31.0), 2305812.5);
+    assert_eq!(julian_day(837, 4, 10.3), 2026871.8);
+    assert_eq!(julian_day(-123, 12, 31.0), 1676496.5);
+    assert_eq!(julian_day(-122, 1, 1.0), 1676497.5);
+    assert_eq!(julian_day(-1000, 7, 12.5), 1356001.0);
+    assert_eq!(julian_day(-1000, 2, 29.0), 1355866.5);
+    assert_eq!(julian_day(-1001, 8, 17.9), 1355671.4);
+    assert_eq!(julian_day(-4712, 1, 1.5), 0.0);
+
+    // The Gregorian reform: October 4th, 1582 was followed by October 15th, 1582.
+    assert_eq!(julian_day(1582, 10, 15.0) - julian_day(1582, 10, 4.0), 1.0);
+
+    // Dates used in the documentation examples.
+    assert_eq!(julian_day(1599, 12, 29.5), 2305445.0);
+    assert_eq!(julian_day(1399, 12, 19.5), 2232395.0);
+}
+
+#[test]
+fn it_calendar_date() {
+    assert_eq!(calendar_date(2451545.0), (2000, 1, 1.5));
+    assert_eq!(calendar_date(2299160.5), (1582, 10, 15.0));
+    assert_eq!(calendar_date(2299159.5), (1582, 10, 4.0));
+    assert_eq!(calendar_date(1842713.0), (333, 1, 27.5));
+    assert_eq!(calendar_date(1507900.13).0, -584);
+    assert_eq!(calendar_date(1507900.13).1, 5);
+    assert!((calendar_date(1507900.13).2 - 28.63).abs() < 1e-8);
+    assert_eq!(calendar_date(0.0), (-4712, 1, 1.5));
+}
+
+#[test]
+fn it_julian_round_trip() {
+    let mut jd = 0.25;
+    while jd < 3_000_000.0 {
+        let (year, month, day) = calendar_date(jd);
+        assert!((julian_day(year, month, day) - jd).abs() < 1e-8);
+        jd += 1234.5678;
+    }
+}
+
+#[test]
+fn it_modified_julian_day() {
+    assert_eq!(jd_to_mjd(2451545.0), 51544.5);
+    assert_eq!(jd_to_mjd(julian_day(1858, 11, 17.0)), 0.0);
+    assert_eq!(jd_to_mjd(julian_day(2023, 2, 25.0)), 60000.0);
+    assert_eq!(mjd_to_jd(0.0), 2400000.5);
+
+    // A Modified Julian Day starts at midnight.
+    let (year, month, day) = calendar_date(mjd_to_jd(51544.0));
+    assert_eq!((year, month, day), (2000, 1, 1.0));
+
+    for &jd in [0.0, 2299160.5, 2451545.0, 3912545.0].iter() {
+        assert_eq!(mjd_to_jd(jd_to_mjd(jd)), jd);
+    }
+}
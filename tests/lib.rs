@@ -2,6 +2,7 @@ extern crate vsop87;
 
 use vsop87::*;
 
+#[cfg(feature = "mercury")]
 #[test]
 fn it_kepler() {
     let vsop87_elts = vsop87::mercury(2451545.0);
@@ -20,9 +21,10 @@ fn it_kepler() {
     assert!(elements.inclination() > 0.122260 && elements.inclination() < 0.122261);
     assert!(elements.ascending_node() > 0.843525 && elements.ascending_node() < 0.843527);
     assert!(elements.periapsis() > 1.35183 && elements.periapsis() < 1.35185);
-    assert!(elements.mean_anomaly() > 4.40259 && elements.mean_anomaly() < 4.40261);
+    assert!(elements.mean_anomaly() > 3.05075 && elements.mean_anomaly() < 3.05077);
 }
 
+#[cfg(feature = "mercury")]
 #[test]
 fn it_convert() {
     let vsop87_elts = vsop87::mercury(2451545.0);
@@ -44,3 +46,927 @@ fn it_convert() {
     assert!(new_vsop87_elts.q > 0.0406161540 && new_vsop87_elts.q < 0.0406161542);
     assert!(new_vsop87_elts.p > 0.04563512 && new_vsop87_elts.p < 0.04563588);
 }
+
+#[cfg(feature = "mars")]
+#[test]
+fn it_direction_cosines() {
+    for &jde in &[2451545.0, 2415020.0, 2378495.0, 2232395.0] {
+        let coordinates = vsop87d::mars(jde);
+        let (x, y, z) = coordinates.direction_cosines();
+
+        assert!((x * x + y * y + z * z - 1.0).abs() < 1e-15);
+        assert!((z - coordinates.latitude().sin()).abs() < 1e-15);
+    }
+}
+
+#[cfg(feature = "saturn")]
+#[test]
+fn it_index_variable() {
+    let coordinates = vsop87d::saturn(2451545.0);
+
+    assert_eq!(coordinates[Variable::Longitude], coordinates.longitude());
+    assert_eq!(coordinates[Variable::Latitude], coordinates.latitude());
+    assert_eq!(coordinates[Variable::Distance], coordinates.distance());
+
+    for &variable in &[Variable::Longitude, Variable::Latitude, Variable::Distance] {
+        assert_eq!(coordinates.get(variable), coordinates[variable]);
+    }
+}
+
+#[cfg(feature = "earth")]
+#[cfg(not(feature = "no_std"))]
+#[test]
+fn it_write_json() {
+    let coordinates = vsop87d::earth(2305445.0);
+    let mut output = Vec::new();
+
+    coordinates.write_json(&mut output).unwrap();
+
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        format!(
+            "{{\"longitude\":{},\"latitude\":{},\"distance\":{}}}",
+            coordinates.longitude(),
+            coordinates.latitude(),
+            coordinates.distance()
+        )
+    );
+
+    let mut output = Vec::new();
+    vsop87b::earth(2451545.0).write_json(&mut output).unwrap();
+    let output = String::from_utf8(output).unwrap();
+
+    assert!(output.starts_with("{\"longitude\":1.75192"));
+    assert!(output.contains(",\"latitude\":-0.0000039"));
+    assert!(output.contains(",\"distance\":0.98332"));
+    assert!(output.ends_with('}'));
+
+    let mut output = Vec::new();
+    SphericalCoordinates::new(f64::NAN, f64::INFINITY, f64::NEG_INFINITY)
+        .write_json(&mut output)
+        .unwrap();
+
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "{\"longitude\":null,\"latitude\":null,\"distance\":null}"
+    );
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_to_rectangular() {
+    let dates = [2451545.0, 2415020.5, 2488069.5, 2378496.5];
+
+    for &jde in &dates {
+        let pairs = [
+            (vsop87d::mercury(jde), vsop87c::mercury(jde)),
+            (vsop87d::venus(jde), vsop87c::venus(jde)),
+            (vsop87d::earth(jde), vsop87c::earth(jde)),
+            (vsop87d::mars(jde), vsop87c::mars(jde)),
+            (vsop87d::jupiter(jde), vsop87c::jupiter(jde)),
+            (vsop87d::saturn(jde), vsop87c::saturn(jde)),
+            (vsop87d::uranus(jde), vsop87c::uranus(jde)),
+            (vsop87d::neptune(jde), vsop87c::neptune(jde)),
+            (vsop87b::mercury(jde), vsop87a::mercury(jde)),
+            (vsop87b::venus(jde), vsop87a::venus(jde)),
+            (vsop87b::earth(jde), vsop87a::earth(jde)),
+            (vsop87b::mars(jde), vsop87a::mars(jde)),
+            (vsop87b::jupiter(jde), vsop87a::jupiter(jde)),
+            (vsop87b::saturn(jde), vsop87a::saturn(jde)),
+            (vsop87b::uranus(jde), vsop87a::uranus(jde)),
+            (vsop87b::neptune(jde), vsop87a::neptune(jde)),
+        ];
+
+        for (spherical, expected) in &pairs {
+            let rectangular = spherical.to_rectangular();
+            let tolerance = 1e-7 * spherical.distance();
+
+            assert!((rectangular.x - expected.x).abs() < tolerance);
+            assert!((rectangular.y - expected.y).abs() < tolerance);
+            assert!((rectangular.z - expected.z).abs() < tolerance);
+        }
+    }
+}
+
+#[cfg(all(
+    feature = "venus",
+    feature = "earth",
+    feature = "mars",
+    feature = "saturn",
+    feature = "neptune"
+))]
+#[test]
+fn it_to_spherical() {
+    // At J2000 the equinox of the day and the J2000 equinox are the same.
+    let spherical = vsop87a::earth(2451545.0).to_spherical();
+    let expected = vsop87d::earth(2451545.0);
+
+    assert!((spherical.longitude() - expected.longitude()).abs() < 1e-7);
+    assert!((spherical.latitude() - expected.latitude()).abs() < 1e-7);
+    assert!((spherical.distance() - expected.distance()).abs() < 1e-7);
+
+    let dates = [2451545.0, 2415020.5, 2488069.5, 2378496.5];
+
+    for &jde in &dates {
+        let pairs = [
+            (vsop87a::earth(jde), vsop87b::earth(jde)),
+            (vsop87a::mars(jde), vsop87b::mars(jde)),
+            (vsop87a::saturn(jde), vsop87b::saturn(jde)),
+            (vsop87c::earth(jde), vsop87d::earth(jde)),
+            (vsop87c::venus(jde), vsop87d::venus(jde)),
+            (vsop87c::neptune(jde), vsop87d::neptune(jde)),
+        ];
+
+        for (rectangular, expected) in &pairs {
+            let spherical = rectangular.to_spherical();
+
+            assert!((spherical.longitude() - expected.longitude()).abs() < 1e-7);
+            assert!((spherical.latitude() - expected.latitude()).abs() < 1e-7);
+            assert!(
+                (spherical.distance() - expected.distance()).abs() < 1e-7 * expected.distance()
+            );
+        }
+    }
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_planet_dispatch() {
+    let jde = 2451545.0;
+
+    for &planet in &Planet::ALL {
+        let (a, b, c, d, e) = match planet {
+            Planet::Mercury => (
+                vsop87a::mercury(jde),
+                vsop87b::mercury(jde),
+                vsop87c::mercury(jde),
+                vsop87d::mercury(jde),
+                vsop87e::mercury(jde),
+            ),
+            Planet::Venus => (
+                vsop87a::venus(jde),
+                vsop87b::venus(jde),
+                vsop87c::venus(jde),
+                vsop87d::venus(jde),
+                vsop87e::venus(jde),
+            ),
+            Planet::Earth => (
+                vsop87a::earth(jde),
+                vsop87b::earth(jde),
+                vsop87c::earth(jde),
+                vsop87d::earth(jde),
+                vsop87e::earth(jde),
+            ),
+            Planet::Mars => (
+                vsop87a::mars(jde),
+                vsop87b::mars(jde),
+                vsop87c::mars(jde),
+                vsop87d::mars(jde),
+                vsop87e::mars(jde),
+            ),
+            Planet::Jupiter => (
+                vsop87a::jupiter(jde),
+                vsop87b::jupiter(jde),
+                vsop87c::jupiter(jde),
+                vsop87d::jupiter(jde),
+                vsop87e::jupiter(jde),
+            ),
+            Planet::Saturn => (
+                vsop87a::saturn(jde),
+                vsop87b::saturn(jde),
+                vsop87c::saturn(jde),
+                vsop87d::saturn(jde),
+                vsop87e::saturn(jde),
+            ),
+            Planet::Uranus => (
+                vsop87a::uranus(jde),
+                vsop87b::uranus(jde),
+                vsop87c::uranus(jde),
+                vsop87d::uranus(jde),
+                vsop87e::uranus(jde),
+            ),
+            Planet::Neptune => (
+                vsop87a::neptune(jde),
+                vsop87b::neptune(jde),
+                vsop87c::neptune(jde),
+                vsop87d::neptune(jde),
+                vsop87e::neptune(jde),
+            ),
+        };
+
+        assert_eq!(vsop87a::planet(planet, jde), a);
+        assert_eq!(vsop87b::planet(planet, jde), b);
+        assert_eq!(vsop87c::planet(planet, jde), c);
+        assert_eq!(vsop87d::planet(planet, jde), d);
+        assert_eq!(vsop87e::planet(planet, jde), e);
+    }
+}
+
+#[cfg(all(feature = "jupiter", feature = "saturn"))]
+#[test]
+fn it_horner_regression() {
+    let coordinates = vsop87a::jupiter(2451545.0);
+    assert!(coordinates.x > 4.0011740267 && coordinates.x < 4.0011740269);
+    assert!(coordinates.y > 2.9385810076 && coordinates.y < 2.9385810078);
+    assert!(coordinates.z > -0.101786 && coordinates.z < -0.101782);
+
+    let coordinates = vsop87a::saturn(2122820.0);
+    assert!(coordinates.x > -7.9395559174 && coordinates.x < -7.9395559172);
+    assert!(coordinates.y > -5.8435867017 && coordinates.y < -5.8435867015);
+    assert!(coordinates.z > 0.416558 && coordinates.z < 0.416562);
+
+    let coordinates = vsop87d::jupiter(2232395.0);
+    assert!(coordinates.longitude() > 3.0889515349 && coordinates.longitude() < 3.0889515351);
+    assert!(coordinates.latitude() > 0.0231157946 && coordinates.latitude() < 0.0231157948);
+    assert!(coordinates.distance() > 5.44915664 && coordinates.distance() < 5.44915740);
+
+    let coordinates = vsop87d::saturn(2195870.0);
+    assert!(coordinates.longitude() > 2.2948875822 && coordinates.longitude() < 2.2948875824);
+    assert!(coordinates.latitude() > 0.0178533696 && coordinates.latitude() < 0.0178533698);
+    assert!(coordinates.distance() > 9.18575957 && coordinates.distance() < 9.18576033);
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_to_f32() {
+    for &jde in &[2451545.0, 2232395.0, 2488069.5] {
+        for &planet in &Planet::ALL {
+            let double = vsop87d::planet(planet, jde);
+            let single = vsop87d::planet_f32(planet, jde);
+
+            assert_eq!(single, double.to_f32());
+            assert!((f64::from(single.longitude()) - double.longitude()).abs() < 5e-7);
+            assert!((f64::from(single.latitude()) - double.latitude()).abs() < 5e-8);
+            assert!(
+                (f64::from(single.distance()) - double.distance()).abs() < 1e-7 * double.distance()
+            );
+
+            let single = vsop87b::planet_f32(planet, jde);
+            assert_eq!(single, vsop87b::planet(planet, jde).to_f32());
+            assert_eq!(single[Variable::Distance], single.distance());
+        }
+    }
+}
+
+#[cfg(all(feature = "earth", feature = "mars"))]
+#[test]
+fn it_vector_ops() {
+    let mars = vsop87a::mars(2451545.0);
+    let earth = vsop87a::earth(2451545.0);
+
+    let sum = mars + earth;
+    assert_eq!(sum.x, mars.x + earth.x);
+    assert_eq!(sum.y, mars.y + earth.y);
+    assert_eq!(sum.z, mars.z + earth.z);
+    assert_eq!(sum - earth, mars + earth - earth);
+
+    let geocentric = mars - earth;
+    assert_eq!(geocentric.x, mars.x - earth.x);
+    assert_eq!(geocentric.y, mars.y - earth.y);
+    assert_eq!(geocentric.z, mars.z - earth.z);
+
+    let scaled = mars * 2.0;
+    assert_eq!(scaled.x, mars.x * 2.0);
+    assert_eq!(scaled / 2.0, mars);
+
+    // The norm is the heliocentric distance.
+    assert!((mars.norm() - vsop87b::mars(2451545.0).distance()).abs() < 1e-6);
+    let unit = earth / earth.norm();
+    assert!((unit.norm() - 1.0).abs() < 1e-15);
+
+    // The dot product is commutative.
+    assert_eq!(mars.dot(&earth), earth.dot(&mars));
+    assert!((mars.dot(&mars) - mars.norm() * mars.norm()).abs() < 1e-12);
+
+    // The cross product is anticommutative, and perpendicular to both vectors.
+    let cross = mars.cross(&earth);
+    let reverse = earth.cross(&mars);
+    assert_eq!(cross.x, -reverse.x);
+    assert_eq!(cross.y, -reverse.y);
+    assert_eq!(cross.z, -reverse.z);
+    assert!(cross.dot(&mars).abs() < 1e-12);
+    assert!(cross.dot(&earth).abs() < 1e-12);
+    assert_eq!(mars.cross(&mars).norm(), 0.0);
+
+    let x = RectangularCoordinates {
+        x: 1.0,
+        y: 0.0,
+        z: 0.0,
+    };
+    let y = RectangularCoordinates {
+        x: 0.0,
+        y: 1.0,
+        z: 0.0,
+    };
+    assert_eq!(
+        x.cross(&y),
+        RectangularCoordinates {
+            x: 0.0,
+            y: 0.0,
+            z: 1.0
+        }
+    );
+}
+
+#[cfg(feature = "mars")]
+#[test]
+fn it_angular_separation() {
+    use std::f64::consts::PI;
+
+    let mars = vsop87d::mars(2451545.0);
+    assert_eq!(angular_separation(&mars, &mars), 0.0);
+
+    // The distance is ignored.
+    let far = SphericalCoordinates::new(mars.longitude(), mars.latitude(), 10.0);
+    assert_eq!(angular_separation(&mars, &far), 0.0);
+
+    // Antipodal directions.
+    let a = SphericalCoordinates::new(0.3, 0.4, 1.0);
+    let b = SphericalCoordinates::new(0.3 + PI, -0.4, 1.0);
+    assert!((angular_separation(&a, &b) - PI).abs() < 1e-12);
+
+    // Poles and the ecliptic.
+    let north = SphericalCoordinates::new(0.0, PI / 2.0, 1.0);
+    let south = SphericalCoordinates::new(2.0, -PI / 2.0, 1.0);
+    let equinox = SphericalCoordinates::new(0.0, 0.0, 1.0);
+    assert!((angular_separation(&north, &south) - PI).abs() < 1e-12);
+    assert!((angular_separation(&north, &equinox) - PI / 2.0).abs() < 1e-12);
+
+    // Along the ecliptic the separation is the difference in longitude, also across 0.
+    let a = SphericalCoordinates::new(6.2, 0.0, 1.0);
+    let b = SphericalCoordinates::new(0.1, 0.0, 1.0);
+    assert!((angular_separation(&a, &b) - (0.1 + 2.0 * PI - 6.2)).abs() < 1e-12);
+    assert_eq!(angular_separation(&a, &b), angular_separation(&b, &a));
+}
+
+#[cfg(feature = "earth")]
+#[test]
+fn it_calculate_var() {
+    let jde = 2448976.5;
+    let t = calculate_t(jde);
+    assert!((t - -0.007032169747).abs() < 1e-12);
+
+    // The distance of the Earth, computed series by series.
+    let distance = vsop87d::EARTH
+        .distance
+        .iter()
+        .rev()
+        .fold(0.0, |distance, [a, b, c]| {
+            distance * t + calculate_var(t, a, b, c)
+        });
+    assert!((distance - vsop87d::earth(jde).distance()).abs() < 1e-12);
+
+    assert_eq!(calculate_var(t, &[], &[], &[]), 0.0);
+}
+
+#[test]
+fn it_calculate_var_cosine() {
+    // Every term in its own lane, so that the vectorized cosine is checked on its own, in every
+    // quadrant, and for the largest arguments of the series.
+    let mut angles = vec![0.0, 1e-300, -0.5, 0.785, 0.786, 1.57, 3.15, 4.71, 6.3];
+    for i in 0..4_000 {
+        let i = f64::from(i);
+        angles.push((i * 0.618_034).fract() * 2e5 - 1e5);
+        angles.push(i * std::f64::consts::FRAC_PI_4);
+    }
+
+    for &angle in &angles {
+        for lane in 0..4 {
+            let mut a = [0.0; 4];
+            let mut b = [0.0; 4];
+            a[lane] = 1.0;
+            b[lane] = angle;
+
+            let value = calculate_var(0.0, &a, &b, &[0.0; 4]);
+            assert!(
+                (value - angle.cos()).abs() < 5e-16,
+                "cos({}) = {}",
+                angle,
+                value
+            );
+        }
+    }
+
+    // The frequencies are multiplied by the time before the cosine.
+    let value = calculate_var(-3.5, &[1.0; 4], &[0.1; 4], &[26_000.0; 4]);
+    assert!((value - 4.0 * (0.1 - 3.5 * 26_000.0_f64).cos()).abs() < 1e-14);
+}
+
+#[test]
+fn it_calculate_var_large_arguments() {
+    // Around and beyond the largest arguments of the VSOP87 series, where the reduction of the
+    // vectorized cosine is no longer exact, the terms must still be as accurate as with the scalar
+    // cosine.
+    for &frequency in [3e5, 4.1e5, 1e6, 1e7, 1.5e8, 1e9, 3e10, 1e12].iter() {
+        for &t in [-4.0, -1.0, 0.3, 2.5].iter() {
+            let b = [0_f64, 0.5, 1.0, 1.5];
+            let c = [frequency, frequency * 1.1, -frequency, frequency * 0.9];
+            let expected = b
+                .iter()
+                .zip(c.iter())
+                .fold(0_f64, |sum, (b, c)| sum + (b + c * t).cos());
+
+            let value = calculate_var(t, &[1.0; 4], &b, &c);
+            assert!(
+                (value - expected).abs() < 1e-15,
+                "C = {}, t = {}: {} instead of {}",
+                frequency,
+                t,
+                value,
+                expected
+            );
+        }
+    }
+}
+
+#[cfg(feature = "high_precision")]
+#[test]
+fn it_calculate_var_compensated() {
+    // Large terms that cancel each other: a naive sum loses the small ones.
+    let a = [1e16, 1.0, -1e16, 1.0];
+    assert_eq!(calculate_var(0.0, &a, &[0.0; 4], &[0.0; 4]), 2.0);
+}
+
+#[test]
+#[should_panic]
+fn it_calculate_var_mismatched_columns() {
+    let _ = calculate_var(0.0, &[1.0, 2.0], &[0.0], &[0.0, 0.0]);
+}
+
+#[cfg(feature = "earth")]
+#[test]
+fn it_earth_moon_offset() {
+    // Example 47.a from Astronomical Algorithms, by Jean Meeus.
+    let jde = 2448724.5;
+    let moon = SphericalCoordinates::new(
+        133.162655_f64.to_radians(),
+        -3.229126_f64.to_radians(),
+        368409.7 / 149597870.7,
+    );
+
+    let offset = earth_moon_offset(&moon);
+    assert!((offset.norm() * 149597870.7 - 4476.0).abs() < 1.0);
+
+    let vsop87a_offset = vsop87a::earth_moon(jde) - vsop87a::earth(jde);
+    assert!((offset - vsop87a_offset).norm() < 1e-7);
+}
+
+#[cfg(not(feature = "no_std"))]
+#[test]
+fn it_display() {
+    let spherical =
+        SphericalCoordinates::new(123.456789_f64.to_radians(), -1.5_f64.to_radians(), 1.0);
+    assert_eq!(
+        spherical.to_string(),
+        "longitude 123.456789°, latitude -1.500000°, distance 1.00000000 AU"
+    );
+    assert_eq!(
+        format!("{:.2}", spherical),
+        "longitude 123.46°, latitude -1.50°, distance 1.00000000 AU"
+    );
+    assert_eq!(
+        format!("{:#}", spherical),
+        "longitude 123°27′24.44″, latitude -1°30′00.00″, distance 1.00000000 AU"
+    );
+    assert_eq!(
+        format!("{:#.0}", spherical),
+        "longitude 123°27′24″, latitude -1°30′00″, distance 1.00000000 AU"
+    );
+
+    // The rounding of the arcseconds is carried to the arcminutes and degrees.
+    let carry = SphericalCoordinates::new(10.999_999_9_f64.to_radians(), 0.0, 5.2);
+    assert_eq!(
+        format!("{:#}", carry),
+        "longitude 11°00′00.00″, latitude 0°00′00.00″, distance 5.20000000 AU"
+    );
+
+    let rectangular = RectangularCoordinates {
+        x: 1.0,
+        y: -0.5,
+        z: 0.0000123456,
+    };
+    assert_eq!(
+        rectangular.to_string(),
+        "x 1.00000000 AU, y -0.50000000 AU, z 0.00001235 AU"
+    );
+    assert_eq!(
+        format!("{:.3}", rectangular),
+        "x 1.000 AU, y -0.500 AU, z 0.000 AU"
+    );
+
+    let equatorial = spherical.to_equatorial(0.0);
+    assert_eq!(
+        equatorial.to_string(),
+        "right ascension 8.230453h, declination -1.500000°, distance 1.00000000 AU"
+    );
+    assert_eq!(
+        format!("{:#}", equatorial),
+        "right ascension 8h13m49.63s, declination -1°30′00.00″, distance 1.00000000 AU"
+    );
+}
+
+#[cfg(feature = "venus")]
+#[test]
+fn it_degree_accessors() {
+    let coordinates = vsop87d::venus(2451545.0);
+
+    assert_eq!(
+        coordinates.longitude_deg(),
+        coordinates.longitude().to_degrees()
+    );
+    assert_eq!(
+        coordinates.latitude_deg(),
+        coordinates.latitude().to_degrees()
+    );
+    assert!((0.0..360.0).contains(&coordinates.longitude_deg()));
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_from_conversions() {
+    for &planet in Planet::ALL.iter() {
+        let spherical = vsop87b::planet(planet, 2451545.0);
+        let rectangular: RectangularCoordinates = spherical.into();
+        assert_eq!(rectangular, spherical.to_rectangular());
+        assert_eq!(RectangularCoordinates::from(spherical), rectangular);
+
+        let back: SphericalCoordinates = rectangular.into();
+        assert_eq!(back, rectangular.to_spherical());
+        assert_eq!(SphericalCoordinates::from(rectangular), back);
+        assert!((back.longitude() - spherical.longitude()).abs() < 1e-12);
+    }
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_valid_range() {
+    // Bretagnon & Francou (1988): 1" over 4,000 years around J2000.0 for the inner planets, over
+    // 2,000 years for Jupiter and Saturn, and over 6,000 years for Uranus and Neptune.
+    let span = |planet: Planet| {
+        let (min, max) = planet.valid_range();
+        assert!((min + max) / 2.0 == 2451545.0);
+        (max - min) / 2.0 / 365_250.0
+    };
+
+    for &planet in &[Planet::Mercury, Planet::Venus, Planet::Earth, Planet::Mars] {
+        assert_eq!(span(planet), 4.0);
+        assert_eq!(planet.valid_range(), (MIN_JDE, MAX_JDE));
+        assert!(span(planet) < span(Planet::Uranus));
+        assert!(span(planet) > span(Planet::Jupiter));
+    }
+    assert_eq!(span(Planet::Jupiter), 2.0);
+    assert_eq!(span(Planet::Saturn), 2.0);
+    assert_eq!(span(Planet::Uranus), 6.0);
+    assert_eq!(span(Planet::Neptune), 6.0);
+}
+
+#[cfg(all(feature = "mars", feature = "jupiter", feature = "neptune"))]
+#[test]
+fn it_check_range() {
+    let (min, max) = Planet::Jupiter.valid_range();
+    assert_eq!(Planet::Jupiter.check_range(2451545.0), Ok(()));
+    assert_eq!(Planet::Jupiter.check_range(min), Ok(()));
+    assert_eq!(Planet::Jupiter.check_range(max), Ok(()));
+    assert_eq!(
+        Planet::Jupiter.check_range(MIN_JDE),
+        Err(Vsop87Error::OutOfRange {
+            jde: MIN_JDE,
+            min,
+            max,
+        })
+    );
+    assert!(Planet::Jupiter.check_range(f64::NAN).is_err());
+
+    // The outer planets are valid beyond the span of the inner ones.
+    assert!(Planet::Neptune.check_range(MAX_JDE + 1.0).is_ok());
+    assert!(Planet::Mars.check_range(MAX_JDE + 1.0).is_err());
+}
+
+#[cfg(feature = "earth")]
+#[test]
+fn it_distance_units() {
+    assert_eq!(AU_KM, 149_597_870.7);
+    assert!((SPEED_OF_LIGHT_AU_PER_DAY * AU_KM / 86_400.0 - 299_792.458).abs() < 1e-9);
+
+    // The Earth is about 1 AU from the Sun, and its light takes about 8.3 minutes to reach it.
+    let earth = vsop87d::earth(2451545.0);
+    assert!((earth.distance_km() / 1.496e8 - 1.0).abs() < 0.02);
+    assert_eq!(earth.distance_km(), earth.distance() * AU_KM);
+    assert!((earth.distance_light_minutes() - 8.3).abs() < 0.15);
+
+    let one_au = SphericalCoordinates::new(0.0, 0.0, 1.0);
+    assert!((one_au.distance_light_minutes() - 8.316746).abs() < 1e-6);
+
+    let earth = vsop87a::earth(2451545.0);
+    assert!((earth.norm_km() / 1.496e8 - 1.0).abs() < 0.02);
+    assert!((earth.norm_km() - vsop87d::earth(2451545.0).distance_km()).abs() < 1.0);
+}
+
+#[test]
+fn it_calculate_var_const() {
+    let (mut a, mut b, mut c) = ([0.0; 100], [0.0; 100], [0.0; 100]);
+    let mut x = 0.0;
+    for ((a, b), c) in a.iter_mut().zip(b.iter_mut()).zip(c.iter_mut()) {
+        *a = 1e-3 / (1.0 + x);
+        *b = (x * 0.37) % 6.0;
+        *c = x * 1234.567;
+        x += 1.0;
+    }
+    let terms = [a, b, c];
+
+    for &jde in &[2451545.0, 990545.0, 3912545.0, 2232395.0] {
+        let t = calculate_t(jde);
+        assert_eq!(
+            calculate_var_const(t, &terms),
+            calculate_var(t, &terms[0], &terms[1], &terms[2])
+        );
+    }
+
+    // An empty series sums to zero.
+    assert_eq!(calculate_var_const(0.5, &[[]; 3]), 0.0);
+}
+
+#[test]
+fn it_normalize_angle() {
+    use std::f64::consts::PI;
+
+    // Inputs already in range are unchanged.
+    for &angle in &[0.0, 1.0, PI, 6.0] {
+        assert_eq!(normalize_angle(angle), angle);
+    }
+
+    // Negative inputs.
+    assert!((normalize_angle(-1.0) - (2.0 * PI - 1.0)).abs() < 1e-15);
+    assert!((normalize_angle(-7.0) - (4.0 * PI - 7.0)).abs() < 1e-14);
+    assert_eq!(normalize_angle(-2.0 * PI), 0.0);
+    assert_eq!(normalize_angle(-1e-17), 0.0);
+
+    // Inputs larger than 4π.
+    assert!((normalize_angle(4.0 * PI + 1.0) - 1.0).abs() < 1e-14);
+    assert!((normalize_angle(1000.0) - (1000.0 - 159.0 * 2.0 * PI)).abs() < 1e-12);
+    assert_eq!(normalize_angle(2.0 * PI), 0.0);
+
+    assert!(normalize_angle(f64::NAN).is_nan());
+}
+
+#[test]
+fn it_normalize_angle_signed() {
+    use std::f64::consts::PI;
+
+    // Inputs already in range are unchanged.
+    for &angle in &[0.0, 1.0, -1.0, PI, -PI + 1e-9] {
+        assert_eq!(normalize_angle_signed(angle), angle);
+    }
+
+    // The range is (-π, π].
+    assert_eq!(normalize_angle_signed(-PI), PI);
+    assert!((normalize_angle_signed(3.0 * PI) - PI).abs() < 1e-15);
+
+    // Negative inputs.
+    assert!((normalize_angle_signed(-4.0) - (2.0 * PI - 4.0)).abs() < 1e-15);
+    assert!((normalize_angle_signed(-9.0 * PI / 2.0) + PI / 2.0).abs() < 1e-14);
+
+    // Inputs larger than 4π.
+    assert!((normalize_angle_signed(4.0 * PI + 1.0) - 1.0).abs() < 1e-14);
+    assert!((normalize_angle_signed(4.0 * PI + 4.0) - (4.0 - 2.0 * PI)).abs() < 1e-14);
+
+    assert!(normalize_angle_signed(f64::NAN).is_nan());
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_normalized_solutions() {
+    use std::f64::consts::PI;
+
+    for &jde in &[2451545.0, 990545.0, 3912545.0, 2232395.0] {
+        for &planet in &Planet::ALL {
+            let longitude = vsop87d::planet(planet, jde).longitude();
+            assert!((0.0..2.0 * PI).contains(&longitude));
+            assert_eq!(normalize_angle(longitude), longitude);
+        }
+        let l = vsop87::mars(jde).l;
+        assert!((0.0..2.0 * PI).contains(&l));
+    }
+}
+
+#[cfg(feature = "neptune")]
+#[test]
+fn it_fixed_coordinates() {
+    let coordinates = vsop87d::neptune(2451545.0);
+    let fixed = coordinates.to_fixed();
+    let back = fixed.to_spherical();
+
+    let angle_unit = 1.0 / FixedCoordinates::ANGLE_SCALE;
+    let distance_unit = 1.0 / FixedCoordinates::DISTANCE_SCALE;
+    assert!((back.longitude() - coordinates.longitude()).abs() <= angle_unit);
+    assert!((back.latitude() - coordinates.latitude()).abs() <= angle_unit);
+    assert!((back.distance() - coordinates.distance()).abs() <= distance_unit);
+    assert_eq!(back.to_fixed(), fixed);
+
+    // One arcsecond, and one kilometer.
+    let one = SphericalCoordinates::new((1.0_f64 / 3600.0).to_radians(), 0.0, 1.0 / AU_KM);
+    assert_eq!(
+        one.to_fixed(),
+        FixedCoordinates::new(1_000_000, 0, 1_000_000)
+    );
+
+    // The quantization never reverses the order of two values.
+    let mut previous = i64::MIN;
+    for i in 0..10_000 {
+        let longitude = f64::from(i) * 1e-12;
+        let value = SphericalCoordinates::new(longitude, -longitude, longitude)
+            .to_fixed()
+            .longitude();
+        assert!(value >= previous);
+        previous = value;
+    }
+}
+
+#[test]
+fn it_approx_eq() {
+    use std::f64::consts::PI;
+
+    let a = SphericalCoordinates::new(0.001, -0.2, 5.0);
+    let b = SphericalCoordinates::new(2.0 * PI - 0.001, -0.2, 5.0);
+    assert!(a.approx_eq(&b, 0.0021, 0.0));
+    assert!(b.approx_eq(&a, 0.0021, 0.0));
+    assert!(!a.approx_eq(&b, 0.0019, 0.0));
+
+    let c = SphericalCoordinates::new(0.001, -0.2001, 5.0);
+    assert!(a.approx_eq(&c, 1.1e-4, 0.0));
+    assert!(!a.approx_eq(&c, 0.9e-4, 0.0));
+
+    let d = SphericalCoordinates::new(0.001, -0.2, 5.0 + 5e-6);
+    assert!(a.approx_eq(&d, 0.0, 1.1e-6));
+    assert!(!a.approx_eq(&d, 0.0, 0.9e-6));
+
+    let p = a.to_rectangular();
+    let q = b.to_rectangular();
+    assert!(p.approx_eq(&q, 5.0 * 0.0021));
+    assert!(!p.approx_eq(&q, 5.0 * 0.0019));
+    assert!(p.approx_eq(&p, 0.0));
+}
+
+#[cfg(feature = "mars")]
+#[test]
+fn it_lerp() {
+    let a = SphericalCoordinates::new(359_f64.to_radians(), 0.1, 2.0);
+    let b = SphericalCoordinates::new(1_f64.to_radians(), 0.3, 4.0);
+
+    // Across the seam, the middle is at 0°, not at 180°.
+    let middle = a.lerp(&b, 0.5);
+    assert!(normalize_angle_signed(middle.longitude()).abs() < 1e-12);
+    assert!((middle.latitude() - 0.2).abs() < 1e-12);
+    assert!((middle.distance() - 3.0).abs() < 1e-12);
+
+    // The same the other way around.
+    let middle = b.lerp(&a, 0.5);
+    assert!(normalize_angle_signed(middle.longitude()).abs() < 1e-12);
+
+    // The result is normalized.
+    let quarter = a.lerp(&b, 0.75);
+    assert!((quarter.longitude_deg() - 0.5).abs() < 1e-9);
+    let quarter = a.lerp(&b, 0.25);
+    assert!((quarter.longitude_deg() - 359.5).abs() < 1e-9);
+
+    // The ends are the given coordinates.
+    assert!(a.lerp(&b, 0.0).approx_eq(&a, 1e-12, 1e-12));
+    assert!(a.lerp(&b, 1.0).approx_eq(&b, 1e-12, 1e-12));
+
+    // Between two samples of a planet, the error is small.
+    let start = vsop87d::mars(2451545.0);
+    let end = vsop87d::mars(2451546.0);
+    let interpolated = start.lerp(&end, 0.5);
+    assert!(interpolated.approx_eq(&vsop87d::mars(2451545.5), 1e-5, 1e-5));
+}
+
+#[cfg(feature = "saturn")]
+#[test]
+fn it_translation_matrix() {
+    let coordinates = vsop87a::saturn(2451545.0);
+
+    let matrix = coordinates.to_translation_matrix_scaled(2.5);
+    assert_eq!(
+        matrix[3],
+        [
+            coordinates.x * 2.5,
+            coordinates.y * 2.5,
+            coordinates.z * 2.5,
+            1.0
+        ]
+    );
+    for (column, values) in matrix.iter().take(3).enumerate() {
+        for (row, &value) in values.iter().enumerate() {
+            assert_eq!(value, if row == column { 1.0 } else { 0.0 });
+        }
+    }
+
+    let matrix = coordinates.to_translation_matrix();
+    assert_eq!(
+        matrix[3],
+        [coordinates.x, coordinates.y, coordinates.z, 1.0]
+    );
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_variant() {
+    fn compute<V: Vsop87Variant>(planet: Planet, jde: f64) -> V::Output {
+        V::compute(planet, jde)
+    }
+
+    for &planet in Planet::ALL.iter() {
+        let jde = 2415020.0;
+        assert_eq!(
+            compute::<Vsop87A>(planet, jde),
+            vsop87a::planet(planet, jde)
+        );
+        assert_eq!(
+            compute::<Vsop87B>(planet, jde),
+            vsop87b::planet(planet, jde)
+        );
+        assert_eq!(
+            compute::<Vsop87C>(planet, jde),
+            vsop87c::planet(planet, jde)
+        );
+        assert_eq!(
+            compute::<Vsop87D>(planet, jde),
+            vsop87d::planet(planet, jde)
+        );
+        assert_eq!(
+            compute::<Vsop87E>(planet, jde),
+            vsop87e::planet(planet, jde)
+        );
+    }
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_term_count() {
+    assert_eq!(Planet::Mercury.term_count(Variable::Longitude, 0), 1380);
+    assert_eq!(Planet::Mercury.term_count(Variable::Longitude, 5), 13);
+    assert_eq!(Planet::Mercury.term_count(Variable::Latitude, 0), 818);
+    assert_eq!(Planet::Mercury.term_count(Variable::Distance, 0), 1215);
+    assert_eq!(Planet::Mercury.term_count(Variable::Distance, 4), 18);
+
+    // Out of range orders have no terms.
+    assert_eq!(Planet::Mercury.term_count(Variable::Distance, 6), 0);
+    assert_eq!(Planet::Neptune.term_count(Variable::Longitude, 100), 0);
+
+    for &planet in Planet::ALL.iter() {
+        for &variable in [Variable::Longitude, Variable::Latitude, Variable::Distance].iter() {
+            assert!(planet.term_count(variable, 0) > 0);
+        }
+    }
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_vsop87_to_fk5() {
+    let arcseconds = |radians: f64| radians.to_degrees() * 3600.0;
+
+    // On the ecliptic, at the equinox, for the J2000.0 equinox.
+    let coordinates = SphericalCoordinates::new(0.0, 0.0, 1.0);
+    let fk5 = vsop87_to_fk5(2451545.0, &coordinates);
+    assert!((arcseconds(normalize_angle_signed(fk5.longitude())) - -0.09033).abs() < 1e-9);
+    assert!((arcseconds(fk5.latitude()) - 0.03916).abs() < 1e-9);
+    assert_eq!(fk5.distance(), 1.0);
+
+    // The correction stays below 0.1" for the planets, and always decreases the longitude.
+    for &planet in Planet::ALL.iter() {
+        for &jde in [2451545.0, 2415020.0, 2488069.5].iter() {
+            let coordinates = vsop87d::planet(planet, jde);
+            let fk5 = vsop87_to_fk5(jde, &coordinates);
+
+            let delta_lon = arcseconds(normalize_angle_signed(
+                fk5.longitude() - coordinates.longitude(),
+            ));
+            let delta_lat = arcseconds(fk5.latitude() - coordinates.latitude());
+            assert!(delta_lon < 0.0 && delta_lon > -0.1);
+            assert!(delta_lat.abs() < 0.056);
+        }
+    }
+}
+
+#[cfg(feature = "uranus")]
+#[test]
+fn it_as_tuple() {
+    let coordinates = vsop87d::uranus(2451545.0);
+    let (longitude, latitude, distance) = coordinates.as_tuple();
+    assert_eq!(longitude, coordinates.longitude());
+    assert_eq!(latitude, coordinates.latitude());
+    assert_eq!(distance, coordinates.distance());
+    assert_eq!(<(f64, f64, f64)>::from(coordinates), coordinates.as_tuple());
+
+    let single = coordinates.to_f32();
+    let tuple: (f32, f32, f32) = single.into();
+    assert_eq!(
+        tuple,
+        (single.longitude(), single.latitude(), single.distance())
+    );
+
+    let coordinates = vsop87a::uranus(2451545.0);
+    let (x, y, z) = coordinates.as_tuple();
+    assert_eq!((x, y, z), (coordinates.x, coordinates.y, coordinates.z));
+    assert_eq!(<(f64, f64, f64)>::from(coordinates), (x, y, z));
+}
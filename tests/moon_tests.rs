@@ -0,0 +1,41 @@
+extern crate vsop87;
+use vsop87::*;
+
+#[test]
+fn it_position() {
+    // Example 47.a from Astronomical Algorithms.
+    let moon = moon::position(2448724.5);
+    assert!((moon.longitude().to_degrees() - 133.162655).abs() < 0.01);
+    assert!((moon.latitude().to_degrees() - -3.229126).abs() < 0.01);
+    assert!((moon.distance_km() - 368_409.7).abs() < 50.0);
+
+    // The latitude never goes beyond the inclination of the orbit, of about 5.1°.
+    for hour in 0..24 * 28 {
+        let moon = moon::position(2451545.0 + f64::from(hour) / 24.0);
+        assert!(moon.latitude().to_degrees().abs() < 5.35);
+        assert!((0.0..std::f64::consts::PI * 2.0).contains(&moon.longitude()));
+    }
+}
+
+#[cfg(feature = "earth")]
+#[test]
+fn it_geocentric_offset() {
+    // Over a lunar month, the Moon goes from the perigee to the apogee, around 0.00257 AU.
+    let distances: Vec<f64> = (0..30)
+        .map(|day| moon::geocentric_offset(2451545.0 + f64::from(day)).norm())
+        .collect();
+    let mean = distances.iter().sum::<f64>() / distances.len() as f64;
+    assert!((mean - 0.00257).abs() < 0.00005);
+    for distance in distances {
+        assert!(distance > 356_000.0 / AU_KM && distance < 407_000.0 / AU_KM);
+    }
+
+    // The offset gives the center of the Earth from the Earth - Moon barycenter.
+    for i in 0..100 {
+        let jde = 2415020.0 + f64::from(i) * 365.25;
+        let offset = earth_moon_offset(&moon::position(jde)).precess(jde, 2451545.0);
+        let earth = vsop87a::earth_moon(jde) - offset;
+
+        assert!((earth - vsop87a::earth(jde)).norm_km() < 2.0);
+    }
+}
@@ -0,0 +1,17 @@
+#![cfg(feature = "nalgebra")]
+
+extern crate vsop87;
+use vsop87::*;
+
+#[cfg(feature = "saturn")]
+#[test]
+fn it_nalgebra() {
+    let coordinates = vsop87a::saturn(2451545.0);
+
+    let vector: nalgebra::Vector3<f64> = coordinates.into();
+    assert_eq!(vector.x, coordinates.x);
+    assert_eq!(vector.y, coordinates.y);
+    assert_eq!(vector.z, coordinates.z);
+    assert_eq!(vector.norm(), coordinates.norm());
+    assert_eq!(RectangularCoordinates::from(vector), coordinates);
+}
@@ -0,0 +1,49 @@
+extern crate vsop87;
+use vsop87::*;
+
+#[test]
+fn it_nutation() {
+    // Meeus, Astronomical Algorithms, example 22.a: April 10th, 1987 at 0h TD.
+    let (delta_psi, delta_epsilon) = nutation::nutation(2446895.5);
+
+    assert!((delta_psi.to_degrees() * 3600.0 - -3.788).abs() < 0.0005);
+    assert!((delta_epsilon.to_degrees() * 3600.0 - 9.443).abs() < 0.0005);
+
+    // True obliquity: 23°26'36.850".
+    let obliquity = mean_obliquity(2446895.5) + delta_epsilon;
+    assert!((obliquity.to_degrees() * 3600.0 - 84396.850).abs() < 0.001);
+
+    // The main term has an amplitude of 17.2" in longitude and 9.2" in obliquity.
+    for i in 0..700 {
+        let (delta_psi, delta_epsilon) = nutation::nutation(2451545.0 + f64::from(i) * 10.0);
+
+        assert!(delta_psi.to_degrees().abs() * 3600.0 < 19.0);
+        assert!(delta_epsilon.to_degrees().abs() * 3600.0 < 10.5);
+    }
+}
+
+#[test]
+fn it_nutation_models() {
+    // January 1st, 2006 at 0h TT, compared with the nut80 and nut00b routines of the IAU SOFA
+    // library, as given in its test suite. The IAU 1980 series is truncated to 63 terms, while the
+    // 77 terms of IAU 2000B are the whole series.
+    let jde = 2453736.5;
+    let (delta_psi, delta_epsilon) = nutation::nutation_with(jde, Model::Iau1980);
+    assert_eq!((delta_psi, delta_epsilon), nutation::nutation(jde));
+    assert!((delta_psi + 0.964_365_835_322_656_4e-5).abs() < 2e-9);
+    assert!((delta_epsilon - 0.406_005_100_687_971_3e-4).abs() < 2e-9);
+
+    let (delta_psi, delta_epsilon) = nutation::nutation_with(jde, Model::Iau2006);
+    assert!((delta_psi + 0.963_255_229_114_836_3e-5).abs() < 1e-11);
+    assert!((delta_epsilon - 0.406_319_710_662_115_9e-4).abs() < 1e-11);
+
+    // The two theories differ by a few hundredths of an arcsecond.
+    for i in 0..100 {
+        let jde = 2451545.0 + f64::from(i) * 73.0;
+        let (psi_1980, epsilon_1980) = nutation::nutation_with(jde, Model::Iau1980);
+        let (psi_2006, epsilon_2006) = nutation::nutation_with(jde, Model::Iau2006);
+
+        assert!((psi_2006 - psi_1980).to_degrees().abs() * 3600.0 < 0.05);
+        assert!((epsilon_2006 - epsilon_1980).to_degrees().abs() * 3600.0 < 0.05);
+    }
+}
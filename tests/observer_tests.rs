@@ -0,0 +1,41 @@
+#![cfg(feature = "earth")]
+#![cfg_attr(not(feature = "all_planets"), allow(unused_imports))]
+
+extern crate vsop87;
+use vsop87::*;
+
+#[cfg(feature = "venus")]
+#[test]
+fn it_rise_transit_set() {
+    // Meeus, Astronomical Algorithms, example 15.a: Venus from Boston on March 20th, 1988. It
+    // rose at m = 0.51766, transited at m = 0.81980, and set at m = 0.12130 (fractions of the day,
+    // in UT).
+    let midnight = julian_day(1988, 3, 20.0);
+    let latitude = (42.0 + 20.0 / 60.0_f64).to_radians();
+    let longitude = -(71.0 + 5.0 / 60.0_f64).to_radians();
+
+    let (rise, transit, set) =
+        observer::rise_transit_set(Planet::Venus, midnight, latitude, longitude);
+    assert!((rise.unwrap() - midnight - 0.51766).abs() < 1e-4);
+    assert!((transit - midnight - 0.81980).abs() < 1e-4);
+    assert!((set.unwrap() - midnight - 0.12130).abs() < 1e-4);
+}
+
+#[cfg(feature = "jupiter")]
+#[test]
+fn it_rise_transit_set_circumpolar() {
+    // Jupiter was about 8° north of the equator on January 1st, 2000, so it never set close to the
+    // north pole, and it never rose close to the south pole.
+    let midnight = julian_day(2000, 1, 1.0);
+    let north = observer::rise_transit_set(Planet::Jupiter, midnight, 89_f64.to_radians(), 0.0);
+    let south = observer::rise_transit_set(Planet::Jupiter, midnight, -89_f64.to_radians(), 0.0);
+
+    assert_eq!((north.0, north.2), (None, None));
+    assert_eq!((south.0, south.2), (None, None));
+
+    // The transit does not depend on the latitude.
+    let equator = observer::rise_transit_set(Planet::Jupiter, midnight, 0.0, 0.0);
+    assert!((north.1 - equator.1).abs() < 1e-9);
+    assert!(equator.0.is_some() && equator.2.is_some());
+    assert!((midnight..midnight + 1.0).contains(&equator.1));
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "earth")]
+#![cfg_attr(not(feature = "all_planets"), allow(unused_imports))]
+
+extern crate vsop87;
+use vsop87::*;
+
+/// IAU 2015 rotation elements of Mars, at the given date.
+#[cfg(feature = "mars")]
+fn mars_elements(jde: f64) -> (f64, f64, f64, f64) {
+    let t = (jde - 2451545.0) / 36525.0;
+    (
+        (317.68143 - 0.1061 * t).to_radians(),
+        (52.88650 - 0.0609 * t).to_radians(),
+        176.630_f64.to_radians(),
+        350.89198226_f64.to_radians(),
+    )
+}
+
+#[cfg(feature = "mars")]
+#[test]
+fn it_sub_earth() {
+    // Example 42.a from Astronomical Algorithms: ω = 111.55°, De = +12.44°.
+    let jde = 2448935.5;
+    let (ra, dec, w0, w_dot) = mars_elements(jde);
+    let (longitude, latitude) = physical::sub_earth(Planet::Mars, jde, ra, dec, w0, w_dot);
+    assert!((longitude.to_degrees() - 111.55).abs() < 1.0);
+    assert!((latitude.to_degrees() - 12.44).abs() < 1.0);
+
+    // The west longitude of the central meridian grows with the rotation, by about 14.6° per
+    // hour.
+    let (later, _) = physical::sub_earth(Planet::Mars, jde + 1.0 / 24.0, ra, dec, w0, w_dot);
+    let hourly = normalize_angle_signed(later - longitude).to_degrees();
+    assert!((hourly - 14.6).abs() < 0.1);
+
+    // With a retrograde rotation, the longitude is counted the other way.
+    let (retrograde, _) = physical::sub_earth(Planet::Mars, jde, ra, dec, w0, -w_dot);
+    let (later, _) = physical::sub_earth(Planet::Mars, jde + 1.0 / 24.0, ra, dec, w0, -w_dot);
+    assert!((normalize_angle_signed(later - retrograde).to_degrees() - 14.6).abs() < 0.1);
+}
+
+#[cfg(feature = "mars")]
+#[test]
+fn it_sub_solar() {
+    // Example 42.a from Astronomical Algorithms: Ds = -2.76°.
+    let jde = 2448935.5;
+    let (ra, dec, w0, w_dot) = mars_elements(jde);
+    let (sun_longitude, latitude) = physical::sub_solar(Planet::Mars, jde, ra, dec, w0, w_dot);
+    assert!((latitude.to_degrees() - -2.76).abs() < 1.0);
+
+    // The phase angle of Mars was 38.7°, and it separates both points.
+    let (earth_longitude, earth_latitude) =
+        physical::sub_earth(Planet::Mars, jde, ra, dec, w0, w_dot);
+    let separation = angular_separation(
+        &SphericalCoordinates::new(sun_longitude, latitude, 1.0),
+        &SphericalCoordinates::new(earth_longitude, earth_latitude, 1.0),
+    );
+    assert!((separation - geocentric::phase_angle(Planet::Mars, jde)).abs() < 0.01);
+}
@@ -0,0 +1,52 @@
+extern crate vsop87;
+use vsop87::*;
+
+#[test]
+fn it_precession_matrix() {
+    // No time, no rotation.
+    let m = precession::ecliptic_precession_matrix(2451545.0, 2451545.0);
+    for (i, row) in m.iter().enumerate() {
+        for (j, &value) in row.iter().enumerate() {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert!((value - expected).abs() < 1e-15);
+        }
+    }
+
+    // The matrix is orthogonal.
+    let m = precession::ecliptic_precession_matrix(2451545.0, 2159345.0);
+    for i in 0..3 {
+        for j in 0..3 {
+            let dot: f64 = (0..3).map(|k| m[i][k] * m[j][k]).sum();
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert!((dot - expected).abs() < 1e-14);
+        }
+    }
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_precess() {
+    // One arcsecond, in radians.
+    let arcsecond = 1_f64.to_radians() / 3600.0;
+
+    for &jde in &[
+        2451545.0, 2415020.0, 2488069.5, 2378495.0, 2305445.0, 2159345.0,
+    ] {
+        for &planet in &Planet::ALL {
+            let precessed = vsop87a::planet(planet, jde).precess(2451545.0, jde);
+            let expected = vsop87c::planet(planet, jde);
+
+            let separation =
+                angular_separation(&precessed.to_spherical(), &expected.to_spherical());
+            assert!(separation < arcsecond);
+            assert!((precessed.norm() - expected.norm()).abs() < 1e-7 * expected.norm());
+        }
+    }
+
+    // Precession moves the equinox about 50" per year, along the ecliptic: after one century, the
+    // general precession in longitude is 5029.0966" + 1.11113".
+    let earth = vsop87a::earth(2451545.0);
+    let precessed = earth.precess(2451545.0, 2451545.0 + 36525.0).to_spherical();
+    let shift = precessed.longitude() - earth.to_spherical().longitude();
+    assert!((shift.to_degrees() * 3600.0 - 5030.2077).abs() < 0.1);
+}
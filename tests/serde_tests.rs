@@ -0,0 +1,119 @@
+#![cfg(feature = "serde")]
+
+extern crate serde_json;
+extern crate vsop87;
+use vsop87::*;
+
+#[cfg(feature = "jupiter")]
+#[test]
+fn it_serde_spherical() {
+    let coordinates = vsop87d::jupiter(2232395.0);
+    let json = serde_json::to_string(&coordinates).unwrap();
+
+    assert!(json.starts_with("{\"longitude\":"));
+    assert!(json.contains(",\"latitude\":"));
+    assert!(json.contains(",\"distance\":"));
+
+    let back: SphericalCoordinates = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        back.longitude().to_bits(),
+        coordinates.longitude().to_bits()
+    );
+    assert_eq!(back.latitude().to_bits(), coordinates.latitude().to_bits());
+    assert_eq!(back.distance().to_bits(), coordinates.distance().to_bits());
+}
+
+#[cfg(feature = "saturn")]
+#[test]
+fn it_serde_rectangular() {
+    let coordinates = vsop87a::saturn(2122820.0);
+    let json = serde_json::to_string(&coordinates).unwrap();
+
+    assert!(json.starts_with("{\"x\":"));
+
+    let back: RectangularCoordinates = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.x.to_bits(), coordinates.x.to_bits());
+    assert_eq!(back.y.to_bits(), coordinates.y.to_bits());
+    assert_eq!(back.z.to_bits(), coordinates.z.to_bits());
+}
+
+#[cfg(feature = "venus")]
+#[test]
+fn it_serde_equatorial() {
+    let coordinates = vsop87d::venus(2451545.0).to_equatorial(mean_obliquity(2451545.0));
+    let json = serde_json::to_string(&coordinates).unwrap();
+
+    assert!(json.starts_with("{\"right_ascension\":"));
+    assert!(json.contains(",\"declination\":"));
+
+    let back: EquatorialCoordinates = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, coordinates);
+}
+
+#[cfg(feature = "venus")]
+#[test]
+fn it_serde_galactic() {
+    let coordinates = vsop87b::venus(2451545.0).to_galactic(mean_obliquity(2451545.0));
+    let json = serde_json::to_string(&coordinates).unwrap();
+
+    assert!(json.starts_with("{\"galactic_longitude\":"));
+    assert!(json.contains(",\"galactic_latitude\":"));
+
+    let back: GalacticCoordinates = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, coordinates);
+}
+
+#[cfg(feature = "mercury")]
+#[test]
+fn it_serde_elements() {
+    let elements = mercury(2451545.0);
+    let json = serde_json::to_string(&elements).unwrap();
+    let back: VSOP87Elements = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, elements);
+
+    let keplerian = KeplerianElements::from(elements);
+    let json = serde_json::to_string(&keplerian).unwrap();
+
+    assert!(json.starts_with("{\"eccentricity\":"));
+    assert!(json.contains(",\"semimajor_axis\":"));
+    assert!(json.contains(",\"inclination\":"));
+    assert!(json.contains(",\"ascending_node\":"));
+    assert!(json.contains(",\"periapsis\":"));
+    assert!(json.contains(",\"mean_longitude\":"));
+
+    let back: KeplerianElements = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, keplerian);
+}
+
+#[cfg(feature = "mars")]
+#[test]
+fn it_serde_state_vector() {
+    let state = StateVector::from(vsop87a::state(Planet::Mars, 2451545.0));
+    let json = serde_json::to_string(&state).unwrap();
+
+    assert!(json.starts_with("{\"position\":{\"x\":"));
+    assert!(json.contains(",\"velocity\":{\"x\":"));
+
+    let back: StateVector = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, state);
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_serde_enums() {
+    assert_eq!(serde_json::to_string(&Planet::Mars).unwrap(), "\"Mars\"");
+    for &planet in Planet::ALL.iter() {
+        let json = serde_json::to_string(&planet).unwrap();
+        assert_eq!(serde_json::from_str::<Planet>(&json).unwrap(), planet);
+    }
+    assert!(serde_json::from_str::<Planet>("\"Pluto\"").is_err());
+
+    assert_eq!(
+        serde_json::to_string(&Variable::Latitude).unwrap(),
+        "\"Latitude\""
+    );
+    assert_eq!(
+        serde_json::from_str::<events::Station>("\"Retrograde\"").unwrap(),
+        events::Station::Retrograde
+    );
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "time")]
+
+extern crate vsop87;
+use time::{Date, Month, OffsetDateTime, UtcOffset};
+use vsop87::*;
+
+#[test]
+fn it_jde_from_time() {
+    // The Julian Day starts at noon, so midnight is half a day before.
+    let midnight = Date::from_calendar_date(2000, Month::January, 1)
+        .unwrap()
+        .midnight()
+        .assume_utc();
+    assert_eq!(jde_from_time(&midnight, Some(0.0)), 2451544.5);
+
+    // The offset is taken into account.
+    let offset = midnight.to_offset(UtcOffset::from_hms(-5, 0, 0).unwrap());
+    assert_eq!(jde_from_time(&offset, Some(0.0)), 2451544.5);
+
+    // Dates before the Unix epoch, and fractions of a second.
+    let datetime = OffsetDateTime::from_unix_timestamp_nanos(-386_310_816_500_000_000).unwrap();
+    let jde = jde_from_time(&datetime, Some(0.0));
+    assert!(((jde - 2436116.31) * 86_400.0 + 0.5).abs() < 1e-4);
+
+    assert_eq!(
+        jde_from_time(&midnight, Some(64.0)),
+        ut_to_tt(2451544.5, 64.0)
+    );
+}
@@ -0,0 +1,113 @@
+extern crate vsop87;
+use vsop87::*;
+
+#[test]
+fn it_ut_to_tt() {
+    let jd_ut = 2451545.0;
+
+    assert_eq!(ut_to_tt(jd_ut, 0.0), jd_ut);
+    assert!((ut_to_tt(jd_ut, 86_400.0) - (jd_ut + 1.0)).abs() < 1e-9);
+    assert!((ut_to_tt(jd_ut, 64.0) - jd_ut - 64.0 / 86_400.0).abs() < 1e-9);
+    assert!((ut_to_tt(jd_ut, -3.5) - jd_ut + 3.5 / 86_400.0).abs() < 1e-9);
+}
+
+#[test]
+fn it_tt_to_ut() {
+    for &delta_t in [0.0, 64.0, -3.5, 10_583.6].iter() {
+        let jde = ut_to_tt(2415020.0, delta_t);
+        assert!((tt_to_ut(jde, delta_t) - 2415020.0).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn it_tt_to_tdb() {
+    // TDB − TT is periodic, with an amplitude of about 1.7 ms and a period of a year. It is the
+    // largest three months after the perihelion of the Earth, at the beginning of April.
+    let mut extremes = (0_f64, 0_f64);
+    let mut maximum = 0.0;
+    let mut jde = 2451545.0;
+    while jde < 2451545.0 + 365.25 {
+        let difference = (tt_to_tdb(jde) - jde) * 86_400.0;
+        if difference > extremes.1 {
+            maximum = jde;
+        }
+        extremes = (extremes.0.min(difference), extremes.1.max(difference));
+        assert!((tdb_to_tt(tt_to_tdb(jde)) - jde).abs() < 1e-12);
+        jde += 1.0;
+    }
+    assert!(extremes.0 < -0.0016 && extremes.0 > -0.0017);
+    assert!(extremes.1 > 0.0016 && extremes.1 < 0.0017);
+    assert!((maximum - julian_day(2000, 4, 3.0)).abs() < 10.0);
+}
+
+#[test]
+fn it_delta_t() {
+    // Values from the Five Millennium Canon of Solar Eclipses.
+    assert!((delta_t(2000.0) - 63.86).abs() < 0.01);
+    assert!((delta_t(1900.0) - -2.79).abs() < 0.01);
+    assert!((delta_t(1800.0) - 13.72).abs() < 0.01);
+    assert!((delta_t(1600.0) - 120.0).abs() < 0.1);
+    assert!((delta_t(1000.0) - 1574.2).abs() < 0.1);
+    assert!((delta_t(0.0) - 10583.6).abs() < 0.1);
+    assert!((delta_t(-500.0) - 17190.0).abs() < 20.0);
+
+    // The expressions join smoothly at the ends of their intervals.
+    for &year in &[
+        -500.0, 500.0, 1600.0, 1700.0, 1800.0, 1860.0, 1900.0, 1920.0, 1941.0, 1961.0, 1986.0,
+        2005.0, 2050.0, 2150.0,
+    ] {
+        let before = delta_t(year - 1e-6);
+        let after = delta_t(year);
+        assert!((before - after).abs() < 1.0 + 0.01 * after.abs());
+    }
+}
+
+#[test]
+fn it_greenwich_mean_sidereal_time() {
+    // Meeus, Astronomical Algorithms, example 12.a: April 10th, 1987 at 0h UT, 13h10m46.3668s.
+    let sidereal_time = greenwich_mean_sidereal_time(2446895.5);
+    assert!(
+        (sidereal_time.to_degrees() / 15.0 - (13.0 + 10.0 / 60.0 + 46.3668 / 3600.0)).abs() < 1e-8
+    );
+
+    // Example 12.b: the same day at 19h21m00s UT, 8h34m57.0896s.
+    let sidereal_time = greenwich_mean_sidereal_time(2446896.30625);
+    assert!((sidereal_time.to_degrees() - 128.7378734).abs() < 1e-6);
+
+    // The Earth rotates once relative to the equinox in 23h56m4.0905s.
+    let day = 2451545.0;
+    let sidereal_day = (23.0 + 56.0 / 60.0 + 4.0905 / 3600.0) / 24.0;
+    let difference =
+        greenwich_mean_sidereal_time(day + sidereal_day) - greenwich_mean_sidereal_time(day);
+    assert!(difference.abs() < 1e-8);
+}
+
+#[test]
+fn it_apparent_sidereal_time() {
+    // Meeus, Astronomical Algorithms, example 12.a: 13h10m46.1351s, with Δψ = -3.788" and
+    // ε = 23°26'36.85".
+    let nutation_longitude = (-3.788_f64 / 3600.0).to_radians();
+    let obliquity = (23.0 + 26.0 / 60.0 + 36.85 / 3600.0_f64).to_radians();
+    let sidereal_time = apparent_sidereal_time(2446895.5, nutation_longitude, obliquity);
+    assert!(
+        (sidereal_time.to_degrees() / 15.0 - (13.0 + 10.0 / 60.0 + 46.1351 / 3600.0)).abs() < 1e-7
+    );
+
+    // Without nutation, it is the mean sidereal time.
+    assert_eq!(
+        apparent_sidereal_time(2451545.0, 0.0, obliquity),
+        greenwich_mean_sidereal_time(2451545.0)
+    );
+}
+
+#[test]
+fn it_local_sidereal_time() {
+    let greenwich = greenwich_mean_sidereal_time(2446896.30625);
+    assert_eq!(local_sidereal_time(greenwich, 0.0), greenwich);
+    assert!((local_sidereal_time(greenwich, 1.0) - greenwich - 1.0).abs() < 1e-12);
+
+    // The result is normalized.
+    let local = local_sidereal_time(greenwich, -3.0);
+    assert!((0.0..2.0 * std::f64::consts::PI).contains(&local));
+    assert!((local_sidereal_time(6.0, 1.0) - (7.0 - 2.0 * std::f64::consts::PI)).abs() < 1e-15);
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "earth")]
+
+extern crate vsop87;
+use vsop87::*;
+
+#[test]
+fn it_local_mean_sidereal_time_from_ut() {
+    // Meeus, Astronomical Algorithms, example 12.b: 8h34m57.0896s.
+    let sidereal_time = topocentric::local_mean_sidereal_time_from_ut(2446896.30625, 0.0);
+    assert!((sidereal_time.to_degrees() - 128.7378734).abs() < 1e-6);
+
+    // The longitude is positive to the east.
+    let east = topocentric::local_mean_sidereal_time_from_ut(2446896.30625, 1.0);
+    assert!((east - sidereal_time - 1.0).abs() < 1e-12);
+
+    // The result is normalized.
+    let west = topocentric::local_mean_sidereal_time_from_ut(2446896.30625, -3.0);
+    assert!((0.0..2.0 * std::f64::consts::PI).contains(&west));
+}
+
+#[test]
+fn it_observer_position() {
+    // Meeus, Astronomical Algorithms, example 11.a: Palomar Observatory.
+    let latitude = (33.0 + 21.0 / 60.0 + 22.0 / 3600.0_f64).to_radians();
+    let position = topocentric::observer_position(latitude, 1706.0, 0.0);
+    let radius = topocentric::EARTH_EQUATORIAL_RADIUS / AU_KM;
+
+    assert!((position.z / radius - 0.546861).abs() < 1e-6);
+    assert!((position.x / radius - 0.836339).abs() < 1e-6);
+    assert_eq!(position.y, 0.0);
+
+    // At the poles, the observer is on the polar axis.
+    let pole = topocentric::observer_position(std::f64::consts::FRAC_PI_2, 0.0, 1.0);
+    assert!((pole.z / radius - (1.0 - topocentric::EARTH_FLATTENING)).abs() < 1e-12);
+    assert!(pole.x.abs() < 1e-15 && pole.y.abs() < 1e-15);
+}
+
+#[cfg(feature = "mars")]
+#[test]
+fn it_planet() {
+    // Meeus, Astronomical Algorithms, example 40.a: Mars from Palomar Observatory, on August 28th,
+    // 2003 at 3h17m UT. The parallax moves it by +1.29s in right ascension and by -14.1" in
+    // declination.
+    let jd = 2452879.63681;
+    let jde = ut_to_tt(jd, delta_t(2003.66));
+    let latitude = (33.0 + 21.0 / 60.0 + 22.0 / 3600.0_f64).to_radians();
+    let longitude = -(7.0 + 47.0 / 60.0 + 27.0 / 3600.0_f64).to_radians() * 15.0;
+    let sidereal_time = topocentric::local_mean_sidereal_time_from_ut(jd, longitude);
+
+    let obliquity = mean_obliquity(jde) + nutation::nutation(jde).1;
+    let geocentric = geocentric::apparent(Planet::Mars, jde).to_equatorial(obliquity);
+    let topocentric = topocentric::planet(Planet::Mars, jde, latitude, 1706.0, sidereal_time)
+        .to_equatorial(obliquity);
+
+    let delta_ra = (topocentric.right_ascension() - geocentric.right_ascension()).to_degrees();
+    let delta_dec = (topocentric.declination() - geocentric.declination()).to_degrees();
+    assert!((delta_ra * 240.0 - 1.29).abs() < 0.01);
+    assert!((delta_dec * 3600.0 - -14.1).abs() < 0.1);
+    assert!((topocentric.distance() - geocentric.distance()).abs() < 5e-5);
+}
+
+#[cfg(all(feature = "mars", feature = "neptune"))]
+#[test]
+fn it_parallax_range() {
+    // The largest shift over a day, as the rotation of the Earth moves the observer.
+    let max_shift = |planet: Planet, jde: f64| {
+        let geocentric = geocentric::apparent(planet, jde);
+        (0..24)
+            .map(|hour| {
+                let sidereal_time = f64::from(hour) * std::f64::consts::PI / 12.0;
+                let topocentric = topocentric::planet(planet, jde, 0.0, 0.0, sidereal_time);
+                angular_separation(&geocentric, &topocentric).to_degrees() * 3600.0
+            })
+            .fold(0.0, f64::max)
+    };
+
+    // Mars at its closest approach in almost 60,000 years, at 0.3727 AU: 8.794" / 0.3727.
+    let mars = max_shift(Planet::Mars, 2452878.9);
+    assert!(mars > 23.0 && mars < 23.7);
+
+    // Neptune is always more than 28 AU away.
+    let neptune = max_shift(Planet::Neptune, 2452878.9);
+    assert!(neptune < 0.4);
+}
@@ -0,0 +1,230 @@
+//! Verification of the solutions against the `vsop87.chk` reference file.
+//!
+//! The *VSOP87* distribution includes a `vsop87.chk` file with the values of every solution, for
+//! every body, at the dates JD 2451545.0 to JD 2122820.0 in steps of 36,525 days. Each record is a
+//! header line with the name of the solution, the body and the date (`JD2451545.0`), followed by
+//! lines of `name = value` pairs. The derivatives (primed names, such as `x'`) are ignored.
+//!
+//! The file itself is not distributed with the crate. `tests/data/vsop87.chk.excerpt` has the
+//! same records for *VSOP87* and *VSOP87A* to *VSOP87E*, for every body and every date, with the
+//! reference values asserted in the tests of each solution. The values are checked with the
+//! tolerances of those tests, given by the `tolerance` function.
+
+#![cfg(feature = "all_planets")]
+
+extern crate vsop87;
+use vsop87::*;
+
+use std::{fs, path::Path};
+
+/// A record of the verification file.
+#[derive(Debug)]
+struct Record {
+    solution: String,
+    body: String,
+    jde: f64,
+    values: Vec<(String, f64)>,
+}
+
+/// Maximum difference with the reference value of a variable of a solution.
+///
+/// Most values are given with 10 decimals, and must match within 10⁻¹⁰: half a unit of the last
+/// decimal for the rounding of the reference value, and the rest for the rounding differences of
+/// the evaluation. The last variable of each record (`p`, `r` or `z`) only has 7 or 8 decimals,
+/// and far from J2000 the values of the outer planets differ by a few times 10⁻⁷, and up to
+/// 2·10⁻⁶ *AU* for the *z* coordinate of Uranus in *VSOP87A* and *VSOP87E*.
+fn tolerance(solution: &str, name: &str) -> f64 {
+    match (solution, name) {
+        ("VSOP87A", "z") | ("VSOP87E", "z") => 2.5e-6,
+        ("VSOP87", "p") | ("VSOP87C", "z") | (_, "r") => 4e-7,
+        _ => 1e-10,
+    }
+}
+
+/// Parses the records of a verification file.
+fn parse(text: &str) -> Vec<Record> {
+    let mut records: Vec<Record> = Vec::new();
+    for line in text.lines() {
+        let tokens: Vec<_> = line.split_whitespace().collect();
+        match tokens.as_slice() {
+            [solution, body, date, ..] if solution.starts_with("VSOP87") => {
+                records.push(Record {
+                    solution: solution.to_string(),
+                    body: body.to_string(),
+                    jde: date.trim_start_matches("JD").parse().expect("invalid date"),
+                    values: Vec::new(),
+                });
+            }
+            _ => {
+                if let Some(record) = records.last_mut() {
+                    let line = line.replace('=', " = ");
+                    let tokens: Vec<_> = line.split_whitespace().collect();
+                    for pair in tokens.windows(3) {
+                        if let [name, "=", value] = pair {
+                            if !name.ends_with('\'') {
+                                record.values.push((
+                                    name.to_lowercase(),
+                                    value.parse().expect("invalid value"),
+                                ));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    records
+}
+
+/// Computes the values of a record with this crate, or `None` if the body is not supported.
+fn compute(record: &Record) -> Option<Vec<(&'static str, f64)>> {
+    let planet = match record.body.as_str() {
+        "MERCURY" => Some(Planet::Mercury),
+        "VENUS" => Some(Planet::Venus),
+        "EARTH" => Some(Planet::Earth),
+        "MARS" => Some(Planet::Mars),
+        "JUPITER" => Some(Planet::Jupiter),
+        "SATURN" => Some(Planet::Saturn),
+        "URANUS" => Some(Planet::Uranus),
+        "NEPTUNE" => Some(Planet::Neptune),
+        _ => None,
+    };
+    let jde = record.jde;
+
+    let rectangular = |coordinates: RectangularCoordinates| {
+        vec![
+            ("x", coordinates.x),
+            ("y", coordinates.y),
+            ("z", coordinates.z),
+        ]
+    };
+    let spherical = |coordinates: SphericalCoordinates| {
+        vec![
+            ("l", coordinates.longitude()),
+            ("b", coordinates.latitude()),
+            ("r", coordinates.distance()),
+        ]
+    };
+
+    match (record.solution.as_str(), record.body.as_str(), planet) {
+        ("VSOP87", body, _) => {
+            let elements = match body {
+                "MERCURY" => vsop87::mercury(jde),
+                "VENUS" => vsop87::venus(jde),
+                "EMB" => vsop87::earth_moon(jde),
+                "MARS" => vsop87::mars(jde),
+                "JUPITER" => vsop87::jupiter(jde),
+                "SATURN" => vsop87::saturn(jde),
+                "URANUS" => vsop87::uranus(jde),
+                "NEPTUNE" => vsop87::neptune(jde),
+                _ => return None,
+            };
+            Some(vec![
+                ("a", elements.a),
+                ("l", elements.l),
+                ("k", elements.k),
+                ("h", elements.h),
+                ("q", elements.q),
+                ("p", elements.p),
+            ])
+        }
+        ("VSOP87A", "EMB", _) => Some(rectangular(vsop87a::earth_moon(jde))),
+        ("VSOP87A", _, Some(planet)) => Some(rectangular(vsop87a::planet(planet, jde))),
+        ("VSOP87B", _, Some(planet)) => Some(spherical(vsop87b::planet(planet, jde))),
+        ("VSOP87C", _, Some(planet)) => Some(rectangular(vsop87c::planet(planet, jde))),
+        ("VSOP87D", _, Some(planet)) => Some(spherical(vsop87d::planet(planet, jde))),
+        ("VSOP87E", "SUN", _) => Some(rectangular(vsop87e::sun(jde))),
+        ("VSOP87E", _, Some(planet)) => Some(rectangular(vsop87e::planet(planet, jde))),
+        _ => None,
+    }
+}
+
+/// Checks every supported record, and returns the number of values checked.
+fn check(records: &[Record]) -> usize {
+    let mut checked = 0;
+    for record in records {
+        if let Some(computed) = compute(record) {
+            for (name, expected) in &record.values {
+                let (_, value) = computed
+                    .iter()
+                    .find(|(computed_name, _)| computed_name == name)
+                    .expect("unknown variable");
+
+                assert!(
+                    (value - expected).abs() < tolerance(&record.solution, name),
+                    "{} {} JD{}: {} = {}, expected {}",
+                    record.solution,
+                    record.body,
+                    record.jde,
+                    name,
+                    value,
+                    expected
+                );
+                checked += 1;
+            }
+        }
+    }
+
+    checked
+}
+
+#[test]
+fn it_vsop87_chk_excerpt() {
+    // Values of the reference file that are also asserted in the tests of each solution.
+    let excerpt = "
+ VSOP87    MERCURY   JD2451545.0
+ a =  0.3870982122 au     l =  4.4026057779 rd     k =  0.0446647518
+ h =  0.2007208958        q =  0.0406161541
+ VSOP87A   MERCURY   JD2451545.0
+ x = -0.1300934115 au     y = -0.4472876716 au
+ VSOP87D   MERCURY   JD2451545.0
+ l =  4.4293481036 rd     b = -0.0527573409 rd
+ VSOP87    NEPTUNE   JD2122820.0
+ a = 30.2002490334 au     l =  2.4115747749 rd     k =  0.0032261110
+ h =  0.0104510184
+ VSOP87A   NEPTUNE   JD2122820.0
+ x = -22.7985170870 au    y = 19.5994768858 au
+ VSOP87D   NEPTUNE   JD2122820.0
+ l =  2.2124988267 rd     b =  0.0027498093 rd
+";
+
+    let records = parse(excerpt);
+    assert_eq!(records.len(), 6);
+    assert_eq!(records[0].values.len(), 5);
+    assert_eq!(records[5].jde, 2122820.0);
+
+    assert_eq!(check(&records), 17);
+}
+
+#[test]
+fn it_vsop87_chk() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data/vsop87.chk.excerpt");
+    let text = fs::read_to_string(&path).expect("tests/data/vsop87.chk.excerpt not found");
+
+    let records = parse(&text);
+    for solution in &[
+        "VSOP87", "VSOP87A", "VSOP87B", "VSOP87C", "VSOP87D", "VSOP87E",
+    ] {
+        for body in &[
+            "MERCURY", "VENUS", "EARTH", "MARS", "JUPITER", "SATURN", "URANUS", "NEPTUNE",
+        ] {
+            // The elements of VSOP87 are given for the Earth - Moon barycenter, not the Earth.
+            let body = if *solution == "VSOP87" && *body == "EARTH" {
+                "EMB"
+            } else {
+                body
+            };
+            let dates = records
+                .iter()
+                .filter(|record| record.solution == *solution && record.body == body)
+                .count();
+            assert_eq!(dates, 10, "{} {}", solution, body);
+        }
+    }
+
+    // 6 elements for 80 VSOP87 records, 3 coordinates for the other 420, including the
+    // Earth - Moon barycenter in VSOP87A and the Sun in VSOP87E.
+    assert_eq!(records.len(), 500);
+    assert_eq!(check(&records), 6 * 80 + 3 * 420);
+}
@@ -1,5 +1,6 @@
 extern crate vsop87;
 
+#[cfg(feature = "mercury")]
 #[test]
 fn it_mercury() {
     let vsop87_elts = vsop87::mercury(2451545.0);
@@ -93,6 +94,7 @@ fn it_mercury() {
     assert!(vsop87_elts.p > 0.04677625 && vsop87_elts.p < 0.04677701);
 }
 
+#[cfg(feature = "venus")]
 #[test]
 fn it_venus() {
     let vsop87_elts = vsop87::venus(2451545.0);
@@ -186,6 +188,7 @@ fn it_venus() {
     assert!(vsop87_elts.p > 0.02913516 && vsop87_elts.p < 0.02913592);
 }
 
+#[cfg(feature = "mars")]
 #[test]
 fn it_mars() {
     let vsop87_elts = vsop87::mars(2451545.0);
@@ -279,6 +282,7 @@ fn it_mars() {
     assert!(vsop87_elts.p > 0.01324009 && vsop87_elts.p < 0.01324085);
 }
 
+#[cfg(feature = "jupiter")]
 #[test]
 fn it_jupiter() {
     let vsop87_elts = vsop87::jupiter(2451545.0);
@@ -372,6 +376,7 @@ fn it_jupiter() {
     assert!(vsop87_elts.p > 0.01141772 && vsop87_elts.p < 0.01141848);
 }
 
+#[cfg(feature = "saturn")]
 #[test]
 fn it_saturn() {
     let vsop87_elts = vsop87::saturn(2451545.0);
@@ -465,6 +470,7 @@ fn it_saturn() {
     assert!(vsop87_elts.p > 0.01930558 && vsop87_elts.p < 0.01930634);
 }
 
+#[cfg(feature = "uranus")]
 #[test]
 fn it_uranus() {
     let vsop87_elts = vsop87::uranus(2451545.0);
@@ -558,6 +564,7 @@ fn it_uranus() {
     assert!(vsop87_elts.p > 0.00660903 && vsop87_elts.p < 0.00660979);
 }
 
+#[cfg(feature = "neptune")]
 #[test]
 fn it_neptune() {
     let vsop87_elts = vsop87::neptune(2451545.0);
@@ -651,6 +658,7 @@ fn it_neptune() {
     assert!(vsop87_elts.p > 0.01149039 && vsop87_elts.p < 0.01149115);
 }
 
+#[cfg(feature = "earth")]
 #[test]
 fn it_earth_moon() {
     let vsop87_elts = vsop87::earth_moon(2451545.0);
@@ -743,3 +751,196 @@ fn it_earth_moon() {
     assert!(vsop87_elts.q > 0.0010301900 && vsop87_elts.q < 0.0010301902);
     assert!(vsop87_elts.p > -0.00005346 && vsop87_elts.p < -0.00005270);
 }
+
+#[cfg(all(feature = "mercury", feature = "venus", feature = "mars"))]
+#[test]
+fn it_keplerian_elements() {
+    // Mean elements for J2000, from Meeus, Astronomical Algorithms, table 31.A. The VSOP87
+    // elements include periodic terms, so they only match approximately.
+    let mercury = vsop87::KeplerianElements::from(vsop87::mercury(2451545.0));
+    assert!((mercury.semimajor_axis() - 0.387098310).abs() < 1e-6);
+    assert!((mercury.eccentricity() - 0.20563175).abs() < 1e-5);
+    assert!((mercury.inclination().to_degrees() - 7.004986).abs() < 1e-4);
+    assert!((mercury.ascending_node().to_degrees() - 48.330893).abs() < 1e-3);
+    assert!((mercury.perihelion_longitude().to_degrees() - 77.456119).abs() < 2e-3);
+    assert!((mercury.mean_longitude().to_degrees() - 252.250906).abs() < 1e-3);
+    assert_eq!(mercury.perihelion_longitude(), mercury.periapsis());
+    assert!((mercury.argument_of_periapsis().to_degrees() - 29.125226).abs() < 3e-3);
+
+    // The longitude of the perihelion of Venus is in the second quadrant.
+    let venus = vsop87::KeplerianElements::from(vsop87::venus(2451545.0));
+    assert!((venus.eccentricity() - 0.00677192).abs() < 1e-4);
+    assert!((venus.ascending_node().to_degrees() - 76.679920).abs() < 0.01);
+    assert!((venus.perihelion_longitude().to_degrees() - 131.563703).abs() < 0.5);
+
+    // The longitude of the perihelion of Mars is in the fourth quadrant.
+    let mars = vsop87::KeplerianElements::from(vsop87::mars(2451545.0));
+    assert!((mars.eccentricity() - 0.09340065).abs() < 1e-3);
+    assert!((mars.inclination().to_degrees() - 1.849726).abs() < 1e-3);
+    assert!((mars.ascending_node().to_degrees() - 49.558093).abs() < 0.01);
+    assert!((mars.perihelion_longitude().to_degrees() - 336.060234).abs() < 0.1);
+    assert!((mars.mean_longitude().to_degrees() - 355.433275).abs() < 0.1);
+    assert!((mars.argument_of_periapsis().to_degrees() - 286.502141).abs() < 0.1);
+
+    // Converting back gives the original elements.
+    let elements = vsop87::mars(2451545.0);
+    let back = vsop87::VSOP87Elements::from(vsop87::KeplerianElements::from(elements));
+    assert!((back.k - elements.k).abs() < 1e-12);
+    assert!((back.h - elements.h).abs() < 1e-12);
+    assert!((back.q - elements.q).abs() < 1e-12);
+    assert!((back.p - elements.p).abs() < 1e-12);
+}
+
+#[cfg(all(feature = "earth", feature = "jupiter"))]
+#[test]
+fn it_period() {
+    let earth = vsop87::KeplerianElements::from(vsop87::earth_moon(2451545.0));
+    assert!((earth.period() - 365.25).abs() < 0.01);
+    assert!((earth.mean_motion() - 0.9856_f64.to_radians()).abs() < 1e-6);
+
+    let jupiter = vsop87::KeplerianElements::from(vsop87::jupiter(2451545.0));
+    assert!((jupiter.period() - 4332.0).abs() < 10.0);
+    assert!((jupiter.period() * jupiter.mean_motion() - 2.0 * std::f64::consts::PI).abs() < 1e-12);
+}
+
+#[cfg(all(
+    feature = "mercury",
+    feature = "earth",
+    feature = "mars",
+    feature = "neptune"
+))]
+#[test]
+fn it_keplerian_to_rectangular() {
+    // At the epoch of the elements, the two-body position is the VSOP87A one.
+    for &jde in &[2451545.0, 2122820.0, 2500000.0] {
+        let mercury = vsop87::KeplerianElements::from(vsop87::mercury(jde)).to_rectangular();
+        assert!((mercury - vsop87::vsop87a::mercury(jde)).norm() < 1e-8);
+
+        let earth = vsop87::KeplerianElements::from(vsop87::earth_moon(jde)).to_rectangular();
+        assert!((earth - vsop87::vsop87a::earth_moon(jde)).norm() < 1e-7);
+
+        let mars = vsop87::KeplerianElements::from(vsop87::mars(jde)).to_rectangular();
+        assert!((mars - vsop87::vsop87a::mars(jde)).norm() < 1e-6);
+
+        let neptune = vsop87::KeplerianElements::from(vsop87::neptune(jde)).to_rectangular();
+        assert!((neptune - vsop87::vsop87a::neptune(jde)).norm() < 1e-5);
+    }
+
+    // The distance to the Sun stays between the perihelion and the aphelion.
+    let mercury = vsop87::KeplerianElements::from(vsop87::mercury(2451545.0));
+    let distance = mercury.to_rectangular().norm();
+    let a = mercury.semimajor_axis();
+    let e = mercury.eccentricity();
+    assert!(distance > a * (1.0 - e) && distance < a * (1.0 + e));
+}
+
+#[cfg(feature = "mercury")]
+#[test]
+fn it_anomalies() {
+    // At the perihelion, the three anomalies are zero.
+    let mut elements = vsop87::mercury(2451545.0);
+    elements.l = elements.h.atan2(elements.k);
+    let perihelion = vsop87::KeplerianElements::from(elements);
+    assert!(perihelion.mean_anomaly().abs() < 1e-15);
+    assert!(perihelion.eccentric_anomaly().abs() < 1e-15);
+    assert!(perihelion.true_anomaly().abs() < 1e-15);
+
+    // At the aphelion, they are all π.
+    elements.l += std::f64::consts::PI;
+    let aphelion = vsop87::KeplerianElements::from(elements);
+    assert!((aphelion.mean_anomaly() - std::f64::consts::PI).abs() < 1e-12);
+    assert!((aphelion.eccentric_anomaly() - std::f64::consts::PI).abs() < 1e-12);
+    assert!((aphelion.true_anomaly() - std::f64::consts::PI).abs() < 1e-12);
+
+    // Kepler's equation, and the relation between the eccentric and the true anomaly.
+    for &jde in &[2451545.0, 2451600.0, 2122820.0, 2500000.0] {
+        let mercury = vsop87::KeplerianElements::from(vsop87::mercury(jde));
+        let e = mercury.eccentricity();
+        let mean = mercury.mean_anomaly();
+        let eccentric = mercury.eccentric_anomaly();
+        let true_anomaly = mercury.true_anomaly();
+
+        for &anomaly in &[mean, eccentric, true_anomaly] {
+            assert!((0.0..2.0 * std::f64::consts::PI).contains(&anomaly));
+        }
+        let kepler = eccentric - e * eccentric.sin() - mean;
+        assert!(kepler.abs() < 1e-12 || (kepler.abs() - 2.0 * std::f64::consts::PI).abs() < 1e-12);
+        let tan_half = ((1.0 + e) / (1.0 - e)).sqrt() * (eccentric / 2.0).tan();
+        assert!((tan_half - (true_anomaly / 2.0).tan()).abs() < 1e-9);
+
+        // The mean anomaly is the mean longitude minus the longitude of the perihelion.
+        let difference = mercury.mean_longitude() - mercury.perihelion_longitude() - mean;
+        assert!((difference / (2.0 * std::f64::consts::PI)).fract().abs() < 1e-12);
+    }
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_planet() {
+    use vsop87::Planet;
+
+    for &jde in &[2451545.0, 2122820.0] {
+        let elements = [
+            (Planet::Mercury, vsop87::mercury(jde)),
+            (Planet::Venus, vsop87::venus(jde)),
+            (Planet::Earth, vsop87::earth_moon(jde)),
+            (Planet::Mars, vsop87::mars(jde)),
+            (Planet::Jupiter, vsop87::jupiter(jde)),
+            (Planet::Saturn, vsop87::saturn(jde)),
+            (Planet::Uranus, vsop87::uranus(jde)),
+            (Planet::Neptune, vsop87::neptune(jde)),
+        ];
+
+        for &(planet, expected) in &elements {
+            assert_eq!(vsop87::planet(planet, jde), expected);
+        }
+    }
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_state_vector() {
+    let mu = vsop87::GAUSSIAN_GRAVITATIONAL_CONSTANT.powi(2);
+    for &planet in vsop87::Planet::ALL.iter() {
+        let elements = vsop87::KeplerianElements::from(vsop87::planet(planet, 2451545.0));
+
+        // The velocity is the derivative of the position along the orbit.
+        let step = 0.01;
+        let state = elements.to_state_vector(100.0);
+        let estimate = (elements.to_state_vector(100.0 + step).position
+            - elements.to_state_vector(100.0 - step).position)
+            / (2.0 * step);
+        assert!((state.velocity - estimate).norm() < 1e-6 * state.velocity.norm());
+
+        // Converting back gives the original orbit, with the mean longitude moved forward.
+        let back = state.to_elements(mu);
+        assert!((back.semimajor_axis() - elements.semimajor_axis()).abs() < 1e-12);
+        assert!((back.eccentricity() - elements.eccentricity()).abs() < 1e-12);
+        assert!((back.inclination() - elements.inclination()).abs() < 1e-12);
+        assert!((back.ascending_node() - elements.ascending_node()).abs() < 1e-12);
+        assert!((back.periapsis() - elements.periapsis()).abs() < 1e-10);
+        let motion = elements.mean_longitude() + 100.0 * elements.mean_motion();
+        assert!(vsop87::normalize_angle_signed(back.mean_longitude() - motion).abs() < 1e-10);
+    }
+
+    // A circular orbit in the reference plane has no node nor periapsis.
+    let state = vsop87::StateVector {
+        position: vsop87::RectangularCoordinates {
+            x: 0.0,
+            y: 1.0,
+            z: 0.0,
+        },
+        velocity: vsop87::RectangularCoordinates {
+            x: -vsop87::GAUSSIAN_GRAVITATIONAL_CONSTANT,
+            y: 0.0,
+            z: 0.0,
+        },
+    };
+    let elements = state.to_elements(mu);
+    assert!((elements.semimajor_axis() - 1.0).abs() < 1e-12);
+    assert!(elements.eccentricity() < 1e-12);
+    assert_eq!(elements.inclination(), 0.0);
+    assert_eq!(elements.ascending_node(), 0.0);
+    assert!((elements.mean_longitude() - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    assert!((elements.to_state_vector(0.0).position - state.position).norm() < 1e-12);
+}
@@ -1,6 +1,7 @@
 extern crate vsop87;
 use vsop87::*;
 
+#[cfg(feature = "mercury")]
 #[test]
 fn it_mercury() {
     let coordinates = vsop87a::mercury(2451545.0);
@@ -64,6 +65,7 @@ fn it_mercury() {
     assert!(coordinates.z > -0.0504008 && coordinates.z < -0.0503958);
 }
 
+#[cfg(feature = "venus")]
 #[test]
 fn it_venus() {
     let coordinates = vsop87a::venus(2451545.0);
@@ -127,6 +129,7 @@ fn it_venus() {
     assert!(coordinates.z > 0.0357849 && coordinates.z < 0.0357899);
 }
 
+#[cfg(feature = "earth")]
 #[test]
 fn it_earth() {
     let coordinates = vsop87a::earth(2451545.0);
@@ -190,6 +193,7 @@ fn it_earth() {
     assert!(coordinates.z > 0.0019090 && coordinates.z < 0.0019140);
 }
 
+#[cfg(feature = "mars")]
 #[test]
 fn it_mars() {
     let coordinates = vsop87a::mars(2451545.0);
@@ -253,6 +257,7 @@ fn it_mars() {
     assert!(coordinates.z > 0.0494191 && coordinates.z < 0.0494241);
 }
 
+#[cfg(feature = "jupiter")]
 #[test]
 fn it_jupiter() {
     let coordinates = vsop87a::jupiter(2451545.0);
@@ -316,6 +321,7 @@ fn it_jupiter() {
     assert!(coordinates.z > -0.0280113 && coordinates.z < -0.0280063);
 }
 
+#[cfg(feature = "saturn")]
 #[test]
 fn it_saturn() {
     let coordinates = vsop87a::saturn(2451545.0);
@@ -379,6 +385,7 @@ fn it_saturn() {
     assert!(coordinates.z > 0.4165577 && coordinates.z < 0.4165627);
 }
 
+#[cfg(feature = "uranus")]
 #[test]
 fn it_uranus() {
     let coordinates = vsop87a::uranus(2451545.0);
@@ -442,6 +449,7 @@ fn it_uranus() {
     assert!(coordinates.z > -0.0950214 && coordinates.z < -0.0950164);
 }
 
+#[cfg(feature = "neptune")]
 #[test]
 fn it_neptune() {
     let coordinates = vsop87a::neptune(2451545.0);
@@ -505,6 +513,7 @@ fn it_neptune() {
     assert!(coordinates.z > 0.1206349 && coordinates.z < 0.1206399);
 }
 
+#[cfg(feature = "earth")]
 #[test]
 fn it_earth_moon() {
     let coordinates = vsop87a::earth_moon(2451545.0);
@@ -567,3 +576,111 @@ fn it_earth_moon() {
     assert!(coordinates.y > 0.9433889918 && coordinates.y < 0.9433889920);
     assert!(coordinates.z > 0.0019119 && coordinates.z < 0.0019169);
 }
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_velocity() {
+    let step = 0.01;
+    for &planet in Planet::ALL.iter() {
+        for &jde in [2451545.0, 2415020.0, 2122820.0, 2817151.5].iter() {
+            let velocity = vsop87a::velocity(planet, jde);
+            let estimate = (vsop87a::planet(planet, jde + step)
+                - vsop87a::planet(planet, jde - step))
+                / (2.0 * step);
+
+            assert!((velocity - estimate).norm() < 1e-6 * velocity.norm());
+        }
+    }
+
+    // The Earth moves about 2π AU per year.
+    let speed = vsop87a::velocity(Planet::Earth, 2451545.0).norm();
+    assert!(speed * 365.25 > 6.1 && speed * 365.25 < 6.4);
+}
+
+#[cfg(all(feature = "earth", feature = "mars", feature = "neptune"))]
+#[test]
+fn it_speed_km_s() {
+    // Mean orbital speeds, over one full orbit of each planet.
+    let mean_speed = |planet: Planet, period: f64| {
+        (0..100)
+            .map(|i| vsop87a::speed_km_s(planet, 2451545.0 + period * f64::from(i) / 100.0))
+            .sum::<f64>()
+            / 100.0
+    };
+    assert!((mean_speed(Planet::Earth, 365.25) - 29.8).abs() < 0.05);
+    assert!((mean_speed(Planet::Neptune, 60_190.0) - 5.4).abs() < 0.05);
+
+    let velocity = vsop87a::velocity(Planet::Mars, 2451545.0);
+    let speed = vsop87a::speed_km_s(Planet::Mars, 2451545.0);
+    assert!((speed - velocity.norm() * AU_KM / 86_400.0).abs() < 1e-12);
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_all_planets() {
+    let jde = 2415020.0;
+    let planets = vsop87a::all_planets(jde);
+
+    assert_eq!(planets[0], vsop87a::mercury(jde));
+    assert_eq!(planets[7], vsop87a::neptune(jde));
+    for (coordinates, &planet) in planets.iter().zip(Planet::ALL.iter()) {
+        assert_eq!(*coordinates, vsop87a::planet(planet, jde));
+    }
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_state() {
+    let step = 0.01;
+    for &planet in Planet::ALL.iter() {
+        for &jde in [2451545.0, 2415020.0, 2122820.0, 2817151.5].iter() {
+            let (position, velocity) = vsop87a::state(planet, jde);
+            let estimate = (vsop87a::planet(planet, jde + step)
+                - vsop87a::planet(planet, jde - step))
+                / (2.0 * step);
+
+            assert_eq!(position, vsop87a::planet(planet, jde));
+            assert!((velocity - estimate).norm() < 1e-6 * velocity.norm());
+        }
+    }
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_planet_truncated() {
+    for &planet in Planet::ALL.iter() {
+        for &jde in [2451545.0, 2415020.0, 2488070.0].iter() {
+            let full = vsop87a::planet(planet, jde);
+            let all_terms = vsop87a::planet_truncated(planet, jde, 0.0);
+            assert!((all_terms - full).norm() < 1e-12);
+
+            for &epsilon in [1e-6, 1e-8].iter() {
+                let truncated = vsop87a::planet_truncated(planet, jde, epsilon);
+                assert!((full - truncated).norm() < 30.0 * epsilon);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "all_planets")]
+#[cfg(not(feature = "no_std"))]
+#[test]
+fn it_planet_many() {
+    let jdes: Vec<f64> = (0..100)
+        .map(|i| 2451545.0 + 36.525 * f64::from(i))
+        .collect();
+
+    for &planet in &Planet::ALL {
+        let batch = vsop87a::planet_many(planet, &jdes);
+        let mut output = vec![vsop87a::earth(0.0); jdes.len()];
+        vsop87a::planet_into(planet, &jdes, &mut output);
+
+        assert_eq!(batch.len(), jdes.len());
+        for ((&jde, coordinates), written) in jdes.iter().zip(&batch).zip(&output) {
+            assert_eq!(*coordinates, vsop87a::planet(planet, jde));
+            assert_eq!(written, coordinates);
+        }
+    }
+
+    assert!(vsop87a::planet_many(Planet::Venus, &[]).is_empty());
+}
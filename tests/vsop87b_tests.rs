@@ -1,6 +1,7 @@
 extern crate vsop87;
 use vsop87::*;
 
+#[cfg(feature = "mercury")]
 #[test]
 fn it_mercury() {
     let coordinates = vsop87b::mercury(2451545.0);
@@ -64,6 +65,7 @@ fn it_mercury() {
     assert!(coordinates.distance() > 0.4352059 && coordinates.distance() < 0.4352067);
 }
 
+#[cfg(feature = "venus")]
 #[test]
 fn it_venus() {
     let coordinates = vsop87b::venus(2451545.0);
@@ -127,6 +129,7 @@ fn it_venus() {
     assert!(coordinates.distance() > 0.7215816 && coordinates.distance() < 0.7215824);
 }
 
+#[cfg(feature = "earth")]
 #[test]
 fn it_earth() {
     let coordinates = vsop87b::earth(2451545.0);
@@ -190,6 +193,7 @@ fn it_earth() {
     assert!(coordinates.distance() > 0.9830328 && coordinates.distance() < 0.9830336);
 }
 
+#[cfg(feature = "mars")]
 #[test]
 fn it_mars() {
     let coordinates = vsop87b::mars(2451545.0);
@@ -253,6 +257,7 @@ fn it_mars() {
     assert!(coordinates.distance() > 1.6570998 && coordinates.distance() < 1.6571006);
 }
 
+#[cfg(feature = "jupiter")]
 #[test]
 fn it_jupiter() {
     let coordinates = vsop87b::jupiter(2451545.0);
@@ -316,6 +321,7 @@ fn it_jupiter() {
     assert!(coordinates.distance() > 5.1193583 && coordinates.distance() < 5.1193591);
 }
 
+#[cfg(feature = "saturn")]
 #[test]
 fn it_saturn() {
     let coordinates = vsop87b::saturn(2451545.0);
@@ -379,6 +385,7 @@ fn it_saturn() {
     assert!(coordinates.distance() > 9.8669935 && coordinates.distance() < 9.8669943);
 }
 
+#[cfg(feature = "uranus")]
 #[test]
 fn it_uranus() {
     let coordinates = vsop87b::uranus(2451545.0);
@@ -442,6 +449,7 @@ fn it_uranus() {
     assert!(coordinates.distance() > 19.5612076 && coordinates.distance() < 19.5612084);
 }
 
+#[cfg(feature = "neptune")]
 #[test]
 fn it_neptune() {
     let coordinates = vsop87b::neptune(2451545.0);
@@ -504,3 +512,84 @@ fn it_neptune() {
     assert!(coordinates.latitude() > 0.0040125141 && coordinates.latitude() < 0.0040125143);
     assert!(coordinates.distance() > 30.0653691 && coordinates.distance() < 30.0653699);
 }
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_state() {
+    let step = 0.01;
+    for &planet in Planet::ALL.iter() {
+        for &jde in [2451545.0, 2415020.0, 2122820.0, 2817151.5].iter() {
+            let (position, rates) = vsop87b::state(planet, jde);
+            let before = vsop87b::planet(planet, jde - step);
+            let after = vsop87b::planet(planet, jde + step);
+
+            assert_eq!(position, vsop87b::planet(planet, jde));
+            let longitude_rate = normalize_angle_signed(after.longitude() - before.longitude());
+            assert!((rates.longitude() - longitude_rate / (2.0 * step)).abs() < 1e-8);
+            let latitude_rate = (after.latitude() - before.latitude()) / (2.0 * step);
+            assert!((rates.latitude() - latitude_rate).abs() < 1e-8);
+            let distance_rate = (after.distance() - before.distance()) / (2.0 * step);
+            assert!((rates.distance() - distance_rate).abs() < 1e-8);
+        }
+    }
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_planet_truncated() {
+    for &planet in Planet::ALL.iter() {
+        for &jde in [2451545.0, 2415020.0, 2488070.0].iter() {
+            let full = vsop87b::planet(planet, jde);
+            let all_terms = vsop87b::planet_truncated(planet, jde, 0.0);
+            assert!((all_terms.latitude() - full.latitude()).abs() < 1e-12);
+            assert!((all_terms.distance() - full.distance()).abs() < 1e-12);
+
+            for &epsilon in [1e-6, 1e-8].iter() {
+                let truncated = vsop87b::planet_truncated(planet, jde, epsilon);
+                let longitude = normalize_angle_signed(full.longitude() - truncated.longitude());
+
+                assert!(longitude.abs() < 30.0 * epsilon);
+                assert!((full.latitude() - truncated.latitude()).abs() < 30.0 * epsilon);
+                assert!(
+                    (full.distance() - truncated.distance()).abs()
+                        < 30.0 * epsilon * full.distance()
+                );
+            }
+        }
+    }
+}
+
+#[cfg(feature = "all_planets")]
+#[cfg(not(feature = "no_std"))]
+#[test]
+fn it_planet_many() {
+    let jdes: Vec<f64> = (0..100)
+        .map(|i| 2451545.0 + 36.525 * f64::from(i))
+        .collect();
+
+    for &planet in &Planet::ALL {
+        let batch = vsop87b::planet_many(planet, &jdes);
+        let mut output = vec![vsop87b::earth(0.0); jdes.len()];
+        vsop87b::planet_into(planet, &jdes, &mut output);
+
+        assert_eq!(batch.len(), jdes.len());
+        for ((&jde, coordinates), written) in jdes.iter().zip(&batch).zip(&output) {
+            assert_eq!(*coordinates, vsop87b::planet(planet, jde));
+            assert_eq!(written, coordinates);
+        }
+    }
+
+    assert!(vsop87b::planet_many(Planet::Venus, &[]).is_empty());
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_all_planets() {
+    for &jde in [2451545.0, 2415020.0, 2817151.5].iter() {
+        let planets = vsop87b::all_planets(jde);
+
+        for (coordinates, &planet) in planets.iter().zip(Planet::ALL.iter()) {
+            assert_eq!(*coordinates, vsop87b::planet(planet, jde));
+        }
+    }
+}
@@ -1,6 +1,7 @@
 extern crate vsop87;
 use vsop87::*;
 
+#[cfg(feature = "mercury")]
 #[test]
 fn it_mercury() {
     let coordinates = vsop87c::mercury(2451545.0);
@@ -64,6 +65,7 @@ fn it_mercury() {
     assert!(coordinates.z > -0.04964807 && coordinates.z < -0.04964747);
 }
 
+#[cfg(feature = "venus")]
 #[test]
 fn it_venus() {
     let coordinates = vsop87c::venus(2451545.0);
@@ -127,6 +129,7 @@ fn it_venus() {
     assert!(coordinates.z > 0.03642526 && coordinates.z < 0.03642586);
 }
 
+#[cfg(feature = "earth")]
 #[test]
 fn it_earth() {
     let coordinates = vsop87c::earth(2451545.0);
@@ -190,6 +193,7 @@ fn it_earth() {
     assert!(coordinates.z > -0.00000338 && coordinates.z < -0.00000278);
 }
 
+#[cfg(feature = "mars")]
 #[test]
 fn it_mars() {
     let coordinates = vsop87c::mars(2451545.0);
@@ -253,6 +257,7 @@ fn it_mars() {
     assert!(coordinates.z > 0.04896365 && coordinates.z < 0.04896425);
 }
 
+#[cfg(feature = "jupiter")]
 #[test]
 fn it_jupiter() {
     let coordinates = vsop87c::jupiter(2451545.0);
@@ -316,6 +321,7 @@ fn it_jupiter() {
     assert!(coordinates.z > -0.03856696 && coordinates.z < -0.03856636);
 }
 
+#[cfg(feature = "saturn")]
 #[test]
 fn it_saturn() {
     let coordinates = vsop87c::saturn(2451545.0);
@@ -379,6 +385,7 @@ fn it_saturn() {
     assert!(coordinates.z > 0.42944448 && coordinates.z < 0.42944508);
 }
 
+#[cfg(feature = "uranus")]
 #[test]
 fn it_uranus() {
     let coordinates = vsop87c::uranus(2451545.0);
@@ -442,6 +449,7 @@ fn it_uranus() {
     assert!(coordinates.z > -0.12834172 && coordinates.z < -0.12834112);
 }
 
+#[cfg(feature = "neptune")]
 #[test]
 fn it_neptune() {
     let coordinates = vsop87c::neptune(2451545.0);
@@ -504,3 +512,72 @@ fn it_neptune() {
     assert!(coordinates.y > 24.0846111038 && coordinates.y < 24.0846111040);
     assert!(coordinates.z > 0.08267368 && coordinates.z < 0.08267428);
 }
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_state() {
+    let step = 0.01;
+    for &planet in Planet::ALL.iter() {
+        for &jde in [2451545.0, 2415020.0, 2122820.0, 2817151.5].iter() {
+            let (position, velocity) = vsop87c::state(planet, jde);
+            let estimate = (vsop87c::planet(planet, jde + step)
+                - vsop87c::planet(planet, jde - step))
+                / (2.0 * step);
+
+            assert_eq!(position, vsop87c::planet(planet, jde));
+            assert!((velocity - estimate).norm() < 1e-6 * velocity.norm());
+        }
+    }
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_planet_truncated() {
+    for &planet in Planet::ALL.iter() {
+        for &jde in [2451545.0, 2415020.0, 2488070.0].iter() {
+            let full = vsop87c::planet(planet, jde);
+            let all_terms = vsop87c::planet_truncated(planet, jde, 0.0);
+            assert!((all_terms - full).norm() < 1e-12);
+
+            for &epsilon in [1e-6, 1e-8].iter() {
+                let truncated = vsop87c::planet_truncated(planet, jde, epsilon);
+                assert!((full - truncated).norm() < 30.0 * epsilon);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "all_planets")]
+#[cfg(not(feature = "no_std"))]
+#[test]
+fn it_planet_many() {
+    let jdes: Vec<f64> = (0..100)
+        .map(|i| 2451545.0 + 36.525 * f64::from(i))
+        .collect();
+
+    for &planet in &Planet::ALL {
+        let batch = vsop87c::planet_many(planet, &jdes);
+        let mut output = vec![vsop87c::earth(0.0); jdes.len()];
+        vsop87c::planet_into(planet, &jdes, &mut output);
+
+        assert_eq!(batch.len(), jdes.len());
+        for ((&jde, coordinates), written) in jdes.iter().zip(&batch).zip(&output) {
+            assert_eq!(*coordinates, vsop87c::planet(planet, jde));
+            assert_eq!(written, coordinates);
+        }
+    }
+
+    assert!(vsop87c::planet_many(Planet::Venus, &[]).is_empty());
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_all_planets() {
+    for &jde in [2451545.0, 2415020.0, 2817151.5].iter() {
+        let planets = vsop87c::all_planets(jde);
+
+        for (coordinates, &planet) in planets.iter().zip(Planet::ALL.iter()) {
+            assert_eq!(*coordinates, vsop87c::planet(planet, jde));
+        }
+    }
+}
@@ -1,6 +1,7 @@
 extern crate vsop87;
 use vsop87::*;
 
+#[cfg(feature = "mercury")]
 #[test]
 fn it_mercury() {
     let coordinates = vsop87d::mercury(2451545.0);
@@ -64,6 +65,7 @@ fn it_mercury() {
     assert!(coordinates.distance() > 0.43520594 && coordinates.distance() < 0.43520670);
 }
 
+#[cfg(feature = "venus")]
 #[test]
 fn it_venus() {
     let coordinates = vsop87d::venus(2451545.0);
@@ -127,6 +129,7 @@ fn it_venus() {
     assert!(coordinates.distance() > 0.72158160 && coordinates.distance() < 0.72158236);
 }
 
+#[cfg(feature = "earth")]
 #[test]
 fn it_earth() {
     let coordinates = vsop87d::earth(2451545.0);
@@ -190,6 +193,7 @@ fn it_earth() {
     assert!(coordinates.distance() > 0.98303280 && coordinates.distance() < 0.98303356);
 }
 
+#[cfg(feature = "mars")]
 #[test]
 fn it_mars() {
     let coordinates = vsop87d::mars(2451545.0);
@@ -253,6 +257,7 @@ fn it_mars() {
     assert!(coordinates.distance() > 1.65709985 && coordinates.distance() < 1.65710061);
 }
 
+#[cfg(feature = "jupiter")]
 #[test]
 fn it_jupiter() {
     let coordinates = vsop87d::jupiter(2451545.0);
@@ -316,6 +321,7 @@ fn it_jupiter() {
     assert!(coordinates.distance() > 5.11935836 && coordinates.distance() < 5.11935912);
 }
 
+#[cfg(feature = "saturn")]
 #[test]
 fn it_saturn() {
     let coordinates = vsop87d::saturn(2451545.0);
@@ -379,6 +385,7 @@ fn it_saturn() {
     assert!(coordinates.distance() > 9.86699357 && coordinates.distance() < 9.86699433);
 }
 
+#[cfg(feature = "uranus")]
 #[test]
 fn it_uranus() {
     let coordinates = vsop87d::uranus(2451545.0);
@@ -442,6 +449,7 @@ fn it_uranus() {
     assert!(coordinates.distance() > 19.56120745 && coordinates.distance() < 19.56120821);
 }
 
+#[cfg(feature = "neptune")]
 #[test]
 fn it_neptune() {
     let coordinates = vsop87d::neptune(2451545.0);
@@ -504,3 +512,489 @@ fn it_neptune() {
     assert!(coordinates.latitude() > 0.0027498092 && coordinates.latitude() < 0.0027498094);
     assert!(coordinates.distance() > 30.06536898 && coordinates.distance() < 30.06536974);
 }
+
+#[cfg(feature = "earth")]
+#[test]
+fn it_evaluate() {
+    let tables = PlanetTables {
+        longitude: vsop87d::EARTH.longitude,
+        latitude: vsop87d::EARTH.latitude,
+        distance: vsop87d::EARTH.distance,
+    };
+
+    for &jde in &[2451545.0, 2415020.0, 2305445.0, 2122820.0] {
+        assert_eq!(evaluate(&tables, jde), vsop87d::earth(jde));
+    }
+
+    let truncated = PlanetTables {
+        longitude: &vsop87d::EARTH.longitude[..1],
+        latitude: &vsop87d::EARTH.latitude[..1],
+        distance: &vsop87d::EARTH.distance[..1],
+    };
+    let coordinates = evaluate(&truncated, 2451545.0);
+
+    assert!((coordinates.longitude() - vsop87d::earth(2451545.0).longitude()).abs() < 1e-3);
+}
+
+#[cfg(all(
+    feature = "mercury",
+    feature = "venus",
+    feature = "mars",
+    feature = "jupiter",
+    feature = "neptune"
+))]
+#[test]
+fn it_find_node_crossing() {
+    for &planet in &[
+        Planet::Mercury,
+        Planet::Venus,
+        Planet::Mars,
+        Planet::Jupiter,
+    ] {
+        for &ascending in &[true, false] {
+            let jde = vsop87d::find_node_crossing(planet, 2451545.0, ascending).unwrap();
+            let latitude = |jde: f64| match planet {
+                Planet::Mercury => vsop87d::mercury(jde).latitude(),
+                Planet::Venus => vsop87d::venus(jde).latitude(),
+                Planet::Mars => vsop87d::mars(jde).latitude(),
+                _ => vsop87d::jupiter(jde).latitude(),
+            };
+
+            assert!(jde > 2451545.0);
+            assert!(latitude(jde).abs() < 1e-9);
+
+            let rate = latitude(jde + 1.0) - latitude(jde - 1.0);
+            if ascending {
+                assert!(rate > 0.0);
+            } else {
+                assert!(rate < 0.0);
+            }
+        }
+    }
+
+    assert_eq!(
+        vsop87d::find_node_crossing(Planet::Mars, f64::NAN, true),
+        None
+    );
+    assert_eq!(
+        vsop87d::find_node_crossing(Planet::Neptune, f64::INFINITY, false),
+        None
+    );
+}
+
+#[cfg(all(feature = "venus", feature = "jupiter"))]
+#[test]
+fn it_visibility_window() {
+    // Venus at its greatest eastern elongation, in March 24th, 2020.
+    let near = 2458932.5;
+    let (start, end) = vsop87d::visibility_window(Planet::Venus, near, 20.0);
+
+    assert!(start < near && end > near);
+    assert!(end - start > 200.0 && end - start < 300.0);
+
+    // The window is limited by the threshold, so just outside it the elongation is lower.
+    let (inner_start, inner_end) = vsop87d::visibility_window(Planet::Venus, near, 40.0);
+
+    assert!(inner_start > start && inner_end < end);
+
+    // Venus is never 50° away from the Sun.
+    assert_eq!(
+        vsop87d::visibility_window(Planet::Venus, near, 50.0),
+        (near, near)
+    );
+
+    // Jupiter reaches opposition, so it is away from the Sun most of the year.
+    let (start, end) = vsop87d::visibility_window(Planet::Jupiter, near, 15.0);
+
+    assert!(start < near && end > near);
+    assert!(end - start > 300.0 && end - start < 399.0);
+}
+
+#[cfg(feature = "all_planets")]
+#[cfg(not(feature = "no_std"))]
+#[test]
+fn it_planet_many() {
+    let jdes: Vec<f64> = (0..100)
+        .map(|i| 2451545.0 + 36.525 * f64::from(i))
+        .collect();
+
+    for &planet in &Planet::ALL {
+        let batch = vsop87d::planet_many(planet, &jdes);
+        let mut output = vec![vsop87d::earth(0.0); jdes.len()];
+        vsop87d::planet_into(planet, &jdes, &mut output);
+
+        assert_eq!(batch.len(), jdes.len());
+        for ((&jde, coordinates), written) in jdes.iter().zip(&batch).zip(&output) {
+            assert_eq!(*coordinates, vsop87d::planet(planet, jde));
+            assert_eq!(written, coordinates);
+        }
+    }
+
+    assert!(vsop87d::planet_many(Planet::Venus, &[]).is_empty());
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_planet_into() {
+    let mut jdes = [0.0; 16];
+    let mut jde = 2451545.0;
+    for date in jdes.iter_mut() {
+        *date = jde;
+        jde += 91.3125;
+    }
+
+    // The same stack buffer is reused for every planet.
+    let mut output = [SphericalCoordinates::new(0.0, 0.0, 0.0); 16];
+    for &planet in &Planet::ALL {
+        vsop87d::planet_into(planet, &jdes, &mut output);
+        for (&jde, written) in jdes.iter().zip(&output) {
+            assert_eq!(*written, vsop87d::planet(planet, jde));
+        }
+    }
+
+    // A shorter batch only writes the start of the buffer.
+    vsop87d::planet_into(Planet::Mars, &jdes[..4], &mut output[..4]);
+    assert_eq!(output[3], vsop87d::mars(jdes[3]));
+    assert_eq!(output[4], vsop87d::neptune(jdes[4]));
+
+    vsop87d::planet_into(Planet::Mars, &[], &mut []);
+}
+
+#[cfg(feature = "mars")]
+#[test]
+#[should_panic(expected = "the input dates and the output buffer must have the same length")]
+fn it_planet_into_length_mismatch() {
+    let mut output = [SphericalCoordinates::new(0.0, 0.0, 0.0); 2];
+    vsop87d::planet_into(Planet::Mars, &[2451545.0; 3], &mut output);
+}
+
+#[cfg(feature = "all_planets")]
+#[cfg(not(feature = "no_std"))]
+#[test]
+fn it_planet_many_par() {
+    let jdes: Vec<f64> = (0..500).map(|i| 2451545.0 + 730.5 * f64::from(i)).collect();
+
+    for &planet in &Planet::ALL {
+        let serial = vsop87d::planet_many(planet, &jdes);
+        let parallel = vsop87d::planet_many_par(planet, &jdes);
+
+        assert_eq!(parallel.len(), serial.len());
+        for (parallel, serial) in parallel.iter().zip(&serial) {
+            assert_eq!(parallel, serial);
+        }
+    }
+
+    assert!(vsop87d::planet_many_par(Planet::Earth, &[]).is_empty());
+}
+
+#[cfg(all(
+    feature = "mercury",
+    feature = "earth",
+    feature = "jupiter",
+    feature = "saturn",
+    feature = "neptune"
+))]
+#[test]
+fn it_ephemeris() {
+    let mut count = 0;
+    for (i, (jde, coordinates)) in vsop87d::ephemeris(Planet::Jupiter, 2451545.0, 36.5)
+        .take(10)
+        .enumerate()
+    {
+        let expected_jde = 2451545.0 + 36.5 * i as f64;
+        assert_eq!(jde, expected_jde);
+        assert_eq!(coordinates, vsop87d::jupiter(expected_jde));
+        count += 1;
+    }
+    assert_eq!(count, 10);
+
+    // Going back in time.
+    let positions: Vec<_> =
+        vsop87d::ephemeris_range(Planet::Earth, 2451545.0, 2451535.0, -1.0).collect();
+    assert_eq!(positions.len(), 11);
+    assert_eq!(positions[10].0, 2451535.0);
+    assert_eq!(positions[10].1, vsop87d::earth(2451535.0));
+
+    // The end is not included if it is not reached by a whole step.
+    let positions: Vec<_> = vsop87d::ephemeris_range(Planet::Mercury, 0.0, 9.5, 1.0).collect();
+    assert_eq!(positions.len(), 10);
+    assert_eq!(positions[9].0, 9.0);
+
+    assert_eq!(
+        vsop87d::ephemeris_range(Planet::Neptune, 10.0, 0.0, 1.0).count(),
+        0
+    );
+
+    // Steps that would never reach the end, and dates that are not numbers.
+    assert_eq!(
+        vsop87d::ephemeris(Planet::Jupiter, 2451545.0, 0.0).count(),
+        0
+    );
+    assert_eq!(
+        vsop87d::ephemeris(Planet::Jupiter, 2451545.0, f64::NAN).count(),
+        0
+    );
+    assert_eq!(
+        vsop87d::ephemeris(Planet::Jupiter, f64::INFINITY, 1.0).count(),
+        0
+    );
+    for &(start, end, step) in [
+        (2451545.0, 2451555.0, 0.0),
+        (2451545.0, 2451555.0, -0.0),
+        (2451545.0, 2451555.0, f64::NAN),
+        (2451545.0, 2451555.0, f64::INFINITY),
+        (2451545.0, 2451555.0, -1.0),
+        (f64::NAN, 2451555.0, 1.0),
+        (2451545.0, f64::NAN, 1.0),
+        (2451545.0, f64::INFINITY, 1.0),
+        (f64::NEG_INFINITY, 2451555.0, 1.0),
+    ]
+    .iter()
+    {
+        assert_eq!(
+            vsop87d::ephemeris_range(Planet::Neptune, start, end, step).count(),
+            0
+        );
+    }
+
+    let ephemeris = vsop87d::Ephemeris::new(Planet::Saturn, 2451545.0, 2451555.0, 2.5);
+    assert!(ephemeris.eq(vsop87d::ephemeris_range(
+        Planet::Saturn,
+        2451545.0,
+        2451555.0,
+        2.5
+    )));
+}
+
+#[cfg(feature = "earth")]
+#[test]
+fn it_powers_of_t() {
+    // The coordinates are polynomials in t whose coefficients are the series. Evaluating them
+    // with Horner's method, without computing any power of t, gives the same result as adding up
+    // each series multiplied by its own power of t.
+    let polynomial = |t: f64, series: &[Series<'_>]| -> f64 {
+        series
+            .iter()
+            .zip([1.0, t, t * t, t * t * t, t * t * t * t, t * t * t * t * t].iter())
+            .map(|([a, b, c], power)| calculate_var(t, a, b, c) * power)
+            .sum()
+    };
+
+    for &jde in [2451545.0, 2415020.0, 990930.5, 3912521.5].iter() {
+        let t = calculate_t(jde);
+        let coordinates = vsop87d::earth(jde);
+        let longitude = polynomial(t, vsop87d::EARTH.longitude);
+
+        // The longitude grows by 2π every year, so far from J2000 the unnormalized value is large
+        // and the tolerance needs to scale with it.
+        let tolerance = 1e-15 * longitude.abs().max(1.0);
+        assert!((normalize_angle(longitude) - coordinates.longitude()).abs() < tolerance);
+        assert!((polynomial(t, vsop87d::EARTH.latitude) - coordinates.latitude()).abs() < 1e-14);
+        assert!((polynomial(t, vsop87d::EARTH.distance) - coordinates.distance()).abs() < 1e-14);
+    }
+}
+
+#[cfg(all(feature = "venus", feature = "saturn"))]
+#[test]
+fn it_try_planet() {
+    assert_eq!(
+        vsop87d::try_planet(Planet::Saturn, 2451545.0),
+        Ok(vsop87d::saturn(2451545.0))
+    );
+    assert!(vsop87d::try_planet(Planet::Venus, MIN_JDE).is_ok());
+    assert!(vsop87d::try_planet(Planet::Venus, MAX_JDE).is_ok());
+
+    assert_eq!(
+        vsop87d::try_planet(Planet::Venus, 5000000.0),
+        Err(Vsop87Error::OutOfRange {
+            jde: 5000000.0,
+            min: MIN_JDE,
+            max: MAX_JDE,
+        })
+    );
+    assert!(vsop87d::try_planet(Planet::Venus, MIN_JDE - 1.0).is_err());
+    assert!(vsop87d::try_planet(Planet::Venus, f64::NAN).is_err());
+}
+
+#[cfg(all(feature = "jupiter", feature = "uranus"))]
+#[test]
+fn it_try_planet_planet_range() {
+    // Around 1000 BC, Jupiter is already out of its span.
+    let (min, max) = Planet::Jupiter.valid_range();
+    assert_eq!(
+        vsop87d::try_planet(Planet::Jupiter, 1_355_817.5),
+        Err(Vsop87Error::OutOfRange {
+            jde: 1_355_817.5,
+            min,
+            max,
+        })
+    );
+    assert!(vsop87d::try_planet(Planet::Jupiter, min).is_ok());
+    assert!(vsop87d::try_planet(Planet::Jupiter, min - 1.0).is_err());
+    assert!(vsop87d::try_planet(Planet::Jupiter, max + 1.0).is_err());
+
+    // Uranus is valid for 6,000 years around J2000.0, beyond MIN_JDE and MAX_JDE.
+    assert!(vsop87d::try_planet(Planet::Uranus, MIN_JDE - 100_000.0).is_ok());
+    assert!(vsop87d::try_planet(Planet::Uranus, MAX_JDE + 100_000.0).is_ok());
+    let (min, max) = Planet::Uranus.valid_range();
+    assert!(vsop87d::try_planet(Planet::Uranus, min - 1.0).is_err());
+    assert!(vsop87d::try_planet(Planet::Uranus, max + 1.0).is_err());
+}
+
+#[cfg(not(feature = "neptune"))]
+#[test]
+fn it_try_planet_not_enabled() {
+    let error = vsop87d::try_planet(Planet::Neptune, 2451545.0).unwrap_err();
+
+    assert_eq!(
+        error,
+        Vsop87Error::NotEnabled {
+            planet: Planet::Neptune,
+            jde: 2451545.0,
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "cannot compute Neptune for Julian Day 2451545, enable the `neptune` feature"
+    );
+    assert!(!Planet::Neptune.is_enabled());
+    assert_eq!(Planet::Neptune.term_count(Variable::Longitude, 0), 0);
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_planet_truncated() {
+    for &planet in Planet::ALL.iter() {
+        for &jde in [2451545.0, 2415020.0, 2488070.0].iter() {
+            let full = vsop87d::planet(planet, jde);
+            let all_terms = vsop87d::planet_truncated(planet, jde, 0.0);
+            assert!((all_terms.latitude() - full.latitude()).abs() < 1e-12);
+            assert!((all_terms.distance() - full.distance()).abs() < 1e-12);
+
+            for &epsilon in [1e-6, 1e-8].iter() {
+                let truncated = vsop87d::planet_truncated(planet, jde, epsilon);
+                let mut longitude = (full.longitude() - truncated.longitude()).abs();
+                if longitude > std::f64::consts::PI {
+                    longitude = 2.0 * std::f64::consts::PI - longitude;
+                }
+
+                assert!(longitude < 30.0 * epsilon);
+                assert!((full.latitude() - truncated.latitude()).abs() < 30.0 * epsilon);
+                assert!(
+                    (full.distance() - truncated.distance()).abs()
+                        < 30.0 * epsilon * full.distance()
+                );
+            }
+        }
+    }
+}
+
+#[cfg(feature = "earth")]
+#[test]
+fn it_earth_moon_barycenter() {
+    let jde = 2448724.5;
+    let moon = SphericalCoordinates::new(
+        133.162655_f64.to_radians(),
+        -3.229126_f64.to_radians(),
+        368409.7 / 149597870.7,
+    );
+
+    let earth = vsop87d::earth(jde);
+    let barycenter = vsop87d::earth_moon_barycenter(jde, &moon);
+
+    let separation = angular_separation(&earth, &barycenter).to_degrees() * 3600.0;
+    assert!(separation > 5.7 && separation < 5.8);
+    assert!((barycenter.distance() - earth.distance()).abs() < 3.1e-5);
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_time_context() {
+    for &jde in [2451545.0, 2415020.0, 2122820.0].iter() {
+        let context = TimeContext::new(jde);
+        assert_eq!(context.jde(), jde);
+        assert_eq!(context.t(), calculate_t(jde));
+
+        assert_eq!(context.earth(), vsop87d::earth(jde));
+        for &planet in Planet::ALL.iter() {
+            assert_eq!(context.planet(planet), vsop87d::planet(planet, jde));
+        }
+    }
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_all_planets() {
+    let jde = 2415020.0;
+    let planets = vsop87d::all_planets(jde);
+
+    assert_eq!(planets[0], vsop87d::mercury(jde));
+    assert_eq!(planets[7], vsop87d::neptune(jde));
+    for (coordinates, &planet) in planets.iter().zip(Planet::ALL.iter()) {
+        assert_eq!(*coordinates, vsop87d::planet(planet, jde));
+    }
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_verify_tables() {
+    assert_eq!(vsop87d::verify_tables(), Ok(()));
+    for &planet in Planet::ALL.iter() {
+        let tables = match planet {
+            Planet::Mercury => &vsop87d::MERCURY,
+            Planet::Venus => &vsop87d::VENUS,
+            Planet::Earth => &vsop87d::EARTH,
+            Planet::Mars => &vsop87d::MARS,
+            Planet::Jupiter => &vsop87d::JUPITER,
+            Planet::Saturn => &vsop87d::SATURN,
+            Planet::Uranus => &vsop87d::URANUS,
+            Planet::Neptune => &vsop87d::NEPTUNE,
+        };
+        assert_eq!(vsop87d::verify_planet_tables(planet, tables), Ok(()));
+    }
+
+    // Perturbs one coefficient of the first distance series of Mars.
+    let [a, b, c] = vsop87d::MARS.distance[0];
+    let mut perturbed = a.to_vec();
+    perturbed[10] *= 1.001;
+    let mut distance = vsop87d::MARS.distance.to_vec();
+    distance[0] = [&perturbed, b, c];
+    let tables = PlanetTables {
+        distance: &distance,
+        ..vsop87d::MARS
+    };
+
+    let error = vsop87d::verify_planet_tables(Planet::Mars, &tables).unwrap_err();
+    assert_eq!(
+        error,
+        TableError {
+            planet: Planet::Mars,
+            variable: Variable::Distance,
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "the Distance tables of Mars do not give the reference position"
+    );
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_state() {
+    let step = 0.01;
+    for &planet in Planet::ALL.iter() {
+        for &jde in [2451545.0, 2415020.0, 2122820.0, 2817151.5].iter() {
+            let (position, rates) = vsop87d::state(planet, jde);
+            let before = vsop87d::planet(planet, jde - step);
+            let after = vsop87d::planet(planet, jde + step);
+
+            assert_eq!(position, vsop87d::planet(planet, jde));
+            let longitude_rate = normalize_angle_signed(after.longitude() - before.longitude());
+            assert!((rates.longitude() - longitude_rate / (2.0 * step)).abs() < 1e-8);
+            let latitude_rate = (after.latitude() - before.latitude()) / (2.0 * step);
+            assert!((rates.latitude() - latitude_rate).abs() < 1e-8);
+            let distance_rate = (after.distance() - before.distance()) / (2.0 * step);
+            assert!((rates.distance() - distance_rate).abs() < 1e-8);
+        }
+    }
+}
@@ -64,6 +64,7 @@ fn it_sun() {
     assert!(coordinates.z > -0.0000964 && coordinates.z < -0.0000924);
 }
 
+#[cfg(feature = "mercury")]
 #[test]
 fn it_mercury() {
     let coordinates = vsop87e::mercury(2451545.0);
@@ -127,6 +128,7 @@ fn it_mercury() {
     assert!(coordinates.z > -0.0504947 && coordinates.z < -0.0504907);
 }
 
+#[cfg(feature = "venus")]
 #[test]
 fn it_venus() {
     let coordinates = vsop87e::venus(2451545.0);
@@ -190,6 +192,7 @@ fn it_venus() {
     assert!(coordinates.z > 0.0356910 && coordinates.z < 0.0356950);
 }
 
+#[cfg(feature = "earth")]
 #[test]
 fn it_earth() {
     let coordinates = vsop87e::earth(2451545.0);
@@ -253,6 +256,7 @@ fn it_earth() {
     assert!(coordinates.z > 0.0018151 && coordinates.z < 0.0018191);
 }
 
+#[cfg(feature = "mars")]
 #[test]
 fn it_mars() {
     let coordinates = vsop87e::mars(2451545.0);
@@ -316,6 +320,7 @@ fn it_mars() {
     assert!(coordinates.z > 0.0493252 && coordinates.z < 0.0493292);
 }
 
+#[cfg(feature = "jupiter")]
 #[test]
 fn it_jupiter() {
     let coordinates = vsop87e::jupiter(2451545.0);
@@ -379,6 +384,7 @@ fn it_jupiter() {
     assert!(coordinates.z > -0.0281051 && coordinates.z < -0.0281011);
 }
 
+#[cfg(feature = "saturn")]
 #[test]
 fn it_saturn() {
     let coordinates = vsop87e::saturn(2451545.0);
@@ -442,6 +448,7 @@ fn it_saturn() {
     assert!(coordinates.z > 0.4164638 && coordinates.z < 0.4164678);
 }
 
+#[cfg(feature = "uranus")]
 #[test]
 fn it_uranus() {
     let coordinates = vsop87e::uranus(2451545.0);
@@ -505,6 +512,7 @@ fn it_uranus() {
     assert!(coordinates.z > -0.0951153 && coordinates.z < -0.0951113);
 }
 
+#[cfg(feature = "neptune")]
 #[test]
 fn it_neptune() {
     let coordinates = vsop87e::neptune(2451545.0);
@@ -567,3 +575,154 @@ fn it_neptune() {
     assert!(coordinates.y > 19.5945850297 && coordinates.y < 19.5945850299);
     assert!(coordinates.z > 0.1205410 && coordinates.z < 0.1205450);
 }
+
+#[test]
+fn it_sun_barycenter_offset() {
+    let mut max_distance = 0_f64;
+
+    // From 1960 to 2020, in steps of 20 days.
+    for i in 0..1096 {
+        let jde = 2436934.5 + f64::from(i) * 20.0;
+        let offset = vsop87e::sun_barycenter_offset(jde);
+        let sun = vsop87e::sun(jde);
+
+        assert_eq!(offset, sun);
+
+        let distance = (offset.x * offset.x + offset.y * offset.y + offset.z * offset.z).sqrt();
+        max_distance = max_distance.max(distance);
+    }
+
+    assert!(max_distance > 0.009 && max_distance < 0.011);
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_heliocentric() {
+    for &jde in [2451545.0, 2415020.0, 2122820.0, 2817151.5].iter() {
+        for &planet in Planet::ALL.iter() {
+            let heliocentric = vsop87e::heliocentric(planet, jde);
+            assert!((heliocentric - vsop87a::planet(planet, jde)).norm() < 5e-7);
+        }
+    }
+}
+
+#[cfg(feature = "mars")]
+#[test]
+fn it_barycentric_conversions() {
+    for &jde in [2451545.0, 2415020.0, 2488069.5].iter() {
+        let sun = vsop87e::sun(jde);
+        let barycentric = vsop87e::mars(jde);
+
+        let heliocentric = barycentric_to_heliocentric(&barycentric, &sun);
+        assert_eq!(heliocentric, vsop87e::heliocentric(Planet::Mars, jde));
+        assert!((heliocentric - vsop87a::mars(jde)).norm() < 5e-7);
+
+        let back = heliocentric_to_barycentric(&heliocentric, &sun);
+        assert!((back - barycentric).norm() < 1e-15);
+        assert!(
+            (heliocentric_to_barycentric(&vsop87a::mars(jde), &sun) - barycentric).norm() < 5e-7
+        );
+    }
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_barycenter() {
+    // Inverse masses of the planets (the Earth with the Moon), in solar masses.
+    let inverse_masses = [
+        6023600.0, 408523.71, 328900.56, 3098708.0, 1047.3486, 3497.898, 22902.98, 19412.24,
+    ];
+
+    for &jde in [2451545.0, 2415020.0, 2122820.0, 2817151.5].iter() {
+        let mut moment = vsop87e::sun(jde);
+        let mut mass = 1.0;
+        for (&planet, &inverse_mass) in Planet::ALL.iter().zip(inverse_masses.iter()) {
+            moment = moment + vsop87e::planet(planet, jde) / inverse_mass;
+            mass += 1.0 / inverse_mass;
+        }
+
+        // The minor bodies are not included, so the center of mass is not exactly at the origin.
+        assert!((moment / mass).norm() < 2e-5);
+    }
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_state() {
+    let step = 0.01;
+    for &planet in Planet::ALL.iter() {
+        for &jde in [2451545.0, 2415020.0, 2122820.0, 2817151.5].iter() {
+            let (position, velocity) = vsop87e::state(planet, jde);
+            let estimate = (vsop87e::planet(planet, jde + step)
+                - vsop87e::planet(planet, jde - step))
+                / (2.0 * step);
+
+            assert_eq!(position, vsop87e::planet(planet, jde));
+            assert!((velocity - estimate).norm() < 1e-6 * velocity.norm());
+        }
+    }
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_planet_truncated() {
+    for &planet in Planet::ALL.iter() {
+        for &jde in [2451545.0, 2415020.0, 2488070.0].iter() {
+            let full = vsop87e::planet(planet, jde);
+            let all_terms = vsop87e::planet_truncated(planet, jde, 0.0);
+            assert!((all_terms - full).norm() < 1e-12);
+
+            for &epsilon in [1e-6, 1e-8].iter() {
+                let truncated = vsop87e::planet_truncated(planet, jde, epsilon);
+                assert!((full - truncated).norm() < 30.0 * epsilon);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "all_planets")]
+#[cfg(not(feature = "no_std"))]
+#[test]
+fn it_planet_many() {
+    let jdes: Vec<f64> = (0..100)
+        .map(|i| 2451545.0 + 36.525 * f64::from(i))
+        .collect();
+
+    for &planet in &Planet::ALL {
+        let batch = vsop87e::planet_many(planet, &jdes);
+        let mut output = vec![vsop87e::earth(0.0); jdes.len()];
+        vsop87e::planet_into(planet, &jdes, &mut output);
+
+        assert_eq!(batch.len(), jdes.len());
+        for ((&jde, coordinates), written) in jdes.iter().zip(&batch).zip(&output) {
+            assert_eq!(*coordinates, vsop87e::planet(planet, jde));
+            assert_eq!(written, coordinates);
+        }
+    }
+
+    assert!(vsop87e::planet_many(Planet::Venus, &[]).is_empty());
+}
+
+#[cfg(feature = "all_planets")]
+#[test]
+fn it_all_planets() {
+    for &jde in [2451545.0, 2415020.0, 2817151.5].iter() {
+        let planets = vsop87e::all_planets(jde);
+
+        for (coordinates, &planet) in planets.iter().zip(Planet::ALL.iter()) {
+            assert_eq!(*coordinates, vsop87e::planet(planet, jde));
+        }
+    }
+}
+
+#[test]
+fn it_sun_state() {
+    let step = 0.01;
+    for &jde in [2451545.0, 2415020.0, 2122820.0, 2817151.5].iter() {
+        let (position, velocity) = vsop87e::sun_state(jde);
+        let estimate = (vsop87e::sun(jde + step) - vsop87e::sun(jde - step)) / (2.0 * step);
+
+        assert!((position - vsop87e::sun(jde)).norm() < 1e-15);
+        assert!((velocity - estimate).norm() < 1e-6 * velocity.norm());
+    }
+}